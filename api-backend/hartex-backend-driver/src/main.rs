@@ -32,8 +32,6 @@
 #![deny(warnings)]
 
 use std::env;
-#[cfg(not(unix))]
-use std::future;
 use std::time::Duration;
 
 use axum::routing::post;
@@ -44,9 +42,9 @@ use bb8_postgres::PostgresConnectionManager;
 use dotenvy::Error;
 use hartex_errors::dotenv;
 use hartex_log::log;
+use hartex_signal_utils::shutdown;
 use miette::IntoDiagnostic;
 use tokio::net::TcpListener;
-use tokio::signal;
 use tower_http::timeout::TimeoutLayer;
 use tower_http::trace::TraceLayer;
 
@@ -99,31 +97,3 @@ pub async fn main() -> miette::Result<()> {
 
     Ok(())
 }
-
-/// Creates a shutdown signal future for the Axum server to wait for in graceful shutdown.
-///
-/// This listens for both CTRL+C and SIGTERM (Unix-specific).
-#[allow(clippy::ignored_unit_patterns)]
-async fn shutdown() {
-    let ctrl_c = async {
-        signal::ctrl_c()
-            .await
-            .expect("failed to install ctrl+c handler");
-    };
-
-    #[cfg(unix)]
-    let terminate = async {
-        signal::unix::signal(signal::unix::SignalKind::terminate())
-            .expect("failed to install signal handler")
-            .recv()
-            .await;
-    };
-
-    #[cfg(not(unix))]
-    let terminate = future::pending::<()>();
-
-    tokio::select! {
-        _ = ctrl_c => {},
-        _ = terminate => {},
-    }
-}
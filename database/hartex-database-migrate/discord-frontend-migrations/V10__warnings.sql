@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."Warnings" (
+    "created_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    "guild_id" TEXT NOT NULL,
+    "id" BIGSERIAL NOT NULL PRIMARY KEY,
+    "moderator_id" TEXT NOT NULL,
+    "reason" TEXT NOT NULL,
+    "user_id" TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS "Warnings_guild_id_user_id" ON "Nightly"."Warnings" ("guild_id", "user_id");
@@ -0,0 +1,7 @@
+ALTER TABLE "Nightly"."GuildConfigurations"
+ADD COLUMN "source" TEXT NOT NULL DEFAULT '',
+ADD COLUMN "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW();
+
+ALTER TABLE "Nightly"."GuildConfigurations"
+ALTER COLUMN "appearance_nickname" DROP NOT NULL,
+ALTER COLUMN "appearance_colour" DROP NOT NULL;
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."GatewaySessions" (
+    "shard_id" INTEGER NOT NULL PRIMARY KEY,
+    "shard_total" INTEGER NOT NULL,
+    "session_id" TEXT NOT NULL,
+    "sequence" BIGINT NOT NULL,
+    "resume_url" TEXT,
+    "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
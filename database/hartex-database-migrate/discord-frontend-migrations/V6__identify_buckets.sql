@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."IdentifyBuckets" (
+    "bucket_id" INTEGER NOT NULL PRIMARY KEY,
+    "last_identify" TIMESTAMP WITH TIME ZONE NOT NULL
+);
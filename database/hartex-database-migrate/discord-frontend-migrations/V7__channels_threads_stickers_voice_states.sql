@@ -0,0 +1,54 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedChannels" (
+    "guild_id" TEXT NOT NULL,
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "kind" SMALLINT NOT NULL,
+    "name" TEXT,
+    "nsfw" BOOLEAN,
+    "parent_id" TEXT,
+    "permission_overwrites" JSONB,
+    "position" INTEGER,
+    "rate_limit_per_user" INTEGER,
+    "topic" TEXT
+);
+
+CREATE INDEX IF NOT EXISTS "CachedChannels_guild_id" ON "Nightly"."CachedChannels" ("guild_id");
+
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedStickers" (
+    "available" BOOLEAN NOT NULL,
+    "description" TEXT,
+    "format_type" SMALLINT NOT NULL,
+    "guild_id" TEXT NOT NULL,
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "name" TEXT NOT NULL,
+    "tags" TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS "CachedStickers_guild_id" ON "Nightly"."CachedStickers" ("guild_id");
+
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedThreads" (
+    "guild_id" TEXT NOT NULL,
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "kind" SMALLINT NOT NULL,
+    "name" TEXT,
+    "owner_id" TEXT,
+    "parent_id" TEXT,
+    "thread_metadata" JSONB
+);
+
+CREATE INDEX IF NOT EXISTS "CachedThreads_guild_id" ON "Nightly"."CachedThreads" ("guild_id");
+
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedVoiceStates" (
+    "channel_id" TEXT,
+    "deaf" BOOLEAN NOT NULL,
+    "guild_id" TEXT NOT NULL,
+    "mute" BOOLEAN NOT NULL,
+    "request_to_speak_timestamp" TIMESTAMP WITH TIME ZONE,
+    "self_deaf" BOOLEAN NOT NULL,
+    "self_mute" BOOLEAN NOT NULL,
+    "self_stream" BOOLEAN NOT NULL,
+    "self_video" BOOLEAN NOT NULL,
+    "session_id" TEXT NOT NULL,
+    "suppress" BOOLEAN NOT NULL,
+    "user_id" TEXT NOT NULL,
+    PRIMARY KEY("guild_id", "user_id")
+);
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedMessages" (
+    "attachments" JSONB NOT NULL,
+    "author_id" TEXT NOT NULL,
+    "channel_id" TEXT NOT NULL,
+    "content" TEXT NOT NULL,
+    "edited_timestamp" TIMESTAMP WITH TIME ZONE,
+    "guild_id" TEXT NOT NULL,
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "timestamp" TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS "CachedMessages_guild_id_timestamp" ON "Nightly"."CachedMessages" ("guild_id", "timestamp");
+
+CREATE INDEX IF NOT EXISTS "CachedMessages_timestamp" ON "Nightly"."CachedMessages" ("timestamp");
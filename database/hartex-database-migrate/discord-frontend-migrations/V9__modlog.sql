@@ -0,0 +1,5 @@
+ALTER TABLE "Nightly"."CachedGuilds"
+ADD COLUMN "preferred_locale" TEXT NOT NULL DEFAULT 'en-US';
+
+ALTER TABLE "Nightly"."GuildConfigurations"
+ADD COLUMN "plugins_modlog_loggers" JSONB NOT NULL DEFAULT '[]'::JSONB;
@@ -3,7 +3,117 @@
 #[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod types { }#[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod queries
-{ pub mod plugin_enabled
+{ pub mod guild_configuration_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct GuildConfigurationUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::ArraySql<Item = T7>,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = T10>,T12: cornucopia_async::JsonSql,> { pub guild_id: T1,pub source: T2,pub dashboard_admins: T4,pub dashboard_editors: T6,pub dashboard_viewers: T8,pub appearance_nickname: Option<T9>,pub appearance_colour: Option<i64>,pub enabled_plugins: T11,pub plugins_modlog_loggers: T12,}pub fn guild_configuration_upsert() -> GuildConfigurationUpsertStmt
+{ GuildConfigurationUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO
+    \"Nightly\".\"GuildConfigurations\" (\"guild_id\", \"source\", \"dashboard_admins\", \"dashboard_editors\", \"dashboard_viewers\", \"appearance_nickname\", \"appearance_colour\", \"enabled_plugins\", \"plugins_modlog_loggers\", \"updated_at\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW())
+ON CONFLICT (\"guild_id\") DO UPDATE
+    SET
+        \"appearance_colour\" = $7,
+        \"appearance_nickname\" = $6,
+        \"dashboard_admins\" = $3,
+        \"dashboard_editors\" = $4,
+        \"dashboard_viewers\" = $5,
+        \"enabled_plugins\" = $8,
+        \"plugins_modlog_loggers\" = $9,
+        \"source\" = $2,
+        \"updated_at\" = NOW()")) } pub struct
+GuildConfigurationUpsertStmt(cornucopia_async::private::Stmt); impl GuildConfigurationUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::ArraySql<Item = T5>,T7:
+cornucopia_async::StringSql,T8:
+cornucopia_async::ArraySql<Item = T7>,T9:
+cornucopia_async::StringSql,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::ArraySql<Item = T10>,T12:
+cornucopia_async::JsonSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,source: &'a T2,dashboard_admins: &'a T4,dashboard_editors: &'a T6,dashboard_viewers: &'a T8,appearance_nickname: &'a Option<T9>,appearance_colour: &'a Option<i64>,enabled_plugins: &'a T11,plugins_modlog_loggers: &'a T12,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,source,dashboard_admins,dashboard_editors,dashboard_viewers,appearance_nickname,appearance_colour,enabled_plugins,plugins_modlog_loggers,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::ArraySql<Item = T7>,T9: cornucopia_async::StringSql,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = T10>,T12: cornucopia_async::JsonSql,>
+cornucopia_async::Params<'a, GuildConfigurationUpsertParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for GuildConfigurationUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GuildConfigurationUpsertParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.source,&params.dashboard_admins,&params.dashboard_editors,&params.dashboard_viewers,&params.appearance_nickname,&params.appearance_colour,&params.enabled_plugins,&params.plugins_modlog_loggers,)) }
+}}pub mod modlog_loggers
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct ModlogLoggers
+{ pub enabled_plugins : Vec<String>,pub plugins_modlog_loggers : serde_json::Value,}pub struct ModlogLoggersBorrowed<'a> { pub enabled_plugins : cornucopia_async::ArrayIterator<'a, &'a str>,pub plugins_modlog_loggers : postgres_types::Json<&'a serde_json::value::RawValue>,}
+impl<'a> From<ModlogLoggersBorrowed<'a>> for ModlogLoggers
+{
+    fn from(ModlogLoggersBorrowed { enabled_plugins,plugins_modlog_loggers,}: ModlogLoggersBorrowed<'a>) ->
+    Self { Self { enabled_plugins: enabled_plugins.map(|v| v.into()).collect(),plugins_modlog_loggers: serde_json::from_str(plugins_modlog_loggers.0.get()).unwrap(),} }
+}pub struct ModlogLoggersQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> ModlogLoggersBorrowed,
+    mapper: fn(ModlogLoggersBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> ModlogLoggersQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(ModlogLoggersBorrowed) -> R) ->
+    ModlogLoggersQuery<'a,C,R,N>
+    {
+        ModlogLoggersQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn modlog_loggers() -> ModlogLoggersStmt
+{ ModlogLoggersStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"enabled_plugins\",
+    \"plugins_modlog_loggers\"
+FROM
+    \"Nightly\".\"GuildConfigurations\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+ModlogLoggersStmt(cornucopia_async::private::Stmt); impl ModlogLoggersStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> ModlogLoggersQuery<'a,C,
+ModlogLoggers, 1>
+{
+    ModlogLoggersQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { ModlogLoggersBorrowed { enabled_plugins: row.get(0),plugins_modlog_loggers: row.get(1),} }, mapper: |it| { <ModlogLoggers>::from(it) },
+    }
+} }}pub mod plugin_enabled
 { use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct PluginEnabledParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub plugin: T1,pub guild_id: T2,}pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
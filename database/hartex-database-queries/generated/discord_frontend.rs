@@ -3,26 +3,74 @@
 #[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod types { }#[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod queries
-{ pub mod cached_emoji_select_by_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectByGuildId
-{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByGuildIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
-impl<'a> From<CachedEmojiSelectByGuildIdBorrowed<'a>> for CachedEmojiSelectByGuildId
+{ pub mod cached_channel_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_channel_delete_by_guild_id() -> CachedChannelDeleteByGuildIdStmt
+{ CachedChannelDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedChannelDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedChannelDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
 {
-    fn from(CachedEmojiSelectByGuildIdBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByGuildIdBorrowed<'a>) ->
-    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
-}pub struct CachedEmojiSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod cached_channel_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_channel_delete_by_id() -> CachedChannelDeleteByIdStmt
+{ CachedChannelDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedChannelDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedChannelDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_channel_delete_stale_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedChannelDeleteStaleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub fn cached_channel_delete_stale_by_guild_id() -> CachedChannelDeleteStaleByGuildIdStmt
+{ CachedChannelDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
+WHERE
+    \"guild_id\" = $1
+    AND NOT (\"id\" = ANY($2))")) } pub struct
+CachedChannelDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedChannelDeleteStaleByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,ids,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,>
+cornucopia_async::Params<'a, CachedChannelDeleteStaleByGuildIdParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedChannelDeleteStaleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedChannelDeleteStaleByGuildIdParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.ids,)) }
+}}pub mod cached_channel_exists_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByGuildIdBorrowed,
-    mapper: fn(CachedEmojiSelectByGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByGuildIdBorrowed) -> R) ->
-    CachedEmojiSelectByGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
     {
-        CachedEmojiSelectByGuildIdQuery
+        BoolQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -49,45 +97,112 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_emoji_select_by_guild_id() -> CachedEmojiSelectByGuildIdStmt
-{ CachedEmojiSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn cached_channel_exists_by_id() -> CachedChannelExistsByIdStmt
+{ CachedChannelExistsByIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
+    WHERE
+        \"id\" = $1
+)")) } pub struct
+CachedChannelExistsByIdStmt(cornucopia_async::private::Stmt); impl CachedChannelExistsByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> BoolQuery<'a,C,
+bool, 1>
+{
+    BoolQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod cached_channel_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedChannelSelectByGuildId
+{ pub guild_id : String,pub id : String,pub kind : i16,pub name : Option<String>,pub nsfw : Option<bool>,pub parent_id : Option<String>,pub permission_overwrites : Option<serde_json::Value>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<String>,}pub struct CachedChannelSelectByGuildIdBorrowed<'a> { pub guild_id : &'a str,pub id : &'a str,pub kind : i16,pub name : Option<&'a str>,pub nsfw : Option<bool>,pub parent_id : Option<&'a str>,pub permission_overwrites : Option<postgres_types::Json<&'a serde_json::value::RawValue>>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<&'a str>,}
+impl<'a> From<CachedChannelSelectByGuildIdBorrowed<'a>> for CachedChannelSelectByGuildId
+{
+    fn from(CachedChannelSelectByGuildIdBorrowed { guild_id,id,kind,name,nsfw,parent_id,permission_overwrites,position,rate_limit_per_user,topic,}: CachedChannelSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { guild_id: guild_id.into(),id: id.into(),kind,name: name.map(|v| v.into()),nsfw,parent_id: parent_id.map(|v| v.into()),permission_overwrites: permission_overwrites.map(|v| serde_json::from_str(v.0.get()).unwrap()),position,rate_limit_per_user,topic: topic.map(|v| v.into()),} }
+}pub struct CachedChannelSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedChannelSelectByGuildIdBorrowed,
+    mapper: fn(CachedChannelSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedChannelSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedChannelSelectByGuildIdBorrowed) -> R) ->
+    CachedChannelSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedChannelSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_channel_select_by_guild_id() -> CachedChannelSelectByGuildIdStmt
+{ CachedChannelSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
 WHERE
     \"guild_id\" = $1")) } pub struct
-CachedEmojiSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByGuildIdStmt
+CachedChannelSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedChannelSelectByGuildIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-guild_id: &'a T1,) -> CachedEmojiSelectByGuildIdQuery<'a,C,
-CachedEmojiSelectByGuildId, 1>
+guild_id: &'a T1,) -> CachedChannelSelectByGuildIdQuery<'a,C,
+CachedChannelSelectByGuildId, 1>
 {
-    CachedEmojiSelectByGuildIdQuery
+    CachedChannelSelectByGuildIdQuery
     {
         client, params: [guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedEmojiSelectByGuildIdBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectByGuildId>::from(it) },
+        |row| { CachedChannelSelectByGuildIdBorrowed { guild_id: row.get(0),id: row.get(1),kind: row.get(2),name: row.get(3),nsfw: row.get(4),parent_id: row.get(5),permission_overwrites: row.get(6),position: row.get(7),rate_limit_per_user: row.get(8),topic: row.get(9),} }, mapper: |it| { <CachedChannelSelectByGuildId>::from(it) },
     }
-} }}pub mod cached_emoji_select_by_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectById
-{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
-impl<'a> From<CachedEmojiSelectByIdBorrowed<'a>> for CachedEmojiSelectById
+} }}pub mod cached_channel_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedChannelSelectById
+{ pub guild_id : String,pub id : String,pub kind : i16,pub name : Option<String>,pub nsfw : Option<bool>,pub parent_id : Option<String>,pub permission_overwrites : Option<serde_json::Value>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<String>,}pub struct CachedChannelSelectByIdBorrowed<'a> { pub guild_id : &'a str,pub id : &'a str,pub kind : i16,pub name : Option<&'a str>,pub nsfw : Option<bool>,pub parent_id : Option<&'a str>,pub permission_overwrites : Option<postgres_types::Json<&'a serde_json::value::RawValue>>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<&'a str>,}
+impl<'a> From<CachedChannelSelectByIdBorrowed<'a>> for CachedChannelSelectById
 {
-    fn from(CachedEmojiSelectByIdBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByIdBorrowed<'a>) ->
-    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
-}pub struct CachedEmojiSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+    fn from(CachedChannelSelectByIdBorrowed { guild_id,id,kind,name,nsfw,parent_id,permission_overwrites,position,rate_limit_per_user,topic,}: CachedChannelSelectByIdBorrowed<'a>) ->
+    Self { Self { guild_id: guild_id.into(),id: id.into(),kind,name: name.map(|v| v.into()),nsfw,parent_id: parent_id.map(|v| v.into()),permission_overwrites: permission_overwrites.map(|v| serde_json::from_str(v.0.get()).unwrap()),position,rate_limit_per_user,topic: topic.map(|v| v.into()),} }
+}pub struct CachedChannelSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByIdBorrowed,
-    mapper: fn(CachedEmojiSelectByIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedChannelSelectByIdBorrowed,
+    mapper: fn(CachedChannelSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedChannelSelectByIdQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByIdBorrowed) -> R) ->
-    CachedEmojiSelectByIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(CachedChannelSelectByIdBorrowed) -> R) ->
+    CachedChannelSelectByIdQuery<'a,C,R,N>
     {
-        CachedEmojiSelectByIdQuery
+        CachedChannelSelectByIdQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -114,74 +229,45 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_emoji_select_by_id() -> CachedEmojiSelectByIdStmt
-{ CachedEmojiSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn cached_channel_select_by_id() -> CachedChannelSelectByIdStmt
+{ CachedChannelSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
 WHERE
     \"id\" = $1")) } pub struct
-CachedEmojiSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByIdStmt
+CachedChannelSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedChannelSelectByIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,) -> CachedEmojiSelectByIdQuery<'a,C,
-CachedEmojiSelectById, 1>
+id: &'a T1,) -> CachedChannelSelectByIdQuery<'a,C,
+CachedChannelSelectById, 1>
 {
-    CachedEmojiSelectByIdQuery
+    CachedChannelSelectByIdQuery
     {
         client, params: [id,], stmt: &mut self.0, extractor:
-        |row| { CachedEmojiSelectByIdBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectById>::from(it) },
+        |row| { CachedChannelSelectByIdBorrowed { guild_id: row.get(0),id: row.get(1),kind: row.get(2),name: row.get(3),nsfw: row.get(4),parent_id: row.get(5),permission_overwrites: row.get(6),position: row.get(7),rate_limit_per_user: row.get(8),topic: row.get(9),} }, mapper: |it| { <CachedChannelSelectById>::from(it) },
     }
-} }}pub mod cached_emoji_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedEmojiUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub animated: bool,pub name: T1,pub id: T2,pub guild_id: T3,pub managed: bool,}pub fn cached_emoji_upsert() -> CachedEmojiUpsertStmt
-{ CachedEmojiUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\" (\"animated\", \"name\", \"id\", \"guild_id\", \"managed\")
-VALUES ($1, $2, $3, $4, $5)
-ON CONFLICT (\"id\") DO UPDATE
-    SET
-        \"guild_id\" = $4,
-        \"animated\" = $1,
-        \"name\" = $2,
-        \"managed\" = $5")) } pub struct
-CachedEmojiUpsertStmt(cornucopia_async::private::Stmt); impl CachedEmojiUpsertStmt
-{ pub async fn bind<'a, C:
-GenericClient,T1:
-cornucopia_async::StringSql,T2:
-cornucopia_async::StringSql,T3:
-cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-animated: &'a bool,name: &'a T1,id: &'a T2,guild_id: &'a T3,managed: &'a bool,) -> Result<u64, tokio_postgres::Error>
-{
-    let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[animated,name,id,guild_id,managed,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, CachedEmojiUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedEmojiUpsertStmt
+} }}pub mod cached_channel_select_by_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedChannelSelectByIds
+{ pub guild_id : String,pub id : String,pub kind : i16,pub name : Option<String>,pub nsfw : Option<bool>,pub parent_id : Option<String>,pub permission_overwrites : Option<serde_json::Value>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<String>,}pub struct CachedChannelSelectByIdsBorrowed<'a> { pub guild_id : &'a str,pub id : &'a str,pub kind : i16,pub name : Option<&'a str>,pub nsfw : Option<bool>,pub parent_id : Option<&'a str>,pub permission_overwrites : Option<postgres_types::Json<&'a serde_json::value::RawValue>>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<&'a str>,}
+impl<'a> From<CachedChannelSelectByIdsBorrowed<'a>> for CachedChannelSelectByIds
 {
-    fn
-    params(&'a mut self, client: &'a  C, params: &'a
-    CachedEmojiUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-    tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.animated,&params.name,&params.id,&params.guild_id,&params.managed,)) }
-}}pub mod cached_guild_select_by_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedGuildSelectById
-{ pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : Vec<String>,pub icon : Option<String>,pub id : String,pub large : bool,pub mfa_level : i16,pub name : String,pub owner_id : String,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,}pub struct CachedGuildSelectByIdBorrowed<'a> { pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : cornucopia_async::ArrayIterator<'a, &'a str>,pub icon : Option<&'a str>,pub id : &'a str,pub large : bool,pub mfa_level : i16,pub name : &'a str,pub owner_id : &'a str,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,}
-impl<'a> From<CachedGuildSelectByIdBorrowed<'a>> for CachedGuildSelectById
-{
-    fn from(CachedGuildSelectByIdBorrowed { default_message_notifications,explicit_content_filter,features,icon,id,large,mfa_level,name,owner_id,premium_subscription_count,premium_tier,verification_level,}: CachedGuildSelectByIdBorrowed<'a>) ->
-    Self { Self { default_message_notifications,explicit_content_filter,features: features.map(|v| v.into()).collect(),icon: icon.map(|v| v.into()),id: id.into(),large,mfa_level,name: name.into(),owner_id: owner_id.into(),premium_subscription_count,premium_tier,verification_level,} }
-}pub struct CachedGuildSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+    fn from(CachedChannelSelectByIdsBorrowed { guild_id,id,kind,name,nsfw,parent_id,permission_overwrites,position,rate_limit_per_user,topic,}: CachedChannelSelectByIdsBorrowed<'a>) ->
+    Self { Self { guild_id: guild_id.into(),id: id.into(),kind,name: name.map(|v| v.into()),nsfw,parent_id: parent_id.map(|v| v.into()),permission_overwrites: permission_overwrites.map(|v| serde_json::from_str(v.0.get()).unwrap()),position,rate_limit_per_user,topic: topic.map(|v| v.into()),} }
+}pub struct CachedChannelSelectByIdsQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedGuildSelectByIdBorrowed,
-    mapper: fn(CachedGuildSelectByIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedGuildSelectByIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedChannelSelectByIdsBorrowed,
+    mapper: fn(CachedChannelSelectByIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedChannelSelectByIdsQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedGuildSelectByIdBorrowed) -> R) ->
-    CachedGuildSelectByIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(CachedChannelSelectByIdsBorrowed) -> R) ->
+    CachedChannelSelectByIdsQuery<'a,C,R,N>
     {
-        CachedGuildSelectByIdQuery
+        CachedChannelSelectByIdsQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -208,85 +294,3900 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_guild_select_by_id() -> CachedGuildSelectByIdStmt
-{ CachedGuildSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn cached_channel_select_by_ids() -> CachedChannelSelectByIdsStmt
+{ CachedChannelSelectByIdsStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
 WHERE
-    \"id\" = $1")) } pub struct
-CachedGuildSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedGuildSelectByIdStmt
+    \"id\" = ANY($1)")) } pub struct
+CachedChannelSelectByIdsStmt(cornucopia_async::private::Stmt); impl CachedChannelSelectByIdsStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
-cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,) -> CachedGuildSelectByIdQuery<'a,C,
-CachedGuildSelectById, 1>
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> CachedChannelSelectByIdsQuery<'a,C,
+CachedChannelSelectByIds, 1>
 {
-    CachedGuildSelectByIdQuery
+    CachedChannelSelectByIdsQuery
     {
-        client, params: [id,], stmt: &mut self.0, extractor:
-        |row| { CachedGuildSelectByIdBorrowed { default_message_notifications: row.get(0),explicit_content_filter: row.get(1),features: row.get(2),icon: row.get(3),id: row.get(4),large: row.get(5),mfa_level: row.get(6),name: row.get(7),owner_id: row.get(8),premium_subscription_count: row.get(9),premium_tier: row.get(10),verification_level: row.get(11),} }, mapper: |it| { <CachedGuildSelectById>::from(it) },
+        client, params: [ids,], stmt: &mut self.0, extractor:
+        |row| { CachedChannelSelectByIdsBorrowed { guild_id: row.get(0),id: row.get(1),kind: row.get(2),name: row.get(3),nsfw: row.get(4),parent_id: row.get(5),permission_overwrites: row.get(6),position: row.get(7),rate_limit_per_user: row.get(8),topic: row.get(9),} }, mapper: |it| { <CachedChannelSelectByIds>::from(it) },
     }
-} }}pub mod cached_guild_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedGuildUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,> { pub default_message_notifications: i16,pub explicit_content_filter: i16,pub features: T2,pub icon: Option<T3>,pub large: bool,pub name: T4,pub owner_id: T5,pub id: T6,pub mfa_level: i16,pub premium_subscription_count: Option<i64>,pub premium_tier: i16,pub verification_level: i16,}pub fn cached_guild_upsert() -> CachedGuildUpsertStmt
-{ CachedGuildUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO
-    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\" (\"default_message_notifications\", \"explicit_content_filter\", \"features\", \"icon\", \"large\", \"name\", \"owner_id\", \"id\", \"mfa_level\", \"premium_subscription_count\", \"premium_tier\", \"verification_level\")
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+} }}pub mod cached_channel_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedChannelUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::JsonSql,T6: cornucopia_async::StringSql,> { pub guild_id: T1,pub id: T2,pub kind: i16,pub name: Option<T3>,pub nsfw: Option<bool>,pub parent_id: Option<T4>,pub permission_overwrites: Option<T5>,pub position: Option<i32>,pub rate_limit_per_user: Option<i32>,pub topic: Option<T6>,}pub fn cached_channel_upsert() -> CachedChannelUpsertStmt
+{ CachedChannelUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedChannels\" (\"guild_id\", \"id\", \"kind\", \"name\", \"nsfw\", \"parent_id\", \"permission_overwrites\", \"position\", \"rate_limit_per_user\", \"topic\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
 ON CONFLICT (\"id\") DO UPDATE
     SET
-        \"default_message_notifications\" = $1,
-        \"explicit_content_filter\" = $2,
-        \"features\" = $3,
-        \"icon\" = $4,
-        \"large\" = $5,
-        \"mfa_level\" = $9,
-        \"name\" = $6,
-        \"owner_id\" = $7,
-        \"premium_subscription_count\" = $10,
-        \"premium_tier\" = $11,
-        \"verification_level\" = $12")) } pub struct
-CachedGuildUpsertStmt(cornucopia_async::private::Stmt); impl CachedGuildUpsertStmt
+        \"guild_id\" = $1,
+        \"kind\" = $3,
+        \"name\" = $4,
+        \"nsfw\" = $5,
+        \"parent_id\" = $6,
+        \"permission_overwrites\" = $7,
+        \"position\" = $8,
+        \"rate_limit_per_user\" = $9,
+        \"topic\" = $10")) } pub struct
+CachedChannelUpsertStmt(cornucopia_async::private::Stmt); impl CachedChannelUpsertStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
-cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,T4:
 cornucopia_async::StringSql,T5:
-cornucopia_async::StringSql,T6:
+cornucopia_async::JsonSql,T6:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-default_message_notifications: &'a i16,explicit_content_filter: &'a i16,features: &'a T2,icon: &'a Option<T3>,large: &'a bool,name: &'a T4,owner_id: &'a T5,id: &'a T6,mfa_level: &'a i16,premium_subscription_count: &'a Option<i64>,premium_tier: &'a i16,verification_level: &'a i16,) -> Result<u64, tokio_postgres::Error>
+guild_id: &'a T1,id: &'a T2,kind: &'a i16,name: &'a Option<T3>,nsfw: &'a Option<bool>,parent_id: &'a Option<T4>,permission_overwrites: &'a Option<T5>,position: &'a Option<i32>,rate_limit_per_user: &'a Option<i32>,topic: &'a Option<T6>,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[default_message_notifications,explicit_content_filter,features,icon,large,name,owner_id,id,mfa_level,premium_subscription_count,premium_tier,verification_level,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, CachedGuildUpsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedGuildUpsertStmt
+    client.execute(stmt, &[guild_id,id,kind,name,nsfw,parent_id,permission_overwrites,position,rate_limit_per_user,topic,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::JsonSql,T6: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedChannelUpsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedChannelUpsertStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedGuildUpsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    CachedChannelUpsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.default_message_notifications,&params.explicit_content_filter,&params.features,&params.icon,&params.large,&params.name,&params.owner_id,&params.id,&params.mfa_level,&params.premium_subscription_count,&params.premium_tier,&params.verification_level,)) }
-}}pub mod cached_member_select_by_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByGuildId
-{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,}pub struct CachedMemberSelectByGuildIdBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,}
-impl<'a> From<CachedMemberSelectByGuildIdBorrowed<'a>> for CachedMemberSelectByGuildId
+    { Box::pin(self.bind(client, &params.guild_id,&params.id,&params.kind,&params.name,&params.nsfw,&params.parent_id,&params.permission_overwrites,&params.position,&params.rate_limit_per_user,&params.topic,)) }
+}}pub mod cached_channel_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedChannelUpsertManyParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::ArraySql<Item = i16>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = Option<T6>>,T8: cornucopia_async::ArraySql<Item = Option<bool>>,T9: cornucopia_async::StringSql,T10: cornucopia_async::ArraySql<Item = Option<T9>>,T11: cornucopia_async::JsonSql,T12: cornucopia_async::ArraySql<Item = Option<T11>>,T13: cornucopia_async::ArraySql<Item = Option<i32>>,T14: cornucopia_async::ArraySql<Item = Option<i32>>,T15: cornucopia_async::StringSql,T16: cornucopia_async::ArraySql<Item = Option<T15>>,> { pub guild_id: T2,pub id: T4,pub kind: T5,pub name: T7,pub nsfw: T8,pub parent_id: T10,pub permission_overwrites: T12,pub position: T13,pub rate_limit_per_user: T14,pub topic: T16,}pub fn cached_channel_upsert_many() -> CachedChannelUpsertManyStmt
+{ CachedChannelUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedChannels\" (\"guild_id\", \"id\", \"kind\", \"name\", \"nsfw\", \"parent_id\", \"permission_overwrites\", \"position\", \"rate_limit_per_user\", \"topic\")
+SELECT DISTINCT ON (\"id\")
+    \"guild_id\",
+    \"id\",
+    \"kind\",
+    \"name\",
+    \"nsfw\",
+    \"parent_id\",
+    \"permission_overwrites\",
+    \"position\",
+    \"rate_limit_per_user\",
+    \"topic\"
+FROM
+    unnest(
+        CAST($1 AS TEXT[]),
+        CAST($2 AS TEXT[]),
+        CAST($3 AS SMALLINT[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS BOOLEAN[]),
+        CAST($6 AS TEXT[]),
+        CAST($7 AS JSONB[]),
+        CAST($8 AS INTEGER[]),
+        CAST($9 AS INTEGER[]),
+        CAST($10 AS TEXT[])
+    ) WITH ORDINALITY AS \"entities\" (\"guild_id\", \"id\", \"kind\", \"name\", \"nsfw\", \"parent_id\", \"permission_overwrites\", \"position\", \"rate_limit_per_user\", \"topic\", \"ordinality\")
+ORDER BY
+    \"id\", \"ordinality\" DESC
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"guild_id\" = EXCLUDED.\"guild_id\",
+        \"kind\" = EXCLUDED.\"kind\",
+        \"name\" = EXCLUDED.\"name\",
+        \"nsfw\" = EXCLUDED.\"nsfw\",
+        \"parent_id\" = EXCLUDED.\"parent_id\",
+        \"permission_overwrites\" = EXCLUDED.\"permission_overwrites\",
+        \"position\" = EXCLUDED.\"position\",
+        \"rate_limit_per_user\" = EXCLUDED.\"rate_limit_per_user\",
+        \"topic\" = EXCLUDED.\"topic\"")) } pub struct
+CachedChannelUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedChannelUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,T5:
+cornucopia_async::ArraySql<Item = i16>,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = Option<T6>>,T8:
+cornucopia_async::ArraySql<Item = Option<bool>>,T9:
+cornucopia_async::StringSql,T10:
+cornucopia_async::ArraySql<Item = Option<T9>>,T11:
+cornucopia_async::JsonSql,T12:
+cornucopia_async::ArraySql<Item = Option<T11>>,T13:
+cornucopia_async::ArraySql<Item = Option<i32>>,T14:
+cornucopia_async::ArraySql<Item = Option<i32>>,T15:
+cornucopia_async::StringSql,T16:
+cornucopia_async::ArraySql<Item = Option<T15>>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T2,id: &'a T4,kind: &'a T5,name: &'a T7,nsfw: &'a T8,parent_id: &'a T10,permission_overwrites: &'a T12,position: &'a T13,rate_limit_per_user: &'a T14,topic: &'a T16,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,id,kind,name,nsfw,parent_id,permission_overwrites,position,rate_limit_per_user,topic,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::ArraySql<Item = i16>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = Option<T6>>,T8: cornucopia_async::ArraySql<Item = Option<bool>>,T9: cornucopia_async::StringSql,T10: cornucopia_async::ArraySql<Item = Option<T9>>,T11: cornucopia_async::JsonSql,T12: cornucopia_async::ArraySql<Item = Option<T11>>,T13: cornucopia_async::ArraySql<Item = Option<i32>>,T14: cornucopia_async::ArraySql<Item = Option<i32>>,T15: cornucopia_async::StringSql,T16: cornucopia_async::ArraySql<Item = Option<T15>>,>
+cornucopia_async::Params<'a, CachedChannelUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedChannelUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedChannelUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.id,&params.kind,&params.name,&params.nsfw,&params.parent_id,&params.permission_overwrites,&params.position,&params.rate_limit_per_user,&params.topic,)) }
+}}pub mod cached_emoji_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_emoji_delete_by_guild_id() -> CachedEmojiDeleteByGuildIdStmt
+{ CachedEmojiDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedEmojiDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod cached_emoji_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_emoji_delete_by_id() -> CachedEmojiDeleteByIdStmt
+{ CachedEmojiDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedEmojiDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_emoji_delete_stale_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedEmojiDeleteStaleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub fn cached_emoji_delete_stale_by_guild_id() -> CachedEmojiDeleteStaleByGuildIdStmt
+{ CachedEmojiDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"guild_id\" = $1
+    AND NOT (\"id\" = ANY($2))")) } pub struct
+CachedEmojiDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiDeleteStaleByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,ids,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,>
+cornucopia_async::Params<'a, CachedEmojiDeleteStaleByGuildIdParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedEmojiDeleteStaleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedEmojiDeleteStaleByGuildIdParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.ids,)) }
+}}pub mod cached_emoji_exists_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_emoji_exists_by_id() -> CachedEmojiExistsByIdStmt
+{ CachedEmojiExistsByIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+    WHERE
+        \"id\" = $1
+)")) } pub struct
+CachedEmojiExistsByIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiExistsByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> BoolQuery<'a,C,
+bool, 1>
+{
+    BoolQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod cached_emoji_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectByGuildId
+{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByGuildIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
+impl<'a> From<CachedEmojiSelectByGuildIdBorrowed<'a>> for CachedEmojiSelectByGuildId
+{
+    fn from(CachedEmojiSelectByGuildIdBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
+}pub struct CachedEmojiSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByGuildIdBorrowed,
+    mapper: fn(CachedEmojiSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByGuildIdBorrowed) -> R) ->
+    CachedEmojiSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedEmojiSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_emoji_select_by_guild_id() -> CachedEmojiSelectByGuildIdStmt
+{ CachedEmojiSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedEmojiSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedEmojiSelectByGuildIdQuery<'a,C,
+CachedEmojiSelectByGuildId, 1>
+{
+    CachedEmojiSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedEmojiSelectByGuildIdBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_emoji_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectById
+{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
+impl<'a> From<CachedEmojiSelectByIdBorrowed<'a>> for CachedEmojiSelectById
+{
+    fn from(CachedEmojiSelectByIdBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
+}pub struct CachedEmojiSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByIdBorrowed,
+    mapper: fn(CachedEmojiSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByIdBorrowed) -> R) ->
+    CachedEmojiSelectByIdQuery<'a,C,R,N>
+    {
+        CachedEmojiSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_emoji_select_by_id() -> CachedEmojiSelectByIdStmt
+{ CachedEmojiSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedEmojiSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedEmojiSelectByIdQuery<'a,C,
+CachedEmojiSelectById, 1>
+{
+    CachedEmojiSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedEmojiSelectByIdBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectById>::from(it) },
+    }
+} }}pub mod cached_emoji_select_by_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectByIds
+{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByIdsBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
+impl<'a> From<CachedEmojiSelectByIdsBorrowed<'a>> for CachedEmojiSelectByIds
+{
+    fn from(CachedEmojiSelectByIdsBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByIdsBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
+}pub struct CachedEmojiSelectByIdsQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByIdsBorrowed,
+    mapper: fn(CachedEmojiSelectByIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByIdsQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByIdsBorrowed) -> R) ->
+    CachedEmojiSelectByIdsQuery<'a,C,R,N>
+    {
+        CachedEmojiSelectByIdsQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_emoji_select_by_ids() -> CachedEmojiSelectByIdsStmt
+{ CachedEmojiSelectByIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"id\" = ANY($1)")) } pub struct
+CachedEmojiSelectByIdsStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> CachedEmojiSelectByIdsQuery<'a,C,
+CachedEmojiSelectByIds, 1>
+{
+    CachedEmojiSelectByIdsQuery
+    {
+        client, params: [ids,], stmt: &mut self.0, extractor:
+        |row| { CachedEmojiSelectByIdsBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectByIds>::from(it) },
+    }
+} }}pub mod cached_emoji_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedEmojiUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub animated: bool,pub name: T1,pub id: T2,pub guild_id: T3,pub managed: bool,}pub fn cached_emoji_upsert() -> CachedEmojiUpsertStmt
+{ CachedEmojiUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\" (\"animated\", \"name\", \"id\", \"guild_id\", \"managed\")
+VALUES ($1, $2, $3, $4, $5)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"guild_id\" = $4,
+        \"animated\" = $1,
+        \"name\" = $2,
+        \"managed\" = $5")) } pub struct
+CachedEmojiUpsertStmt(cornucopia_async::private::Stmt); impl CachedEmojiUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+animated: &'a bool,name: &'a T1,id: &'a T2,guild_id: &'a T3,managed: &'a bool,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[animated,name,id,guild_id,managed,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedEmojiUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedEmojiUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedEmojiUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.animated,&params.name,&params.id,&params.guild_id,&params.managed,)) }
+}}pub mod cached_emoji_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedEmojiUpsertManyParams<T1: cornucopia_async::ArraySql<Item = bool>,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::ArraySql<Item = bool>,> { pub animated: T1,pub name: T3,pub id: T5,pub guild_id: T7,pub managed: T8,}pub fn cached_emoji_upsert_many() -> CachedEmojiUpsertManyStmt
+{ CachedEmojiUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\" (\"animated\", \"name\", \"id\", \"guild_id\", \"managed\")
+SELECT DISTINCT ON (\"id\")
+    \"animated\",
+    \"name\",
+    \"id\",
+    \"guild_id\",
+    \"managed\"
+FROM
+    unnest(
+        CAST($1 AS BOOLEAN[]),
+        CAST($2 AS TEXT[]),
+        CAST($3 AS TEXT[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS BOOLEAN[])
+    ) WITH ORDINALITY AS \"entities\" (\"animated\", \"name\", \"id\", \"guild_id\", \"managed\", \"ordinality\")
+ORDER BY
+    \"id\", \"ordinality\" DESC
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"animated\" = EXCLUDED.\"animated\",
+        \"name\" = EXCLUDED.\"name\",
+        \"guild_id\" = EXCLUDED.\"guild_id\",
+        \"managed\" = EXCLUDED.\"managed\"")) } pub struct
+CachedEmojiUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedEmojiUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::ArraySql<Item = bool>,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::ArraySql<Item = T4>,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = T6>,T8:
+cornucopia_async::ArraySql<Item = bool>,>(&'a mut self, client: &'a  C,
+animated: &'a T1,name: &'a T3,id: &'a T5,guild_id: &'a T7,managed: &'a T8,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[animated,name,id,guild_id,managed,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::ArraySql<Item = bool>,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::ArraySql<Item = bool>,>
+cornucopia_async::Params<'a, CachedEmojiUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedEmojiUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedEmojiUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.animated,&params.name,&params.id,&params.guild_id,&params.managed,)) }
+}}pub mod cached_guild_count
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct I64Query<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> i64,
+    mapper: fn(i64) -> T,
+} impl<'a, C, T:'a, const N: usize> I64Query<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(i64) -> R) ->
+    I64Query<'a,C,R,N>
+    {
+        I64Query
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_guild_count() -> CachedGuildCountStmt
+{ CachedGuildCountStmt(cornucopia_async::private::Stmt::new("SELECT
+    count(*)
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"")) } pub struct
+CachedGuildCountStmt(cornucopia_async::private::Stmt); impl CachedGuildCountStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> I64Query<'a,C,
+i64, 0>
+{
+    I64Query
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod cached_guild_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_guild_delete_by_id() -> CachedGuildDeleteByIdStmt
+{ CachedGuildDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedGuildDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedGuildDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_guild_exists_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_guild_exists_by_id() -> CachedGuildExistsByIdStmt
+{ CachedGuildExistsByIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"
+    WHERE
+        \"id\" = $1
+)")) } pub struct
+CachedGuildExistsByIdStmt(cornucopia_async::private::Stmt); impl CachedGuildExistsByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> BoolQuery<'a,C,
+bool, 1>
+{
+    BoolQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod cached_guild_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedGuildSelectById
+{ pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : Vec<String>,pub icon : Option<String>,pub id : String,pub large : bool,pub mfa_level : i16,pub name : String,pub owner_id : String,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,pub preferred_locale : String,}pub struct CachedGuildSelectByIdBorrowed<'a> { pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : cornucopia_async::ArrayIterator<'a, &'a str>,pub icon : Option<&'a str>,pub id : &'a str,pub large : bool,pub mfa_level : i16,pub name : &'a str,pub owner_id : &'a str,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,pub preferred_locale : &'a str,}
+impl<'a> From<CachedGuildSelectByIdBorrowed<'a>> for CachedGuildSelectById
+{
+    fn from(CachedGuildSelectByIdBorrowed { default_message_notifications,explicit_content_filter,features,icon,id,large,mfa_level,name,owner_id,premium_subscription_count,premium_tier,verification_level,preferred_locale,}: CachedGuildSelectByIdBorrowed<'a>) ->
+    Self { Self { default_message_notifications,explicit_content_filter,features: features.map(|v| v.into()).collect(),icon: icon.map(|v| v.into()),id: id.into(),large,mfa_level,name: name.into(),owner_id: owner_id.into(),premium_subscription_count,premium_tier,verification_level,preferred_locale: preferred_locale.into(),} }
+}pub struct CachedGuildSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedGuildSelectByIdBorrowed,
+    mapper: fn(CachedGuildSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedGuildSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedGuildSelectByIdBorrowed) -> R) ->
+    CachedGuildSelectByIdQuery<'a,C,R,N>
+    {
+        CachedGuildSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_guild_select_by_id() -> CachedGuildSelectByIdStmt
+{ CachedGuildSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedGuildSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedGuildSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedGuildSelectByIdQuery<'a,C,
+CachedGuildSelectById, 1>
+{
+    CachedGuildSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedGuildSelectByIdBorrowed { default_message_notifications: row.get(0),explicit_content_filter: row.get(1),features: row.get(2),icon: row.get(3),id: row.get(4),large: row.get(5),mfa_level: row.get(6),name: row.get(7),owner_id: row.get(8),premium_subscription_count: row.get(9),premium_tier: row.get(10),verification_level: row.get(11),preferred_locale: row.get(12),} }, mapper: |it| { <CachedGuildSelectById>::from(it) },
+    }
+} }}pub mod cached_guild_select_by_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedGuildSelectByIds
+{ pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : Vec<String>,pub icon : Option<String>,pub id : String,pub large : bool,pub mfa_level : i16,pub name : String,pub owner_id : String,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,pub preferred_locale : String,}pub struct CachedGuildSelectByIdsBorrowed<'a> { pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : cornucopia_async::ArrayIterator<'a, &'a str>,pub icon : Option<&'a str>,pub id : &'a str,pub large : bool,pub mfa_level : i16,pub name : &'a str,pub owner_id : &'a str,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,pub preferred_locale : &'a str,}
+impl<'a> From<CachedGuildSelectByIdsBorrowed<'a>> for CachedGuildSelectByIds
+{
+    fn from(CachedGuildSelectByIdsBorrowed { default_message_notifications,explicit_content_filter,features,icon,id,large,mfa_level,name,owner_id,premium_subscription_count,premium_tier,verification_level,preferred_locale,}: CachedGuildSelectByIdsBorrowed<'a>) ->
+    Self { Self { default_message_notifications,explicit_content_filter,features: features.map(|v| v.into()).collect(),icon: icon.map(|v| v.into()),id: id.into(),large,mfa_level,name: name.into(),owner_id: owner_id.into(),premium_subscription_count,premium_tier,verification_level,preferred_locale: preferred_locale.into(),} }
+}pub struct CachedGuildSelectByIdsQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedGuildSelectByIdsBorrowed,
+    mapper: fn(CachedGuildSelectByIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedGuildSelectByIdsQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedGuildSelectByIdsBorrowed) -> R) ->
+    CachedGuildSelectByIdsQuery<'a,C,R,N>
+    {
+        CachedGuildSelectByIdsQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_guild_select_by_ids() -> CachedGuildSelectByIdsStmt
+{ CachedGuildSelectByIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"
+WHERE
+    \"id\" = ANY($1)")) } pub struct
+CachedGuildSelectByIdsStmt(cornucopia_async::private::Stmt); impl CachedGuildSelectByIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> CachedGuildSelectByIdsQuery<'a,C,
+CachedGuildSelectByIds, 1>
+{
+    CachedGuildSelectByIdsQuery
+    {
+        client, params: [ids,], stmt: &mut self.0, extractor:
+        |row| { CachedGuildSelectByIdsBorrowed { default_message_notifications: row.get(0),explicit_content_filter: row.get(1),features: row.get(2),icon: row.get(3),id: row.get(4),large: row.get(5),mfa_level: row.get(6),name: row.get(7),owner_id: row.get(8),premium_subscription_count: row.get(9),premium_tier: row.get(10),verification_level: row.get(11),preferred_locale: row.get(12),} }, mapper: |it| { <CachedGuildSelectByIds>::from(it) },
+    }
+} }}pub mod cached_guild_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedGuildUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,> { pub default_message_notifications: i16,pub explicit_content_filter: i16,pub features: T2,pub icon: Option<T3>,pub large: bool,pub name: T4,pub owner_id: T5,pub id: T6,pub mfa_level: i16,pub premium_subscription_count: Option<i64>,pub premium_tier: i16,pub verification_level: i16,pub preferred_locale: T7,}pub fn cached_guild_upsert() -> CachedGuildUpsertStmt
+{ CachedGuildUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\" (\"default_message_notifications\", \"explicit_content_filter\", \"features\", \"icon\", \"large\", \"name\", \"owner_id\", \"id\", \"mfa_level\", \"premium_subscription_count\", \"premium_tier\", \"verification_level\", \"preferred_locale\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"default_message_notifications\" = $1,
+        \"explicit_content_filter\" = $2,
+        \"features\" = $3,
+        \"icon\" = $4,
+        \"large\" = $5,
+        \"mfa_level\" = $9,
+        \"name\" = $6,
+        \"owner_id\" = $7,
+        \"preferred_locale\" = $13,
+        \"premium_subscription_count\" = $10,
+        \"premium_tier\" = $11,
+        \"verification_level\" = $12")) } pub struct
+CachedGuildUpsertStmt(cornucopia_async::private::Stmt); impl CachedGuildUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+default_message_notifications: &'a i16,explicit_content_filter: &'a i16,features: &'a T2,icon: &'a Option<T3>,large: &'a bool,name: &'a T4,owner_id: &'a T5,id: &'a T6,mfa_level: &'a i16,premium_subscription_count: &'a Option<i64>,premium_tier: &'a i16,verification_level: &'a i16,preferred_locale: &'a T7,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[default_message_notifications,explicit_content_filter,features,icon,large,name,owner_id,id,mfa_level,premium_subscription_count,premium_tier,verification_level,preferred_locale,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedGuildUpsertParams<T1,T2,T3,T4,T5,T6,T7,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedGuildUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedGuildUpsertParams<T1,T2,T3,T4,T5,T6,T7,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.default_message_notifications,&params.explicit_content_filter,&params.features,&params.icon,&params.large,&params.name,&params.owner_id,&params.id,&params.mfa_level,&params.premium_subscription_count,&params.premium_tier,&params.verification_level,&params.preferred_locale,)) }
+}}pub mod cached_guild_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedGuildUpsertManyParams<T1: cornucopia_async::ArraySql<Item = i16>,T2: cornucopia_async::ArraySql<Item = i16>,T3: cornucopia_async::JsonSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = Option<T5>>,T7: cornucopia_async::ArraySql<Item = bool>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = T8>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = T10>,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = T12>,T14: cornucopia_async::ArraySql<Item = i16>,T15: cornucopia_async::ArraySql<Item = Option<i64>>,T16: cornucopia_async::ArraySql<Item = i16>,T17: cornucopia_async::ArraySql<Item = i16>,T18: cornucopia_async::StringSql,T19: cornucopia_async::ArraySql<Item = T18>,> { pub default_message_notifications: T1,pub explicit_content_filter: T2,pub features: T4,pub icon: T6,pub large: T7,pub name: T9,pub owner_id: T11,pub id: T13,pub mfa_level: T14,pub premium_subscription_count: T15,pub premium_tier: T16,pub verification_level: T17,pub preferred_locale: T19,}pub fn cached_guild_upsert_many() -> CachedGuildUpsertManyStmt
+{ CachedGuildUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\" (\"default_message_notifications\", \"explicit_content_filter\", \"features\", \"icon\", \"large\", \"name\", \"owner_id\", \"id\", \"mfa_level\", \"premium_subscription_count\", \"premium_tier\", \"verification_level\", \"preferred_locale\")
+SELECT DISTINCT ON (\"id\")
+    \"default_message_notifications\",
+    \"explicit_content_filter\",
+    ARRAY(SELECT jsonb_array_elements_text(\"features\")),
+    \"icon\",
+    \"large\",
+    \"name\",
+    \"owner_id\",
+    \"id\",
+    \"mfa_level\",
+    \"premium_subscription_count\",
+    \"premium_tier\",
+    \"verification_level\",
+    \"preferred_locale\"
+FROM
+    unnest(
+        CAST($1 AS SMALLINT[]),
+        CAST($2 AS SMALLINT[]),
+        CAST($3 AS JSONB[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS BOOLEAN[]),
+        CAST($6 AS TEXT[]),
+        CAST($7 AS TEXT[]),
+        CAST($8 AS TEXT[]),
+        CAST($9 AS SMALLINT[]),
+        CAST($10 AS BIGINT[]),
+        CAST($11 AS SMALLINT[]),
+        CAST($12 AS SMALLINT[]),
+        CAST($13 AS TEXT[])
+    ) WITH ORDINALITY AS \"entities\" (\"default_message_notifications\", \"explicit_content_filter\", \"features\", \"icon\", \"large\", \"name\", \"owner_id\", \"id\", \"mfa_level\", \"premium_subscription_count\", \"premium_tier\", \"verification_level\", \"preferred_locale\", \"ordinality\")
+ORDER BY
+    \"id\", \"ordinality\" DESC
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"default_message_notifications\" = EXCLUDED.\"default_message_notifications\",
+        \"explicit_content_filter\" = EXCLUDED.\"explicit_content_filter\",
+        \"features\" = EXCLUDED.\"features\",
+        \"icon\" = EXCLUDED.\"icon\",
+        \"large\" = EXCLUDED.\"large\",
+        \"name\" = EXCLUDED.\"name\",
+        \"owner_id\" = EXCLUDED.\"owner_id\",
+        \"mfa_level\" = EXCLUDED.\"mfa_level\",
+        \"premium_subscription_count\" = EXCLUDED.\"premium_subscription_count\",
+        \"premium_tier\" = EXCLUDED.\"premium_tier\",
+        \"verification_level\" = EXCLUDED.\"verification_level\",
+        \"preferred_locale\" = EXCLUDED.\"preferred_locale\"")) } pub struct
+CachedGuildUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedGuildUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::ArraySql<Item = i16>,T2:
+cornucopia_async::ArraySql<Item = i16>,T3:
+cornucopia_async::JsonSql,T4:
+cornucopia_async::ArraySql<Item = T3>,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::ArraySql<Item = Option<T5>>,T7:
+cornucopia_async::ArraySql<Item = bool>,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::ArraySql<Item = T8>,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::ArraySql<Item = T10>,T12:
+cornucopia_async::StringSql,T13:
+cornucopia_async::ArraySql<Item = T12>,T14:
+cornucopia_async::ArraySql<Item = i16>,T15:
+cornucopia_async::ArraySql<Item = Option<i64>>,T16:
+cornucopia_async::ArraySql<Item = i16>,T17:
+cornucopia_async::ArraySql<Item = i16>,T18:
+cornucopia_async::StringSql,T19:
+cornucopia_async::ArraySql<Item = T18>,>(&'a mut self, client: &'a  C,
+default_message_notifications: &'a T1,explicit_content_filter: &'a T2,features: &'a T4,icon: &'a T6,large: &'a T7,name: &'a T9,owner_id: &'a T11,id: &'a T13,mfa_level: &'a T14,premium_subscription_count: &'a T15,premium_tier: &'a T16,verification_level: &'a T17,preferred_locale: &'a T19,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[default_message_notifications,explicit_content_filter,features,icon,large,name,owner_id,id,mfa_level,premium_subscription_count,premium_tier,verification_level,preferred_locale,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::ArraySql<Item = i16>,T2: cornucopia_async::ArraySql<Item = i16>,T3: cornucopia_async::JsonSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = Option<T5>>,T7: cornucopia_async::ArraySql<Item = bool>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = T8>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = T10>,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = T12>,T14: cornucopia_async::ArraySql<Item = i16>,T15: cornucopia_async::ArraySql<Item = Option<i64>>,T16: cornucopia_async::ArraySql<Item = i16>,T17: cornucopia_async::ArraySql<Item = i16>,T18: cornucopia_async::StringSql,T19: cornucopia_async::ArraySql<Item = T18>,>
+cornucopia_async::Params<'a, CachedGuildUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,T17,T18,T19,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedGuildUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedGuildUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,T17,T18,T19,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.default_message_notifications,&params.explicit_content_filter,&params.features,&params.icon,&params.large,&params.name,&params.owner_id,&params.id,&params.mfa_level,&params.premium_subscription_count,&params.premium_tier,&params.verification_level,&params.preferred_locale,)) }
+}}pub mod cached_member_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &str,
+    mapper: fn(&str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_delete_by_guild_id() -> CachedMemberDeleteByGuildIdStmt
+{ CachedMemberDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    \"guild_id\" = $1
+RETURNING
+    \"user_id\"")) } pub struct
+CachedMemberDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberDeleteByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> StringQuery<'a,C,
+String, 1>
+{
+    StringQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod cached_member_delete_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberDeleteByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}pub fn cached_member_delete_by_user_id_and_guild_id() -> CachedMemberDeleteByUserIdAndGuildIdStmt
+{ CachedMemberDeleteByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    \"user_id\" = $1
+    AND \"guild_id\" = $2")) } pub struct
+CachedMemberDeleteByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberDeleteByUserIdAndGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[user_id,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedMemberDeleteByUserIdAndGuildIdParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMemberDeleteByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberDeleteByUserIdAndGuildIdParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.user_id,&params.guild_id,)) }
+}}pub mod cached_member_delete_stale_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberDeleteStaleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub user_ids: T3,}pub fn cached_member_delete_stale_by_guild_id() -> CachedMemberDeleteStaleByGuildIdStmt
+{ CachedMemberDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    \"guild_id\" = $1
+    AND NOT (\"user_id\" = ANY($2))")) } pub struct
+CachedMemberDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberDeleteStaleByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,user_ids: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,user_ids,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,>
+cornucopia_async::Params<'a, CachedMemberDeleteStaleByGuildIdParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMemberDeleteStaleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberDeleteStaleByGuildIdParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.user_ids,)) }
+}}pub mod cached_member_exists_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberExistsByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_exists_by_user_id_and_guild_id() -> CachedMemberExistsByUserIdAndGuildIdStmt
+{ CachedMemberExistsByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+    WHERE
+        \"user_id\" = $1
+        AND \"guild_id\" = $2
+)")) } pub struct
+CachedMemberExistsByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberExistsByUserIdAndGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> BoolQuery<'a,C,
+bool, 2>
+{
+    BoolQuery
+    {
+        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedMemberExistsByUserIdAndGuildIdParams<T1,T2,>, BoolQuery<'a, C,
+bool, 2>, C> for CachedMemberExistsByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberExistsByUserIdAndGuildIdParams<T1,T2,>) -> BoolQuery<'a, C,
+    bool, 2>
+    { self.bind(client, &params.user_id,&params.guild_id,) }
+}}pub mod cached_member_remove_role_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberRemoveRoleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub role_id: T1,pub guild_id: T2,}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &str,
+    mapper: fn(&str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_remove_role_by_guild_id() -> CachedMemberRemoveRoleByGuildIdStmt
+{ CachedMemberRemoveRoleByGuildIdStmt(cornucopia_async::private::Stmt::new("UPDATE
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+SET
+    \"roles\" = array_remove(\"roles\", $1)
+WHERE
+    \"guild_id\" = $2
+    AND $1 = ANY(\"roles\")
+RETURNING
+    \"user_id\"")) } pub struct
+CachedMemberRemoveRoleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberRemoveRoleByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+role_id: &'a T1,guild_id: &'a T2,) -> StringQuery<'a,C,
+String, 2>
+{
+    StringQuery
+    {
+        client, params: [role_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedMemberRemoveRoleByGuildIdParams<T1,T2,>, StringQuery<'a, C,
+String, 2>, C> for CachedMemberRemoveRoleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberRemoveRoleByGuildIdParams<T1,T2,>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.role_id,&params.guild_id,) }
+}}pub mod cached_member_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByGuildId
+{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,}pub struct CachedMemberSelectByGuildIdBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,}
+impl<'a> From<CachedMemberSelectByGuildIdBorrowed<'a>> for CachedMemberSelectByGuildId
+{
+    fn from(CachedMemberSelectByGuildIdBorrowed { flags,joined_at,nick,roles,guild_id,user_id,}: CachedMemberSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),} }
+}pub struct CachedMemberSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByGuildIdBorrowed,
+    mapper: fn(CachedMemberSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMemberSelectByGuildIdBorrowed) -> R) ->
+    CachedMemberSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedMemberSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_select_by_guild_id() -> CachedMemberSelectByGuildIdStmt
+{ CachedMemberSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedMemberSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedMemberSelectByGuildIdQuery<'a,C,
+CachedMemberSelectByGuildId, 1>
+{
+    CachedMemberSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedMemberSelectByGuildIdBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),} }, mapper: |it| { <CachedMemberSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_member_select_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberSelectByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByUserIdAndGuildId
+{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,}pub struct CachedMemberSelectByUserIdAndGuildIdBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,}
+impl<'a> From<CachedMemberSelectByUserIdAndGuildIdBorrowed<'a>> for CachedMemberSelectByUserIdAndGuildId
+{
+    fn from(CachedMemberSelectByUserIdAndGuildIdBorrowed { flags,joined_at,nick,roles,guild_id,user_id,}: CachedMemberSelectByUserIdAndGuildIdBorrowed<'a>) ->
+    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),} }
+}pub struct CachedMemberSelectByUserIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByUserIdAndGuildIdBorrowed,
+    mapper: fn(CachedMemberSelectByUserIdAndGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByUserIdAndGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMemberSelectByUserIdAndGuildIdBorrowed) -> R) ->
+    CachedMemberSelectByUserIdAndGuildIdQuery<'a,C,R,N>
+    {
+        CachedMemberSelectByUserIdAndGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_select_by_user_id_and_guild_id() -> CachedMemberSelectByUserIdAndGuildIdStmt
+{ CachedMemberSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    user_id = $1 AND
+    guild_id = $2")) } pub struct
+CachedMemberSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByUserIdAndGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> CachedMemberSelectByUserIdAndGuildIdQuery<'a,C,
+CachedMemberSelectByUserIdAndGuildId, 2>
+{
+    CachedMemberSelectByUserIdAndGuildIdQuery
+    {
+        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedMemberSelectByUserIdAndGuildIdBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),} }, mapper: |it| { <CachedMemberSelectByUserIdAndGuildId>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedMemberSelectByUserIdAndGuildIdParams<T1,T2,>, CachedMemberSelectByUserIdAndGuildIdQuery<'a, C,
+CachedMemberSelectByUserIdAndGuildId, 2>, C> for CachedMemberSelectByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberSelectByUserIdAndGuildIdParams<T1,T2,>) -> CachedMemberSelectByUserIdAndGuildIdQuery<'a, C,
+    CachedMemberSelectByUserIdAndGuildId, 2>
+    { self.bind(client, &params.user_id,&params.guild_id,) }
+}}pub mod cached_member_select_by_user_ids_and_guild_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberSelectByUserIdsAndGuildIdsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,> { pub user_ids: T2,pub guild_ids: T4,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByUserIdsAndGuildIds
+{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,}pub struct CachedMemberSelectByUserIdsAndGuildIdsBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,}
+impl<'a> From<CachedMemberSelectByUserIdsAndGuildIdsBorrowed<'a>> for CachedMemberSelectByUserIdsAndGuildIds
+{
+    fn from(CachedMemberSelectByUserIdsAndGuildIdsBorrowed { flags,joined_at,nick,roles,guild_id,user_id,}: CachedMemberSelectByUserIdsAndGuildIdsBorrowed<'a>) ->
+    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),} }
+}pub struct CachedMemberSelectByUserIdsAndGuildIdsQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByUserIdsAndGuildIdsBorrowed,
+    mapper: fn(CachedMemberSelectByUserIdsAndGuildIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByUserIdsAndGuildIdsQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMemberSelectByUserIdsAndGuildIdsBorrowed) -> R) ->
+    CachedMemberSelectByUserIdsAndGuildIdsQuery<'a,C,R,N>
+    {
+        CachedMemberSelectByUserIdsAndGuildIdsQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_select_by_user_ids_and_guild_ids() -> CachedMemberSelectByUserIdsAndGuildIdsStmt
+{ CachedMemberSelectByUserIdsAndGuildIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    (\"user_id\", \"guild_id\") IN (
+        SELECT
+            *
+        FROM
+            unnest(CAST($1 AS TEXT[]), CAST($2 AS TEXT[]))
+    )")) } pub struct
+CachedMemberSelectByUserIdsAndGuildIdsStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByUserIdsAndGuildIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,>(&'a mut self, client: &'a  C,
+user_ids: &'a T2,guild_ids: &'a T4,) -> CachedMemberSelectByUserIdsAndGuildIdsQuery<'a,C,
+CachedMemberSelectByUserIdsAndGuildIds, 2>
+{
+    CachedMemberSelectByUserIdsAndGuildIdsQuery
+    {
+        client, params: [user_ids,guild_ids,], stmt: &mut self.0, extractor:
+        |row| { CachedMemberSelectByUserIdsAndGuildIdsBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),} }, mapper: |it| { <CachedMemberSelectByUserIdsAndGuildIds>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,> cornucopia_async::Params<'a,
+CachedMemberSelectByUserIdsAndGuildIdsParams<T1,T2,T3,T4,>, CachedMemberSelectByUserIdsAndGuildIdsQuery<'a, C,
+CachedMemberSelectByUserIdsAndGuildIds, 2>, C> for CachedMemberSelectByUserIdsAndGuildIdsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberSelectByUserIdsAndGuildIdsParams<T1,T2,T3,T4,>) -> CachedMemberSelectByUserIdsAndGuildIdsQuery<'a, C,
+    CachedMemberSelectByUserIdsAndGuildIds, 2>
+    { self.bind(client, &params.user_ids,&params.guild_ids,) }
+}}pub mod cached_member_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,> { pub flags: i64,pub joined_at: Option<time::OffsetDateTime>,pub nick: Option<T1>,pub user_id: T2,pub guild_id: T3,pub roles: T5,}pub fn cached_member_upsert() -> CachedMemberUpsertStmt
+{ CachedMemberUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedMembers\" (\"flags\", \"joined_at\", \"nick\", \"user_id\", \"guild_id\", \"roles\")
+VALUES ($1, $2, $3, $4, $5, $6)
+ON CONFLICT (\"user_id\", \"guild_id\") DO UPDATE
+    SET
+        \"flags\" = $1,
+        \"joined_at\" = $2,
+        \"nick\" = $3,
+        \"roles\" = $6")) } pub struct
+CachedMemberUpsertStmt(cornucopia_async::private::Stmt); impl CachedMemberUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::ArraySql<Item = T4>,>(&'a mut self, client: &'a  C,
+flags: &'a i64,joined_at: &'a Option<time::OffsetDateTime>,nick: &'a Option<T1>,user_id: &'a T2,guild_id: &'a T3,roles: &'a T5,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[flags,joined_at,nick,user_id,guild_id,roles,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,>
+cornucopia_async::Params<'a, CachedMemberUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMemberUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.flags,&params.joined_at,&params.nick,&params.user_id,&params.guild_id,&params.roles,)) }
+}}pub mod cached_member_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberUpsertManyParams<T1: cornucopia_async::ArraySql<Item = i64>,T2: cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = Option<T3>>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::ArraySql<Item = T7>,T9: cornucopia_async::JsonSql,T10: cornucopia_async::ArraySql<Item = T9>,> { pub flags: T1,pub joined_at: T2,pub nick: T4,pub user_id: T6,pub guild_id: T8,pub roles: T10,}pub fn cached_member_upsert_many() -> CachedMemberUpsertManyStmt
+{ CachedMemberUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedMembers\" (\"flags\", \"joined_at\", \"nick\", \"user_id\", \"guild_id\", \"roles\")
+SELECT DISTINCT ON (\"user_id\", \"guild_id\")
+    \"flags\",
+    \"joined_at\",
+    \"nick\",
+    \"user_id\",
+    \"guild_id\",
+    ARRAY(SELECT jsonb_array_elements_text(\"roles\"))
+FROM
+    unnest(
+        CAST($1 AS BIGINT[]),
+        CAST($2 AS TIMESTAMP WITH TIME ZONE[]),
+        CAST($3 AS TEXT[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS TEXT[]),
+        CAST($6 AS JSONB[])
+    ) WITH ORDINALITY AS \"entities\" (\"flags\", \"joined_at\", \"nick\", \"user_id\", \"guild_id\", \"roles\", \"ordinality\")
+ORDER BY
+    \"user_id\", \"guild_id\", \"ordinality\" DESC
+ON CONFLICT (\"user_id\", \"guild_id\") DO UPDATE
+    SET
+        \"flags\" = EXCLUDED.\"flags\",
+        \"joined_at\" = EXCLUDED.\"joined_at\",
+        \"nick\" = EXCLUDED.\"nick\",
+        \"roles\" = EXCLUDED.\"roles\"")) } pub struct
+CachedMemberUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedMemberUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::ArraySql<Item = i64>,T2:
+cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = Option<T3>>,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::ArraySql<Item = T5>,T7:
+cornucopia_async::StringSql,T8:
+cornucopia_async::ArraySql<Item = T7>,T9:
+cornucopia_async::JsonSql,T10:
+cornucopia_async::ArraySql<Item = T9>,>(&'a mut self, client: &'a  C,
+flags: &'a T1,joined_at: &'a T2,nick: &'a T4,user_id: &'a T6,guild_id: &'a T8,roles: &'a T10,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[flags,joined_at,nick,user_id,guild_id,roles,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::ArraySql<Item = i64>,T2: cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = Option<T3>>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::ArraySql<Item = T7>,T9: cornucopia_async::JsonSql,T10: cornucopia_async::ArraySql<Item = T9>,>
+cornucopia_async::Params<'a, CachedMemberUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMemberUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.flags,&params.joined_at,&params.nick,&params.user_id,&params.guild_id,&params.roles,)) }
+}}pub mod cached_message_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &str,
+    mapper: fn(&str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_message_delete_by_guild_id() -> CachedMessageDeleteByGuildIdStmt
+{ CachedMessageDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"guild_id\" = $1
+RETURNING
+    \"id\"")) } pub struct
+CachedMessageDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMessageDeleteByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> StringQuery<'a,C,
+String, 1>
+{
+    StringQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod cached_message_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_message_delete_by_id() -> CachedMessageDeleteByIdStmt
+{ CachedMessageDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedMessageDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedMessageDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_message_delete_by_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_message_delete_by_ids() -> CachedMessageDeleteByIdsStmt
+{ CachedMessageDeleteByIdsStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"id\" = ANY($1)")) } pub struct
+CachedMessageDeleteByIdsStmt(cornucopia_async::private::Stmt); impl CachedMessageDeleteByIdsStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[ids,]).await
+} }}pub mod cached_message_delete_excess_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMessageDeleteExcessByGuildIdParams<T1: cornucopia_async::StringSql,> { pub guild_id: T1,pub capacity: i64,}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &str,
+    mapper: fn(&str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_message_delete_excess_by_guild_id() -> CachedMessageDeleteExcessByGuildIdStmt
+{ CachedMessageDeleteExcessByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"id\" IN (
+        SELECT
+            \"id\"
+        FROM
+            \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+        WHERE
+            \"guild_id\" = $1
+        ORDER BY
+            \"timestamp\" DESC
+        OFFSET $2
+    )
+RETURNING
+    \"id\"")) } pub struct
+CachedMessageDeleteExcessByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMessageDeleteExcessByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,capacity: &'a i64,) -> StringQuery<'a,C,
+String, 2>
+{
+    StringQuery
+    {
+        client, params: [guild_id,capacity,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedMessageDeleteExcessByGuildIdParams<T1,>, StringQuery<'a, C,
+String, 2>, C> for CachedMessageDeleteExcessByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMessageDeleteExcessByGuildIdParams<T1,>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.guild_id,&params.capacity,) }
+}}pub mod cached_message_delete_expired
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &str,
+    mapper: fn(&str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_message_delete_expired() -> CachedMessageDeleteExpiredStmt
+{ CachedMessageDeleteExpiredStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"timestamp\" < $1
+RETURNING
+    \"id\"")) } pub struct
+CachedMessageDeleteExpiredStmt(cornucopia_async::private::Stmt); impl CachedMessageDeleteExpiredStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+before: &'a time::OffsetDateTime,) -> StringQuery<'a,C,
+String, 1>
+{
+    StringQuery
+    {
+        client, params: [before,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod cached_message_delete_stale_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMessageDeleteStaleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub fn cached_message_delete_stale_by_guild_id() -> CachedMessageDeleteStaleByGuildIdStmt
+{ CachedMessageDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"guild_id\" = $1
+    AND NOT (\"id\" = ANY($2))")) } pub struct
+CachedMessageDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMessageDeleteStaleByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,ids,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,>
+cornucopia_async::Params<'a, CachedMessageDeleteStaleByGuildIdParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMessageDeleteStaleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMessageDeleteStaleByGuildIdParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.ids,)) }
+}}pub mod cached_message_exists_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_message_exists_by_id() -> CachedMessageExistsByIdStmt
+{ CachedMessageExistsByIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+    WHERE
+        \"id\" = $1
+)")) } pub struct
+CachedMessageExistsByIdStmt(cornucopia_async::private::Stmt); impl CachedMessageExistsByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> BoolQuery<'a,C,
+bool, 1>
+{
+    BoolQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod cached_message_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedMessageSelectByGuildId
+{ pub attachments : serde_json::Value,pub author_id : String,pub channel_id : String,pub content : String,pub edited_timestamp : Option<time::OffsetDateTime>,pub guild_id : String,pub id : String,pub timestamp : time::OffsetDateTime,}pub struct CachedMessageSelectByGuildIdBorrowed<'a> { pub attachments : postgres_types::Json<&'a serde_json::value::RawValue>,pub author_id : &'a str,pub channel_id : &'a str,pub content : &'a str,pub edited_timestamp : Option<time::OffsetDateTime>,pub guild_id : &'a str,pub id : &'a str,pub timestamp : time::OffsetDateTime,}
+impl<'a> From<CachedMessageSelectByGuildIdBorrowed<'a>> for CachedMessageSelectByGuildId
+{
+    fn from(CachedMessageSelectByGuildIdBorrowed { attachments,author_id,channel_id,content,edited_timestamp,guild_id,id,timestamp,}: CachedMessageSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { attachments: serde_json::from_str(attachments.0.get()).unwrap(),author_id: author_id.into(),channel_id: channel_id.into(),content: content.into(),edited_timestamp,guild_id: guild_id.into(),id: id.into(),timestamp,} }
+}pub struct CachedMessageSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMessageSelectByGuildIdBorrowed,
+    mapper: fn(CachedMessageSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMessageSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMessageSelectByGuildIdBorrowed) -> R) ->
+    CachedMessageSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedMessageSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_message_select_by_guild_id() -> CachedMessageSelectByGuildIdStmt
+{ CachedMessageSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedMessageSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMessageSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedMessageSelectByGuildIdQuery<'a,C,
+CachedMessageSelectByGuildId, 1>
+{
+    CachedMessageSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedMessageSelectByGuildIdBorrowed { attachments: row.get(0),author_id: row.get(1),channel_id: row.get(2),content: row.get(3),edited_timestamp: row.get(4),guild_id: row.get(5),id: row.get(6),timestamp: row.get(7),} }, mapper: |it| { <CachedMessageSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_message_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedMessageSelectById
+{ pub attachments : serde_json::Value,pub author_id : String,pub channel_id : String,pub content : String,pub edited_timestamp : Option<time::OffsetDateTime>,pub guild_id : String,pub id : String,pub timestamp : time::OffsetDateTime,}pub struct CachedMessageSelectByIdBorrowed<'a> { pub attachments : postgres_types::Json<&'a serde_json::value::RawValue>,pub author_id : &'a str,pub channel_id : &'a str,pub content : &'a str,pub edited_timestamp : Option<time::OffsetDateTime>,pub guild_id : &'a str,pub id : &'a str,pub timestamp : time::OffsetDateTime,}
+impl<'a> From<CachedMessageSelectByIdBorrowed<'a>> for CachedMessageSelectById
+{
+    fn from(CachedMessageSelectByIdBorrowed { attachments,author_id,channel_id,content,edited_timestamp,guild_id,id,timestamp,}: CachedMessageSelectByIdBorrowed<'a>) ->
+    Self { Self { attachments: serde_json::from_str(attachments.0.get()).unwrap(),author_id: author_id.into(),channel_id: channel_id.into(),content: content.into(),edited_timestamp,guild_id: guild_id.into(),id: id.into(),timestamp,} }
+}pub struct CachedMessageSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMessageSelectByIdBorrowed,
+    mapper: fn(CachedMessageSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMessageSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMessageSelectByIdBorrowed) -> R) ->
+    CachedMessageSelectByIdQuery<'a,C,R,N>
+    {
+        CachedMessageSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_message_select_by_id() -> CachedMessageSelectByIdStmt
+{ CachedMessageSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedMessageSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedMessageSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedMessageSelectByIdQuery<'a,C,
+CachedMessageSelectById, 1>
+{
+    CachedMessageSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedMessageSelectByIdBorrowed { attachments: row.get(0),author_id: row.get(1),channel_id: row.get(2),content: row.get(3),edited_timestamp: row.get(4),guild_id: row.get(5),id: row.get(6),timestamp: row.get(7),} }, mapper: |it| { <CachedMessageSelectById>::from(it) },
+    }
+} }}pub mod cached_message_select_by_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedMessageSelectByIds
+{ pub attachments : serde_json::Value,pub author_id : String,pub channel_id : String,pub content : String,pub edited_timestamp : Option<time::OffsetDateTime>,pub guild_id : String,pub id : String,pub timestamp : time::OffsetDateTime,}pub struct CachedMessageSelectByIdsBorrowed<'a> { pub attachments : postgres_types::Json<&'a serde_json::value::RawValue>,pub author_id : &'a str,pub channel_id : &'a str,pub content : &'a str,pub edited_timestamp : Option<time::OffsetDateTime>,pub guild_id : &'a str,pub id : &'a str,pub timestamp : time::OffsetDateTime,}
+impl<'a> From<CachedMessageSelectByIdsBorrowed<'a>> for CachedMessageSelectByIds
+{
+    fn from(CachedMessageSelectByIdsBorrowed { attachments,author_id,channel_id,content,edited_timestamp,guild_id,id,timestamp,}: CachedMessageSelectByIdsBorrowed<'a>) ->
+    Self { Self { attachments: serde_json::from_str(attachments.0.get()).unwrap(),author_id: author_id.into(),channel_id: channel_id.into(),content: content.into(),edited_timestamp,guild_id: guild_id.into(),id: id.into(),timestamp,} }
+}pub struct CachedMessageSelectByIdsQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMessageSelectByIdsBorrowed,
+    mapper: fn(CachedMessageSelectByIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMessageSelectByIdsQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMessageSelectByIdsBorrowed) -> R) ->
+    CachedMessageSelectByIdsQuery<'a,C,R,N>
+    {
+        CachedMessageSelectByIdsQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_message_select_by_ids() -> CachedMessageSelectByIdsStmt
+{ CachedMessageSelectByIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"id\" = ANY($1)")) } pub struct
+CachedMessageSelectByIdsStmt(cornucopia_async::private::Stmt); impl CachedMessageSelectByIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> CachedMessageSelectByIdsQuery<'a,C,
+CachedMessageSelectByIds, 1>
+{
+    CachedMessageSelectByIdsQuery
+    {
+        client, params: [ids,], stmt: &mut self.0, extractor:
+        |row| { CachedMessageSelectByIdsBorrowed { attachments: row.get(0),author_id: row.get(1),channel_id: row.get(2),content: row.get(3),edited_timestamp: row.get(4),guild_id: row.get(5),id: row.get(6),timestamp: row.get(7),} }, mapper: |it| { <CachedMessageSelectByIds>::from(it) },
+    }
+} }}pub mod cached_message_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMessageUpsertParams<T1: cornucopia_async::JsonSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,> { pub attachments: T1,pub author_id: T2,pub channel_id: T3,pub content: T4,pub edited_timestamp: Option<time::OffsetDateTime>,pub guild_id: T5,pub id: T6,pub timestamp: time::OffsetDateTime,}pub fn cached_message_upsert() -> CachedMessageUpsertStmt
+{ CachedMessageUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedMessages\" (\"attachments\", \"author_id\", \"channel_id\", \"content\", \"edited_timestamp\", \"guild_id\", \"id\", \"timestamp\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"attachments\" = $1,
+        \"author_id\" = $2,
+        \"channel_id\" = $3,
+        \"content\" = $4,
+        \"edited_timestamp\" = $5,
+        \"guild_id\" = $6,
+        \"timestamp\" = $8")) } pub struct
+CachedMessageUpsertStmt(cornucopia_async::private::Stmt); impl CachedMessageUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::JsonSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+attachments: &'a T1,author_id: &'a T2,channel_id: &'a T3,content: &'a T4,edited_timestamp: &'a Option<time::OffsetDateTime>,guild_id: &'a T5,id: &'a T6,timestamp: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[attachments,author_id,channel_id,content,edited_timestamp,guild_id,id,timestamp,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::JsonSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedMessageUpsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMessageUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMessageUpsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.attachments,&params.author_id,&params.channel_id,&params.content,&params.edited_timestamp,&params.guild_id,&params.id,&params.timestamp,)) }
+}}pub mod cached_message_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMessageUpsertManyParams<T1: cornucopia_async::JsonSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::ArraySql<Item = T7>,T9: cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = T10>,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = T12>,T14: cornucopia_async::ArraySql<Item = time::OffsetDateTime>,> { pub attachments: T2,pub author_id: T4,pub channel_id: T6,pub content: T8,pub edited_timestamp: T9,pub guild_id: T11,pub id: T13,pub timestamp: T14,}pub fn cached_message_upsert_many() -> CachedMessageUpsertManyStmt
+{ CachedMessageUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedMessages\" (\"attachments\", \"author_id\", \"channel_id\", \"content\", \"edited_timestamp\", \"guild_id\", \"id\", \"timestamp\")
+SELECT DISTINCT ON (\"id\")
+    \"attachments\",
+    \"author_id\",
+    \"channel_id\",
+    \"content\",
+    \"edited_timestamp\",
+    \"guild_id\",
+    \"id\",
+    \"timestamp\"
+FROM
+    unnest(
+        CAST($1 AS JSONB[]),
+        CAST($2 AS TEXT[]),
+        CAST($3 AS TEXT[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS TIMESTAMP WITH TIME ZONE[]),
+        CAST($6 AS TEXT[]),
+        CAST($7 AS TEXT[]),
+        CAST($8 AS TIMESTAMP WITH TIME ZONE[])
+    ) WITH ORDINALITY AS \"entities\" (\"attachments\", \"author_id\", \"channel_id\", \"content\", \"edited_timestamp\", \"guild_id\", \"id\", \"timestamp\", \"ordinality\")
+ORDER BY
+    \"id\", \"ordinality\" DESC
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"attachments\" = EXCLUDED.\"attachments\",
+        \"author_id\" = EXCLUDED.\"author_id\",
+        \"channel_id\" = EXCLUDED.\"channel_id\",
+        \"content\" = EXCLUDED.\"content\",
+        \"edited_timestamp\" = EXCLUDED.\"edited_timestamp\",
+        \"guild_id\" = EXCLUDED.\"guild_id\",
+        \"timestamp\" = EXCLUDED.\"timestamp\"")) } pub struct
+CachedMessageUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedMessageUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::JsonSql,T2:
+cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::ArraySql<Item = T5>,T7:
+cornucopia_async::StringSql,T8:
+cornucopia_async::ArraySql<Item = T7>,T9:
+cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::ArraySql<Item = T10>,T12:
+cornucopia_async::StringSql,T13:
+cornucopia_async::ArraySql<Item = T12>,T14:
+cornucopia_async::ArraySql<Item = time::OffsetDateTime>,>(&'a mut self, client: &'a  C,
+attachments: &'a T2,author_id: &'a T4,channel_id: &'a T6,content: &'a T8,edited_timestamp: &'a T9,guild_id: &'a T11,id: &'a T13,timestamp: &'a T14,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[attachments,author_id,channel_id,content,edited_timestamp,guild_id,id,timestamp,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::JsonSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::ArraySql<Item = T7>,T9: cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = T10>,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = T12>,T14: cornucopia_async::ArraySql<Item = time::OffsetDateTime>,>
+cornucopia_async::Params<'a, CachedMessageUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMessageUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMessageUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.attachments,&params.author_id,&params.channel_id,&params.content,&params.edited_timestamp,&params.guild_id,&params.id,&params.timestamp,)) }
+}}pub mod cached_role_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_role_delete_by_guild_id() -> CachedRoleDeleteByGuildIdStmt
+{ CachedRoleDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedRoleDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod cached_role_delete_by_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleDeleteByIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,}pub fn cached_role_delete_by_id_and_guild_id() -> CachedRoleDeleteByIdAndGuildIdStmt
+{ CachedRoleDeleteByIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"id\" = $1
+    AND \"guild_id\" = $2")) } pub struct
+CachedRoleDeleteByIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleDeleteByIdAndGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedRoleDeleteByIdAndGuildIdParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedRoleDeleteByIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleDeleteByIdAndGuildIdParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.guild_id,)) }
+}}pub mod cached_role_delete_stale_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleDeleteStaleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub fn cached_role_delete_stale_by_guild_id() -> CachedRoleDeleteStaleByGuildIdStmt
+{ CachedRoleDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"guild_id\" = $1
+    AND NOT (\"id\" = ANY($2))")) } pub struct
+CachedRoleDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleDeleteStaleByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,ids,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,>
+cornucopia_async::Params<'a, CachedRoleDeleteStaleByGuildIdParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedRoleDeleteStaleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleDeleteStaleByGuildIdParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.ids,)) }
+}}pub mod cached_role_exists_by_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleExistsByIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,}pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_role_exists_by_id_and_guild_id() -> CachedRoleExistsByIdAndGuildIdStmt
+{ CachedRoleExistsByIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+    WHERE
+        \"id\" = $1
+        AND \"guild_id\" = $2
+)")) } pub struct
+CachedRoleExistsByIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleExistsByIdAndGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,guild_id: &'a T2,) -> BoolQuery<'a,C,
+bool, 2>
+{
+    BoolQuery
+    {
+        client, params: [id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedRoleExistsByIdAndGuildIdParams<T1,T2,>, BoolQuery<'a, C,
+bool, 2>, C> for CachedRoleExistsByIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleExistsByIdAndGuildIdParams<T1,T2,>) -> BoolQuery<'a, C,
+    bool, 2>
+    { self.bind(client, &params.id,&params.guild_id,) }
+}}pub mod cached_role_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByGuildId
+{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByGuildIdBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
+impl<'a> From<CachedRoleSelectByGuildIdBorrowed<'a>> for CachedRoleSelectByGuildId
+{
+    fn from(CachedRoleSelectByGuildIdBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
+}pub struct CachedRoleSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByGuildIdBorrowed,
+    mapper: fn(CachedRoleSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedRoleSelectByGuildIdBorrowed) -> R) ->
+    CachedRoleSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedRoleSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_role_select_by_guild_id() -> CachedRoleSelectByGuildIdStmt
+{ CachedRoleSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedRoleSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedRoleSelectByGuildIdQuery<'a,C,
+CachedRoleSelectByGuildId, 1>
+{
+    CachedRoleSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedRoleSelectByGuildIdBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_role_select_by_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleSelectByIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByIdAndGuildId
+{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByIdAndGuildIdBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
+impl<'a> From<CachedRoleSelectByIdAndGuildIdBorrowed<'a>> for CachedRoleSelectByIdAndGuildId
+{
+    fn from(CachedRoleSelectByIdAndGuildIdBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByIdAndGuildIdBorrowed<'a>) ->
+    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
+}pub struct CachedRoleSelectByIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByIdAndGuildIdBorrowed,
+    mapper: fn(CachedRoleSelectByIdAndGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByIdAndGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedRoleSelectByIdAndGuildIdBorrowed) -> R) ->
+    CachedRoleSelectByIdAndGuildIdQuery<'a,C,R,N>
+    {
+        CachedRoleSelectByIdAndGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_role_select_by_id_and_guild_id() -> CachedRoleSelectByIdAndGuildIdStmt
+{ CachedRoleSelectByIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"id\" = $1 AND
+    \"guild_id\" = $2")) } pub struct
+CachedRoleSelectByIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByIdAndGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,guild_id: &'a T2,) -> CachedRoleSelectByIdAndGuildIdQuery<'a,C,
+CachedRoleSelectByIdAndGuildId, 2>
+{
+    CachedRoleSelectByIdAndGuildIdQuery
+    {
+        client, params: [id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedRoleSelectByIdAndGuildIdBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByIdAndGuildId>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedRoleSelectByIdAndGuildIdParams<T1,T2,>, CachedRoleSelectByIdAndGuildIdQuery<'a, C,
+CachedRoleSelectByIdAndGuildId, 2>, C> for CachedRoleSelectByIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleSelectByIdAndGuildIdParams<T1,T2,>) -> CachedRoleSelectByIdAndGuildIdQuery<'a, C,
+    CachedRoleSelectByIdAndGuildId, 2>
+    { self.bind(client, &params.id,&params.guild_id,) }
+}}pub mod cached_role_select_by_ids_and_guild_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleSelectByIdsAndGuildIdsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,> { pub ids: T2,pub guild_ids: T4,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByIdsAndGuildIds
+{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByIdsAndGuildIdsBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
+impl<'a> From<CachedRoleSelectByIdsAndGuildIdsBorrowed<'a>> for CachedRoleSelectByIdsAndGuildIds
+{
+    fn from(CachedRoleSelectByIdsAndGuildIdsBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByIdsAndGuildIdsBorrowed<'a>) ->
+    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
+}pub struct CachedRoleSelectByIdsAndGuildIdsQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByIdsAndGuildIdsBorrowed,
+    mapper: fn(CachedRoleSelectByIdsAndGuildIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByIdsAndGuildIdsQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedRoleSelectByIdsAndGuildIdsBorrowed) -> R) ->
+    CachedRoleSelectByIdsAndGuildIdsQuery<'a,C,R,N>
+    {
+        CachedRoleSelectByIdsAndGuildIdsQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_role_select_by_ids_and_guild_ids() -> CachedRoleSelectByIdsAndGuildIdsStmt
+{ CachedRoleSelectByIdsAndGuildIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    (\"id\", \"guild_id\") IN (
+        SELECT
+            *
+        FROM
+            unnest(CAST($1 AS TEXT[]), CAST($2 AS TEXT[]))
+    )")) } pub struct
+CachedRoleSelectByIdsAndGuildIdsStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByIdsAndGuildIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,guild_ids: &'a T4,) -> CachedRoleSelectByIdsAndGuildIdsQuery<'a,C,
+CachedRoleSelectByIdsAndGuildIds, 2>
+{
+    CachedRoleSelectByIdsAndGuildIdsQuery
+    {
+        client, params: [ids,guild_ids,], stmt: &mut self.0, extractor:
+        |row| { CachedRoleSelectByIdsAndGuildIdsBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByIdsAndGuildIds>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,> cornucopia_async::Params<'a,
+CachedRoleSelectByIdsAndGuildIdsParams<T1,T2,T3,T4,>, CachedRoleSelectByIdsAndGuildIdsQuery<'a, C,
+CachedRoleSelectByIdsAndGuildIds, 2>, C> for CachedRoleSelectByIdsAndGuildIdsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleSelectByIdsAndGuildIdsParams<T1,T2,T3,T4,>) -> CachedRoleSelectByIdsAndGuildIdsQuery<'a, C,
+    CachedRoleSelectByIdsAndGuildIds, 2>
+    { self.bind(client, &params.ids,&params.guild_ids,) }
+}}pub mod cached_role_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub color: i64,pub icon: Option<T1>,pub id: T2,pub guild_id: T3,pub flags: i32,pub hoist: bool,pub managed: bool,pub mentionable: bool,pub position: i32,}pub fn cached_role_upsert() -> CachedRoleUpsertStmt
+{ CachedRoleUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedRoles\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"position\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (\"id\", \"guild_id\") DO UPDATE
+    SET
+        \"color\" = $1,
+        \"icon\" = $2,
+        \"flags\" = $5,
+        \"hoist\" = $6,
+        \"managed\" = $7,
+        \"mentionable\" = $8,
+        \"position\" = $9")) } pub struct
+CachedRoleUpsertStmt(cornucopia_async::private::Stmt); impl CachedRoleUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+color: &'a i64,icon: &'a Option<T1>,id: &'a T2,guild_id: &'a T3,flags: &'a i32,hoist: &'a bool,managed: &'a bool,mentionable: &'a bool,position: &'a i32,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[color,icon,id,guild_id,flags,hoist,managed,mentionable,position,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedRoleUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedRoleUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.color,&params.icon,&params.id,&params.guild_id,&params.flags,&params.hoist,&params.managed,&params.mentionable,&params.position,)) }
+}}pub mod cached_role_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleUpsertManyParams<T1: cornucopia_async::ArraySql<Item = i64>,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = Option<T2>>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::ArraySql<Item = i32>,T9: cornucopia_async::ArraySql<Item = bool>,T10: cornucopia_async::ArraySql<Item = bool>,T11: cornucopia_async::ArraySql<Item = bool>,T12: cornucopia_async::ArraySql<Item = i32>,> { pub color: T1,pub icon: T3,pub id: T5,pub guild_id: T7,pub flags: T8,pub hoist: T9,pub managed: T10,pub mentionable: T11,pub position: T12,}pub fn cached_role_upsert_many() -> CachedRoleUpsertManyStmt
+{ CachedRoleUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedRoles\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"position\")
+SELECT DISTINCT ON (\"id\", \"guild_id\")
+    \"color\",
+    \"icon\",
+    \"id\",
+    \"guild_id\",
+    \"flags\",
+    \"hoist\",
+    \"managed\",
+    \"mentionable\",
+    \"position\"
+FROM
+    unnest(
+        CAST($1 AS BIGINT[]),
+        CAST($2 AS TEXT[]),
+        CAST($3 AS TEXT[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS INTEGER[]),
+        CAST($6 AS BOOLEAN[]),
+        CAST($7 AS BOOLEAN[]),
+        CAST($8 AS BOOLEAN[]),
+        CAST($9 AS INTEGER[])
+    ) WITH ORDINALITY AS \"entities\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"position\", \"ordinality\")
+ORDER BY
+    \"id\", \"guild_id\", \"ordinality\" DESC
+ON CONFLICT (\"id\", \"guild_id\") DO UPDATE
+    SET
+        \"color\" = EXCLUDED.\"color\",
+        \"icon\" = EXCLUDED.\"icon\",
+        \"flags\" = EXCLUDED.\"flags\",
+        \"hoist\" = EXCLUDED.\"hoist\",
+        \"managed\" = EXCLUDED.\"managed\",
+        \"mentionable\" = EXCLUDED.\"mentionable\",
+        \"position\" = EXCLUDED.\"position\"")) } pub struct
+CachedRoleUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedRoleUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::ArraySql<Item = i64>,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = Option<T2>>,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::ArraySql<Item = T4>,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = T6>,T8:
+cornucopia_async::ArraySql<Item = i32>,T9:
+cornucopia_async::ArraySql<Item = bool>,T10:
+cornucopia_async::ArraySql<Item = bool>,T11:
+cornucopia_async::ArraySql<Item = bool>,T12:
+cornucopia_async::ArraySql<Item = i32>,>(&'a mut self, client: &'a  C,
+color: &'a T1,icon: &'a T3,id: &'a T5,guild_id: &'a T7,flags: &'a T8,hoist: &'a T9,managed: &'a T10,mentionable: &'a T11,position: &'a T12,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[color,icon,id,guild_id,flags,hoist,managed,mentionable,position,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::ArraySql<Item = i64>,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = Option<T2>>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::ArraySql<Item = i32>,T9: cornucopia_async::ArraySql<Item = bool>,T10: cornucopia_async::ArraySql<Item = bool>,T11: cornucopia_async::ArraySql<Item = bool>,T12: cornucopia_async::ArraySql<Item = i32>,>
+cornucopia_async::Params<'a, CachedRoleUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedRoleUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.color,&params.icon,&params.id,&params.guild_id,&params.flags,&params.hoist,&params.managed,&params.mentionable,&params.position,)) }
+}}pub mod cached_sticker_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_sticker_delete_by_guild_id() -> CachedStickerDeleteByGuildIdStmt
+{ CachedStickerDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedStickers\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedStickerDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedStickerDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod cached_sticker_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_sticker_delete_by_id() -> CachedStickerDeleteByIdStmt
+{ CachedStickerDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedStickers\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedStickerDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedStickerDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_sticker_delete_stale_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedStickerDeleteStaleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub fn cached_sticker_delete_stale_by_guild_id() -> CachedStickerDeleteStaleByGuildIdStmt
+{ CachedStickerDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedStickers\"
+WHERE
+    \"guild_id\" = $1
+    AND NOT (\"id\" = ANY($2))")) } pub struct
+CachedStickerDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedStickerDeleteStaleByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,ids,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,>
+cornucopia_async::Params<'a, CachedStickerDeleteStaleByGuildIdParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedStickerDeleteStaleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedStickerDeleteStaleByGuildIdParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.ids,)) }
+}}pub mod cached_sticker_exists_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_sticker_exists_by_id() -> CachedStickerExistsByIdStmt
+{ CachedStickerExistsByIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedStickers\"
+    WHERE
+        \"id\" = $1
+)")) } pub struct
+CachedStickerExistsByIdStmt(cornucopia_async::private::Stmt); impl CachedStickerExistsByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> BoolQuery<'a,C,
+bool, 1>
+{
+    BoolQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod cached_sticker_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedStickerSelectByGuildId
+{ pub available : bool,pub description : Option<String>,pub format_type : i16,pub guild_id : String,pub id : String,pub name : String,pub tags : String,}pub struct CachedStickerSelectByGuildIdBorrowed<'a> { pub available : bool,pub description : Option<&'a str>,pub format_type : i16,pub guild_id : &'a str,pub id : &'a str,pub name : &'a str,pub tags : &'a str,}
+impl<'a> From<CachedStickerSelectByGuildIdBorrowed<'a>> for CachedStickerSelectByGuildId
+{
+    fn from(CachedStickerSelectByGuildIdBorrowed { available,description,format_type,guild_id,id,name,tags,}: CachedStickerSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { available,description: description.map(|v| v.into()),format_type,guild_id: guild_id.into(),id: id.into(),name: name.into(),tags: tags.into(),} }
+}pub struct CachedStickerSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedStickerSelectByGuildIdBorrowed,
+    mapper: fn(CachedStickerSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedStickerSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedStickerSelectByGuildIdBorrowed) -> R) ->
+    CachedStickerSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedStickerSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_sticker_select_by_guild_id() -> CachedStickerSelectByGuildIdStmt
+{ CachedStickerSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedStickers\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedStickerSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedStickerSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedStickerSelectByGuildIdQuery<'a,C,
+CachedStickerSelectByGuildId, 1>
+{
+    CachedStickerSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedStickerSelectByGuildIdBorrowed { available: row.get(0),description: row.get(1),format_type: row.get(2),guild_id: row.get(3),id: row.get(4),name: row.get(5),tags: row.get(6),} }, mapper: |it| { <CachedStickerSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_sticker_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedStickerSelectById
+{ pub available : bool,pub description : Option<String>,pub format_type : i16,pub guild_id : String,pub id : String,pub name : String,pub tags : String,}pub struct CachedStickerSelectByIdBorrowed<'a> { pub available : bool,pub description : Option<&'a str>,pub format_type : i16,pub guild_id : &'a str,pub id : &'a str,pub name : &'a str,pub tags : &'a str,}
+impl<'a> From<CachedStickerSelectByIdBorrowed<'a>> for CachedStickerSelectById
+{
+    fn from(CachedStickerSelectByIdBorrowed { available,description,format_type,guild_id,id,name,tags,}: CachedStickerSelectByIdBorrowed<'a>) ->
+    Self { Self { available,description: description.map(|v| v.into()),format_type,guild_id: guild_id.into(),id: id.into(),name: name.into(),tags: tags.into(),} }
+}pub struct CachedStickerSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedStickerSelectByIdBorrowed,
+    mapper: fn(CachedStickerSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedStickerSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedStickerSelectByIdBorrowed) -> R) ->
+    CachedStickerSelectByIdQuery<'a,C,R,N>
+    {
+        CachedStickerSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_sticker_select_by_id() -> CachedStickerSelectByIdStmt
+{ CachedStickerSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedStickers\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedStickerSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedStickerSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedStickerSelectByIdQuery<'a,C,
+CachedStickerSelectById, 1>
+{
+    CachedStickerSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedStickerSelectByIdBorrowed { available: row.get(0),description: row.get(1),format_type: row.get(2),guild_id: row.get(3),id: row.get(4),name: row.get(5),tags: row.get(6),} }, mapper: |it| { <CachedStickerSelectById>::from(it) },
+    }
+} }}pub mod cached_sticker_select_by_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedStickerSelectByIds
+{ pub available : bool,pub description : Option<String>,pub format_type : i16,pub guild_id : String,pub id : String,pub name : String,pub tags : String,}pub struct CachedStickerSelectByIdsBorrowed<'a> { pub available : bool,pub description : Option<&'a str>,pub format_type : i16,pub guild_id : &'a str,pub id : &'a str,pub name : &'a str,pub tags : &'a str,}
+impl<'a> From<CachedStickerSelectByIdsBorrowed<'a>> for CachedStickerSelectByIds
+{
+    fn from(CachedStickerSelectByIdsBorrowed { available,description,format_type,guild_id,id,name,tags,}: CachedStickerSelectByIdsBorrowed<'a>) ->
+    Self { Self { available,description: description.map(|v| v.into()),format_type,guild_id: guild_id.into(),id: id.into(),name: name.into(),tags: tags.into(),} }
+}pub struct CachedStickerSelectByIdsQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedStickerSelectByIdsBorrowed,
+    mapper: fn(CachedStickerSelectByIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedStickerSelectByIdsQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedStickerSelectByIdsBorrowed) -> R) ->
+    CachedStickerSelectByIdsQuery<'a,C,R,N>
+    {
+        CachedStickerSelectByIdsQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_sticker_select_by_ids() -> CachedStickerSelectByIdsStmt
+{ CachedStickerSelectByIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedStickers\"
+WHERE
+    \"id\" = ANY($1)")) } pub struct
+CachedStickerSelectByIdsStmt(cornucopia_async::private::Stmt); impl CachedStickerSelectByIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> CachedStickerSelectByIdsQuery<'a,C,
+CachedStickerSelectByIds, 1>
+{
+    CachedStickerSelectByIdsQuery
+    {
+        client, params: [ids,], stmt: &mut self.0, extractor:
+        |row| { CachedStickerSelectByIdsBorrowed { available: row.get(0),description: row.get(1),format_type: row.get(2),guild_id: row.get(3),id: row.get(4),name: row.get(5),tags: row.get(6),} }, mapper: |it| { <CachedStickerSelectByIds>::from(it) },
+    }
+} }}pub mod cached_sticker_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedStickerUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,> { pub available: bool,pub description: Option<T1>,pub format_type: i16,pub guild_id: T2,pub id: T3,pub name: T4,pub tags: T5,}pub fn cached_sticker_upsert() -> CachedStickerUpsertStmt
+{ CachedStickerUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedStickers\" (\"available\", \"description\", \"format_type\", \"guild_id\", \"id\", \"name\", \"tags\")
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"available\" = $1,
+        \"description\" = $2,
+        \"format_type\" = $3,
+        \"guild_id\" = $4,
+        \"name\" = $6,
+        \"tags\" = $7")) } pub struct
+CachedStickerUpsertStmt(cornucopia_async::private::Stmt); impl CachedStickerUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+available: &'a bool,description: &'a Option<T1>,format_type: &'a i16,guild_id: &'a T2,id: &'a T3,name: &'a T4,tags: &'a T5,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[available,description,format_type,guild_id,id,name,tags,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedStickerUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedStickerUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedStickerUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.available,&params.description,&params.format_type,&params.guild_id,&params.id,&params.name,&params.tags,)) }
+}}pub mod cached_sticker_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedStickerUpsertManyParams<T1: cornucopia_async::ArraySql<Item = bool>,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = Option<T2>>,T4: cornucopia_async::ArraySql<Item = i16>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::ArraySql<Item = T7>,T9: cornucopia_async::StringSql,T10: cornucopia_async::ArraySql<Item = T9>,T11: cornucopia_async::StringSql,T12: cornucopia_async::ArraySql<Item = T11>,> { pub available: T1,pub description: T3,pub format_type: T4,pub guild_id: T6,pub id: T8,pub name: T10,pub tags: T12,}pub fn cached_sticker_upsert_many() -> CachedStickerUpsertManyStmt
+{ CachedStickerUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedStickers\" (\"available\", \"description\", \"format_type\", \"guild_id\", \"id\", \"name\", \"tags\")
+SELECT DISTINCT ON (\"id\")
+    \"available\",
+    \"description\",
+    \"format_type\",
+    \"guild_id\",
+    \"id\",
+    \"name\",
+    \"tags\"
+FROM
+    unnest(
+        CAST($1 AS BOOLEAN[]),
+        CAST($2 AS TEXT[]),
+        CAST($3 AS SMALLINT[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS TEXT[]),
+        CAST($6 AS TEXT[]),
+        CAST($7 AS TEXT[])
+    ) WITH ORDINALITY AS \"entities\" (\"available\", \"description\", \"format_type\", \"guild_id\", \"id\", \"name\", \"tags\", \"ordinality\")
+ORDER BY
+    \"id\", \"ordinality\" DESC
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"available\" = EXCLUDED.\"available\",
+        \"description\" = EXCLUDED.\"description\",
+        \"format_type\" = EXCLUDED.\"format_type\",
+        \"guild_id\" = EXCLUDED.\"guild_id\",
+        \"name\" = EXCLUDED.\"name\",
+        \"tags\" = EXCLUDED.\"tags\"")) } pub struct
+CachedStickerUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedStickerUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::ArraySql<Item = bool>,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = Option<T2>>,T4:
+cornucopia_async::ArraySql<Item = i16>,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::ArraySql<Item = T5>,T7:
+cornucopia_async::StringSql,T8:
+cornucopia_async::ArraySql<Item = T7>,T9:
+cornucopia_async::StringSql,T10:
+cornucopia_async::ArraySql<Item = T9>,T11:
+cornucopia_async::StringSql,T12:
+cornucopia_async::ArraySql<Item = T11>,>(&'a mut self, client: &'a  C,
+available: &'a T1,description: &'a T3,format_type: &'a T4,guild_id: &'a T6,id: &'a T8,name: &'a T10,tags: &'a T12,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[available,description,format_type,guild_id,id,name,tags,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::ArraySql<Item = bool>,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = Option<T2>>,T4: cornucopia_async::ArraySql<Item = i16>,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,T7: cornucopia_async::StringSql,T8: cornucopia_async::ArraySql<Item = T7>,T9: cornucopia_async::StringSql,T10: cornucopia_async::ArraySql<Item = T9>,T11: cornucopia_async::StringSql,T12: cornucopia_async::ArraySql<Item = T11>,>
+cornucopia_async::Params<'a, CachedStickerUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedStickerUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedStickerUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.available,&params.description,&params.format_type,&params.guild_id,&params.id,&params.name,&params.tags,)) }
+}}pub mod cached_thread_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_thread_delete_by_guild_id() -> CachedThreadDeleteByGuildIdStmt
+{ CachedThreadDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedThreadDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedThreadDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod cached_thread_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_thread_delete_by_id() -> CachedThreadDeleteByIdStmt
+{ CachedThreadDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedThreadDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedThreadDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_thread_delete_stale_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedThreadDeleteStaleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub fn cached_thread_delete_stale_by_guild_id() -> CachedThreadDeleteStaleByGuildIdStmt
+{ CachedThreadDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"guild_id\" = $1
+    AND NOT (\"id\" = ANY($2))")) } pub struct
+CachedThreadDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedThreadDeleteStaleByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,ids,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,>
+cornucopia_async::Params<'a, CachedThreadDeleteStaleByGuildIdParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedThreadDeleteStaleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedThreadDeleteStaleByGuildIdParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.ids,)) }
+}}pub mod cached_thread_exists_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_exists_by_id() -> CachedThreadExistsByIdStmt
+{ CachedThreadExistsByIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+    WHERE
+        \"id\" = $1
+)")) } pub struct
+CachedThreadExistsByIdStmt(cornucopia_async::private::Stmt); impl CachedThreadExistsByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> BoolQuery<'a,C,
+bool, 1>
+{
+    BoolQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod cached_thread_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedThreadSelectByGuildId
+{ pub guild_id : String,pub id : String,pub kind : i16,pub name : Option<String>,pub owner_id : Option<String>,pub parent_id : Option<String>,pub thread_metadata : Option<serde_json::Value>,}pub struct CachedThreadSelectByGuildIdBorrowed<'a> { pub guild_id : &'a str,pub id : &'a str,pub kind : i16,pub name : Option<&'a str>,pub owner_id : Option<&'a str>,pub parent_id : Option<&'a str>,pub thread_metadata : Option<postgres_types::Json<&'a serde_json::value::RawValue>>,}
+impl<'a> From<CachedThreadSelectByGuildIdBorrowed<'a>> for CachedThreadSelectByGuildId
+{
+    fn from(CachedThreadSelectByGuildIdBorrowed { guild_id,id,kind,name,owner_id,parent_id,thread_metadata,}: CachedThreadSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { guild_id: guild_id.into(),id: id.into(),kind,name: name.map(|v| v.into()),owner_id: owner_id.map(|v| v.into()),parent_id: parent_id.map(|v| v.into()),thread_metadata: thread_metadata.map(|v| serde_json::from_str(v.0.get()).unwrap()),} }
+}pub struct CachedThreadSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedThreadSelectByGuildIdBorrowed,
+    mapper: fn(CachedThreadSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedThreadSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedThreadSelectByGuildIdBorrowed) -> R) ->
+    CachedThreadSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedThreadSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_select_by_guild_id() -> CachedThreadSelectByGuildIdStmt
+{ CachedThreadSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedThreadSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedThreadSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedThreadSelectByGuildIdQuery<'a,C,
+CachedThreadSelectByGuildId, 1>
+{
+    CachedThreadSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedThreadSelectByGuildIdBorrowed { guild_id: row.get(0),id: row.get(1),kind: row.get(2),name: row.get(3),owner_id: row.get(4),parent_id: row.get(5),thread_metadata: row.get(6),} }, mapper: |it| { <CachedThreadSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_thread_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedThreadSelectById
+{ pub guild_id : String,pub id : String,pub kind : i16,pub name : Option<String>,pub owner_id : Option<String>,pub parent_id : Option<String>,pub thread_metadata : Option<serde_json::Value>,}pub struct CachedThreadSelectByIdBorrowed<'a> { pub guild_id : &'a str,pub id : &'a str,pub kind : i16,pub name : Option<&'a str>,pub owner_id : Option<&'a str>,pub parent_id : Option<&'a str>,pub thread_metadata : Option<postgres_types::Json<&'a serde_json::value::RawValue>>,}
+impl<'a> From<CachedThreadSelectByIdBorrowed<'a>> for CachedThreadSelectById
+{
+    fn from(CachedThreadSelectByIdBorrowed { guild_id,id,kind,name,owner_id,parent_id,thread_metadata,}: CachedThreadSelectByIdBorrowed<'a>) ->
+    Self { Self { guild_id: guild_id.into(),id: id.into(),kind,name: name.map(|v| v.into()),owner_id: owner_id.map(|v| v.into()),parent_id: parent_id.map(|v| v.into()),thread_metadata: thread_metadata.map(|v| serde_json::from_str(v.0.get()).unwrap()),} }
+}pub struct CachedThreadSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedThreadSelectByIdBorrowed,
+    mapper: fn(CachedThreadSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedThreadSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedThreadSelectByIdBorrowed) -> R) ->
+    CachedThreadSelectByIdQuery<'a,C,R,N>
+    {
+        CachedThreadSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_select_by_id() -> CachedThreadSelectByIdStmt
+{ CachedThreadSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedThreadSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedThreadSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedThreadSelectByIdQuery<'a,C,
+CachedThreadSelectById, 1>
+{
+    CachedThreadSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedThreadSelectByIdBorrowed { guild_id: row.get(0),id: row.get(1),kind: row.get(2),name: row.get(3),owner_id: row.get(4),parent_id: row.get(5),thread_metadata: row.get(6),} }, mapper: |it| { <CachedThreadSelectById>::from(it) },
+    }
+} }}pub mod cached_thread_select_by_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedThreadSelectByIds
+{ pub guild_id : String,pub id : String,pub kind : i16,pub name : Option<String>,pub owner_id : Option<String>,pub parent_id : Option<String>,pub thread_metadata : Option<serde_json::Value>,}pub struct CachedThreadSelectByIdsBorrowed<'a> { pub guild_id : &'a str,pub id : &'a str,pub kind : i16,pub name : Option<&'a str>,pub owner_id : Option<&'a str>,pub parent_id : Option<&'a str>,pub thread_metadata : Option<postgres_types::Json<&'a serde_json::value::RawValue>>,}
+impl<'a> From<CachedThreadSelectByIdsBorrowed<'a>> for CachedThreadSelectByIds
+{
+    fn from(CachedThreadSelectByIdsBorrowed { guild_id,id,kind,name,owner_id,parent_id,thread_metadata,}: CachedThreadSelectByIdsBorrowed<'a>) ->
+    Self { Self { guild_id: guild_id.into(),id: id.into(),kind,name: name.map(|v| v.into()),owner_id: owner_id.map(|v| v.into()),parent_id: parent_id.map(|v| v.into()),thread_metadata: thread_metadata.map(|v| serde_json::from_str(v.0.get()).unwrap()),} }
+}pub struct CachedThreadSelectByIdsQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedThreadSelectByIdsBorrowed,
+    mapper: fn(CachedThreadSelectByIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedThreadSelectByIdsQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedThreadSelectByIdsBorrowed) -> R) ->
+    CachedThreadSelectByIdsQuery<'a,C,R,N>
+    {
+        CachedThreadSelectByIdsQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_select_by_ids() -> CachedThreadSelectByIdsStmt
+{ CachedThreadSelectByIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"id\" = ANY($1)")) } pub struct
+CachedThreadSelectByIdsStmt(cornucopia_async::private::Stmt); impl CachedThreadSelectByIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> CachedThreadSelectByIdsQuery<'a,C,
+CachedThreadSelectByIds, 1>
+{
+    CachedThreadSelectByIdsQuery
+    {
+        client, params: [ids,], stmt: &mut self.0, extractor:
+        |row| { CachedThreadSelectByIdsBorrowed { guild_id: row.get(0),id: row.get(1),kind: row.get(2),name: row.get(3),owner_id: row.get(4),parent_id: row.get(5),thread_metadata: row.get(6),} }, mapper: |it| { <CachedThreadSelectByIds>::from(it) },
+    }
+} }}pub mod cached_thread_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedThreadUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::JsonSql,> { pub guild_id: T1,pub id: T2,pub kind: i16,pub name: Option<T3>,pub owner_id: Option<T4>,pub parent_id: Option<T5>,pub thread_metadata: Option<T6>,}pub fn cached_thread_upsert() -> CachedThreadUpsertStmt
+{ CachedThreadUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedThreads\" (\"guild_id\", \"id\", \"kind\", \"name\", \"owner_id\", \"parent_id\", \"thread_metadata\")
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"guild_id\" = $1,
+        \"kind\" = $3,
+        \"name\" = $4,
+        \"owner_id\" = $5,
+        \"parent_id\" = $6,
+        \"thread_metadata\" = $7")) } pub struct
+CachedThreadUpsertStmt(cornucopia_async::private::Stmt); impl CachedThreadUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::JsonSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,id: &'a T2,kind: &'a i16,name: &'a Option<T3>,owner_id: &'a Option<T4>,parent_id: &'a Option<T5>,thread_metadata: &'a Option<T6>,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,id,kind,name,owner_id,parent_id,thread_metadata,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::JsonSql,>
+cornucopia_async::Params<'a, CachedThreadUpsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedThreadUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedThreadUpsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.id,&params.kind,&params.name,&params.owner_id,&params.parent_id,&params.thread_metadata,)) }
+}}pub mod cached_thread_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedThreadUpsertManyParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::ArraySql<Item = i16>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = Option<T6>>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = Option<T8>>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = Option<T10>>,T12: cornucopia_async::JsonSql,T13: cornucopia_async::ArraySql<Item = Option<T12>>,> { pub guild_id: T2,pub id: T4,pub kind: T5,pub name: T7,pub owner_id: T9,pub parent_id: T11,pub thread_metadata: T13,}pub fn cached_thread_upsert_many() -> CachedThreadUpsertManyStmt
+{ CachedThreadUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedThreads\" (\"guild_id\", \"id\", \"kind\", \"name\", \"owner_id\", \"parent_id\", \"thread_metadata\")
+SELECT DISTINCT ON (\"id\")
+    \"guild_id\",
+    \"id\",
+    \"kind\",
+    \"name\",
+    \"owner_id\",
+    \"parent_id\",
+    \"thread_metadata\"
+FROM
+    unnest(
+        CAST($1 AS TEXT[]),
+        CAST($2 AS TEXT[]),
+        CAST($3 AS SMALLINT[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS TEXT[]),
+        CAST($6 AS TEXT[]),
+        CAST($7 AS JSONB[])
+    ) WITH ORDINALITY AS \"entities\" (\"guild_id\", \"id\", \"kind\", \"name\", \"owner_id\", \"parent_id\", \"thread_metadata\", \"ordinality\")
+ORDER BY
+    \"id\", \"ordinality\" DESC
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"guild_id\" = EXCLUDED.\"guild_id\",
+        \"kind\" = EXCLUDED.\"kind\",
+        \"name\" = EXCLUDED.\"name\",
+        \"owner_id\" = EXCLUDED.\"owner_id\",
+        \"parent_id\" = EXCLUDED.\"parent_id\",
+        \"thread_metadata\" = EXCLUDED.\"thread_metadata\"")) } pub struct
+CachedThreadUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedThreadUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,T5:
+cornucopia_async::ArraySql<Item = i16>,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = Option<T6>>,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::ArraySql<Item = Option<T8>>,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::ArraySql<Item = Option<T10>>,T12:
+cornucopia_async::JsonSql,T13:
+cornucopia_async::ArraySql<Item = Option<T12>>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T2,id: &'a T4,kind: &'a T5,name: &'a T7,owner_id: &'a T9,parent_id: &'a T11,thread_metadata: &'a T13,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,id,kind,name,owner_id,parent_id,thread_metadata,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::ArraySql<Item = i16>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = Option<T6>>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = Option<T8>>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = Option<T10>>,T12: cornucopia_async::JsonSql,T13: cornucopia_async::ArraySql<Item = Option<T12>>,>
+cornucopia_async::Params<'a, CachedThreadUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedThreadUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedThreadUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.id,&params.kind,&params.name,&params.owner_id,&params.parent_id,&params.thread_metadata,)) }
+}}pub mod cached_user_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_user_delete_by_id() -> CachedUserDeleteByIdStmt
+{ CachedUserDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedUserDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedUserDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_user_delete_unreferenced
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_user_delete_unreferenced() -> CachedUserDeleteUnreferencedStmt
+{ CachedUserDeleteUnreferencedStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\" AS \"users\"
+WHERE
+    NOT EXISTS (
+        SELECT
+            1
+        FROM
+            \"DiscordFrontend\".\"Nightly\".\"CachedMembers\" AS \"members\"
+        WHERE
+            \"members\".\"user_id\" = \"users\".\"id\"
+    )")) } pub struct
+CachedUserDeleteUnreferencedStmt(cornucopia_async::private::Stmt); impl CachedUserDeleteUnreferencedStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[]).await
+} }}pub mod cached_user_delete_unreferenced_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_user_delete_unreferenced_by_id() -> CachedUserDeleteUnreferencedByIdStmt
+{ CachedUserDeleteUnreferencedByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+WHERE
+    \"id\" = $1
+    AND NOT EXISTS (
+        SELECT
+            1
+        FROM
+            \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+        WHERE
+            \"user_id\" = $1
+    )")) } pub struct
+CachedUserDeleteUnreferencedByIdStmt(cornucopia_async::private::Stmt); impl CachedUserDeleteUnreferencedByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_user_exists_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_user_exists_by_id() -> CachedUserExistsByIdStmt
+{ CachedUserExistsByIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+    WHERE
+        \"id\" = $1
+)")) } pub struct
+CachedUserExistsByIdStmt(cornucopia_async::private::Stmt); impl CachedUserExistsByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> BoolQuery<'a,C,
+bool, 1>
+{
+    BoolQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }}pub mod cached_user_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedUserSelectById
+{ pub avatar : Option<String>,pub bot : bool,pub id : String,pub discriminator : String,pub global_name : Option<String>,pub name : String,}pub struct CachedUserSelectByIdBorrowed<'a> { pub avatar : Option<&'a str>,pub bot : bool,pub id : &'a str,pub discriminator : &'a str,pub global_name : Option<&'a str>,pub name : &'a str,}
+impl<'a> From<CachedUserSelectByIdBorrowed<'a>> for CachedUserSelectById
+{
+    fn from(CachedUserSelectByIdBorrowed { avatar,bot,id,discriminator,global_name,name,}: CachedUserSelectByIdBorrowed<'a>) ->
+    Self { Self { avatar: avatar.map(|v| v.into()),bot,id: id.into(),discriminator: discriminator.into(),global_name: global_name.map(|v| v.into()),name: name.into(),} }
+}pub struct CachedUserSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedUserSelectByIdBorrowed,
+    mapper: fn(CachedUserSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedUserSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedUserSelectByIdBorrowed) -> R) ->
+    CachedUserSelectByIdQuery<'a,C,R,N>
+    {
+        CachedUserSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_user_select_by_id() -> CachedUserSelectByIdStmt
+{ CachedUserSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedUserSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedUserSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedUserSelectByIdQuery<'a,C,
+CachedUserSelectById, 1>
+{
+    CachedUserSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedUserSelectByIdBorrowed { avatar: row.get(0),bot: row.get(1),id: row.get(2),discriminator: row.get(3),global_name: row.get(4),name: row.get(5),} }, mapper: |it| { <CachedUserSelectById>::from(it) },
+    }
+} }}pub mod cached_user_select_by_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedUserSelectByIds
+{ pub avatar : Option<String>,pub bot : bool,pub id : String,pub discriminator : String,pub global_name : Option<String>,pub name : String,}pub struct CachedUserSelectByIdsBorrowed<'a> { pub avatar : Option<&'a str>,pub bot : bool,pub id : &'a str,pub discriminator : &'a str,pub global_name : Option<&'a str>,pub name : &'a str,}
+impl<'a> From<CachedUserSelectByIdsBorrowed<'a>> for CachedUserSelectByIds
+{
+    fn from(CachedUserSelectByIdsBorrowed { avatar,bot,id,discriminator,global_name,name,}: CachedUserSelectByIdsBorrowed<'a>) ->
+    Self { Self { avatar: avatar.map(|v| v.into()),bot,id: id.into(),discriminator: discriminator.into(),global_name: global_name.map(|v| v.into()),name: name.into(),} }
+}pub struct CachedUserSelectByIdsQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedUserSelectByIdsBorrowed,
+    mapper: fn(CachedUserSelectByIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedUserSelectByIdsQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedUserSelectByIdsBorrowed) -> R) ->
+    CachedUserSelectByIdsQuery<'a,C,R,N>
+    {
+        CachedUserSelectByIdsQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_user_select_by_ids() -> CachedUserSelectByIdsStmt
+{ CachedUserSelectByIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+WHERE
+    \"id\" = ANY($1)")) } pub struct
+CachedUserSelectByIdsStmt(cornucopia_async::private::Stmt); impl CachedUserSelectByIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> CachedUserSelectByIdsQuery<'a,C,
+CachedUserSelectByIds, 1>
+{
+    CachedUserSelectByIdsQuery
+    {
+        client, params: [ids,], stmt: &mut self.0, extractor:
+        |row| { CachedUserSelectByIdsBorrowed { avatar: row.get(0),bot: row.get(1),id: row.get(2),discriminator: row.get(3),global_name: row.get(4),name: row.get(5),} }, mapper: |it| { <CachedUserSelectByIds>::from(it) },
+    }
+} }}pub mod cached_user_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedUserUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,> { pub avatar: Option<T1>,pub id: T2,pub bot: bool,pub name: T3,pub discriminator: T4,pub global_name: Option<T5>,}pub fn cached_user_upsert() -> CachedUserUpsertStmt
+{ CachedUserUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedUsers\" (\"avatar\", \"id\", \"bot\", \"name\", \"discriminator\", \"global_name\")
+VALUES ($1, $2, $3, $4, $5, $6)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"avatar\" = $1,
+        \"bot\" = $3,
+        \"name\" = $4,
+        \"discriminator\" = $5,
+        \"global_name\" = $6")) } pub struct
+CachedUserUpsertStmt(cornucopia_async::private::Stmt); impl CachedUserUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+avatar: &'a Option<T1>,id: &'a T2,bot: &'a bool,name: &'a T3,discriminator: &'a T4,global_name: &'a Option<T5>,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[avatar,id,bot,name,discriminator,global_name,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedUserUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedUserUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedUserUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.avatar,&params.id,&params.bot,&params.name,&params.discriminator,&params.global_name,)) }
+}}pub mod cached_user_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedUserUpsertManyParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = Option<T1>>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::ArraySql<Item = bool>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = T8>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = Option<T10>>,> { pub avatar: T2,pub id: T4,pub bot: T5,pub name: T7,pub discriminator: T9,pub global_name: T11,}pub fn cached_user_upsert_many() -> CachedUserUpsertManyStmt
+{ CachedUserUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedUsers\" (\"avatar\", \"id\", \"bot\", \"name\", \"discriminator\", \"global_name\")
+SELECT DISTINCT ON (\"id\")
+    \"avatar\",
+    \"id\",
+    \"bot\",
+    \"name\",
+    \"discriminator\",
+    \"global_name\"
+FROM
+    unnest(
+        CAST($1 AS TEXT[]),
+        CAST($2 AS TEXT[]),
+        CAST($3 AS BOOLEAN[]),
+        CAST($4 AS TEXT[]),
+        CAST($5 AS TEXT[]),
+        CAST($6 AS TEXT[])
+    ) WITH ORDINALITY AS \"entities\" (\"avatar\", \"id\", \"bot\", \"name\", \"discriminator\", \"global_name\", \"ordinality\")
+ORDER BY
+    \"id\", \"ordinality\" DESC
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"avatar\" = EXCLUDED.\"avatar\",
+        \"bot\" = EXCLUDED.\"bot\",
+        \"name\" = EXCLUDED.\"name\",
+        \"discriminator\" = EXCLUDED.\"discriminator\",
+        \"global_name\" = EXCLUDED.\"global_name\"")) } pub struct
+CachedUserUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedUserUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = Option<T1>>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,T5:
+cornucopia_async::ArraySql<Item = bool>,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = T6>,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::ArraySql<Item = T8>,T10:
+cornucopia_async::StringSql,T11:
+cornucopia_async::ArraySql<Item = Option<T10>>,>(&'a mut self, client: &'a  C,
+avatar: &'a T2,id: &'a T4,bot: &'a T5,name: &'a T7,discriminator: &'a T9,global_name: &'a T11,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[avatar,id,bot,name,discriminator,global_name,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = Option<T1>>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::ArraySql<Item = bool>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = T8>,T10: cornucopia_async::StringSql,T11: cornucopia_async::ArraySql<Item = Option<T10>>,>
+cornucopia_async::Params<'a, CachedUserUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedUserUpsertManyStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedUserUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.avatar,&params.id,&params.bot,&params.name,&params.discriminator,&params.global_name,)) }
+}}pub mod cached_voice_state_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_voice_state_delete_by_guild_id() -> CachedVoiceStateDeleteByGuildIdStmt
+{ CachedVoiceStateDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedVoiceStateDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod cached_voice_state_delete_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateDeleteByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}pub fn cached_voice_state_delete_by_user_id_and_guild_id() -> CachedVoiceStateDeleteByUserIdAndGuildIdStmt
+{ CachedVoiceStateDeleteByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+WHERE
+    \"user_id\" = $1
+    AND \"guild_id\" = $2")) } pub struct
+CachedVoiceStateDeleteByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateDeleteByUserIdAndGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[user_id,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedVoiceStateDeleteByUserIdAndGuildIdParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedVoiceStateDeleteByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateDeleteByUserIdAndGuildIdParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.user_id,&params.guild_id,)) }
+}}pub mod cached_voice_state_delete_stale_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateDeleteStaleByGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub user_ids: T3,}pub fn cached_voice_state_delete_stale_by_guild_id() -> CachedVoiceStateDeleteStaleByGuildIdStmt
+{ CachedVoiceStateDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+WHERE
+    \"guild_id\" = $1
+    AND NOT (\"user_id\" = ANY($2))")) } pub struct
+CachedVoiceStateDeleteStaleByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateDeleteStaleByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,user_ids: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,user_ids,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,>
+cornucopia_async::Params<'a, CachedVoiceStateDeleteStaleByGuildIdParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedVoiceStateDeleteStaleByGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateDeleteStaleByGuildIdParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.user_ids,)) }
+}}pub mod cached_voice_state_exists_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateExistsByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> bool,
+    mapper: fn(bool) -> T,
+} impl<'a, C, T:'a, const N: usize> BoolQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) ->
+    BoolQuery<'a,C,R,N>
+    {
+        BoolQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_voice_state_exists_by_user_id_and_guild_id() -> CachedVoiceStateExistsByUserIdAndGuildIdStmt
+{ CachedVoiceStateExistsByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+    WHERE
+        \"user_id\" = $1
+        AND \"guild_id\" = $2
+)")) } pub struct
+CachedVoiceStateExistsByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateExistsByUserIdAndGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> BoolQuery<'a,C,
+bool, 2>
+{
+    BoolQuery
+    {
+        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedVoiceStateExistsByUserIdAndGuildIdParams<T1,T2,>, BoolQuery<'a, C,
+bool, 2>, C> for CachedVoiceStateExistsByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateExistsByUserIdAndGuildIdParams<T1,T2,>) -> BoolQuery<'a, C,
+    bool, 2>
+    { self.bind(client, &params.user_id,&params.guild_id,) }
+}}pub mod cached_voice_state_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedVoiceStateSelectByGuildId
+{ pub channel_id : Option<String>,pub deaf : bool,pub guild_id : String,pub mute : bool,pub request_to_speak_timestamp : Option<time::OffsetDateTime>,pub self_deaf : bool,pub self_mute : bool,pub self_stream : bool,pub self_video : bool,pub session_id : String,pub suppress : bool,pub user_id : String,}pub struct CachedVoiceStateSelectByGuildIdBorrowed<'a> { pub channel_id : Option<&'a str>,pub deaf : bool,pub guild_id : &'a str,pub mute : bool,pub request_to_speak_timestamp : Option<time::OffsetDateTime>,pub self_deaf : bool,pub self_mute : bool,pub self_stream : bool,pub self_video : bool,pub session_id : &'a str,pub suppress : bool,pub user_id : &'a str,}
+impl<'a> From<CachedVoiceStateSelectByGuildIdBorrowed<'a>> for CachedVoiceStateSelectByGuildId
+{
+    fn from(CachedVoiceStateSelectByGuildIdBorrowed { channel_id,deaf,guild_id,mute,request_to_speak_timestamp,self_deaf,self_mute,self_stream,self_video,session_id,suppress,user_id,}: CachedVoiceStateSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { channel_id: channel_id.map(|v| v.into()),deaf,guild_id: guild_id.into(),mute,request_to_speak_timestamp,self_deaf,self_mute,self_stream,self_video,session_id: session_id.into(),suppress,user_id: user_id.into(),} }
+}pub struct CachedVoiceStateSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedVoiceStateSelectByGuildIdBorrowed,
+    mapper: fn(CachedVoiceStateSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedVoiceStateSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedVoiceStateSelectByGuildIdBorrowed) -> R) ->
+    CachedVoiceStateSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedVoiceStateSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_voice_state_select_by_guild_id() -> CachedVoiceStateSelectByGuildIdStmt
+{ CachedVoiceStateSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedVoiceStateSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedVoiceStateSelectByGuildIdQuery<'a,C,
+CachedVoiceStateSelectByGuildId, 1>
 {
-    fn from(CachedMemberSelectByGuildIdBorrowed { flags,joined_at,nick,roles,guild_id,user_id,}: CachedMemberSelectByGuildIdBorrowed<'a>) ->
-    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),} }
-}pub struct CachedMemberSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    CachedVoiceStateSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedVoiceStateSelectByGuildIdBorrowed { channel_id: row.get(0),deaf: row.get(1),guild_id: row.get(2),mute: row.get(3),request_to_speak_timestamp: row.get(4),self_deaf: row.get(5),self_mute: row.get(6),self_stream: row.get(7),self_video: row.get(8),session_id: row.get(9),suppress: row.get(10),user_id: row.get(11),} }, mapper: |it| { <CachedVoiceStateSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_voice_state_select_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateSelectByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedVoiceStateSelectByUserIdAndGuildId
+{ pub channel_id : Option<String>,pub deaf : bool,pub guild_id : String,pub mute : bool,pub request_to_speak_timestamp : Option<time::OffsetDateTime>,pub self_deaf : bool,pub self_mute : bool,pub self_stream : bool,pub self_video : bool,pub session_id : String,pub suppress : bool,pub user_id : String,}pub struct CachedVoiceStateSelectByUserIdAndGuildIdBorrowed<'a> { pub channel_id : Option<&'a str>,pub deaf : bool,pub guild_id : &'a str,pub mute : bool,pub request_to_speak_timestamp : Option<time::OffsetDateTime>,pub self_deaf : bool,pub self_mute : bool,pub self_stream : bool,pub self_video : bool,pub session_id : &'a str,pub suppress : bool,pub user_id : &'a str,}
+impl<'a> From<CachedVoiceStateSelectByUserIdAndGuildIdBorrowed<'a>> for CachedVoiceStateSelectByUserIdAndGuildId
+{
+    fn from(CachedVoiceStateSelectByUserIdAndGuildIdBorrowed { channel_id,deaf,guild_id,mute,request_to_speak_timestamp,self_deaf,self_mute,self_stream,self_video,session_id,suppress,user_id,}: CachedVoiceStateSelectByUserIdAndGuildIdBorrowed<'a>) ->
+    Self { Self { channel_id: channel_id.map(|v| v.into()),deaf,guild_id: guild_id.into(),mute,request_to_speak_timestamp,self_deaf,self_mute,self_stream,self_video,session_id: session_id.into(),suppress,user_id: user_id.into(),} }
+}pub struct CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByGuildIdBorrowed,
-    mapper: fn(CachedMemberSelectByGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedVoiceStateSelectByUserIdAndGuildIdBorrowed,
+    mapper: fn(CachedVoiceStateSelectByUserIdAndGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedMemberSelectByGuildIdBorrowed) -> R) ->
-    CachedMemberSelectByGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(CachedVoiceStateSelectByUserIdAndGuildIdBorrowed) -> R) ->
+    CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a,C,R,N>
     {
-        CachedMemberSelectByGuildIdQuery
+        CachedVoiceStateSelectByUserIdAndGuildIdQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -313,45 +4214,56 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_member_select_by_guild_id() -> CachedMemberSelectByGuildIdStmt
-{ CachedMemberSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn cached_voice_state_select_by_user_id_and_guild_id() -> CachedVoiceStateSelectByUserIdAndGuildIdStmt
+{ CachedVoiceStateSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
 WHERE
-    \"guild_id\" = $1")) } pub struct
-CachedMemberSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByGuildIdStmt
+    \"user_id\" = $1
+    AND \"guild_id\" = $2")) } pub struct
+CachedVoiceStateSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateSelectByUserIdAndGuildIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
+cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-guild_id: &'a T1,) -> CachedMemberSelectByGuildIdQuery<'a,C,
-CachedMemberSelectByGuildId, 1>
+user_id: &'a T1,guild_id: &'a T2,) -> CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a,C,
+CachedVoiceStateSelectByUserIdAndGuildId, 2>
 {
-    CachedMemberSelectByGuildIdQuery
+    CachedVoiceStateSelectByUserIdAndGuildIdQuery
     {
-        client, params: [guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedMemberSelectByGuildIdBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),} }, mapper: |it| { <CachedMemberSelectByGuildId>::from(it) },
+        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedVoiceStateSelectByUserIdAndGuildIdBorrowed { channel_id: row.get(0),deaf: row.get(1),guild_id: row.get(2),mute: row.get(3),request_to_speak_timestamp: row.get(4),self_deaf: row.get(5),self_mute: row.get(6),self_stream: row.get(7),self_video: row.get(8),session_id: row.get(9),suppress: row.get(10),user_id: row.get(11),} }, mapper: |it| { <CachedVoiceStateSelectByUserIdAndGuildId>::from(it) },
     }
-} }}pub mod cached_member_select_by_user_id_and_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberSelectByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByUserIdAndGuildId
-{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,}pub struct CachedMemberSelectByUserIdAndGuildIdBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,}
-impl<'a> From<CachedMemberSelectByUserIdAndGuildIdBorrowed<'a>> for CachedMemberSelectByUserIdAndGuildId
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedVoiceStateSelectByUserIdAndGuildIdParams<T1,T2,>, CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a, C,
+CachedVoiceStateSelectByUserIdAndGuildId, 2>, C> for CachedVoiceStateSelectByUserIdAndGuildIdStmt
 {
-    fn from(CachedMemberSelectByUserIdAndGuildIdBorrowed { flags,joined_at,nick,roles,guild_id,user_id,}: CachedMemberSelectByUserIdAndGuildIdBorrowed<'a>) ->
-    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),} }
-}pub struct CachedMemberSelectByUserIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateSelectByUserIdAndGuildIdParams<T1,T2,>) -> CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a, C,
+    CachedVoiceStateSelectByUserIdAndGuildId, 2>
+    { self.bind(client, &params.user_id,&params.guild_id,) }
+}}pub mod cached_voice_state_select_by_user_ids_and_guild_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateSelectByUserIdsAndGuildIdsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,> { pub user_ids: T2,pub guild_ids: T4,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedVoiceStateSelectByUserIdsAndGuildIds
+{ pub channel_id : Option<String>,pub deaf : bool,pub guild_id : String,pub mute : bool,pub request_to_speak_timestamp : Option<time::OffsetDateTime>,pub self_deaf : bool,pub self_mute : bool,pub self_stream : bool,pub self_video : bool,pub session_id : String,pub suppress : bool,pub user_id : String,}pub struct CachedVoiceStateSelectByUserIdsAndGuildIdsBorrowed<'a> { pub channel_id : Option<&'a str>,pub deaf : bool,pub guild_id : &'a str,pub mute : bool,pub request_to_speak_timestamp : Option<time::OffsetDateTime>,pub self_deaf : bool,pub self_mute : bool,pub self_stream : bool,pub self_video : bool,pub session_id : &'a str,pub suppress : bool,pub user_id : &'a str,}
+impl<'a> From<CachedVoiceStateSelectByUserIdsAndGuildIdsBorrowed<'a>> for CachedVoiceStateSelectByUserIdsAndGuildIds
+{
+    fn from(CachedVoiceStateSelectByUserIdsAndGuildIdsBorrowed { channel_id,deaf,guild_id,mute,request_to_speak_timestamp,self_deaf,self_mute,self_stream,self_video,session_id,suppress,user_id,}: CachedVoiceStateSelectByUserIdsAndGuildIdsBorrowed<'a>) ->
+    Self { Self { channel_id: channel_id.map(|v| v.into()),deaf,guild_id: guild_id.into(),mute,request_to_speak_timestamp,self_deaf,self_mute,self_stream,self_video,session_id: session_id.into(),suppress,user_id: user_id.into(),} }
+}pub struct CachedVoiceStateSelectByUserIdsAndGuildIdsQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByUserIdAndGuildIdBorrowed,
-    mapper: fn(CachedMemberSelectByUserIdAndGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByUserIdAndGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedVoiceStateSelectByUserIdsAndGuildIdsBorrowed,
+    mapper: fn(CachedVoiceStateSelectByUserIdsAndGuildIdsBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedVoiceStateSelectByUserIdsAndGuildIdsQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedMemberSelectByUserIdAndGuildIdBorrowed) -> R) ->
-    CachedMemberSelectByUserIdAndGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(CachedVoiceStateSelectByUserIdsAndGuildIdsBorrowed) -> R) ->
+    CachedVoiceStateSelectByUserIdsAndGuildIdsQuery<'a,C,R,N>
     {
-        CachedMemberSelectByUserIdAndGuildIdQuery
+        CachedVoiceStateSelectByUserIdsAndGuildIdsQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -378,87 +4290,188 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_member_select_by_user_id_and_guild_id() -> CachedMemberSelectByUserIdAndGuildIdStmt
-{ CachedMemberSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn cached_voice_state_select_by_user_ids_and_guild_ids() -> CachedVoiceStateSelectByUserIdsAndGuildIdsStmt
+{ CachedVoiceStateSelectByUserIdsAndGuildIdsStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
 WHERE
-    user_id = $1 AND
-    guild_id = $2")) } pub struct
-CachedMemberSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByUserIdAndGuildIdStmt
+    (\"user_id\", \"guild_id\") IN (
+        SELECT
+            *
+        FROM
+            unnest(CAST($1 AS TEXT[]), CAST($2 AS TEXT[]))
+    )")) } pub struct
+CachedVoiceStateSelectByUserIdsAndGuildIdsStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateSelectByUserIdsAndGuildIdsStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
-cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-user_id: &'a T1,guild_id: &'a T2,) -> CachedMemberSelectByUserIdAndGuildIdQuery<'a,C,
-CachedMemberSelectByUserIdAndGuildId, 2>
+cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,>(&'a mut self, client: &'a  C,
+user_ids: &'a T2,guild_ids: &'a T4,) -> CachedVoiceStateSelectByUserIdsAndGuildIdsQuery<'a,C,
+CachedVoiceStateSelectByUserIdsAndGuildIds, 2>
 {
-    CachedMemberSelectByUserIdAndGuildIdQuery
+    CachedVoiceStateSelectByUserIdsAndGuildIdsQuery
     {
-        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedMemberSelectByUserIdAndGuildIdBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),} }, mapper: |it| { <CachedMemberSelectByUserIdAndGuildId>::from(it) },
+        client, params: [user_ids,guild_ids,], stmt: &mut self.0, extractor:
+        |row| { CachedVoiceStateSelectByUserIdsAndGuildIdsBorrowed { channel_id: row.get(0),deaf: row.get(1),guild_id: row.get(2),mute: row.get(3),request_to_speak_timestamp: row.get(4),self_deaf: row.get(5),self_mute: row.get(6),self_stream: row.get(7),self_video: row.get(8),session_id: row.get(9),suppress: row.get(10),user_id: row.get(11),} }, mapper: |it| { <CachedVoiceStateSelectByUserIdsAndGuildIds>::from(it) },
     }
-} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
-CachedMemberSelectByUserIdAndGuildIdParams<T1,T2,>, CachedMemberSelectByUserIdAndGuildIdQuery<'a, C,
-CachedMemberSelectByUserIdAndGuildId, 2>, C> for CachedMemberSelectByUserIdAndGuildIdStmt
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,> cornucopia_async::Params<'a,
+CachedVoiceStateSelectByUserIdsAndGuildIdsParams<T1,T2,T3,T4,>, CachedVoiceStateSelectByUserIdsAndGuildIdsQuery<'a, C,
+CachedVoiceStateSelectByUserIdsAndGuildIds, 2>, C> for CachedVoiceStateSelectByUserIdsAndGuildIdsStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedMemberSelectByUserIdAndGuildIdParams<T1,T2,>) -> CachedMemberSelectByUserIdAndGuildIdQuery<'a, C,
-    CachedMemberSelectByUserIdAndGuildId, 2>
-    { self.bind(client, &params.user_id,&params.guild_id,) }
-}}pub mod cached_member_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,> { pub flags: i64,pub joined_at: Option<time::OffsetDateTime>,pub nick: Option<T1>,pub user_id: T2,pub guild_id: T3,pub roles: T5,}pub fn cached_member_upsert() -> CachedMemberUpsertStmt
-{ CachedMemberUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedMembers\" (\"flags\", \"joined_at\", \"nick\", \"user_id\", \"guild_id\", \"roles\")
-VALUES ($1, $2, $3, $4, $5, $6)
-ON CONFLICT (\"user_id\", \"guild_id\") DO UPDATE
+    CachedVoiceStateSelectByUserIdsAndGuildIdsParams<T1,T2,T3,T4,>) -> CachedVoiceStateSelectByUserIdsAndGuildIdsQuery<'a, C,
+    CachedVoiceStateSelectByUserIdsAndGuildIds, 2>
+    { self.bind(client, &params.user_ids,&params.guild_ids,) }
+}}pub mod cached_voice_state_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,> { pub channel_id: Option<T1>,pub deaf: bool,pub guild_id: T2,pub mute: bool,pub request_to_speak_timestamp: Option<time::OffsetDateTime>,pub self_deaf: bool,pub self_mute: bool,pub self_stream: bool,pub self_video: bool,pub session_id: T3,pub suppress: bool,pub user_id: T4,}pub fn cached_voice_state_upsert() -> CachedVoiceStateUpsertStmt
+{ CachedVoiceStateUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\" (\"channel_id\", \"deaf\", \"guild_id\", \"mute\", \"request_to_speak_timestamp\", \"self_deaf\", \"self_mute\", \"self_stream\", \"self_video\", \"session_id\", \"suppress\", \"user_id\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+ON CONFLICT (\"guild_id\", \"user_id\") DO UPDATE
     SET
-        \"flags\" = $1,
-        \"joined_at\" = $2,
-        \"nick\" = $3,
-        \"roles\" = $6")) } pub struct
-CachedMemberUpsertStmt(cornucopia_async::private::Stmt); impl CachedMemberUpsertStmt
+        \"channel_id\" = $1,
+        \"deaf\" = $2,
+        \"mute\" = $4,
+        \"request_to_speak_timestamp\" = $5,
+        \"self_deaf\" = $6,
+        \"self_mute\" = $7,
+        \"self_stream\" = $8,
+        \"self_video\" = $9,
+        \"session_id\" = $10,
+        \"suppress\" = $11")) } pub struct
+CachedVoiceStateUpsertStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateUpsertStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+channel_id: &'a Option<T1>,deaf: &'a bool,guild_id: &'a T2,mute: &'a bool,request_to_speak_timestamp: &'a Option<time::OffsetDateTime>,self_deaf: &'a bool,self_mute: &'a bool,self_stream: &'a bool,self_video: &'a bool,session_id: &'a T3,suppress: &'a bool,user_id: &'a T4,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[channel_id,deaf,guild_id,mute,request_to_speak_timestamp,self_deaf,self_mute,self_stream,self_video,session_id,suppress,user_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedVoiceStateUpsertParams<T1,T2,T3,T4,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedVoiceStateUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateUpsertParams<T1,T2,T3,T4,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.channel_id,&params.deaf,&params.guild_id,&params.mute,&params.request_to_speak_timestamp,&params.self_deaf,&params.self_mute,&params.self_stream,&params.self_video,&params.session_id,&params.suppress,&params.user_id,)) }
+}}pub mod cached_voice_state_upsert_many
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateUpsertManyParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = Option<T1>>,T3: cornucopia_async::ArraySql<Item = bool>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,T6: cornucopia_async::ArraySql<Item = bool>,T7: cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T8: cornucopia_async::ArraySql<Item = bool>,T9: cornucopia_async::ArraySql<Item = bool>,T10: cornucopia_async::ArraySql<Item = bool>,T11: cornucopia_async::ArraySql<Item = bool>,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = T12>,T14: cornucopia_async::ArraySql<Item = bool>,T15: cornucopia_async::StringSql,T16: cornucopia_async::ArraySql<Item = T15>,> { pub channel_id: T2,pub deaf: T3,pub guild_id: T5,pub mute: T6,pub request_to_speak_timestamp: T7,pub self_deaf: T8,pub self_mute: T9,pub self_stream: T10,pub self_video: T11,pub session_id: T13,pub suppress: T14,pub user_id: T16,}pub fn cached_voice_state_upsert_many() -> CachedVoiceStateUpsertManyStmt
+{ CachedVoiceStateUpsertManyStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\" (\"channel_id\", \"deaf\", \"guild_id\", \"mute\", \"request_to_speak_timestamp\", \"self_deaf\", \"self_mute\", \"self_stream\", \"self_video\", \"session_id\", \"suppress\", \"user_id\")
+SELECT DISTINCT ON (\"guild_id\", \"user_id\")
+    \"channel_id\",
+    \"deaf\",
+    \"guild_id\",
+    \"mute\",
+    \"request_to_speak_timestamp\",
+    \"self_deaf\",
+    \"self_mute\",
+    \"self_stream\",
+    \"self_video\",
+    \"session_id\",
+    \"suppress\",
+    \"user_id\"
+FROM
+    unnest(
+        CAST($1 AS TEXT[]),
+        CAST($2 AS BOOLEAN[]),
+        CAST($3 AS TEXT[]),
+        CAST($4 AS BOOLEAN[]),
+        CAST($5 AS TIMESTAMP WITH TIME ZONE[]),
+        CAST($6 AS BOOLEAN[]),
+        CAST($7 AS BOOLEAN[]),
+        CAST($8 AS BOOLEAN[]),
+        CAST($9 AS BOOLEAN[]),
+        CAST($10 AS TEXT[]),
+        CAST($11 AS BOOLEAN[]),
+        CAST($12 AS TEXT[])
+    ) WITH ORDINALITY AS \"entities\" (\"channel_id\", \"deaf\", \"guild_id\", \"mute\", \"request_to_speak_timestamp\", \"self_deaf\", \"self_mute\", \"self_stream\", \"self_video\", \"session_id\", \"suppress\", \"user_id\", \"ordinality\")
+ORDER BY
+    \"guild_id\", \"user_id\", \"ordinality\" DESC
+ON CONFLICT (\"guild_id\", \"user_id\") DO UPDATE
+    SET
+        \"channel_id\" = EXCLUDED.\"channel_id\",
+        \"deaf\" = EXCLUDED.\"deaf\",
+        \"mute\" = EXCLUDED.\"mute\",
+        \"request_to_speak_timestamp\" = EXCLUDED.\"request_to_speak_timestamp\",
+        \"self_deaf\" = EXCLUDED.\"self_deaf\",
+        \"self_mute\" = EXCLUDED.\"self_mute\",
+        \"self_stream\" = EXCLUDED.\"self_stream\",
+        \"self_video\" = EXCLUDED.\"self_video\",
+        \"session_id\" = EXCLUDED.\"session_id\",
+        \"suppress\" = EXCLUDED.\"suppress\"")) } pub struct
+CachedVoiceStateUpsertManyStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateUpsertManyStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = Option<T1>>,T3:
+cornucopia_async::ArraySql<Item = bool>,T4:
 cornucopia_async::StringSql,T5:
-cornucopia_async::ArraySql<Item = T4>,>(&'a mut self, client: &'a  C,
-flags: &'a i64,joined_at: &'a Option<time::OffsetDateTime>,nick: &'a Option<T1>,user_id: &'a T2,guild_id: &'a T3,roles: &'a T5,) -> Result<u64, tokio_postgres::Error>
+cornucopia_async::ArraySql<Item = T4>,T6:
+cornucopia_async::ArraySql<Item = bool>,T7:
+cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T8:
+cornucopia_async::ArraySql<Item = bool>,T9:
+cornucopia_async::ArraySql<Item = bool>,T10:
+cornucopia_async::ArraySql<Item = bool>,T11:
+cornucopia_async::ArraySql<Item = bool>,T12:
+cornucopia_async::StringSql,T13:
+cornucopia_async::ArraySql<Item = T12>,T14:
+cornucopia_async::ArraySql<Item = bool>,T15:
+cornucopia_async::StringSql,T16:
+cornucopia_async::ArraySql<Item = T15>,>(&'a mut self, client: &'a  C,
+channel_id: &'a T2,deaf: &'a T3,guild_id: &'a T5,mute: &'a T6,request_to_speak_timestamp: &'a T7,self_deaf: &'a T8,self_mute: &'a T9,self_stream: &'a T10,self_video: &'a T11,session_id: &'a T13,suppress: &'a T14,user_id: &'a T16,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[flags,joined_at,nick,user_id,guild_id,roles,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,>
-cornucopia_async::Params<'a, CachedMemberUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedMemberUpsertStmt
+    client.execute(stmt, &[channel_id,deaf,guild_id,mute,request_to_speak_timestamp,self_deaf,self_mute,self_stream,self_video,session_id,suppress,user_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = Option<T1>>,T3: cornucopia_async::ArraySql<Item = bool>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,T6: cornucopia_async::ArraySql<Item = bool>,T7: cornucopia_async::ArraySql<Item = Option<time::OffsetDateTime>>,T8: cornucopia_async::ArraySql<Item = bool>,T9: cornucopia_async::ArraySql<Item = bool>,T10: cornucopia_async::ArraySql<Item = bool>,T11: cornucopia_async::ArraySql<Item = bool>,T12: cornucopia_async::StringSql,T13: cornucopia_async::ArraySql<Item = T12>,T14: cornucopia_async::ArraySql<Item = bool>,T15: cornucopia_async::StringSql,T16: cornucopia_async::ArraySql<Item = T15>,>
+cornucopia_async::Params<'a, CachedVoiceStateUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedVoiceStateUpsertManyStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedMemberUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    CachedVoiceStateUpsertManyParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,T11,T12,T13,T14,T15,T16,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.flags,&params.joined_at,&params.nick,&params.user_id,&params.guild_id,&params.roles,)) }
-}}pub mod cached_role_select_by_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByGuildId
-{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByGuildIdBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
-impl<'a> From<CachedRoleSelectByGuildIdBorrowed<'a>> for CachedRoleSelectByGuildId
+    { Box::pin(self.bind(client, &params.channel_id,&params.deaf,&params.guild_id,&params.mute,&params.request_to_speak_timestamp,&params.self_deaf,&params.self_mute,&params.self_stream,&params.self_video,&params.session_id,&params.suppress,&params.user_id,)) }
+}}pub mod gateway_session_delete_by_shard_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn gateway_session_delete_by_shard_id() -> GatewaySessionDeleteByShardIdStmt
+{ GatewaySessionDeleteByShardIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"GatewaySessions\"
+WHERE
+    \"shard_id\" = $1")) } pub struct
+GatewaySessionDeleteByShardIdStmt(cornucopia_async::private::Stmt); impl GatewaySessionDeleteByShardIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+shard_id: &'a i32,) -> Result<u64, tokio_postgres::Error>
 {
-    fn from(CachedRoleSelectByGuildIdBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByGuildIdBorrowed<'a>) ->
-    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
-}pub struct CachedRoleSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[shard_id,]).await
+} }}pub mod gateway_session_select_all
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct GatewaySessionSelectAll
+{ pub shard_id : i32,pub shard_total : i32,pub session_id : String,pub sequence : i64,pub resume_url : Option<String>,}pub struct GatewaySessionSelectAllBorrowed<'a> { pub shard_id : i32,pub shard_total : i32,pub session_id : &'a str,pub sequence : i64,pub resume_url : Option<&'a str>,}
+impl<'a> From<GatewaySessionSelectAllBorrowed<'a>> for GatewaySessionSelectAll
+{
+    fn from(GatewaySessionSelectAllBorrowed { shard_id,shard_total,session_id,sequence,resume_url,}: GatewaySessionSelectAllBorrowed<'a>) ->
+    Self { Self { shard_id,shard_total,session_id: session_id.into(),sequence,resume_url: resume_url.map(|v| v.into()),} }
+}pub struct GatewaySessionSelectAllQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByGuildIdBorrowed,
-    mapper: fn(CachedRoleSelectByGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> GatewaySessionSelectAllBorrowed,
+    mapper: fn(GatewaySessionSelectAllBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> GatewaySessionSelectAllQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedRoleSelectByGuildIdBorrowed) -> R) ->
-    CachedRoleSelectByGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(GatewaySessionSelectAllBorrowed) -> R) ->
+    GatewaySessionSelectAllQuery<'a,C,R,N>
     {
-        CachedRoleSelectByGuildIdQuery
+        GatewaySessionSelectAllQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -485,45 +4498,69 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_role_select_by_guild_id() -> CachedRoleSelectByGuildIdStmt
-{ CachedRoleSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
-    *
+}pub fn gateway_session_select_all() -> GatewaySessionSelectAllStmt
+{ GatewaySessionSelectAllStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"shard_id\",
+    \"shard_total\",
+    \"session_id\",
+    \"sequence\",
+    \"resume_url\"
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
-WHERE
-    \"guild_id\" = $1")) } pub struct
-CachedRoleSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByGuildIdStmt
+    \"DiscordFrontend\".\"Nightly\".\"GatewaySessions\"")) } pub struct
+GatewaySessionSelectAllStmt(cornucopia_async::private::Stmt); impl GatewaySessionSelectAllStmt
 { pub fn bind<'a, C:
-GenericClient,T1:
-cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-guild_id: &'a T1,) -> CachedRoleSelectByGuildIdQuery<'a,C,
-CachedRoleSelectByGuildId, 1>
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> GatewaySessionSelectAllQuery<'a,C,
+GatewaySessionSelectAll, 0>
 {
-    CachedRoleSelectByGuildIdQuery
+    GatewaySessionSelectAllQuery
     {
-        client, params: [guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedRoleSelectByGuildIdBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByGuildId>::from(it) },
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { GatewaySessionSelectAllBorrowed { shard_id: row.get(0),shard_total: row.get(1),session_id: row.get(2),sequence: row.get(3),resume_url: row.get(4),} }, mapper: |it| { <GatewaySessionSelectAll>::from(it) },
     }
-} }}pub mod cached_role_select_by_id_and_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleSelectByIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByIdAndGuildId
-{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByIdAndGuildIdBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
-impl<'a> From<CachedRoleSelectByIdAndGuildIdBorrowed<'a>> for CachedRoleSelectByIdAndGuildId
+} }}pub mod gateway_session_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct GatewaySessionUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub shard_id: i32,pub shard_total: i32,pub session_id: T1,pub sequence: i64,pub resume_url: Option<T2>,}pub fn gateway_session_upsert() -> GatewaySessionUpsertStmt
+{ GatewaySessionUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"GatewaySessions\" (\"shard_id\", \"shard_total\", \"session_id\", \"sequence\", \"resume_url\", \"updated_at\")
+VALUES ($1, $2, $3, $4, $5, now())
+ON CONFLICT (\"shard_id\") DO UPDATE
+    SET
+        \"shard_total\" = $2,
+        \"session_id\" = $3,
+        \"sequence\" = $4,
+        \"resume_url\" = COALESCE($5, \"GatewaySessions\".\"resume_url\"),
+        \"updated_at\" = now()")) } pub struct
+GatewaySessionUpsertStmt(cornucopia_async::private::Stmt); impl GatewaySessionUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+shard_id: &'a i32,shard_total: &'a i32,session_id: &'a T1,sequence: &'a i64,resume_url: &'a Option<T2>,) -> Result<u64, tokio_postgres::Error>
 {
-    fn from(CachedRoleSelectByIdAndGuildIdBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByIdAndGuildIdBorrowed<'a>) ->
-    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
-}pub struct CachedRoleSelectByIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[shard_id,shard_total,session_id,sequence,resume_url,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, GatewaySessionUpsertParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for GatewaySessionUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GatewaySessionUpsertParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.shard_id,&params.shard_total,&params.session_id,&params.sequence,&params.resume_url,)) }
+}}pub mod identify_bucket_acquire
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct IdentifyBucketAcquireParams { pub bucket_id: i32,pub wait_seconds: i64,}pub struct I32Query<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByIdAndGuildIdBorrowed,
-    mapper: fn(CachedRoleSelectByIdAndGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByIdAndGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> i32,
+    mapper: fn(i32) -> T,
+} impl<'a, C, T:'a, const N: usize> I32Query<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedRoleSelectByIdAndGuildIdBorrowed) -> R) ->
-    CachedRoleSelectByIdAndGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(i32) -> R) ->
+    I32Query<'a,C,R,N>
     {
-        CachedRoleSelectByIdAndGuildIdQuery
+        I32Query
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -550,88 +4587,48 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_role_select_by_id_and_guild_id() -> CachedRoleSelectByIdAndGuildIdStmt
-{ CachedRoleSelectByIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
-    *
-FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
-WHERE
-    \"id\" = $1 AND
-    \"guild_id\" = $2")) } pub struct
-CachedRoleSelectByIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByIdAndGuildIdStmt
+}pub fn identify_bucket_acquire() -> IdentifyBucketAcquireStmt
+{ IdentifyBucketAcquireStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"IdentifyBuckets\" (\"bucket_id\", \"last_identify\")
+VALUES ($1, now())
+ON CONFLICT (\"bucket_id\") DO UPDATE
+    SET
+        \"last_identify\" = now()
+    WHERE \"IdentifyBuckets\".\"last_identify\" <= now() - CAST($2 AS BIGINT) * INTERVAL '1 second'
+RETURNING \"bucket_id\"")) } pub struct
+IdentifyBucketAcquireStmt(cornucopia_async::private::Stmt); impl IdentifyBucketAcquireStmt
 { pub fn bind<'a, C:
-GenericClient,T1:
-cornucopia_async::StringSql,T2:
-cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,guild_id: &'a T2,) -> CachedRoleSelectByIdAndGuildIdQuery<'a,C,
-CachedRoleSelectByIdAndGuildId, 2>
+GenericClient,>(&'a mut self, client: &'a  C,
+bucket_id: &'a i32,wait_seconds: &'a i64,) -> I32Query<'a,C,
+i32, 2>
 {
-    CachedRoleSelectByIdAndGuildIdQuery
+    I32Query
     {
-        client, params: [id,guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedRoleSelectByIdAndGuildIdBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByIdAndGuildId>::from(it) },
+        client, params: [bucket_id,wait_seconds,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
     }
-} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
-CachedRoleSelectByIdAndGuildIdParams<T1,T2,>, CachedRoleSelectByIdAndGuildIdQuery<'a, C,
-CachedRoleSelectByIdAndGuildId, 2>, C> for CachedRoleSelectByIdAndGuildIdStmt
-{
-    fn
-    params(&'a mut self, client: &'a  C, params: &'a
-    CachedRoleSelectByIdAndGuildIdParams<T1,T2,>) -> CachedRoleSelectByIdAndGuildIdQuery<'a, C,
-    CachedRoleSelectByIdAndGuildId, 2>
-    { self.bind(client, &params.id,&params.guild_id,) }
-}}pub mod cached_role_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub color: i64,pub icon: Option<T1>,pub id: T2,pub guild_id: T3,pub flags: i32,pub hoist: bool,pub managed: bool,pub mentionable: bool,pub position: i32,}pub fn cached_role_upsert() -> CachedRoleUpsertStmt
-{ CachedRoleUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedRoles\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"position\")
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-ON CONFLICT (\"id\", \"guild_id\") DO UPDATE
-    SET
-        \"color\" = $1,
-        \"icon\" = $2,
-        \"flags\" = $5,
-        \"hoist\" = $6,
-        \"managed\" = $7,
-        \"mentionable\" = $8,
-        \"position\" = $9")) } pub struct
-CachedRoleUpsertStmt(cornucopia_async::private::Stmt); impl CachedRoleUpsertStmt
-{ pub async fn bind<'a, C:
-GenericClient,T1:
-cornucopia_async::StringSql,T2:
-cornucopia_async::StringSql,T3:
-cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-color: &'a i64,icon: &'a Option<T1>,id: &'a T2,guild_id: &'a T3,flags: &'a i32,hoist: &'a bool,managed: &'a bool,mentionable: &'a bool,position: &'a i32,) -> Result<u64, tokio_postgres::Error>
-{
-    let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[color,icon,id,guild_id,flags,hoist,managed,mentionable,position,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, CachedRoleUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedRoleUpsertStmt
+} }impl <'a, C: GenericClient,> cornucopia_async::Params<'a,
+IdentifyBucketAcquireParams, I32Query<'a, C,
+i32, 2>, C> for IdentifyBucketAcquireStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedRoleUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-    tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.color,&params.icon,&params.id,&params.guild_id,&params.flags,&params.hoist,&params.managed,&params.mentionable,&params.position,)) }
-}}pub mod cached_user_select_by_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedUserSelectById
-{ pub avatar : Option<String>,pub bot : bool,pub id : String,pub discriminator : String,pub global_name : Option<String>,pub name : String,}pub struct CachedUserSelectByIdBorrowed<'a> { pub avatar : Option<&'a str>,pub bot : bool,pub id : &'a str,pub discriminator : &'a str,pub global_name : Option<&'a str>,pub name : &'a str,}
-impl<'a> From<CachedUserSelectByIdBorrowed<'a>> for CachedUserSelectById
-{
-    fn from(CachedUserSelectByIdBorrowed { avatar,bot,id,discriminator,global_name,name,}: CachedUserSelectByIdBorrowed<'a>) ->
-    Self { Self { avatar: avatar.map(|v| v.into()),bot,id: id.into(),discriminator: discriminator.into(),global_name: global_name.map(|v| v.into()),name: name.into(),} }
-}pub struct CachedUserSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+    IdentifyBucketAcquireParams) -> I32Query<'a, C,
+    i32, 2>
+    { self.bind(client, &params.bucket_id,&params.wait_seconds,) }
+}}pub mod warning_count_by_guild_id_and_user_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct WarningCountByGuildIdAndUserIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub guild_id: T1,pub user_id: T2,}pub struct I64Query<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedUserSelectByIdBorrowed,
-    mapper: fn(CachedUserSelectByIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedUserSelectByIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> i64,
+    mapper: fn(i64) -> T,
+} impl<'a, C, T:'a, const N: usize> I64Query<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedUserSelectByIdBorrowed) -> R) ->
-    CachedUserSelectByIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(i64) -> R) ->
+    I64Query<'a,C,R,N>
     {
-        CachedUserSelectByIdQuery
+        I64Query
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -658,55 +4655,60 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_user_select_by_id() -> CachedUserSelectByIdStmt
-{ CachedUserSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
-    *
+}pub fn warning_count_by_guild_id_and_user_id() -> WarningCountByGuildIdAndUserIdStmt
+{ WarningCountByGuildIdAndUserIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    count(*)
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+    \"DiscordFrontend\".\"Nightly\".\"Warnings\"
 WHERE
-    \"id\" = $1")) } pub struct
-CachedUserSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedUserSelectByIdStmt
+    \"guild_id\" = $1 AND
+    \"user_id\" = $2")) } pub struct
+WarningCountByGuildIdAndUserIdStmt(cornucopia_async::private::Stmt); impl WarningCountByGuildIdAndUserIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
+cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,) -> CachedUserSelectByIdQuery<'a,C,
-CachedUserSelectById, 1>
+guild_id: &'a T1,user_id: &'a T2,) -> I64Query<'a,C,
+i64, 2>
 {
-    CachedUserSelectByIdQuery
+    I64Query
     {
-        client, params: [id,], stmt: &mut self.0, extractor:
-        |row| { CachedUserSelectByIdBorrowed { avatar: row.get(0),bot: row.get(1),id: row.get(2),discriminator: row.get(3),global_name: row.get(4),name: row.get(5),} }, mapper: |it| { <CachedUserSelectById>::from(it) },
+        client, params: [guild_id,user_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
     }
-} }}pub mod cached_user_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedUserUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,> { pub avatar: Option<T1>,pub id: T2,pub bot: bool,pub name: T3,pub discriminator: T4,pub global_name: Option<T5>,}pub fn cached_user_upsert() -> CachedUserUpsertStmt
-{ CachedUserUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedUsers\" (\"avatar\", \"id\", \"bot\", \"name\", \"discriminator\", \"global_name\")
-VALUES ($1, $2, $3, $4, $5, $6)
-ON CONFLICT (\"id\") DO UPDATE
-    SET
-        \"avatar\" = $1,
-        \"bot\" = $3,
-        \"name\" = $4,
-        \"discriminator\" = $5,
-        \"global_name\" = $6")) } pub struct
-CachedUserUpsertStmt(cornucopia_async::private::Stmt); impl CachedUserUpsertStmt
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+WarningCountByGuildIdAndUserIdParams<T1,T2,>, I64Query<'a, C,
+i64, 2>, C> for WarningCountByGuildIdAndUserIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    WarningCountByGuildIdAndUserIdParams<T1,T2,>) -> I64Query<'a, C,
+    i64, 2>
+    { self.bind(client, &params.guild_id,&params.user_id,) }
+}}pub mod warning_insert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct WarningInsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,> { pub guild_id: T1,pub moderator_id: T2,pub reason: T3,pub user_id: T4,}pub fn warning_insert() -> WarningInsertStmt
+{ WarningInsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO
+    \"DiscordFrontend\".\"Nightly\".\"Warnings\" (\"guild_id\", \"moderator_id\", \"reason\", \"user_id\")
+VALUES
+    ($1, $2, $3, $4)")) } pub struct
+WarningInsertStmt(cornucopia_async::private::Stmt); impl WarningInsertStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,T4:
-cornucopia_async::StringSql,T5:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-avatar: &'a Option<T1>,id: &'a T2,bot: &'a bool,name: &'a T3,discriminator: &'a T4,global_name: &'a Option<T5>,) -> Result<u64, tokio_postgres::Error>
+guild_id: &'a T1,moderator_id: &'a T2,reason: &'a T3,user_id: &'a T4,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[avatar,id,bot,name,discriminator,global_name,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, CachedUserUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedUserUpsertStmt
+    client.execute(stmt, &[guild_id,moderator_id,reason,user_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, WarningInsertParams<T1,T2,T3,T4,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for WarningInsertStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedUserUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    WarningInsertParams<T1,T2,T3,T4,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.avatar,&params.id,&params.bot,&params.name,&params.discriminator,&params.global_name,)) }
+    { Box::pin(self.bind(client, &params.guild_id,&params.moderator_id,&params.reason,&params.user_id,)) }
 }}}
\ No newline at end of file
@@ -0,0 +1,15 @@
+--! guild_configuration_upsert (guild_id, source, dashboard_admins, dashboard_editors, dashboard_viewers, appearance_nickname?, appearance_colour?, enabled_plugins, plugins_modlog_loggers)
+INSERT INTO
+    "Nightly"."GuildConfigurations" ("guild_id", "source", "dashboard_admins", "dashboard_editors", "dashboard_viewers", "appearance_nickname", "appearance_colour", "enabled_plugins", "plugins_modlog_loggers", "updated_at")
+VALUES (:guild_id, :source, :dashboard_admins, :dashboard_editors, :dashboard_viewers, :appearance_nickname, :appearance_colour, :enabled_plugins, :plugins_modlog_loggers, NOW())
+ON CONFLICT ("guild_id") DO UPDATE
+    SET
+        "appearance_colour" = :appearance_colour,
+        "appearance_nickname" = :appearance_nickname,
+        "dashboard_admins" = :dashboard_admins,
+        "dashboard_editors" = :dashboard_editors,
+        "dashboard_viewers" = :dashboard_viewers,
+        "enabled_plugins" = :enabled_plugins,
+        "plugins_modlog_loggers" = :plugins_modlog_loggers,
+        "source" = :source,
+        "updated_at" = NOW();
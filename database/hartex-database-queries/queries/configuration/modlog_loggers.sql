@@ -0,0 +1,8 @@
+--! modlog_loggers (guild_id) : (enabled_plugins, plugins_modlog_loggers)
+SELECT
+    "enabled_plugins",
+    "plugins_modlog_loggers"
+FROM
+    "Nightly"."GuildConfigurations"
+WHERE
+    "guild_id" = :guild_id;
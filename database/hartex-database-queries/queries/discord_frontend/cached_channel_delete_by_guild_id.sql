@@ -0,0 +1,5 @@
+--! cached_channel_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedChannels"
+WHERE
+    "guild_id" = :guild_id;
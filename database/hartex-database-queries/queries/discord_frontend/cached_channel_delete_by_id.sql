@@ -0,0 +1,5 @@
+--! cached_channel_delete_by_id (id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedChannels"
+WHERE
+    "id" = :id;
@@ -0,0 +1,9 @@
+--! cached_channel_exists_by_id (id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedChannels"
+    WHERE
+        "id" = :id
+);
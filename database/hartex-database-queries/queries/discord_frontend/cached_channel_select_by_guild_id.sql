@@ -0,0 +1,7 @@
+--! cached_channel_select_by_guild_id (guild_id) : (guild_id, id, kind, name?, nsfw?, parent_id?, permission_overwrites?, position?, rate_limit_per_user?, topic?)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedChannels"
+WHERE
+    "guild_id" = :guild_id;
@@ -0,0 +1,7 @@
+--! cached_channel_select_by_id (id) : (guild_id, id, kind, name?, nsfw?, parent_id?, permission_overwrites?, position?, rate_limit_per_user?, topic?)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedChannels"
+WHERE
+    "id" = :id;
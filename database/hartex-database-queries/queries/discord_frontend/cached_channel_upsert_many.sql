@@ -0,0 +1,39 @@
+--! cached_channel_upsert_many (guild_id, id, kind, name[]?, nsfw[]?, parent_id[]?, permission_overwrites[]?, position[]?, rate_limit_per_user[]?, topic[]?)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedChannels" ("guild_id", "id", "kind", "name", "nsfw", "parent_id", "permission_overwrites", "position", "rate_limit_per_user", "topic")
+SELECT DISTINCT ON ("id")
+    "guild_id",
+    "id",
+    "kind",
+    "name",
+    "nsfw",
+    "parent_id",
+    "permission_overwrites",
+    "position",
+    "rate_limit_per_user",
+    "topic"
+FROM
+    unnest(
+        CAST(:guild_id AS TEXT[]),
+        CAST(:id AS TEXT[]),
+        CAST(:kind AS SMALLINT[]),
+        CAST(:name AS TEXT[]),
+        CAST(:nsfw AS BOOLEAN[]),
+        CAST(:parent_id AS TEXT[]),
+        CAST(:permission_overwrites AS JSONB[]),
+        CAST(:position AS INTEGER[]),
+        CAST(:rate_limit_per_user AS INTEGER[]),
+        CAST(:topic AS TEXT[])
+    ) WITH ORDINALITY AS "entities" ("guild_id", "id", "kind", "name", "nsfw", "parent_id", "permission_overwrites", "position", "rate_limit_per_user", "topic", "ordinality")
+ORDER BY
+    "id", "ordinality" DESC
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "guild_id" = EXCLUDED."guild_id",
+        "kind" = EXCLUDED."kind",
+        "name" = EXCLUDED."name",
+        "nsfw" = EXCLUDED."nsfw",
+        "parent_id" = EXCLUDED."parent_id",
+        "permission_overwrites" = EXCLUDED."permission_overwrites",
+        "position" = EXCLUDED."position",
+        "rate_limit_per_user" = EXCLUDED."rate_limit_per_user",
+        "topic" = EXCLUDED."topic";
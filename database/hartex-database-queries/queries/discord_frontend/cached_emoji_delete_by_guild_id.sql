@@ -0,0 +1,5 @@
+--! cached_emoji_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedEmojis"
+WHERE
+    "guild_id" = :guild_id;
@@ -0,0 +1,5 @@
+--! cached_emoji_delete_by_id (id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedEmojis"
+WHERE
+    "id" = :id;
@@ -0,0 +1,9 @@
+--! cached_emoji_exists_by_id (id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedEmojis"
+    WHERE
+        "id" = :id
+);
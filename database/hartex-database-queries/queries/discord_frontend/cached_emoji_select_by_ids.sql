@@ -0,0 +1,7 @@
+--! cached_emoji_select_by_ids (ids) : (id, guild_id, animated, name, managed)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedEmojis"
+WHERE
+    "id" = ANY(:ids);
@@ -0,0 +1,24 @@
+--! cached_emoji_upsert_many (animated, name, id, guild_id, managed)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedEmojis" ("animated", "name", "id", "guild_id", "managed")
+SELECT DISTINCT ON ("id")
+    "animated",
+    "name",
+    "id",
+    "guild_id",
+    "managed"
+FROM
+    unnest(
+        CAST(:animated AS BOOLEAN[]),
+        CAST(:name AS TEXT[]),
+        CAST(:id AS TEXT[]),
+        CAST(:guild_id AS TEXT[]),
+        CAST(:managed AS BOOLEAN[])
+    ) WITH ORDINALITY AS "entities" ("animated", "name", "id", "guild_id", "managed", "ordinality")
+ORDER BY
+    "id", "ordinality" DESC
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "animated" = EXCLUDED."animated",
+        "name" = EXCLUDED."name",
+        "guild_id" = EXCLUDED."guild_id",
+        "managed" = EXCLUDED."managed";
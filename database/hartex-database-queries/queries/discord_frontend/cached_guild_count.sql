@@ -0,0 +1,5 @@
+--! cached_guild_count
+SELECT
+    count(*)
+FROM
+    "DiscordFrontend"."Nightly"."CachedGuilds";
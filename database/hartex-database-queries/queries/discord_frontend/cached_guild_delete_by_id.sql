@@ -0,0 +1,5 @@
+--! cached_guild_delete_by_id (id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedGuilds"
+WHERE
+    "id" = :id;
@@ -0,0 +1,9 @@
+--! cached_guild_exists_by_id (id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedGuilds"
+    WHERE
+        "id" = :id
+);
@@ -1,4 +1,4 @@
---! cached_guild_select_by_id : (default_message_notifications, explicit_content_filter, features, icon?, large, name, owner_id, id, mfa_level, premium_subscription_count?, premium_tier, verification_level)
+--! cached_guild_select_by_id (id) : (default_message_notifications, explicit_content_filter, features, icon?, id, large, mfa_level, name, owner_id, premium_subscription_count?, premium_tier, verification_level, preferred_locale)
 SELECT
     *
 FROM
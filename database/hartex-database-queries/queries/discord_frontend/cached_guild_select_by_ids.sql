@@ -0,0 +1,7 @@
+--! cached_guild_select_by_ids (ids) : (default_message_notifications, explicit_content_filter, features, icon?, id, large, mfa_level, name, owner_id, premium_subscription_count?, premium_tier, verification_level, preferred_locale)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedGuilds"
+WHERE
+    "id" = ANY(:ids);
@@ -1,7 +1,7 @@
---! cached_guild_upsert (default_message_notifications, explicit_content_filter, features, icon?, large, name, owner_id, id, mfa_level, premium_subscription_count?, premium_tier, verification_level)
+--! cached_guild_upsert (default_message_notifications, explicit_content_filter, features, icon?, large, name, owner_id, id, mfa_level, premium_subscription_count?, premium_tier, verification_level, preferred_locale)
 INSERT INTO
-    "DiscordFrontend"."Nightly"."CachedGuilds" ("default_message_notifications", "explicit_content_filter", "features", "icon", "large", "name", "owner_id", "id", "mfa_level", "premium_subscription_count", "premium_tier", "verification_level")
-VALUES (:default_message_notifications, :explicit_content_filter, :features, :icon, :large, :name, :owner_id, :id, :mfa_level, :premium_subscription_count, :premium_tier, :verification_level)
+    "DiscordFrontend"."Nightly"."CachedGuilds" ("default_message_notifications", "explicit_content_filter", "features", "icon", "large", "name", "owner_id", "id", "mfa_level", "premium_subscription_count", "premium_tier", "verification_level", "preferred_locale")
+VALUES (:default_message_notifications, :explicit_content_filter, :features, :icon, :large, :name, :owner_id, :id, :mfa_level, :premium_subscription_count, :premium_tier, :verification_level, :preferred_locale)
 ON CONFLICT ("id") DO UPDATE
     SET
         "default_message_notifications" = :default_message_notifications,
@@ -12,6 +12,7 @@ ON CONFLICT ("id") DO UPDATE
         "mfa_level" = :mfa_level,
         "name" = :name,
         "owner_id" = :owner_id,
+        "preferred_locale" = :preferred_locale,
         "premium_subscription_count" = :premium_subscription_count,
         "premium_tier" = :premium_tier,
         "verification_level" = :verification_level;
@@ -0,0 +1,48 @@
+--! cached_guild_upsert_many (default_message_notifications, explicit_content_filter, features, icon[]?, large, name, owner_id, id, mfa_level, premium_subscription_count[]?, premium_tier, verification_level, preferred_locale)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedGuilds" ("default_message_notifications", "explicit_content_filter", "features", "icon", "large", "name", "owner_id", "id", "mfa_level", "premium_subscription_count", "premium_tier", "verification_level", "preferred_locale")
+SELECT DISTINCT ON ("id")
+    "default_message_notifications",
+    "explicit_content_filter",
+    ARRAY(SELECT jsonb_array_elements_text("features")),
+    "icon",
+    "large",
+    "name",
+    "owner_id",
+    "id",
+    "mfa_level",
+    "premium_subscription_count",
+    "premium_tier",
+    "verification_level",
+    "preferred_locale"
+FROM
+    unnest(
+        CAST(:default_message_notifications AS SMALLINT[]),
+        CAST(:explicit_content_filter AS SMALLINT[]),
+        CAST(:features AS JSONB[]),
+        CAST(:icon AS TEXT[]),
+        CAST(:large AS BOOLEAN[]),
+        CAST(:name AS TEXT[]),
+        CAST(:owner_id AS TEXT[]),
+        CAST(:id AS TEXT[]),
+        CAST(:mfa_level AS SMALLINT[]),
+        CAST(:premium_subscription_count AS BIGINT[]),
+        CAST(:premium_tier AS SMALLINT[]),
+        CAST(:verification_level AS SMALLINT[]),
+        CAST(:preferred_locale AS TEXT[])
+    ) WITH ORDINALITY AS "entities" ("default_message_notifications", "explicit_content_filter", "features", "icon", "large", "name", "owner_id", "id", "mfa_level", "premium_subscription_count", "premium_tier", "verification_level", "preferred_locale", "ordinality")
+ORDER BY
+    "id", "ordinality" DESC
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "default_message_notifications" = EXCLUDED."default_message_notifications",
+        "explicit_content_filter" = EXCLUDED."explicit_content_filter",
+        "features" = EXCLUDED."features",
+        "icon" = EXCLUDED."icon",
+        "large" = EXCLUDED."large",
+        "name" = EXCLUDED."name",
+        "owner_id" = EXCLUDED."owner_id",
+        "mfa_level" = EXCLUDED."mfa_level",
+        "premium_subscription_count" = EXCLUDED."premium_subscription_count",
+        "premium_tier" = EXCLUDED."premium_tier",
+        "verification_level" = EXCLUDED."verification_level",
+        "preferred_locale" = EXCLUDED."preferred_locale";
@@ -0,0 +1,7 @@
+--! cached_member_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedMembers"
+WHERE
+    "guild_id" = :guild_id
+RETURNING
+    "user_id";
@@ -0,0 +1,10 @@
+--! cached_member_remove_role_by_guild_id (role_id, guild_id)
+UPDATE
+    "DiscordFrontend"."Nightly"."CachedMembers"
+SET
+    "roles" = array_remove("roles", :role_id)
+WHERE
+    "guild_id" = :guild_id
+    AND :role_id = ANY("roles")
+RETURNING
+    "user_id";
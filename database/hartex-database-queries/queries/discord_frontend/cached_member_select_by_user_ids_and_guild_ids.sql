@@ -0,0 +1,12 @@
+--! cached_member_select_by_user_ids_and_guild_ids (user_ids, guild_ids) : (flags, joined_at?, nick?, roles, guild_id, user_id)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedMembers"
+WHERE
+    ("user_id", "guild_id") IN (
+        SELECT
+            *
+        FROM
+            unnest(CAST(:user_ids AS TEXT[]), CAST(:guild_ids AS TEXT[]))
+    );
@@ -0,0 +1,26 @@
+--! cached_member_upsert_many (flags, joined_at[]?, nick[]?, user_id, guild_id, roles)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedMembers" ("flags", "joined_at", "nick", "user_id", "guild_id", "roles")
+SELECT DISTINCT ON ("user_id", "guild_id")
+    "flags",
+    "joined_at",
+    "nick",
+    "user_id",
+    "guild_id",
+    ARRAY(SELECT jsonb_array_elements_text("roles"))
+FROM
+    unnest(
+        CAST(:flags AS BIGINT[]),
+        CAST(:joined_at AS TIMESTAMP WITH TIME ZONE[]),
+        CAST(:nick AS TEXT[]),
+        CAST(:user_id AS TEXT[]),
+        CAST(:guild_id AS TEXT[]),
+        CAST(:roles AS JSONB[])
+    ) WITH ORDINALITY AS "entities" ("flags", "joined_at", "nick", "user_id", "guild_id", "roles", "ordinality")
+ORDER BY
+    "user_id", "guild_id", "ordinality" DESC
+ON CONFLICT ("user_id", "guild_id") DO UPDATE
+    SET
+        "flags" = EXCLUDED."flags",
+        "joined_at" = EXCLUDED."joined_at",
+        "nick" = EXCLUDED."nick",
+        "roles" = EXCLUDED."roles";
@@ -0,0 +1,7 @@
+--! cached_message_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "guild_id" = :guild_id
+RETURNING
+    "id";
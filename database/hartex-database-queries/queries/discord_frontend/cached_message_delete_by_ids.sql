@@ -0,0 +1,5 @@
+--! cached_message_delete_by_ids (ids)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "id" = ANY(:ids);
@@ -0,0 +1,17 @@
+--! cached_message_delete_excess_by_guild_id (guild_id, capacity)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "id" IN (
+        SELECT
+            "id"
+        FROM
+            "DiscordFrontend"."Nightly"."CachedMessages"
+        WHERE
+            "guild_id" = :guild_id
+        ORDER BY
+            "timestamp" DESC
+        OFFSET :capacity
+    )
+RETURNING
+    "id";
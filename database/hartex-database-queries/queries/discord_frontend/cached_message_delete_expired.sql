@@ -0,0 +1,7 @@
+--! cached_message_delete_expired (before)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "timestamp" < :before
+RETURNING
+    "id";
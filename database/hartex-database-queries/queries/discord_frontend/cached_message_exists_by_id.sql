@@ -0,0 +1,9 @@
+--! cached_message_exists_by_id (id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedMessages"
+    WHERE
+        "id" = :id
+);
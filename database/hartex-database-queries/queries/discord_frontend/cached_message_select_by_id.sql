@@ -0,0 +1,7 @@
+--! cached_message_select_by_id (id) : (attachments, author_id, channel_id, content, edited_timestamp?, guild_id, id, timestamp)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "id" = :id;
@@ -0,0 +1,7 @@
+--! cached_message_select_by_ids (ids) : (attachments, author_id, channel_id, content, edited_timestamp?, guild_id, id, timestamp)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "id" = ANY(:ids);
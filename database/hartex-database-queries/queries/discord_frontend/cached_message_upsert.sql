@@ -0,0 +1,12 @@
+--! cached_message_upsert (attachments, author_id, channel_id, content, edited_timestamp?, guild_id, id, timestamp)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedMessages" ("attachments", "author_id", "channel_id", "content", "edited_timestamp", "guild_id", "id", "timestamp")
+VALUES (:attachments, :author_id, :channel_id, :content, :edited_timestamp, :guild_id, :id, :timestamp)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "attachments" = :attachments,
+        "author_id" = :author_id,
+        "channel_id" = :channel_id,
+        "content" = :content,
+        "edited_timestamp" = :edited_timestamp,
+        "guild_id" = :guild_id,
+        "timestamp" = :timestamp;
@@ -0,0 +1,33 @@
+--! cached_message_upsert_many (attachments, author_id, channel_id, content, edited_timestamp[]?, guild_id, id, timestamp)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedMessages" ("attachments", "author_id", "channel_id", "content", "edited_timestamp", "guild_id", "id", "timestamp")
+SELECT DISTINCT ON ("id")
+    "attachments",
+    "author_id",
+    "channel_id",
+    "content",
+    "edited_timestamp",
+    "guild_id",
+    "id",
+    "timestamp"
+FROM
+    unnest(
+        CAST(:attachments AS JSONB[]),
+        CAST(:author_id AS TEXT[]),
+        CAST(:channel_id AS TEXT[]),
+        CAST(:content AS TEXT[]),
+        CAST(:edited_timestamp AS TIMESTAMP WITH TIME ZONE[]),
+        CAST(:guild_id AS TEXT[]),
+        CAST(:id AS TEXT[]),
+        CAST(:timestamp AS TIMESTAMP WITH TIME ZONE[])
+    ) WITH ORDINALITY AS "entities" ("attachments", "author_id", "channel_id", "content", "edited_timestamp", "guild_id", "id", "timestamp", "ordinality")
+ORDER BY
+    "id", "ordinality" DESC
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "attachments" = EXCLUDED."attachments",
+        "author_id" = EXCLUDED."author_id",
+        "channel_id" = EXCLUDED."channel_id",
+        "content" = EXCLUDED."content",
+        "edited_timestamp" = EXCLUDED."edited_timestamp",
+        "guild_id" = EXCLUDED."guild_id",
+        "timestamp" = EXCLUDED."timestamp";
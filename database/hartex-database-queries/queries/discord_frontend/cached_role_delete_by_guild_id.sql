@@ -0,0 +1,5 @@
+--! cached_role_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedRoles"
+WHERE
+    "guild_id" = :guild_id;
@@ -0,0 +1,6 @@
+--! cached_role_delete_by_id_and_guild_id (id, guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedRoles"
+WHERE
+    "id" = :id
+    AND "guild_id" = :guild_id;
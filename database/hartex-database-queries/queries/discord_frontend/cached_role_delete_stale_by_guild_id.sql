@@ -0,0 +1,6 @@
+--! cached_role_delete_stale_by_guild_id (guild_id, ids)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedRoles"
+WHERE
+    "guild_id" = :guild_id
+    AND NOT ("id" = ANY(:ids));
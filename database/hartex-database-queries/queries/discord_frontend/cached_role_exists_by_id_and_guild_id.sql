@@ -0,0 +1,10 @@
+--! cached_role_exists_by_id_and_guild_id (id, guild_id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedRoles"
+    WHERE
+        "id" = :id
+        AND "guild_id" = :guild_id
+);
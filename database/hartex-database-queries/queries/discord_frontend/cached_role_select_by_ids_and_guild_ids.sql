@@ -0,0 +1,12 @@
+--! cached_role_select_by_ids_and_guild_ids (ids, guild_ids) : (color, flags, guild_id, hoist, icon?, id, managed, mentionable, position)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedRoles"
+WHERE
+    ("id", "guild_id") IN (
+        SELECT
+            *
+        FROM
+            unnest(CAST(:ids AS TEXT[]), CAST(:guild_ids AS TEXT[]))
+    );
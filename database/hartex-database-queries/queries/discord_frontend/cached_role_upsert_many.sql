@@ -0,0 +1,35 @@
+--! cached_role_upsert_many (color, icon[]?, id, guild_id, flags, hoist, managed, mentionable, position)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedRoles" ("color", "icon", "id", "guild_id", "flags", "hoist", "managed", "mentionable", "position")
+SELECT DISTINCT ON ("id", "guild_id")
+    "color",
+    "icon",
+    "id",
+    "guild_id",
+    "flags",
+    "hoist",
+    "managed",
+    "mentionable",
+    "position"
+FROM
+    unnest(
+        CAST(:color AS BIGINT[]),
+        CAST(:icon AS TEXT[]),
+        CAST(:id AS TEXT[]),
+        CAST(:guild_id AS TEXT[]),
+        CAST(:flags AS INTEGER[]),
+        CAST(:hoist AS BOOLEAN[]),
+        CAST(:managed AS BOOLEAN[]),
+        CAST(:mentionable AS BOOLEAN[]),
+        CAST(:position AS INTEGER[])
+    ) WITH ORDINALITY AS "entities" ("color", "icon", "id", "guild_id", "flags", "hoist", "managed", "mentionable", "position", "ordinality")
+ORDER BY
+    "id", "guild_id", "ordinality" DESC
+ON CONFLICT ("id", "guild_id") DO UPDATE
+    SET
+        "color" = EXCLUDED."color",
+        "icon" = EXCLUDED."icon",
+        "flags" = EXCLUDED."flags",
+        "hoist" = EXCLUDED."hoist",
+        "managed" = EXCLUDED."managed",
+        "mentionable" = EXCLUDED."mentionable",
+        "position" = EXCLUDED."position";
@@ -0,0 +1,5 @@
+--! cached_sticker_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedStickers"
+WHERE
+    "guild_id" = :guild_id;
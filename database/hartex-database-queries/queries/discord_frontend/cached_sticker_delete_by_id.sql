@@ -0,0 +1,5 @@
+--! cached_sticker_delete_by_id (id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedStickers"
+WHERE
+    "id" = :id;
@@ -0,0 +1,9 @@
+--! cached_sticker_exists_by_id (id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedStickers"
+    WHERE
+        "id" = :id
+);
@@ -0,0 +1,7 @@
+--! cached_sticker_select_by_id (id) : (available, description?, format_type, guild_id, id, name, tags)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedStickers"
+WHERE
+    "id" = :id;
@@ -0,0 +1,11 @@
+--! cached_sticker_upsert (available, description?, format_type, guild_id, id, name, tags)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedStickers" ("available", "description", "format_type", "guild_id", "id", "name", "tags")
+VALUES (:available, :description, :format_type, :guild_id, :id, :name, :tags)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "available" = :available,
+        "description" = :description,
+        "format_type" = :format_type,
+        "guild_id" = :guild_id,
+        "name" = :name,
+        "tags" = :tags;
@@ -0,0 +1,30 @@
+--! cached_sticker_upsert_many (available, description[]?, format_type, guild_id, id, name, tags)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedStickers" ("available", "description", "format_type", "guild_id", "id", "name", "tags")
+SELECT DISTINCT ON ("id")
+    "available",
+    "description",
+    "format_type",
+    "guild_id",
+    "id",
+    "name",
+    "tags"
+FROM
+    unnest(
+        CAST(:available AS BOOLEAN[]),
+        CAST(:description AS TEXT[]),
+        CAST(:format_type AS SMALLINT[]),
+        CAST(:guild_id AS TEXT[]),
+        CAST(:id AS TEXT[]),
+        CAST(:name AS TEXT[]),
+        CAST(:tags AS TEXT[])
+    ) WITH ORDINALITY AS "entities" ("available", "description", "format_type", "guild_id", "id", "name", "tags", "ordinality")
+ORDER BY
+    "id", "ordinality" DESC
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "available" = EXCLUDED."available",
+        "description" = EXCLUDED."description",
+        "format_type" = EXCLUDED."format_type",
+        "guild_id" = EXCLUDED."guild_id",
+        "name" = EXCLUDED."name",
+        "tags" = EXCLUDED."tags";
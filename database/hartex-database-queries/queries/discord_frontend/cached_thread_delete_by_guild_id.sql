@@ -0,0 +1,5 @@
+--! cached_thread_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedThreads"
+WHERE
+    "guild_id" = :guild_id;
@@ -0,0 +1,5 @@
+--! cached_thread_delete_by_id (id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedThreads"
+WHERE
+    "id" = :id;
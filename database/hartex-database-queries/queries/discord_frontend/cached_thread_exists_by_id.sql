@@ -0,0 +1,9 @@
+--! cached_thread_exists_by_id (id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedThreads"
+    WHERE
+        "id" = :id
+);
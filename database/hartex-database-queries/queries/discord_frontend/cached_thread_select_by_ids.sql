@@ -0,0 +1,7 @@
+--! cached_thread_select_by_ids (ids) : (guild_id, id, kind, name?, owner_id?, parent_id?, thread_metadata?)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedThreads"
+WHERE
+    "id" = ANY(:ids);
@@ -0,0 +1,11 @@
+--! cached_thread_upsert (guild_id, id, kind, name?, owner_id?, parent_id?, thread_metadata?)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedThreads" ("guild_id", "id", "kind", "name", "owner_id", "parent_id", "thread_metadata")
+VALUES (:guild_id, :id, :kind, :name, :owner_id, :parent_id, :thread_metadata)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "guild_id" = :guild_id,
+        "kind" = :kind,
+        "name" = :name,
+        "owner_id" = :owner_id,
+        "parent_id" = :parent_id,
+        "thread_metadata" = :thread_metadata;
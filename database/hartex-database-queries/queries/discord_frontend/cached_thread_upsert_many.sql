@@ -0,0 +1,30 @@
+--! cached_thread_upsert_many (guild_id, id, kind, name[]?, owner_id[]?, parent_id[]?, thread_metadata[]?)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedThreads" ("guild_id", "id", "kind", "name", "owner_id", "parent_id", "thread_metadata")
+SELECT DISTINCT ON ("id")
+    "guild_id",
+    "id",
+    "kind",
+    "name",
+    "owner_id",
+    "parent_id",
+    "thread_metadata"
+FROM
+    unnest(
+        CAST(:guild_id AS TEXT[]),
+        CAST(:id AS TEXT[]),
+        CAST(:kind AS SMALLINT[]),
+        CAST(:name AS TEXT[]),
+        CAST(:owner_id AS TEXT[]),
+        CAST(:parent_id AS TEXT[]),
+        CAST(:thread_metadata AS JSONB[])
+    ) WITH ORDINALITY AS "entities" ("guild_id", "id", "kind", "name", "owner_id", "parent_id", "thread_metadata", "ordinality")
+ORDER BY
+    "id", "ordinality" DESC
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "guild_id" = EXCLUDED."guild_id",
+        "kind" = EXCLUDED."kind",
+        "name" = EXCLUDED."name",
+        "owner_id" = EXCLUDED."owner_id",
+        "parent_id" = EXCLUDED."parent_id",
+        "thread_metadata" = EXCLUDED."thread_metadata";
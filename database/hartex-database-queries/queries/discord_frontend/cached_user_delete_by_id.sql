@@ -0,0 +1,5 @@
+--! cached_user_delete_by_id (id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedUsers"
+WHERE
+    "id" = :id;
@@ -0,0 +1,12 @@
+--! cached_user_delete_unreferenced
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedUsers" AS "users"
+WHERE
+    NOT EXISTS (
+        SELECT
+            1
+        FROM
+            "DiscordFrontend"."Nightly"."CachedMembers" AS "members"
+        WHERE
+            "members"."user_id" = "users"."id"
+    );
@@ -0,0 +1,13 @@
+--! cached_user_delete_unreferenced_by_id (id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedUsers"
+WHERE
+    "id" = :id
+    AND NOT EXISTS (
+        SELECT
+            1
+        FROM
+            "DiscordFrontend"."Nightly"."CachedMembers"
+        WHERE
+            "user_id" = :id
+    );
@@ -0,0 +1,9 @@
+--! cached_user_exists_by_id (id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedUsers"
+    WHERE
+        "id" = :id
+);
@@ -0,0 +1,7 @@
+--! cached_user_select_by_ids (ids) : (avatar?, bot, id, discriminator, global_name?, name)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedUsers"
+WHERE
+    "id" = ANY(:ids);
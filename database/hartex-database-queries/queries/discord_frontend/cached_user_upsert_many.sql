@@ -0,0 +1,27 @@
+--! cached_user_upsert_many (avatar[]?, id, bot, name, discriminator, global_name[]?)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedUsers" ("avatar", "id", "bot", "name", "discriminator", "global_name")
+SELECT DISTINCT ON ("id")
+    "avatar",
+    "id",
+    "bot",
+    "name",
+    "discriminator",
+    "global_name"
+FROM
+    unnest(
+        CAST(:avatar AS TEXT[]),
+        CAST(:id AS TEXT[]),
+        CAST(:bot AS BOOLEAN[]),
+        CAST(:name AS TEXT[]),
+        CAST(:discriminator AS TEXT[]),
+        CAST(:global_name AS TEXT[])
+    ) WITH ORDINALITY AS "entities" ("avatar", "id", "bot", "name", "discriminator", "global_name", "ordinality")
+ORDER BY
+    "id", "ordinality" DESC
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "avatar" = EXCLUDED."avatar",
+        "bot" = EXCLUDED."bot",
+        "name" = EXCLUDED."name",
+        "discriminator" = EXCLUDED."discriminator",
+        "global_name" = EXCLUDED."global_name";
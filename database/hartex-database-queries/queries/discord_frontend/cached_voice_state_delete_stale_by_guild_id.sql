@@ -0,0 +1,6 @@
+--! cached_voice_state_delete_stale_by_guild_id (guild_id, user_ids)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedVoiceStates"
+WHERE
+    "guild_id" = :guild_id
+    AND NOT ("user_id" = ANY(:user_ids));
@@ -0,0 +1,10 @@
+--! cached_voice_state_exists_by_user_id_and_guild_id (user_id, guild_id)
+SELECT EXISTS (
+    SELECT
+        1
+    FROM
+        "DiscordFrontend"."Nightly"."CachedVoiceStates"
+    WHERE
+        "user_id" = :user_id
+        AND "guild_id" = :guild_id
+);
@@ -0,0 +1,7 @@
+--! cached_voice_state_select_by_guild_id (guild_id) : (channel_id?, deaf, guild_id, mute, request_to_speak_timestamp?, self_deaf, self_mute, self_stream, self_video, session_id, suppress, user_id)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedVoiceStates"
+WHERE
+    "guild_id" = :guild_id;
@@ -0,0 +1,8 @@
+--! cached_voice_state_select_by_user_id_and_guild_id (user_id, guild_id) : (channel_id?, deaf, guild_id, mute, request_to_speak_timestamp?, self_deaf, self_mute, self_stream, self_video, session_id, suppress, user_id)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedVoiceStates"
+WHERE
+    "user_id" = :user_id
+    AND "guild_id" = :guild_id;
@@ -0,0 +1,12 @@
+--! cached_voice_state_select_by_user_ids_and_guild_ids (user_ids, guild_ids) : (channel_id?, deaf, guild_id, mute, request_to_speak_timestamp?, self_deaf, self_mute, self_stream, self_video, session_id, suppress, user_id)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedVoiceStates"
+WHERE
+    ("user_id", "guild_id") IN (
+        SELECT
+            *
+        FROM
+            unnest(CAST(:user_ids AS TEXT[]), CAST(:guild_ids AS TEXT[]))
+    );
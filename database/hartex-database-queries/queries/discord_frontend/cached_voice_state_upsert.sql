@@ -0,0 +1,15 @@
+--! cached_voice_state_upsert (channel_id?, deaf, guild_id, mute, request_to_speak_timestamp?, self_deaf, self_mute, self_stream, self_video, session_id, suppress, user_id)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedVoiceStates" ("channel_id", "deaf", "guild_id", "mute", "request_to_speak_timestamp", "self_deaf", "self_mute", "self_stream", "self_video", "session_id", "suppress", "user_id")
+VALUES (:channel_id, :deaf, :guild_id, :mute, :request_to_speak_timestamp, :self_deaf, :self_mute, :self_stream, :self_video, :session_id, :suppress, :user_id)
+ON CONFLICT ("guild_id", "user_id") DO UPDATE
+    SET
+        "channel_id" = :channel_id,
+        "deaf" = :deaf,
+        "mute" = :mute,
+        "request_to_speak_timestamp" = :request_to_speak_timestamp,
+        "self_deaf" = :self_deaf,
+        "self_mute" = :self_mute,
+        "self_stream" = :self_stream,
+        "self_video" = :self_video,
+        "session_id" = :session_id,
+        "suppress" = :suppress;
@@ -0,0 +1,44 @@
+--! cached_voice_state_upsert_many (channel_id[]?, deaf, guild_id, mute, request_to_speak_timestamp[]?, self_deaf, self_mute, self_stream, self_video, session_id, suppress, user_id)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedVoiceStates" ("channel_id", "deaf", "guild_id", "mute", "request_to_speak_timestamp", "self_deaf", "self_mute", "self_stream", "self_video", "session_id", "suppress", "user_id")
+SELECT DISTINCT ON ("guild_id", "user_id")
+    "channel_id",
+    "deaf",
+    "guild_id",
+    "mute",
+    "request_to_speak_timestamp",
+    "self_deaf",
+    "self_mute",
+    "self_stream",
+    "self_video",
+    "session_id",
+    "suppress",
+    "user_id"
+FROM
+    unnest(
+        CAST(:channel_id AS TEXT[]),
+        CAST(:deaf AS BOOLEAN[]),
+        CAST(:guild_id AS TEXT[]),
+        CAST(:mute AS BOOLEAN[]),
+        CAST(:request_to_speak_timestamp AS TIMESTAMP WITH TIME ZONE[]),
+        CAST(:self_deaf AS BOOLEAN[]),
+        CAST(:self_mute AS BOOLEAN[]),
+        CAST(:self_stream AS BOOLEAN[]),
+        CAST(:self_video AS BOOLEAN[]),
+        CAST(:session_id AS TEXT[]),
+        CAST(:suppress AS BOOLEAN[]),
+        CAST(:user_id AS TEXT[])
+    ) WITH ORDINALITY AS "entities" ("channel_id", "deaf", "guild_id", "mute", "request_to_speak_timestamp", "self_deaf", "self_mute", "self_stream", "self_video", "session_id", "suppress", "user_id", "ordinality")
+ORDER BY
+    "guild_id", "user_id", "ordinality" DESC
+ON CONFLICT ("guild_id", "user_id") DO UPDATE
+    SET
+        "channel_id" = EXCLUDED."channel_id",
+        "deaf" = EXCLUDED."deaf",
+        "mute" = EXCLUDED."mute",
+        "request_to_speak_timestamp" = EXCLUDED."request_to_speak_timestamp",
+        "self_deaf" = EXCLUDED."self_deaf",
+        "self_mute" = EXCLUDED."self_mute",
+        "self_stream" = EXCLUDED."self_stream",
+        "self_video" = EXCLUDED."self_video",
+        "session_id" = EXCLUDED."session_id",
+        "suppress" = EXCLUDED."suppress";
@@ -0,0 +1,5 @@
+--! gateway_session_delete_by_shard_id (shard_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."GatewaySessions"
+WHERE
+    "shard_id" = :shard_id;
@@ -0,0 +1,9 @@
+--! gateway_session_select_all : (shard_id, shard_total, session_id, sequence, resume_url?)
+SELECT
+    "shard_id",
+    "shard_total",
+    "session_id",
+    "sequence",
+    "resume_url"
+FROM
+    "DiscordFrontend"."Nightly"."GatewaySessions";
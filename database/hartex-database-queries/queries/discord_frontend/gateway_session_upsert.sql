@@ -0,0 +1,10 @@
+--! gateway_session_upsert (shard_id, shard_total, session_id, sequence, resume_url?)
+INSERT INTO "DiscordFrontend"."Nightly"."GatewaySessions" ("shard_id", "shard_total", "session_id", "sequence", "resume_url", "updated_at")
+VALUES (:shard_id, :shard_total, :session_id, :sequence, :resume_url, now())
+ON CONFLICT ("shard_id") DO UPDATE
+    SET
+        "shard_total" = :shard_total,
+        "session_id" = :session_id,
+        "sequence" = :sequence,
+        "resume_url" = COALESCE(:resume_url, "GatewaySessions"."resume_url"),
+        "updated_at" = now();
@@ -0,0 +1,8 @@
+--! identify_bucket_acquire (bucket_id, wait_seconds)
+INSERT INTO "DiscordFrontend"."Nightly"."IdentifyBuckets" ("bucket_id", "last_identify")
+VALUES (:bucket_id, now())
+ON CONFLICT ("bucket_id") DO UPDATE
+    SET
+        "last_identify" = now()
+    WHERE "IdentifyBuckets"."last_identify" <= now() - CAST(:wait_seconds AS BIGINT) * INTERVAL '1 second'
+RETURNING "bucket_id";
@@ -0,0 +1,8 @@
+--! warning_count_by_guild_id_and_user_id (guild_id, user_id)
+SELECT
+    count(*)
+FROM
+    "DiscordFrontend"."Nightly"."Warnings"
+WHERE
+    "guild_id" = :guild_id AND
+    "user_id" = :user_id;
@@ -0,0 +1,5 @@
+--! warning_insert (guild_id, moderator_id, reason, user_id)
+INSERT INTO
+    "DiscordFrontend"."Nightly"."Warnings" ("guild_id", "moderator_id", "reason", "user_id")
+VALUES
+    (:guild_id, :moderator_id, :reason, :user_id);
@@ -0,0 +1,102 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # All-in-One Process
+//!
+//! The all-in-one process runs the leader, the worker and the entitycache update service within a
+//! single process, connected with in-process channels instead of Kafka.
+//!
+//! This is intended for local development; the topic names are still read from the environment,
+//! but no Kafka cluster is required. Events forwarded by the leader before the worker and the
+//! entitycache update service have subscribed to their topics are held back until they do.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use hartex_discord_core::dotenvy;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::sync::watch;
+use hartex_discord_transport::Transport;
+use hartex_discord_utils::CLIENT;
+use hartex_discord_utils::TOKEN;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use once_cell::sync::Lazy;
+
+/// Entry point.
+#[allow(clippy::large_futures)]
+#[tokio::main(flavor = "multi_thread")]
+pub async fn main() -> miette::Result<()> {
+    hartex_log::initialize();
+
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    Lazy::force(&CLIENT);
+    Lazy::force(&TOKEN);
+
+    let transport = Transport::memory();
+    let (tx, rx) = watch::channel(false);
+    let (stop_tx, stop_rx) = watch::channel(false);
+
+    let leader = async {
+        let stop = async {
+            tokio::select! {
+                () = hartex_signal_utils::shutdown() => {},
+                () = shutdown(stop_rx) => {},
+            }
+        };
+        let result = hartex_discord_leader::run(transport.clone(), stop).await;
+
+        // the worker and the entitycache update service are only shut down after the leader,
+        // such that the events already forwarded by the leader are still handled
+        tx.send_replace(true);
+
+        result
+    };
+
+    // the leader is stopped as soon as the worker or the entitycache update service exits, such
+    // as when it fails to start, as the events forwarded to it would no longer be handled
+    let worker = async {
+        let result = hartex_discord_worker::run(transport.clone(), shutdown(rx.clone())).await;
+        stop_tx.send_replace(true);
+
+        result
+    };
+    let entitycache = async {
+        let result =
+            hartex_discord_entitycache_service::run(transport.clone(), shutdown(rx.clone())).await;
+        stop_tx.send_replace(true);
+
+        result
+    };
+
+    let (leader, worker, entitycache) = tokio::join!(leader, worker, entitycache);
+
+    leader.and(worker).and(entitycache)
+}
+
+/// Wait until the shutdown of a component is requested.
+async fn shutdown(mut rx: watch::Receiver<bool>) {
+    let _ = rx.wait_for(|shutdown| *shutdown).await;
+}
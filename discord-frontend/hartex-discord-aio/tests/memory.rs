@@ -0,0 +1,113 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::env;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::sync::watch;
+use hartex_discord_core::tokio::time;
+use hartex_discord_entitycache_core::invalidation::Invalidation;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_internal_events::command::GatewayCommand;
+use hartex_discord_internal_events::command::OutboundCommand;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_transport::Consumer;
+use hartex_discord_transport::Delivery;
+use hartex_discord_transport::Transport;
+
+const GUILD_CREATE: &str = r#"{"op":0,"s":1,"t":"GUILD_CREATE","d":{"afk_channel_id":null,"afk_timeout":300,"application_id":null,"banner":null,"channels":[],"default_message_notifications":0,"description":null,"discovery_splash":null,"emojis":[],"explicit_content_filter":0,"features":[],"guild_scheduled_events":[],"icon":null,"id":"1","joined_at":"2024-01-01T00:00:00.000000+00:00","large":false,"max_members":500000,"member_count":1,"members":[],"mfa_level":0,"name":"HarTex","nsfw_level":0,"owner_id":"2","preferred_locale":"en-GB","premium_progress_bar_enabled":false,"premium_subscription_count":0,"premium_tier":0,"presences":[],"public_updates_channel_id":null,"roles":[],"rules_channel_id":null,"safety_alerts_channel_id":null,"splash":null,"stage_instances":[],"stickers":[],"system_channel_flags":0,"system_channel_id":null,"threads":[],"unavailable":false,"vanity_url_code":null,"verification_level":0,"voice_states":[]}}"#;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[tokio::test(crate = "hartex_discord_core::tokio", flavor = "multi_thread")]
+pub async fn memory_transport_test() {
+    env::set_var("ENTITYCACHE_BACKEND", "memory");
+    env::set_var("KAFKA_TOPIC_DEAD_LETTER", "dead-letter");
+    env::set_var(
+        "KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD",
+        "gateway-payload",
+    );
+    env::set_var(
+        "KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_CACHE",
+        "gateway-payload-cache",
+    );
+    env::set_var(
+        "KAFKA_TOPIC_INTERNAL_ENTITYCACHE_INVALIDATION",
+        "invalidation",
+    );
+    env::set_var("KAFKA_TOPIC_INTERNAL_EVENT_PAYLOAD", "internal-event");
+    env::set_var("KAFKA_TOPIC_OUTBOUND_COMMUNICATION", "outbound");
+    env::set_var("SHARD_TOTAL", "1");
+    env::set_var("WORKER_INSTANCE_ID", "test");
+
+    let transport = Transport::memory();
+    let commands = transport.partition_consumer("test", "outbound", 0).unwrap();
+    let invalidations = transport.consumer("test", &["invalidation"]).unwrap();
+
+    let (tx, rx) = watch::channel(false);
+    let worker = hartex_discord_worker::run(transport.clone(), shutdown(rx.clone()));
+    let entitycache = hartex_discord_entitycache_service::run(transport.clone(), shutdown(rx));
+
+    let driver = async {
+        // messages produced before the worker and the entitycache update service have subscribed
+        // to their topics are held back by the broker until they do
+        hartex_discord_leader::forward(&transport, 0, String::from(GUILD_CREATE))
+            .await
+            .unwrap();
+
+        let delivery = recv(&commands).await;
+        let envelope = Envelope::<OutboundCommand>::from_bytes(&delivery.payload.unwrap()).unwrap();
+        assert_eq!(envelope.shard_id, 0);
+        assert!(matches!(
+            envelope.payload.command,
+            GatewayCommand::RequestGuildMembers(request) if request.d.guild_id == Id::new(1)
+        ));
+
+        let delivery = recv(&invalidations).await;
+        let envelope =
+            Envelope::<Vec<Invalidation>>::from_bytes(&delivery.payload.unwrap()).unwrap();
+        assert!(!envelope.payload.is_empty());
+
+        let guild = CachedGuildRepository.get(Id::new(1)).await.unwrap();
+        assert_eq!(guild.name, "HarTex");
+
+        tx.send_replace(true);
+    };
+
+    let (worker, entitycache, ()) = tokio::join!(worker, entitycache, driver);
+    worker.unwrap();
+    entitycache.unwrap();
+}
+
+async fn recv(consumer: &Consumer) -> Delivery {
+    time::timeout(TIMEOUT, consumer.recv())
+        .await
+        .expect("no message was received in time")
+        .unwrap()
+}
+
+async fn shutdown(mut rx: watch::Receiver<bool>) {
+    let _ = rx.wait_for(|shutdown| *shutdown).await;
+}
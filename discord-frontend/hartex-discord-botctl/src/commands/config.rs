@@ -0,0 +1,93 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fs;
+use std::str::FromStr;
+
+use clap::ArgMatches;
+use hartex_discord_configuration_luart::migration::Warning;
+use hartex_discord_configuration_provider::ingest;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::dotenvy;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+/// Checking and uploading guild configurations.
+#[allow(clippy::module_name_repetitions)]
+pub async fn config_command(matches: ArgMatches) -> miette::Result<()> {
+    match matches.subcommand() {
+        Some(("check", subcommand_matches)) => check(subcommand_matches),
+        Some(("upload", subcommand_matches)) => upload(subcommand_matches).await,
+        _ => Ok(()),
+    }
+}
+
+/// Evaluate and validate a configuration without storing it.
+fn check(matches: &ArgMatches) -> miette::Result<()> {
+    let source = read_source(matches)?;
+    let evaluation = ingest::evaluate(&source)?;
+
+    log_warnings(&evaluation.warnings);
+    log::info!("configuration is valid");
+
+    Ok(())
+}
+
+/// Evaluate, validate and store the configuration of a guild.
+async fn upload(matches: &ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let guild_id = Id::<GuildMarker>::from_str(
+        matches
+            .get_one::<String>("guild-id")
+            .map(String::as_str)
+            .unwrap_or_default(),
+    )
+    .into_diagnostic()?;
+    let source = read_source(matches)?;
+
+    let evaluation = ingest::ingest(guild_id, &source).await?;
+
+    log_warnings(&evaluation.warnings);
+    log::info!("uploaded configuration of guild {guild_id}");
+
+    Ok(())
+}
+
+/// Log the warnings produced while evaluating a configuration.
+fn log_warnings(warnings: &[Warning]) {
+    for warning in warnings {
+        log::warn!("{warning}");
+    }
+}
+
+/// Read the configuration source from the file given on the command line.
+fn read_source(matches: &ArgMatches) -> miette::Result<String> {
+    let file = matches
+        .get_one::<String>("file")
+        .map(String::as_str)
+        .unwrap_or_default();
+
+    fs::read_to_string(file).into_diagnostic()
+}
@@ -0,0 +1,167 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::env;
+use std::time::Duration;
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_discord_core::tokio::time::timeout;
+use hartex_discord_internal_events::deadletter::DeadLetter;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_transport::Consumer;
+use hartex_discord_transport::Delivery;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+/// The duration without new dead letters after which the dead-letter topic is considered drained.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Inspecting and re-driving dead letters.
+#[allow(clippy::module_name_repetitions)]
+pub async fn deadletter_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    match matches.subcommand() {
+        Some(("list", subcommand_matches)) => list(subcommand_matches).await,
+        Some(("redrive", subcommand_matches)) => redrive(subcommand_matches).await,
+        _ => Ok(()),
+    }
+}
+
+/// List the dead letters in the dead-letter topic, from the earliest one.
+///
+/// A consumer group that never commits its offsets is used, such that listing the dead letters
+/// does not affect re-driving them.
+async fn list(matches: &ArgMatches) -> miette::Result<()> {
+    let show_payload = matches.get_flag("payload");
+    let topic = env::var("KAFKA_TOPIC_DEAD_LETTER").into_diagnostic()?;
+
+    let consumer = Transport::from_env()?.earliest_consumer(
+        "com.github.teamhartex.hartex.deadletter.inspector",
+        &[&topic],
+    )?;
+
+    let mut listed = 0u64;
+    while let Some((delivery, envelope)) = next_dead_letter(&consumer).await? {
+        let dead_letter = &envelope.payload;
+
+        println!(
+            "[offset {offset}] {consumer} failed to process {event_type} from {topic} (shard {shard_id}) after {attempts} attempt(s) at {timestamp}: {error}",
+            offset = delivery.offset,
+            consumer = dead_letter.consumer,
+            event_type = dead_letter.event_type.as_deref().unwrap_or("UNKNOWN"),
+            topic = dead_letter.topic,
+            shard_id = envelope.shard_id,
+            attempts = dead_letter.attempts,
+            timestamp = envelope.timestamp,
+            error = dead_letter.error,
+        );
+        if show_payload {
+            println!("{}", dead_letter.payload);
+        }
+
+        listed += 1;
+    }
+
+    log::info!("listed {listed} dead letter(s)");
+
+    Ok(())
+}
+
+/// Re-drive the dead letters into the topics they originate from.
+///
+/// The offsets of the dead-letter topic are committed for the consumer group used for re-driving,
+/// such that each dead letter is re-driven at most once.
+async fn redrive(matches: &ArgMatches) -> miette::Result<()> {
+    let limit = matches.get_one::<u64>("limit").copied();
+    let transport = Transport::from_env()?;
+    let topic = env::var("KAFKA_TOPIC_DEAD_LETTER").into_diagnostic()?;
+
+    let producer = transport.producer()?;
+    let consumer = transport.earliest_consumer(
+        "com.github.teamhartex.hartex.deadletter.redriver",
+        &[&topic],
+    )?;
+
+    let mut redriven = 0u64;
+    while !limit.is_some_and(|limit| redriven >= limit) {
+        let Some((delivery, envelope)) = next_dead_letter(&consumer).await? else {
+            break;
+        };
+        let dead_letter = envelope.payload;
+
+        log::trace!(
+            "re-driving {event_type} dead letter at offset {offset} into {topic}",
+            event_type = dead_letter.event_type.as_deref().unwrap_or("UNKNOWN"),
+            offset = delivery.offset,
+            topic = dead_letter.topic,
+        );
+        producer
+            .send(
+                &dead_letter.topic,
+                dead_letter.key.as_deref().unwrap_or_default(),
+                dead_letter.payload.as_bytes(),
+            )
+            .await?;
+        consumer.store_offset(&delivery)?;
+
+        redriven += 1;
+    }
+
+    producer.flush(Duration::from_secs(30))?;
+    consumer.commit()?;
+    log::info!("re-drove {redriven} dead letter(s)");
+
+    Ok(())
+}
+
+/// Receive the next dead letter, or none if the dead-letter topic has been drained.
+///
+/// Messages that are not dead letters are skipped.
+async fn next_dead_letter(
+    consumer: &Consumer,
+) -> miette::Result<Option<(Delivery, Envelope<DeadLetter>)>> {
+    loop {
+        let Ok(result) = timeout(IDLE_TIMEOUT, consumer.recv()).await else {
+            return Ok(None);
+        };
+        let delivery = result?;
+
+        let Some(bytes) = delivery.payload.as_deref() else {
+            continue;
+        };
+
+        match Envelope::<DeadLetter>::from_bytes(bytes) {
+            Ok(envelope) if envelope.event_type == "DEAD_LETTER" => {
+                return Ok(Some((delivery, envelope)));
+            }
+            Ok(_) => (),
+            Err(error) => log::warn!(
+                "skipping malformed dead letter at offset {offset}: {error}",
+                offset = delivery.offset
+            ),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_discord_internal_events::bus::CommandBus;
+use hartex_discord_internal_events::command::GatewayCommand;
+use hartex_discord_internal_events::presence::parse_activity_type;
+use hartex_discord_internal_events::presence::parse_status;
+use hartex_discord_internal_events::presence::PresenceTemplate;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use miette::Report;
+
+/// Changing the presence of the bot.
+#[allow(clippy::module_name_repetitions)]
+pub async fn presence_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let status = matches.get_one::<String>("status").unwrap();
+    let activity_type = matches.get_one::<String>("activity-type").unwrap();
+    let template = PresenceTemplate {
+        activity_type: parse_activity_type(activity_type)
+            .ok_or_else(|| Report::msg(format!("invalid activity type: {activity_type}")))?,
+        status: parse_status(status)
+            .ok_or_else(|| Report::msg(format!("invalid status: {status}")))?,
+        template: matches.get_one::<String>("template").unwrap().clone(),
+    };
+
+    let producer = Transport::from_env()?.producer()?;
+
+    log::trace!("sending presence update to all shards");
+    let bus = CommandBus::new(producer.clone())?;
+    let id = bus.send(GatewayCommand::SetPresence(template)).await?;
+
+    producer.flush(Duration::from_secs(30))?;
+    log::info!("sent presence update command {id}");
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+
+use clap::ArgMatches;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use hartex_discord_core::dotenvy;
+use hartex_discord_core::tokio;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_internal_events::envelope::EnvelopeError;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+/// Recording inbound gateway payloads into a file.
+///
+/// Each line of the gzip-compressed file is an envelope as received from the leader, carrying the
+/// shard ID, the event type, the timestamp and the raw gateway payload.
+#[allow(clippy::module_name_repetitions)]
+pub async fn record_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let path = matches.get_one::<String>("file").unwrap();
+    let limit = matches.get_one::<u64>("limit").copied();
+
+    let topic = env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD").into_diagnostic()?;
+
+    let consumer = Transport::from_env()?.consumer(
+        "com.github.teamhartex.hartex.inbound.gateway.payload.recorder",
+        &[&topic],
+    )?;
+
+    let file = File::create(path).into_diagnostic()?;
+    let mut writer = BufWriter::new(GzEncoder::new(file, Compression::default()));
+
+    let shutdown = hartex_signal_utils::shutdown();
+    tokio::pin!(shutdown);
+
+    log::info!("recording gateway payloads to {path}; press ctrl+c to stop");
+
+    let mut recorded = 0u64;
+    while !limit.is_some_and(|limit| recorded >= limit) {
+        let result = tokio::select! {
+            biased;
+            () = &mut shutdown => break,
+            result = consumer.recv() => result,
+        };
+
+        let delivery = match result {
+            Ok(delivery) => delivery,
+            Err(error) => {
+                println!("{error:?}");
+
+                continue;
+            }
+        };
+        consumer.store_offset(&delivery)?;
+
+        let Some(bytes) = delivery.payload.as_deref() else {
+            continue;
+        };
+
+        // validate the envelope such that the recording can be replayed
+        let envelope: Envelope = match Envelope::from_bytes(bytes) {
+            Ok(envelope) => envelope,
+            Err(error) => {
+                println!("{:?}", Err::<(), EnvelopeError>(error).into_diagnostic());
+
+                continue;
+            }
+        };
+        log::trace!(
+            "[shard {shard_id}] recording {event_type} payload",
+            shard_id = envelope.shard_id,
+            event_type = envelope.event_type
+        );
+
+        writer.write_all(bytes).into_diagnostic()?;
+        writer.write_all(b"\n").into_diagnostic()?;
+        recorded += 1;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|error| error.into_error())
+        .into_diagnostic()?
+        .finish()
+        .into_diagnostic()?;
+    consumer.commit()?;
+    log::info!("recorded {recorded} gateway payload(s) to {path}");
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::env;
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::time::Duration;
+
+use clap::ArgMatches;
+use flate2::read::GzDecoder;
+use hartex_discord_core::dotenvy;
+use hartex_discord_core::tokio::time::sleep;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use miette::Report;
+
+/// Replaying recorded gateway payloads into the worker and entitycache topics.
+///
+/// The payloads are replayed with the delays between them as recorded, divided by the speed
+/// factor; a speed factor of zero replays the payloads as fast as possible.
+#[allow(clippy::module_name_repetitions)]
+pub async fn replay_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let path = matches.get_one::<String>("file").unwrap();
+    let speed = *matches.get_one::<f64>("speed").unwrap();
+    if speed < 0.0 || !speed.is_finite() {
+        return Err(Report::msg(format!("invalid replay speed: {speed}")));
+    }
+
+    let target = matches.get_one::<String>("target").unwrap();
+    let mut topics = Vec::new();
+    if matches!(target.as_str(), "all" | "worker") {
+        topics.push(env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD").into_diagnostic()?);
+    }
+    if matches!(target.as_str(), "all" | "cache") {
+        topics
+            .push(env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_CACHE").into_diagnostic()?);
+    }
+
+    let producer = Transport::from_env()?.producer()?;
+
+    let file = File::open(path).into_diagnostic()?;
+    let reader = BufReader::new(GzDecoder::new(file));
+
+    log::info!("replaying gateway payloads from {path} at {speed}x speed");
+
+    let mut previous_timestamp = None;
+    let mut replayed = 0u64;
+    for line in reader.lines() {
+        let line = line.into_diagnostic()?;
+        if line.is_empty() {
+            continue;
+        }
+
+        let envelope: Envelope = Envelope::from_bytes(line.as_bytes()).into_diagnostic()?;
+
+        if let Some(previous) = previous_timestamp.filter(|_| speed > 0.0) {
+            let delay = Duration::from_millis(envelope.timestamp.saturating_sub(previous));
+            sleep(delay.div_f64(speed)).await;
+        }
+        previous_timestamp = Some(envelope.timestamp);
+
+        log::trace!(
+            "[shard {shard_id}] replaying {event_type} payload",
+            shard_id = envelope.shard_id,
+            event_type = envelope.event_type
+        );
+
+        let key = format!(
+            "INBOUND_GATEWAY_PAYLOAD_SHARD_{shard_id}",
+            shard_id = envelope.shard_id
+        );
+        for topic in &topics {
+            producer.send(topic, &key, line.as_bytes()).await?;
+        }
+
+        replayed += 1;
+    }
+
+    producer.flush(Duration::from_secs(30))?;
+    log::info!("replayed {replayed} gateway payload(s) from {path}");
+
+    Ok(())
+}
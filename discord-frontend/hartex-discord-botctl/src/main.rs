@@ -0,0 +1,182 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Bot Control Executable
+//!
+//! This is the executable for controlling the running bot, such as changing its presence across
+//! all shards, recording and replaying gateway payloads, inspecting and re-driving dead letters,
+//! as well as checking and uploading guild configurations.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use clap::Arg;
+use clap::ArgAction;
+use clap::Command;
+use hartex_discord_core::tokio;
+
+mod cmdline;
+mod commands;
+
+/// Control tool entry point.
+#[tokio::main(flavor = "multi_thread")]
+pub async fn main() -> miette::Result<()> {
+    hartex_log::initialize();
+
+    let command = Command::new("botctl")
+        .subcommand(
+            Command::new("config")
+                .about("Checks and uploads the configurations of guilds.")
+                .subcommand(
+                    Command::new("check")
+                        .about("Evaluates and validates a configuration without storing it.")
+                        .arg(
+                            Arg::new("file")
+                                .required(true)
+                                .action(ArgAction::Set)
+                                .help("The configuration file to check."),
+                        ),
+                )
+                .subcommand(
+                    Command::new("upload")
+                        .about("Evaluates, validates and stores the configuration of a guild.")
+                        .arg(
+                            Arg::new("guild-id")
+                                .required(true)
+                                .action(ArgAction::Set)
+                                .help("The ID of the guild to upload the configuration for."),
+                        )
+                        .arg(
+                            Arg::new("file")
+                                .required(true)
+                                .action(ArgAction::Set)
+                                .help("The configuration file to upload."),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("deadletter")
+                .about("Inspects and re-drives events that could not be processed.")
+                .subcommand(
+                    Command::new("list")
+                        .about("Lists the dead letters in the dead-letter topic.")
+                        .arg(
+                            Arg::new("payload")
+                                .long("payload")
+                                .short('p')
+                                .action(ArgAction::SetTrue)
+                                .help("Whether to print the original payloads."),
+                        ),
+                )
+                .subcommand(
+                    Command::new("redrive")
+                        .about("Re-drives the dead letters into the topics they originate from.")
+                        .arg(
+                            Arg::new("limit")
+                                .long("limit")
+                                .short('l')
+                                .value_parser(clap::value_parser!(u64))
+                                .action(ArgAction::Set)
+                                .help("The maximum number of dead letters to re-drive."),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("presence")
+                .about("Changes the presence of the bot on all shards.")
+                .arg(
+                    Arg::new("template")
+                        .required(true)
+                        .action(ArgAction::Set)
+                        .help(
+                            "The activity text; supports {shard}, {shard_total} and {guild_count}.",
+                        ),
+                )
+                .arg(
+                    Arg::new("status")
+                        .long("status")
+                        .short('s')
+                        .default_value("online")
+                        .action(ArgAction::Set),
+                )
+                .arg(
+                    Arg::new("activity-type")
+                        .long("activity-type")
+                        .short('a')
+                        .default_value("watching")
+                        .action(ArgAction::Set),
+                ),
+        )
+        .subcommand(
+            Command::new("record")
+                .about("Records inbound gateway payloads into a compressed file.")
+                .arg(
+                    Arg::new("file")
+                        .required(true)
+                        .action(ArgAction::Set)
+                        .help("The file to write the recording to."),
+                )
+                .arg(
+                    Arg::new("limit")
+                        .long("limit")
+                        .short('l')
+                        .value_parser(clap::value_parser!(u64))
+                        .action(ArgAction::Set)
+                        .help("The maximum number of payloads to record."),
+                ),
+        )
+        .subcommand(
+            Command::new("replay")
+                .about("Replays recorded gateway payloads into the worker and entitycache topics.")
+                .arg(
+                    Arg::new("file")
+                        .required(true)
+                        .action(ArgAction::Set)
+                        .help("The recording to replay."),
+                )
+                .arg(
+                    Arg::new("speed")
+                        .long("speed")
+                        .short('s')
+                        .default_value("1.0")
+                        .value_parser(clap::value_parser!(f64))
+                        .action(ArgAction::Set)
+                        .help("The replay speed factor; 0 replays as fast as possible."),
+                )
+                .arg(
+                    Arg::new("target")
+                        .long("target")
+                        .short('t')
+                        .default_value("all")
+                        .value_parser(["all", "cache", "worker"])
+                        .action(ArgAction::Set)
+                        .help("The consumers to replay the payloads to."),
+                ),
+        );
+
+    let matches = command.get_matches();
+
+    cmdline::handle(matches).await?;
+
+    Ok(())
+}
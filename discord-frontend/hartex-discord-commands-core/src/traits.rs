@@ -24,6 +24,7 @@ use async_trait::async_trait;
 use hartex_discord_configuration_provider::ConfigurationProvider;
 use hartex_discord_core::discord::http::client::InteractionClient;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::gateway::Intents;
 use hartex_discord_core::discord::model::guild::Permissions;
 use hartex_discord_core::discord::model::id::marker::GuildMarker;
 use hartex_discord_core::discord::model::id::Id;
@@ -66,6 +67,11 @@ pub trait PluginMetadata {
 /// handled.
 #[async_trait]
 pub trait Plugin: PluginMetadata {
+    /// The gateway intents required by the plugin.
+    fn intents(&self) -> Intents {
+        Intents::empty()
+    }
+
     /// Whether a given plugin is enabled.
     async fn enabled(&self, guild_id: Id<GuildMarker>) -> miette::Result<bool> {
         ConfigurationProvider::plugin_enabled(guild_id, self.name()).await
@@ -71,6 +71,7 @@ impl Parse for CommandMetadataMacroInput {
         result.comma3.replace(comma_3);
         result.minimum_permission_level_ident = input.parse().ok();
         result.equal_2 = input.parse().ok();
+        result.minimum_permission_level = input.parse().ok();
         result.comma4 = input.parse().ok();
 
         Ok(result)
@@ -149,7 +150,7 @@ pub fn implement_metadata(
             return None;
         };
 
-        let Some(Expr::Binary(expr)) = parameters.minimum_permission_level.clone() else {
+        let Some(expr) = parameters.minimum_permission_level.clone() else {
             parameters
                 .minimum_permission_level
                 .span()
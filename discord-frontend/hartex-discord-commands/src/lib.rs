@@ -33,5 +33,21 @@
 #![feature(if_let_guard)]
 #![feature(let_chains)]
 
+use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_core::discord::model::gateway::Intents;
+
+use crate::general::General;
+use crate::management::Management;
+use crate::modlog::Modlog;
+use crate::utilities::Utilities;
+
 pub mod general;
+pub mod management;
+pub mod modlog;
 pub mod utilities;
+
+/// The gateway intents required by all plugins.
+#[must_use]
+pub fn intents() -> Intents {
+    General.intents() | Management.intents() | Modlog.intents() | Utilities.intents()
+}
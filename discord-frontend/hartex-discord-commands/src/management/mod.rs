@@ -0,0 +1,55 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Management Plugin
+//!
+//! Command list:
+//! - ban
+//! - kick
+//! - purge
+//! - timeout
+//! - unban
+//! - warn
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::plugin;
+use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_core::discord::model::gateway::Intents;
+
+pub mod ban;
+pub mod kick;
+pub mod moderation;
+pub mod purge;
+pub mod timeout;
+pub mod unban;
+pub mod warn;
+
+/// The management plugin.
+#[plugin(name = "management")]
+pub struct Management;
+
+#[async_trait]
+impl Plugin for Management {
+    fn intents(&self) -> Intents {
+        Intents::GUILDS | Intents::GUILD_MEMBERS
+    }
+}
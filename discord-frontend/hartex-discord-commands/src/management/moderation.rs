@@ -0,0 +1,341 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Moderation Utilities
+//!
+//! Utilities shared by the moderation commands of the management plugin.
+
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::http::error::ErrorType;
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::tokio::sync::OnceCell;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_entitycache_repositories::member::CachedMemberRepository;
+use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+use hartex_discord_utils::interaction::embed_response;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::CLIENT;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+/// The maximum length of an audit log reason.
+const AUDIT_LOG_REASON_LIMIT: usize = 512;
+
+/// The user identifier of the bot, retrieved from Discord upon first use.
+static BOT_USER_ID: OnceCell<Id<UserMarker>> = OnceCell::const_new();
+
+/// The outcome of checking whether a moderator may perform a moderation action on a user.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Hierarchy {
+    /// The moderation action may be performed.
+    Permitted,
+    /// The user is the moderator.
+    TargetIsModerator,
+    /// The user is the bot.
+    TargetIsBot,
+    /// The highest role of the user is not lower than that of the moderator.
+    ModeratorTooLow,
+    /// The highest role of the user is not lower than that of the bot.
+    BotTooLow,
+}
+
+impl Hierarchy {
+    /// Checks the role hierarchy of a guild between a moderator, the bot and a user.
+    ///
+    /// Members missing from the cache are retrieved from Discord, such that a user is only
+    /// considered to have no roles if Discord confirms that they are not a member of the guild.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn check(
+        guild_id: Id<GuildMarker>,
+        moderator_id: Id<UserMarker>,
+        bot_id: Id<UserMarker>,
+        user_id: Id<UserMarker>,
+    ) -> miette::Result<Self> {
+        if user_id == moderator_id {
+            return Ok(Self::TargetIsModerator);
+        }
+
+        if user_id == bot_id {
+            return Ok(Self::TargetIsBot);
+        }
+
+        let owner_id = CachedGuildRepository
+            .get(guild_id)
+            .await
+            .into_diagnostic()?
+            .owner_id;
+
+        Ok(Self::evaluate(&Ranks {
+            owner_id,
+            moderator_id,
+            moderator_position: highest_role_position(guild_id, moderator_id).await?,
+            bot_id,
+            bot_position: highest_role_position(guild_id, bot_id).await?,
+            user_id,
+            user_position: highest_role_position(guild_id, user_id).await?,
+        }))
+    }
+
+    /// Evaluates the role hierarchy of a guild between a moderator, the bot and a user.
+    ///
+    /// The owner of the guild outranks every other member. Users that are not members of the
+    /// guild, such as banned users, have no roles and are outranked by every member.
+    #[must_use]
+    pub fn evaluate(ranks: &Ranks) -> Self {
+        if ranks.user_id == ranks.moderator_id {
+            return Self::TargetIsModerator;
+        }
+
+        if ranks.user_id == ranks.bot_id {
+            return Self::TargetIsBot;
+        }
+
+        if ranks.user_id == ranks.owner_id {
+            return Self::ModeratorTooLow;
+        }
+
+        let Some(user_position) = ranks.user_position else {
+            return Self::Permitted;
+        };
+
+        if ranks.moderator_id != ranks.owner_id
+            && ranks.moderator_position.unwrap_or_default() <= user_position
+        {
+            return Self::ModeratorTooLow;
+        }
+
+        if ranks.bot_id != ranks.owner_id && ranks.bot_position.unwrap_or_default() <= user_position
+        {
+            return Self::BotTooLow;
+        }
+
+        Self::Permitted
+    }
+
+    /// Returns the localized error explaining why the moderation action may not be performed, if
+    /// it may not be.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn error(
+        &self,
+        user_id: Id<UserMarker>,
+        localizer: &Localizer<'_>,
+    ) -> miette::Result<Option<String>> {
+        let error = match self {
+            Self::Permitted => return Ok(None),
+            Self::TargetIsModerator => {
+                localizer.management_plugin_moderation_error_target_moderator()?
+            }
+            Self::TargetIsBot => localizer.management_plugin_moderation_error_target_bot()?,
+            Self::ModeratorTooLow => localizer
+                .management_plugin_moderation_error_moderator_hierarchy(
+                    user_id.mention().to_string(),
+                )?,
+            Self::BotTooLow => localizer
+                .management_plugin_moderation_error_bot_hierarchy(user_id.mention().to_string())?,
+        };
+
+        Ok(Some(error))
+    }
+}
+
+/// The positions of the highest roles of a moderator, the bot and a user in a guild.
+///
+/// A position is `None` if the user is not a member of the guild, and zero if the member has no
+/// roles.
+pub struct Ranks {
+    /// The owner of the guild.
+    pub owner_id: Id<UserMarker>,
+    /// The moderator performing the moderation action.
+    pub moderator_id: Id<UserMarker>,
+    /// The position of the highest role of the moderator.
+    pub moderator_position: Option<i64>,
+    /// The bot.
+    pub bot_id: Id<UserMarker>,
+    /// The position of the highest role of the bot.
+    pub bot_position: Option<i64>,
+    /// The user the moderation action is performed on.
+    pub user_id: Id<UserMarker>,
+    /// The position of the highest role of the user.
+    pub user_position: Option<i64>,
+}
+
+/// Returns the user identifier of the bot.
+///
+/// The application identifier of an interaction is not necessarily the user identifier of the
+/// bot, hence the current user is retrieved from Discord instead.
+#[allow(clippy::missing_errors_doc)]
+pub async fn bot_user_id() -> miette::Result<Id<UserMarker>> {
+    BOT_USER_ID
+        .get_or_try_init(|| async {
+            let user = CLIENT
+                .current_user()
+                .await
+                .into_diagnostic()?
+                .model()
+                .await
+                .into_diagnostic()?;
+
+            Ok(user.id)
+        })
+        .await
+        .copied()
+}
+
+/// Returns the reason for an audit log entry of a moderation action, naming the moderator.
+#[allow(clippy::missing_errors_doc)]
+pub fn audit_log_reason(
+    interaction: &Interaction,
+    reason: &str,
+    localizer: &Localizer<'_>,
+) -> miette::Result<String> {
+    let moderator_name = interaction
+        .author()
+        .map_or_else(String::new, |user| user.name.clone());
+    let audit_log_reason = localizer
+        .management_plugin_moderation_auditlog_reason(moderator_name, reason.to_string())?;
+
+    Ok(audit_log_reason
+        .chars()
+        .take(AUDIT_LOG_REASON_LIMIT)
+        .collect())
+}
+
+/// Returns whether a user is a cached member of a guild.
+#[allow(clippy::missing_errors_doc)]
+pub async fn is_member(guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> miette::Result<bool> {
+    CachedMemberRepository
+        .exists((guild_id, user_id))
+        .await
+        .into_diagnostic()
+}
+
+/// Returns the reason given for a moderation action, or the localized default if none was given.
+#[allow(clippy::missing_errors_doc)]
+pub fn reason_or_default(reason: String, localizer: &Localizer<'_>) -> miette::Result<String> {
+    if reason.is_empty() {
+        return localizer.management_plugin_moderation_no_reason();
+    }
+
+    Ok(reason)
+}
+
+/// Responds to the interaction of a moderation command with an error.
+#[allow(clippy::missing_errors_doc)]
+pub async fn respond_error(
+    interaction: &Interaction,
+    interaction_client: &InteractionClient<'_>,
+    error: String,
+) -> miette::Result<()> {
+    respond(
+        interaction,
+        interaction_client,
+        &ephemeral_error_response(error),
+    )
+    .await
+}
+
+/// Responds to the interaction of a moderation command with the outcome of the moderation action.
+#[allow(clippy::missing_errors_doc)]
+pub async fn respond_success(
+    interaction: &Interaction,
+    interaction_client: &InteractionClient<'_>,
+    description: String,
+) -> miette::Result<()> {
+    let embed = EmbedBuilder::new()
+        .color(0x41_A0_DE)
+        .description(description)
+        .validate()
+        .into_diagnostic()?
+        .build();
+
+    respond(
+        interaction,
+        interaction_client,
+        &embed_response(vec![embed]),
+    )
+    .await
+}
+
+/// Returns the position of the highest role of a member, if the user is a member of the guild.
+///
+/// Members missing from the cache, such as those of large guilds that have not been chunked yet,
+/// are retrieved from Discord.
+async fn highest_role_position(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+) -> miette::Result<Option<i64>> {
+    let role_ids = if is_member(guild_id, user_id).await? {
+        CachedMemberRepository
+            .get((guild_id, user_id))
+            .await
+            .into_diagnostic()?
+            .roles
+    } else {
+        match CLIENT.guild_member(guild_id, user_id).await {
+            Ok(response) => response.model().await.into_diagnostic()?.roles,
+            Err(error) if matches!(error.kind(), ErrorType::Response { status, .. } if status.get() == 404) =>
+            {
+                return Ok(None);
+            }
+            Err(error) => return Err(error).into_diagnostic(),
+        }
+    };
+
+    let roles = CachedRoleRepository
+        .get_many(
+            role_ids
+                .into_iter()
+                .map(|role_id| (guild_id, role_id))
+                .collect(),
+        )
+        .await
+        .into_diagnostic()?;
+
+    Ok(Some(
+        roles
+            .iter()
+            .map(|role| role.position)
+            .max()
+            .unwrap_or_default(),
+    ))
+}
+
+/// Responds to the interaction of a moderation command.
+async fn respond(
+    interaction: &Interaction,
+    interaction_client: &InteractionClient<'_>,
+    response: &InteractionResponse,
+) -> miette::Result<()> {
+    interaction_client
+        .create_response(interaction.id, &interaction.token, response)
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
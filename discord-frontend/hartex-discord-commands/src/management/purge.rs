@@ -0,0 +1,130 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Purge Command
+//!
+//! This command deletes recent messages in the current channel, optionally only those sent by a
+//! certain user.
+
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::http::request::AuditLogReason;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_utils::commands::CommandDataOptionsExt;
+use hartex_discord_utils::CLIENT;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+use crate::management::moderation;
+use crate::management::Management;
+
+/// The maximum age of a message that can be deleted in bulk, in seconds.
+const BULK_DELETE_MAX_AGE: i64 = 14 * 24 * 60 * 60;
+
+/// The maximum number of messages fetched at once.
+const MESSAGE_FETCH_LIMIT: u16 = 100;
+
+/// The `purge` command declaration.
+#[command(name = "purge", plugin = Management, required_permissions = Permissions::MANAGE_MESSAGES)]
+pub struct Purge;
+
+#[async_trait]
+impl Command for Purge {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::ApplicationCommand(command)) = interaction.data.clone() else {
+            unreachable!()
+        };
+
+        let channel_id = interaction.channel.clone().unwrap().id;
+        let count = usize::try_from(command.options.integer_value_of("count")).into_diagnostic()?;
+        let user_id = command
+            .options
+            .iter()
+            .any(|option| option.name == "user")
+            .then(|| command.options.user_value_of("user"));
+        let reason =
+            moderation::reason_or_default(command.options.string_value_of("reason"), &localizer)?;
+
+        // messages by a certain user may be interleaved with others, so as many messages as
+        // possible are fetched to find enough of them
+        let limit = if user_id.is_some() {
+            MESSAGE_FETCH_LIMIT
+        } else {
+            u16::try_from(count).into_diagnostic()?
+        };
+        let messages = CLIENT
+            .channel_messages(channel_id)
+            .limit(limit)
+            .await
+            .into_diagnostic()?
+            .models()
+            .await
+            .into_diagnostic()?;
+
+        // messages older than two weeks cannot be deleted in bulk
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .into_diagnostic()?;
+        let oldest = i64::try_from(now.as_secs()).into_diagnostic()? - BULK_DELETE_MAX_AGE;
+        let message_ids = messages
+            .into_iter()
+            .filter(|message| user_id.map_or(true, |user_id| message.author.id == user_id))
+            .filter(|message| message.timestamp.as_secs() > oldest)
+            .take(count)
+            .map(|message| message.id)
+            .collect::<Vec<_>>();
+
+        let audit_log_reason = moderation::audit_log_reason(&interaction, &reason, &localizer)?;
+        match message_ids.as_slice() {
+            [] => (),
+            [message_id] => {
+                CLIENT
+                    .delete_message(channel_id, *message_id)
+                    .reason(&audit_log_reason)
+                    .await
+                    .into_diagnostic()?;
+            }
+            _ => {
+                CLIENT
+                    .delete_messages(channel_id, &message_ids)
+                    .reason(&audit_log_reason)
+                    .await
+                    .into_diagnostic()?;
+            }
+        }
+
+        let purge_success =
+            localizer.management_plugin_purge_success(message_ids.len().to_string())?;
+
+        moderation::respond_success(&interaction, interaction_client, purge_success).await
+    }
+}
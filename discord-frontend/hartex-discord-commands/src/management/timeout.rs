@@ -0,0 +1,108 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Timeout Command
+//!
+//! This command times out a member of the server for a number of minutes.
+
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::http::request::AuditLogReason;
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::util::Timestamp;
+use hartex_discord_utils::commands::CommandDataOptionsExt;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_discord_utils::CLIENT;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+use crate::management::moderation;
+use crate::management::moderation::Hierarchy;
+use crate::management::Management;
+
+/// The `timeout` command declaration.
+#[command(name = "timeout", plugin = Management, required_permissions = Permissions::MODERATE_MEMBERS)]
+pub struct Timeout;
+
+#[async_trait]
+impl Command for Timeout {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::ApplicationCommand(command)) = interaction.data.clone() else {
+            unreachable!()
+        };
+
+        let guild_id = interaction.guild_id.unwrap();
+        let user_id = command.options.user_value_of("user");
+        let duration = command.options.integer_value_of("duration");
+        let reason =
+            moderation::reason_or_default(command.options.string_value_of("reason"), &localizer)?;
+
+        if !moderation::is_member(guild_id, user_id).await? {
+            let error = localizer
+                .management_plugin_moderation_error_not_member(user_id.mention().to_string())?;
+            return moderation::respond_error(&interaction, interaction_client, error).await;
+        }
+
+        let hierarchy = Hierarchy::check(
+            guild_id,
+            interaction.author_id().unwrap(),
+            moderation::bot_user_id().await?,
+            user_id,
+        )
+        .await?;
+        if let Some(error) = hierarchy.error(user_id, &localizer)? {
+            return moderation::respond_error(&interaction, interaction_client, error).await;
+        }
+
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .into_diagnostic()?;
+        let until = i64::try_from(now.as_secs()).into_diagnostic()? + duration * 60;
+
+        let audit_log_reason = moderation::audit_log_reason(&interaction, &reason, &localizer)?;
+        CLIENT
+            .update_guild_member(guild_id, user_id)
+            .communication_disabled_until(Some(Timestamp::from_secs(until).into_diagnostic()?))
+            .reason(&audit_log_reason)
+            .await
+            .into_diagnostic()?;
+
+        let timeout_success = localizer.management_plugin_timeout_success(
+            reason,
+            until.to_string().discord_relative_timestamp(),
+            user_id.mention().to_string(),
+        )?;
+
+        moderation::respond_success(&interaction, interaction_client, timeout_success).await
+    }
+}
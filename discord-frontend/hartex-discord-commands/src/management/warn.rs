@@ -0,0 +1,154 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Warn Command
+//!
+//! This command records a warning of a member of the server, and notifies the member of it.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::warning_count_by_guild_id_and_user_id::warning_count_by_guild_id_and_user_id;
+use hartex_database_queries::discord_frontend::queries::warning_insert::warning_insert;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_utils::commands::CommandDataOptionsExt;
+use hartex_discord_utils::CLIENT;
+use hartex_discord_utils::DATABASE_POOL;
+use hartex_localization_core::Localizer;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use tokio_postgres::GenericClient;
+
+use crate::management::moderation;
+use crate::management::moderation::Hierarchy;
+use crate::management::Management;
+
+/// The `warn` command declaration.
+#[command(name = "warn", plugin = Management, required_permissions = Permissions::MODERATE_MEMBERS)]
+pub struct Warn;
+
+#[async_trait]
+impl Command for Warn {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::ApplicationCommand(command)) = interaction.data.clone() else {
+            unreachable!()
+        };
+
+        let guild_id = interaction.guild_id.unwrap();
+        let moderator_id = interaction.author_id().unwrap();
+        let user_id = command.options.user_value_of("user");
+        let reason = command.options.string_value_of("reason");
+
+        if !moderation::is_member(guild_id, user_id).await? {
+            let error = localizer
+                .management_plugin_moderation_error_not_member(user_id.mention().to_string())?;
+            return moderation::respond_error(&interaction, interaction_client, error).await;
+        }
+
+        let hierarchy = Hierarchy::check(
+            guild_id,
+            moderator_id,
+            moderation::bot_user_id().await?,
+            user_id,
+        )
+        .await?;
+        if let Some(error) = hierarchy.error(user_id, &localizer)? {
+            return moderation::respond_error(&interaction, interaction_client, error).await;
+        }
+
+        // the guild may be missing from the cache, in which case its identifier is used as its
+        // name in the direct message
+        let guild_name = CachedGuildRepository
+            .get(guild_id)
+            .await
+            .map_or_else(|_| guild_id.to_string(), |guild| guild.name);
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        warning_insert()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &moderator_id.to_string(),
+                &reason,
+                &user_id.to_string(),
+            )
+            .await
+            .into_diagnostic()?;
+        let count = warning_count_by_guild_id_and_user_id()
+            .bind(client, &guild_id.to_string(), &user_id.to_string())
+            .one()
+            .await
+            .into_diagnostic()?;
+
+        // the member may not accept direct messages, which does not prevent the warning from
+        // being recorded
+        let warn_direct_message =
+            localizer.management_plugin_warn_direct_message(guild_name, reason.clone())?;
+        if let Err(error) = notify(user_id, &warn_direct_message).await {
+            log::warn!("failed to notify user {user_id} of warning: {error:?}");
+        }
+
+        let warn_success = localizer.management_plugin_warn_success(
+            count.to_string(),
+            reason,
+            user_id.mention().to_string(),
+        )?;
+
+        moderation::respond_success(&interaction, interaction_client, warn_success).await
+    }
+}
+
+/// Sends a direct message to a user.
+async fn notify(user_id: Id<UserMarker>, content: &str) -> miette::Result<()> {
+    let channel = CLIENT
+        .create_private_channel(user_id)
+        .await
+        .into_diagnostic()?
+        .model()
+        .await
+        .into_diagnostic()?;
+
+    CLIENT
+        .create_message(channel.id)
+        .content(content)
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
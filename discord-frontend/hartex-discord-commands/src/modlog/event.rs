@@ -0,0 +1,78 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Modlog Events
+//!
+//! The events that can be logged by the modlog plugin.
+
+use hartex_discord_configuration_models::eventflags::EventFlags;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::MessageMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+
+/// An event that can be logged by the modlog plugin.
+#[allow(clippy::module_name_repetitions)]
+pub enum ModlogEvent {
+    /// A message has been deleted.
+    MessageDeleted {
+        /// The channel the message was sent in.
+        channel_id: Id<ChannelMarker>,
+        /// The ID of the message.
+        id: Id<MessageMarker>,
+        /// The message before it was deleted, if it was cached.
+        message: Option<MessageEntity>,
+    },
+    /// Multiple messages have been deleted at once.
+    MessagesDeleted {
+        /// The channel the messages were sent in.
+        channel_id: Id<ChannelMarker>,
+        /// The IDs of the messages.
+        ids: Vec<Id<MessageMarker>>,
+        /// The messages before they were deleted, for those that were cached.
+        messages: Vec<MessageEntity>,
+    },
+    /// A message has been edited.
+    MessageUpdated {
+        /// The channel the message was sent in.
+        channel_id: Id<ChannelMarker>,
+        /// The ID of the message.
+        id: Id<MessageMarker>,
+        /// The message before it was edited, if it was cached.
+        before: Option<MessageEntity>,
+        /// The content of the message after it was edited.
+        content: String,
+    },
+}
+
+impl ModlogEvent {
+    /// Returns the event flag a logger must listen to for the event to be logged by it.
+    #[must_use]
+    pub fn flag(&self) -> EventFlags {
+        match self {
+            Self::MessageDeleted { .. } | Self::MessagesDeleted { .. } => {
+                EventFlags::MESSAGE_DELETED
+            }
+            Self::MessageUpdated { .. } => EventFlags::MESSAGE_UPDATED,
+        }
+    }
+}
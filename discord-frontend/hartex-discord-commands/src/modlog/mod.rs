@@ -0,0 +1,217 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Modlog Plugin
+//!
+//! The modlog plugin has no commands; instead, it logs events occurring in a guild to the
+//! channels of the loggers configured for the guild.
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::plugin;
+use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_configuration_models::eventflags::EventFlags;
+use hartex_discord_configuration_models::plugins::modlog::logger::ModlogFormat;
+use hartex_discord_configuration_models::plugins::modlog::logger::ModlogLogger;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::model::channel::message::AllowedMentions;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageDelete;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageDeleteBulk;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageUpdate;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+use hartex_discord_utils::CLIENT;
+use hartex_localization_core::Localizer;
+use hartex_localization_core::LOCALIZATION_HOLDER;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+use crate::modlog::event::ModlogEvent;
+
+pub mod event;
+mod render;
+
+/// The modlog plugin.
+#[plugin(name = "modlog")]
+pub struct Modlog;
+
+#[async_trait]
+impl Plugin for Modlog {
+    fn intents(&self) -> Intents {
+        EventFlags::LOGGED.intents()
+    }
+}
+
+/// Logs an event occurring in a guild to the loggers of the guild listening to it.
+///
+/// A logger failing to log the event does not prevent the remaining loggers from logging it.
+#[allow(clippy::missing_errors_doc)]
+pub async fn log(guild_id: Id<GuildMarker>, event: &ModlogEvent) -> miette::Result<()> {
+    let loggers = ConfigurationProvider::modlog_loggers(guild_id)
+        .await?
+        .into_iter()
+        .filter(|logger| logger.events.contains(event.flag()))
+        .collect::<Vec<_>>();
+    if loggers.is_empty() {
+        return Ok(());
+    }
+
+    // the preferred locale of the guild is only known if the guild is cached, and defaults to
+    // en-US for which there are no localizations
+    let locale = CachedGuildRepository
+        .get(guild_id)
+        .await
+        .ok()
+        .map(|guild| guild.preferred_locale)
+        .filter(|locale| LOCALIZATION_HOLDER.supports(locale))
+        .unwrap_or_else(|| String::from("en-GB"));
+    let localizer = Localizer::new(&LOCALIZATION_HOLDER, &locale);
+
+    for logger in loggers {
+        if let Err(error) = send(&logger, event, &localizer).await {
+            log::warn!(
+                "failed to log event to channel {} of guild {guild_id}: {error:?}",
+                logger.channel
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Logs the deletion of a message.
+///
+/// This must be called before the message is evicted from the cache.
+#[allow(clippy::missing_errors_doc)]
+pub async fn message_delete(message_delete: &MessageDelete) -> miette::Result<()> {
+    let Some(guild_id) = message_delete.guild_id else {
+        return Ok(());
+    };
+
+    let message = CachedMessageRepository
+        .get_many(vec![message_delete.id])
+        .await
+        .into_diagnostic()?
+        .pop();
+
+    log(
+        guild_id,
+        &ModlogEvent::MessageDeleted {
+            channel_id: message_delete.channel_id,
+            id: message_delete.id,
+            message,
+        },
+    )
+    .await
+}
+
+/// Logs the deletion of multiple messages at once.
+///
+/// This must be called before the messages are evicted from the cache.
+#[allow(clippy::missing_errors_doc)]
+pub async fn message_delete_bulk(message_delete_bulk: &MessageDeleteBulk) -> miette::Result<()> {
+    let Some(guild_id) = message_delete_bulk.guild_id else {
+        return Ok(());
+    };
+
+    let mut messages = CachedMessageRepository
+        .get_many(message_delete_bulk.ids.clone())
+        .await
+        .into_diagnostic()?;
+    messages.sort_unstable_by_key(|message| message.id);
+
+    log(
+        guild_id,
+        &ModlogEvent::MessagesDeleted {
+            channel_id: message_delete_bulk.channel_id,
+            ids: message_delete_bulk.ids.clone(),
+            messages,
+        },
+    )
+    .await
+}
+
+/// Logs the edit of a message.
+///
+/// Updates that do not change the content of a message, such as those that only add embeds to
+/// it, are not logged. This must be called before the message is updated in the cache.
+#[allow(clippy::missing_errors_doc)]
+pub async fn message_update(message_update: &MessageUpdate) -> miette::Result<()> {
+    let (Some(guild_id), Some(content)) = (message_update.guild_id, &message_update.content) else {
+        return Ok(());
+    };
+
+    let before = CachedMessageRepository
+        .get_many(vec![message_update.id])
+        .await
+        .into_diagnostic()?
+        .pop();
+    if before
+        .as_ref()
+        .is_some_and(|message| &message.content == content)
+    {
+        return Ok(());
+    }
+
+    log(
+        guild_id,
+        &ModlogEvent::MessageUpdated {
+            channel_id: message_update.channel_id,
+            id: message_update.id,
+            before,
+            content: content.clone(),
+        },
+    )
+    .await
+}
+
+/// Sends an event to the channel of a logger, in the format configured for the logger.
+async fn send(
+    logger: &ModlogLogger,
+    event: &ModlogEvent,
+    localizer: &Localizer<'_>,
+) -> miette::Result<()> {
+    let channel_id = Id::<ChannelMarker>::from_str(&logger.channel).into_diagnostic()?;
+    let allowed_mentions = AllowedMentions::default();
+    let request = CLIENT
+        .create_message(channel_id)
+        .allowed_mentions(Some(&allowed_mentions));
+
+    match logger.format {
+        ModlogFormat::Default => {
+            let content = render::text(event, localizer)?;
+            request.content(&content).await.into_diagnostic()?;
+        }
+        ModlogFormat::Pretty => {
+            let embed = render::embed(event, localizer)?;
+            request.embeds(&[embed]).await.into_diagnostic()?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,294 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Modlog Rendering
+//!
+//! Renders the events logged by the modlog plugin, either as plain text or as embeds.
+
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::channel::message::Embed;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFooterBuilder;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+use crate::modlog::event::ModlogEvent;
+
+/// The maximum length of the content of a message sent to a logger.
+const MESSAGE_CONTENT_LIMIT: usize = 2000;
+
+/// The maximum length of the description of an embed sent to a logger.
+const EMBED_DESCRIPTION_LIMIT: usize = 4096;
+
+/// The maximum length of the value of a field of an embed sent to a logger.
+const EMBED_FIELD_VALUE_LIMIT: usize = 1024;
+
+/// The maximum length of the content of a logged message included in a rendered event.
+const LOGGED_CONTENT_LIMIT: usize = 800;
+
+/// Renders an event as plain text.
+pub fn text(event: &ModlogEvent, localizer: &Localizer<'_>) -> miette::Result<String> {
+    let rendered = match event {
+        ModlogEvent::MessageDeleted {
+            channel_id,
+            id,
+            message,
+        } => {
+            let header = localizer.modlog_plugin_messagedeleted_text(
+                author_mention(message.as_ref(), localizer)?,
+                channel_id.mention().to_string(),
+                id.to_string(),
+            )?;
+            let mut rendered = format!(
+                "{header}\n{}",
+                quote(&content(message.as_ref(), localizer)?)
+            );
+
+            if let Some(attachments) = message.as_ref().and_then(attachments) {
+                let attachments_name =
+                    localizer.modlog_plugin_messagelog_embed_attachments_field_name()?;
+                rendered.push_str(&format!(
+                    "\n{} {attachments}",
+                    format!("{attachments_name}:").discord_bold()
+                ));
+            }
+
+            rendered
+        }
+        ModlogEvent::MessagesDeleted {
+            channel_id,
+            ids,
+            messages,
+        } => {
+            let header = localizer.modlog_plugin_messagesdeleted_text(
+                channel_id.mention().to_string(),
+                ids.len().to_string(),
+            )?;
+
+            format!("{header}\n{}", summary(messages))
+        }
+        ModlogEvent::MessageUpdated {
+            channel_id,
+            id,
+            before,
+            content: after,
+        } => {
+            let header = localizer.modlog_plugin_messageupdated_text(
+                author_mention(before.as_ref(), localizer)?,
+                channel_id.mention().to_string(),
+                id.to_string(),
+            )?;
+            let before_name = localizer.modlog_plugin_messagelog_embed_before_field_name()?;
+            let after_name = localizer.modlog_plugin_messagelog_embed_after_field_name()?;
+
+            format!(
+                "{header}\n{}\n{}\n{}\n{}",
+                format!("{before_name}:").discord_bold(),
+                quote(&content(before.as_ref(), localizer)?),
+                format!("{after_name}:").discord_bold(),
+                quote(&nonempty(after.clone(), localizer)?),
+            )
+        }
+    };
+
+    Ok(truncate(&rendered, MESSAGE_CONTENT_LIMIT))
+}
+
+/// Renders an event as an embed.
+pub fn embed(event: &ModlogEvent, localizer: &Localizer<'_>) -> miette::Result<Embed> {
+    let author_name = localizer.modlog_plugin_messagelog_embed_author_field_name()?;
+    let channel_name = localizer.modlog_plugin_messagelog_embed_channel_field_name()?;
+
+    let builder = match event {
+        ModlogEvent::MessageDeleted {
+            channel_id,
+            id,
+            message,
+        } => {
+            let mut builder = EmbedBuilder::new()
+                .color(0x41_A0_DE)
+                .title(localizer.modlog_plugin_messagedeleted_embed_title()?)
+                .field(EmbedFieldBuilder::new(
+                    author_name,
+                    author_mention(message.as_ref(), localizer)?,
+                ))
+                .field(EmbedFieldBuilder::new(
+                    channel_name,
+                    channel_id.mention().to_string(),
+                ))
+                .field(EmbedFieldBuilder::new(
+                    localizer.modlog_plugin_messagelog_embed_content_field_name()?,
+                    truncate(
+                        &content(message.as_ref(), localizer)?,
+                        EMBED_FIELD_VALUE_LIMIT,
+                    ),
+                ))
+                .footer(EmbedFooterBuilder::new(
+                    localizer.modlog_plugin_messagelog_embed_footer(id.to_string())?,
+                ));
+
+            if let Some(attachments) = message.as_ref().and_then(attachments) {
+                builder = builder.field(EmbedFieldBuilder::new(
+                    localizer.modlog_plugin_messagelog_embed_attachments_field_name()?,
+                    truncate(&attachments, EMBED_FIELD_VALUE_LIMIT),
+                ));
+            }
+
+            builder
+        }
+        ModlogEvent::MessagesDeleted {
+            channel_id,
+            ids,
+            messages,
+        } => EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .title(localizer.modlog_plugin_messagesdeleted_embed_title()?)
+            .description(truncate(&summary(messages), EMBED_DESCRIPTION_LIMIT))
+            .field(EmbedFieldBuilder::new(
+                channel_name,
+                channel_id.mention().to_string(),
+            ))
+            .field(EmbedFieldBuilder::new(
+                localizer.modlog_plugin_messagelog_embed_count_field_name()?,
+                ids.len().to_string(),
+            )),
+        ModlogEvent::MessageUpdated {
+            channel_id,
+            id,
+            before,
+            content: after,
+        } => EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .title(localizer.modlog_plugin_messageupdated_embed_title()?)
+            .field(EmbedFieldBuilder::new(
+                author_name,
+                author_mention(before.as_ref(), localizer)?,
+            ))
+            .field(EmbedFieldBuilder::new(
+                channel_name,
+                channel_id.mention().to_string(),
+            ))
+            .field(EmbedFieldBuilder::new(
+                localizer.modlog_plugin_messagelog_embed_before_field_name()?,
+                truncate(
+                    &content(before.as_ref(), localizer)?,
+                    EMBED_FIELD_VALUE_LIMIT,
+                ),
+            ))
+            .field(EmbedFieldBuilder::new(
+                localizer.modlog_plugin_messagelog_embed_after_field_name()?,
+                truncate(
+                    &nonempty(after.clone(), localizer)?,
+                    EMBED_FIELD_VALUE_LIMIT,
+                ),
+            ))
+            .footer(EmbedFooterBuilder::new(
+                localizer.modlog_plugin_messagelog_embed_footer(id.to_string())?,
+            )),
+    };
+
+    Ok(builder.validate().into_diagnostic()?.build())
+}
+
+/// Returns the names of the attachments of a message, if it has any.
+fn attachments(message: &MessageEntity) -> Option<String> {
+    if message.attachments.is_empty() {
+        return None;
+    }
+
+    Some(
+        message
+            .attachments
+            .iter()
+            .map(|attachment| attachment.filename.clone().discord_inline_code())
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+/// Returns the mention of the author of a message, if the message was cached.
+fn author_mention(
+    message: Option<&MessageEntity>,
+    localizer: &Localizer<'_>,
+) -> miette::Result<String> {
+    match message {
+        Some(message) => Ok(message.author_id.mention().to_string()),
+        None => localizer.modlog_plugin_messagelog_author_unknown(),
+    }
+}
+
+/// Returns the content of a message, truncated to the length included in a rendered event.
+fn content(message: Option<&MessageEntity>, localizer: &Localizer<'_>) -> miette::Result<String> {
+    match message {
+        Some(message) => nonempty(message.content.clone(), localizer),
+        None => localizer.modlog_plugin_messagelog_content_unavailable(),
+    }
+}
+
+/// Truncates content to the length included in a rendered event, replacing empty content with a
+/// placeholder.
+fn nonempty(content: String, localizer: &Localizer<'_>) -> miette::Result<String> {
+    if content.is_empty() {
+        return localizer
+            .modlog_plugin_messagelog_content_empty()
+            .map(MarkdownStyle::discord_italic);
+    }
+
+    Ok(truncate(&content, LOGGED_CONTENT_LIMIT))
+}
+
+/// Quotes text line by line.
+fn quote(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("> {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Summarizes deleted messages, one line per message.
+fn summary(messages: &[MessageEntity]) -> String {
+    messages
+        .iter()
+        .map(|message| {
+            format!(
+                "{}: {}",
+                message.author_id.mention(),
+                truncate(&message.content.replace('\n', " "), 100)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Truncates text to a number of characters, marking the truncation with an ellipsis.
+fn truncate(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_string();
+    }
+
+    let mut truncated = text.chars().take(limit - 1).collect::<String>();
+    truncated.push('…');
+    truncated
+}
@@ -0,0 +1,187 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Info Channel Subcommand
+//!
+//! This command returns information about a channel.
+//!
+//! Threads are cached separately from channels and only have their general information shown.
+
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_core::discord::util::snowflake::Snowflake;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::channel::CachedChannelRepository;
+use hartex_discord_entitycache_repositories::thread::CachedThreadRepository;
+use hartex_discord_utils::commands::CommandDataOptionExt;
+use hartex_discord_utils::commands::CommandDataOptionsExt;
+use hartex_discord_utils::interaction::embed_response;
+use hartex_discord_utils::localizable::Localizable;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+/// Executes the `info channel` command.
+pub async fn execute(
+    interaction: Interaction,
+    interaction_client: &InteractionClient<'_>,
+    option: CommandDataOption,
+    localizer: Localizer<'_>,
+) -> miette::Result<()> {
+    let options = option.assume_subcommand();
+
+    let langid_locale = interaction
+        .locale
+        .clone()
+        .and_then(|locale| locale.parse().ok());
+
+    let channel_id = options.channel_value_of("channel");
+
+    let channelinfo_embed_description = localizer
+        .utilities_plugin_channelinfo_embed_description(channel_id.mention().to_string())?;
+    let channelinfo_embed_generalinfo_field_name =
+        localizer.utilities_plugin_channelinfo_embed_generalinfo_field_name()?;
+    let channelinfo_embed_generalinfo_id_subfield_name =
+        localizer.utilities_plugin_channelinfo_embed_generalinfo_id_subfield_name()?;
+    let channelinfo_embed_generalinfo_created_subfield_name =
+        localizer.utilities_plugin_channelinfo_embed_generalinfo_created_subfield_name()?;
+    let channelinfo_embed_generalinfo_type_subfield_name =
+        localizer.utilities_plugin_channelinfo_embed_generalinfo_type_subfield_name()?;
+    let channelinfo_embed_generalinfo_category_subfield_name =
+        localizer.utilities_plugin_channelinfo_embed_generalinfo_category_subfield_name()?;
+    let channelinfo_embed_generalinfo_parent_subfield_name =
+        localizer.utilities_plugin_channelinfo_embed_generalinfo_parent_subfield_name()?;
+    let channelinfo_embed_attributes_field_name =
+        localizer.utilities_plugin_channelinfo_embed_attributes_field_name()?;
+    let channelinfo_embed_attributes_nsfw_subfield_name =
+        localizer.utilities_plugin_channelinfo_embed_attributes_nsfw_subfield_name()?;
+    let channelinfo_embed_attributes_position_subfield_name =
+        localizer.utilities_plugin_channelinfo_embed_attributes_position_subfield_name()?;
+    let channelinfo_embed_attributes_slowmode_subfield_name =
+        localizer.utilities_plugin_channelinfo_embed_attributes_slowmode_subfield_name()?;
+    let channelinfo_embed_attributes_permission_overwrites_subfield_name = localizer
+        .utilities_plugin_channelinfo_embed_attributes_permission_overwrites_subfield_name()?;
+    let channelinfo_embed_topic_field_name =
+        localizer.utilities_plugin_channelinfo_embed_topic_field_name()?;
+
+    // threads are not in the channel repository
+    let (kind, parent_id, parent_subfield_name, channel) =
+        match CachedChannelRepository.get(channel_id).await {
+            Ok(channel) => (
+                channel.kind,
+                channel.parent_id,
+                channelinfo_embed_generalinfo_category_subfield_name,
+                Some(channel),
+            ),
+            Err(_) => {
+                let thread = CachedThreadRepository
+                    .get(channel_id)
+                    .await
+                    .into_diagnostic()?;
+
+                (
+                    thread.kind,
+                    thread.parent_id,
+                    channelinfo_embed_generalinfo_parent_subfield_name,
+                    None,
+                )
+            }
+        };
+
+    let mut general_information = format!(
+        "{} {}\n{} {}\n{} {}",
+        channelinfo_embed_generalinfo_id_subfield_name,
+        channel_id.to_string().discord_inline_code(),
+        channelinfo_embed_generalinfo_created_subfield_name,
+        (channel_id.timestamp() / 1000)
+            .to_string()
+            .discord_relative_timestamp(),
+        channelinfo_embed_generalinfo_type_subfield_name,
+        format!("{kind:?}").discord_inline_code(),
+    );
+
+    if let Some(parent_id) = parent_id {
+        general_information.push_str(&format!("\n{parent_subfield_name} {}", parent_id.mention()));
+    }
+
+    let mut builder = EmbedBuilder::new()
+        .color(0x41_A0_DE)
+        .description(channelinfo_embed_description)
+        .field(EmbedFieldBuilder::new(
+            format!("<:channels:1131857444809752576> {channelinfo_embed_generalinfo_field_name}"),
+            general_information,
+        ));
+
+    let Some(channel) = channel else {
+        return respond(&interaction, interaction_client, builder).await;
+    };
+
+    builder = builder.field(EmbedFieldBuilder::new(
+        channelinfo_embed_attributes_field_name,
+        format!(
+            "{} {}\n{} {}\n{} {}\n{} {}",
+            channelinfo_embed_attributes_nsfw_subfield_name,
+            channel.nsfw.unwrap_or_default().localize(langid_locale)?,
+            channelinfo_embed_attributes_position_subfield_name,
+            channel.position.unwrap_or_default(),
+            channelinfo_embed_attributes_slowmode_subfield_name,
+            channel.rate_limit_per_user.unwrap_or_default(),
+            channelinfo_embed_attributes_permission_overwrites_subfield_name,
+            channel
+                .permission_overwrites
+                .map_or(0, |overwrites| overwrites.len()),
+        ),
+    ));
+
+    if let Some(topic) = channel.topic.filter(|topic| !topic.is_empty()) {
+        builder = builder.field(EmbedFieldBuilder::new(
+            channelinfo_embed_topic_field_name,
+            topic,
+        ));
+    }
+
+    respond(&interaction, interaction_client, builder).await
+}
+
+/// Responds to the interaction with the built embed.
+async fn respond(
+    interaction: &Interaction,
+    interaction_client: &InteractionClient<'_>,
+    builder: EmbedBuilder,
+) -> miette::Result<()> {
+    let embed = builder.validate().into_diagnostic()?.build();
+
+    interaction_client
+        .create_response(
+            interaction.id,
+            &interaction.token,
+            &embed_response(vec![embed]),
+        )
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
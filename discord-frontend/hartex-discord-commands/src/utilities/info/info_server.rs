@@ -27,7 +27,6 @@
 use std::borrow::Cow;
 use std::fmt::Write;
 
-use futures::future;
 use hartex_discord_cdn::Cdn;
 use hartex_discord_core::discord::http::client::InteractionClient;
 use hartex_discord_core::discord::mention::Mention;
@@ -39,13 +38,16 @@ use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
 use hartex_discord_core::discord::util::builder::embed::ImageSource;
 use hartex_discord_core::discord::util::snowflake::Snowflake;
 use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::channel::CachedChannelRepository;
 use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_entitycache_repositories::member::CachedMemberRepository;
+use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+use hartex_discord_entitycache_repositories::user::CachedUserRepository;
 use hartex_discord_utils::commands::CommandDataOptionExt;
 use hartex_discord_utils::commands::CommandDataOptionsExt;
 use hartex_discord_utils::interaction::embed_response;
 use hartex_discord_utils::localizable::Localizable;
 use hartex_discord_utils::markdown::MarkdownStyle;
-use hartex_discord_utils::CLIENT;
 use hartex_localization_core::Localizer;
 use miette::IntoDiagnostic;
 
@@ -138,11 +140,8 @@ pub async fn execute(
         guild.owner_id.mention(),
     );
 
-    let channels = CLIENT
-        .guild_channels(guild.id)
-        .await
-        .into_diagnostic()?
-        .model()
+    let channels = CachedChannelRepository
+        .get_by_guild_id(guild.id)
         .await
         .into_diagnostic()?;
     let category_count = channels
@@ -184,8 +183,12 @@ pub async fn execute(
             output
         });
 
-    let members = guild.members(guild.id).await.into_diagnostic()?;
-    let users = future::try_join_all(members.iter().map(|member| member.user(member.user_id)))
+    let members = CachedMemberRepository
+        .get_by_guild_id(guild.id)
+        .await
+        .into_diagnostic()?;
+    let users = CachedUserRepository
+        .get_many(members.iter().map(|member| member.user_id).collect())
         .await
         .into_diagnostic()?;
     let humans = users.iter().filter(|user| !user.bot).count();
@@ -196,8 +199,8 @@ pub async fn execute(
         ));
     }
 
-    let roles = guild
-        .roles(guild.id)
+    let roles = CachedRoleRepository
+        .get_by_guild_id(guild.id)
         .await
         .into_diagnostic()?
         .iter()
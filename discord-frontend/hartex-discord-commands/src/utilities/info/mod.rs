@@ -34,6 +34,7 @@ use hartex_localization_core::Localizer;
 use crate::utilities::Utilities;
 
 mod info_bot;
+mod info_channel;
 mod info_emoji;
 mod info_role;
 mod info_server;
@@ -73,6 +74,15 @@ impl Command for Info {
                 )
                 .await
             }
+            "channel" => {
+                info_channel::execute(
+                    interaction,
+                    interaction_client,
+                    subcommand.clone(),
+                    localizer,
+                )
+                .await
+            }
             "emoji" => {
                 info_emoji::execute(
                     interaction,
@@ -0,0 +1,124 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_commands::management::moderation::Hierarchy;
+use hartex_discord_commands::management::moderation::Ranks;
+use hartex_discord_core::discord::model::id::Id;
+
+fn ranks(
+    moderator_position: Option<i64>,
+    bot_position: Option<i64>,
+    user_position: Option<i64>,
+) -> Ranks {
+    Ranks {
+        owner_id: Id::new(1),
+        moderator_id: Id::new(2),
+        moderator_position,
+        bot_id: Id::new(3),
+        bot_position,
+        user_id: Id::new(4),
+        user_position,
+    }
+}
+
+#[test]
+pub fn permitted_test() {
+    let ranks = ranks(Some(10), Some(20), Some(5));
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::Permitted);
+}
+
+#[test]
+pub fn target_owner_test() {
+    let ranks = Ranks {
+        user_id: Id::new(1),
+        ..ranks(Some(10), Some(20), Some(0))
+    };
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::ModeratorTooLow);
+}
+
+#[test]
+pub fn target_moderator_test() {
+    let ranks = Ranks {
+        user_id: Id::new(2),
+        ..ranks(Some(10), Some(20), Some(10))
+    };
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::TargetIsModerator);
+}
+
+#[test]
+pub fn target_bot_test() {
+    let ranks = Ranks {
+        user_id: Id::new(3),
+        ..ranks(Some(10), Some(20), Some(20))
+    };
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::TargetIsBot);
+}
+
+#[test]
+pub fn moderator_too_low_test() {
+    let ranks = ranks(Some(10), Some(20), Some(10));
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::ModeratorTooLow);
+}
+
+#[test]
+pub fn moderator_owner_test() {
+    let ranks = Ranks {
+        moderator_id: Id::new(1),
+        ..ranks(Some(0), Some(20), Some(15))
+    };
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::Permitted);
+}
+
+#[test]
+pub fn bot_too_low_test() {
+    let ranks = ranks(Some(30), Some(20), Some(25));
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::BotTooLow);
+}
+
+#[test]
+pub fn non_member_test() {
+    let ranks = ranks(Some(0), Some(0), None);
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::Permitted);
+}
+
+#[test]
+pub fn member_without_roles_test() {
+    // a member without roles is still a member, hence is not outranked by a moderator without roles
+    let ranks = ranks(Some(0), Some(20), Some(0));
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::ModeratorTooLow);
+}
+
+#[test]
+pub fn moderator_non_member_test() {
+    let ranks = ranks(None, Some(20), Some(5));
+
+    assert_eq!(Hierarchy::evaluate(&ranks), Hierarchy::ModeratorTooLow);
+}
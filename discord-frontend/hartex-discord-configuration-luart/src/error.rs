@@ -0,0 +1,75 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Evaluation Errors
+
+use hartex_discord_configuration_models::error::ConfigurationError;
+use thiserror::Error;
+
+use crate::limits::LimitExceeded;
+
+/// An error occurring while evaluating a configuration.
+#[derive(Debug, Error)]
+pub enum EvaluationError {
+    /// The configuration exceeded a limit on the resources it may consume.
+    #[error(transparent)]
+    LimitExceeded(#[from] LimitExceeded),
+    /// The configuration failed to evaluate, or evaluated to an invalid configuration.
+    #[error(transparent)]
+    Lua(mlua::Error),
+}
+
+impl EvaluationError {
+    /// Returns the error converting the evaluated configuration into a configuration object, if
+    /// the evaluation failed due to such an error.
+    #[must_use]
+    pub fn configuration_error(&self) -> Option<&ConfigurationError> {
+        match self {
+            Self::Lua(error) => ConfigurationError::find(error),
+            Self::LimitExceeded(_) => None,
+        }
+    }
+
+    /// Constructs an evaluation error from an error raised by the runtime, recovering the limit
+    /// exceeded if the error was raised due to a limit.
+    pub(crate) fn from_lua(error: mlua::Error, memory_limit: usize) -> Self {
+        if matches!(error, mlua::Error::MemoryError(_)) {
+            return Self::LimitExceeded(LimitExceeded::Memory(memory_limit));
+        }
+
+        match limit_exceeded(&error) {
+            Some(limit) => Self::LimitExceeded(limit),
+            None => Self::Lua(error),
+        }
+    }
+}
+
+/// Finds the limit exceeded that caused an error raised by the runtime, if any.
+fn limit_exceeded(error: &mlua::Error) -> Option<LimitExceeded> {
+    match error {
+        mlua::Error::CallbackError { cause, .. } | mlua::Error::WithContext { cause, .. } => {
+            limit_exceeded(cause)
+        }
+        mlua::Error::ExternalError(error) => error.downcast_ref::<LimitExceeded>().copied(),
+        _ => None,
+    }
+}
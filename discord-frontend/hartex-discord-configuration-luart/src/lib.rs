@@ -29,28 +29,124 @@
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+use std::cell::Cell;
+use std::time::Instant;
+
 use hartex_discord_configuration_models::Configuration;
+use mlua::HookTriggers;
 use mlua::Lua;
 use mlua::LuaOptions;
-use mlua::Result;
+use mlua::MultiValue;
 use mlua::StdLib;
+use mlua::Value;
+
+use crate::error::EvaluationError;
+use crate::limits::EvaluationLimits;
+use crate::limits::LimitExceeded;
+use crate::limits::CHECK_INTERVAL;
+use crate::migration::Warning;
+use crate::migration::CURRENT_VERSION;
+
+pub mod error;
+pub mod limits;
+pub mod locate;
+pub mod migration;
+pub mod stdlib;
+
+/// The functions of the base library, which is always loaded by the runtime, that are removed
+/// from the globals.
+///
+/// The protected call functions would otherwise catch the errors raised when a limit is exceeded,
+/// and the others load code from outside the configuration or write to the standard output.
+const REMOVED_GLOBALS: [&str; 6] = ["dofile", "load", "loadfile", "pcall", "print", "xpcall"];
+
+/// The name the configuration is evaluated as, which prefixes the locations in evaluation errors.
+pub const CHUNK_NAME: &str = "configuration";
+
+/// The result of evaluating a configuration.
+#[derive(Debug)]
+pub struct Evaluation {
+    /// The configuration object, upgraded to the current version of the configuration schema.
+    pub configuration: Configuration,
+    /// The warnings produced while upgrading the configuration.
+    pub warnings: Vec<Warning>,
+}
+
+/// Evaluates the configuration code and returns a configuration object, with the default limits.
+#[allow(clippy::missing_errors_doc)]
+pub fn evaluate_config(config: &str) -> Result<Configuration, EvaluationError> {
+    evaluate_config_with_limits(config, EvaluationLimits::default())
+}
 
-/// Evaluates the configuration code and returns a configuration object.
+/// Evaluates the configuration code and returns a configuration object, aborting the evaluation
+/// once any of the limits are exceeded.
 #[allow(clippy::missing_errors_doc)]
-pub fn evaluate_config(config: &str) -> Result<Configuration> {
+pub fn evaluate_config_with_limits(
+    config: &str,
+    limits: EvaluationLimits,
+) -> Result<Configuration, EvaluationError> {
+    evaluate(config, limits).map(|evaluation| evaluation.configuration)
+}
+
+/// Evaluates the configuration code, aborting the evaluation once any of the limits are exceeded,
+/// and returns the configuration object along with the warnings produced.
+#[allow(clippy::missing_errors_doc)]
+pub fn evaluate(config: &str, limits: EvaluationLimits) -> Result<Evaluation, EvaluationError> {
+    run(config, limits).map_err(|error| EvaluationError::from_lua(error, limits.memory))
+}
+
+/// Sets up the runtime with the limits applied, evaluates the configuration code and upgrades the
+/// configuration to the current version of the configuration schema.
+fn run(config: &str, limits: EvaluationLimits) -> mlua::Result<Evaluation> {
     let lua = Lua::new_with(StdLib::NONE, LuaOptions::new())?;
 
     let globals = lua.globals();
-    globals.set("VERSION", 10)?;
+    globals.set("VERSION", CURRENT_VERSION)?;
+    for name in REMOVED_GLOBALS {
+        globals.raw_set(name, Value::Nil)?;
+    }
+
+    stdlib::register(&lua)?;
+
+    lua.set_memory_limit(limits.memory)?;
+
+    let started = Instant::now();
+    let executed = Cell::new(0u64);
+    let exceeded = Cell::new(None);
+    lua.set_hook(
+        HookTriggers::new().every_nth_instruction(CHECK_INTERVAL),
+        move |_, _| {
+            executed.set(executed.get() + u64::from(CHECK_INTERVAL));
 
-    let hartexconf_table = lua.create_table()?;
-    let hartexconf_colour_table = lua.create_table()?;
-    let hartexconf_colour_rgb_function = lua.create_function(|_, colour: u32| Ok(colour))?;
-    hartexconf_colour_table.set("rgb", hartexconf_colour_rgb_function)?;
+            if exceeded.get().is_none() {
+                if executed.get() > limits.instructions {
+                    exceeded.set(Some(LimitExceeded::Instructions(limits.instructions)));
+                } else if started.elapsed() > limits.time {
+                    exceeded.set(Some(LimitExceeded::Time(limits.time)));
+                }
+            }
 
-    hartexconf_table.set("colour", hartexconf_colour_table)?;
+            // once a limit is exceeded, every later check fails as well, such that the
+            // evaluation is aborted even if the error is caught
+            match exceeded.get() {
+                Some(limit) => Err(mlua::Error::external(limit)),
+                None => Ok(()),
+            }
+        },
+    );
 
-    globals.set("hartexconf", hartexconf_table)?;
+    let value = lua
+        .load(config)
+        .set_name(format!("={CHUNK_NAME}"))
+        .eval::<Value>()?;
+    let warnings = match &value {
+        Value::Table(table) => migration::migrate(&lua, table)?,
+        _ => Vec::new(),
+    };
+    let configuration = lua.unpack_multi(MultiValue::from_vec(vec![value]))?;
 
-    lua.load(config).eval()
+    Ok(Evaluation {
+        configuration,
+        warnings,
+    })
 }
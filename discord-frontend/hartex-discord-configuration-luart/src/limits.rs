@@ -0,0 +1,68 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Evaluation Limits
+//!
+//! Limits on the resources a configuration may consume while being evaluated, such that
+//! configurations cannot exhaust the resources of the process evaluating them.
+
+use std::time::Duration;
+
+use thiserror::Error;
+
+/// The number of instructions executed between checks against the limits.
+pub(crate) const CHECK_INTERVAL: u32 = 1000;
+
+/// The limits on the resources a configuration may consume while being evaluated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EvaluationLimits {
+    /// The maximum number of instructions executed.
+    pub instructions: u64,
+    /// The maximum amount of memory allocated by the runtime, in bytes.
+    pub memory: usize,
+    /// The maximum duration of the evaluation.
+    pub time: Duration,
+}
+
+impl Default for EvaluationLimits {
+    fn default() -> Self {
+        Self {
+            instructions: 10_000_000,
+            memory: 16 * 1024 * 1024,
+            time: Duration::from_secs(1),
+        }
+    }
+}
+
+/// A limit exceeded by a configuration while being evaluated.
+#[derive(Clone, Copy, Debug, Error, Eq, PartialEq)]
+pub enum LimitExceeded {
+    /// The instruction limit was exceeded.
+    #[error("configuration exceeded the limit of {0} instructions")]
+    Instructions(u64),
+    /// The memory limit was exceeded.
+    #[error("configuration exceeded the memory limit of {0} bytes")]
+    Memory(usize),
+    /// The time limit was exceeded.
+    #[error("configuration exceeded the time limit of {0:?}")]
+    Time(Duration),
+}
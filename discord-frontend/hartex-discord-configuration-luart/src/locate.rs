@@ -0,0 +1,313 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Source Locations
+//!
+//! Locates values of a configuration in its source, by following the path to a value through the
+//! table constructors of the table returned by the configuration. Values that are not constructed
+//! in place, such as those referring to variables, are located at the deepest value along the path
+//! that is.
+
+use std::ops::Range;
+
+/// A token of the source of a configuration.
+#[derive(Debug)]
+struct Token<'a> {
+    /// The kind of the token.
+    kind: TokenKind,
+    /// The text of the token.
+    text: &'a str,
+    /// The byte offset of the token in the source.
+    offset: usize,
+}
+
+/// The kinds of tokens relevant to locating values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TokenKind {
+    /// A name or a keyword.
+    Name,
+    /// A numeric literal.
+    Number,
+    /// A string literal.
+    String,
+    /// An operator or a punctuation.
+    Symbol,
+}
+
+/// The key of an entry of a table constructor.
+#[derive(Debug, Eq, PartialEq)]
+enum Key<'a> {
+    /// A named key, either as `name = value` or `["name"] = value`.
+    Named(&'a str),
+    /// A positional entry.
+    Positional,
+    /// A key of any other expression.
+    Other,
+}
+
+/// Locates the value at a path of a configuration, such as `plugins.modlog.loggers[1].format`,
+/// returning its byte offset and length in the source.
+#[must_use]
+pub fn locate(source: &str, path: &str) -> Option<(usize, usize)> {
+    let tokens = tokenize(source);
+    let mut open = root(&tokens)?;
+    let mut located = None;
+
+    for segment in segments(path) {
+        if !is_symbol(tokens.get(open), "{") {
+            break;
+        }
+
+        let entries = entries(&tokens, open);
+        let entry = match segment {
+            Segment::Field(name) => entries.iter().find(|(key, _)| *key == Key::Named(name)),
+            Segment::Index(index) => entries
+                .iter()
+                .filter(|(key, _)| *key == Key::Positional)
+                .nth(index.checked_sub(1)?),
+        };
+        let Some((_, value)) = entry.filter(|(_, value)| !value.is_empty()) else {
+            break;
+        };
+
+        located = Some(value.clone());
+        open = value.start;
+    }
+
+    located.map(|value| {
+        let start = tokens[value.start].offset;
+        let last = &tokens[value.end - 1];
+
+        (start, last.offset + last.text.len() - start)
+    })
+}
+
+/// A segment of a textual path.
+enum Segment<'a> {
+    /// A field of a table.
+    Field(&'a str),
+    /// A 1-based index into a sequence.
+    Index(usize),
+}
+
+/// Splits a textual path into its segments.
+fn segments(path: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let mut pieces = part.split('[');
+        if let Some(field) = pieces.next().filter(|field| !field.is_empty()) {
+            segments.push(Segment::Field(field));
+        }
+
+        segments.extend(
+            pieces
+                .filter_map(|index| index.trim_end_matches(']').parse().ok())
+                .map(Segment::Index),
+        );
+    }
+
+    segments
+}
+
+/// Finds the table constructor of the last top-level `return` statement.
+fn root(tokens: &[Token]) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut root = None;
+    for (index, token) in tokens.iter().enumerate() {
+        match (token.kind, token.text) {
+            (TokenKind::Symbol, "(" | "[" | "{") => depth += 1,
+            (TokenKind::Symbol, ")" | "]" | "}") => depth = depth.saturating_sub(1),
+            (TokenKind::Name, "return") if depth == 0 => root = Some(index + 1),
+            _ => (),
+        }
+    }
+
+    root
+}
+
+/// Splits the table constructor opening at a token into its entries, as their keys and the token
+/// ranges of their values.
+fn entries<'a>(tokens: &[Token<'a>], open: usize) -> Vec<(Key<'a>, Range<usize>)> {
+    let mut entries = Vec::new();
+    let mut depth = 0usize;
+    let mut start = open + 1;
+
+    for (index, token) in tokens.iter().enumerate().skip(open + 1) {
+        let closes = token.kind == TokenKind::Symbol && token.text == "}" && depth == 0;
+        let separates =
+            token.kind == TokenKind::Symbol && matches!(token.text, "," | ";") && depth == 0;
+
+        if closes || separates {
+            if start < index {
+                entries.push(entry(tokens, start..index));
+            }
+            if closes {
+                break;
+            }
+
+            start = index + 1;
+            continue;
+        }
+
+        match (token.kind, token.text) {
+            (TokenKind::Symbol, "(" | "[" | "{") => depth += 1,
+            (TokenKind::Symbol, ")" | "]" | "}") => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+    }
+
+    entries
+}
+
+/// Determines the key and the value of an entry of a table constructor.
+fn entry<'a>(tokens: &[Token<'a>], range: Range<usize>) -> (Key<'a>, Range<usize>) {
+    let first = &tokens[range.start];
+
+    if first.kind == TokenKind::Name && is_symbol(tokens.get(range.start + 1), "=") {
+        return (Key::Named(first.text), range.start + 2..range.end);
+    }
+
+    if is_symbol(Some(first), "[") {
+        let key = tokens.get(range.start + 1);
+        if key.is_some_and(|key| key.kind == TokenKind::String)
+            && is_symbol(tokens.get(range.start + 2), "]")
+            && is_symbol(tokens.get(range.start + 3), "=")
+        {
+            let text = key.map_or("", |key| key.text);
+            let name = text
+                .get(1..text.len().saturating_sub(1))
+                .unwrap_or_default();
+            return (Key::Named(name), range.start + 4..range.end);
+        }
+
+        let assignment = (range.start..range.end)
+            .find(|index| is_symbol(tokens.get(*index), "="))
+            .unwrap_or(range.start);
+        return (Key::Other, assignment + 1..range.end);
+    }
+
+    (Key::Positional, range)
+}
+
+/// Returns whether a token is a specific symbol.
+fn is_symbol(token: Option<&Token>, symbol: &str) -> bool {
+    token.is_some_and(|token| token.kind == TokenKind::Symbol && token.text == symbol)
+}
+
+/// Splits the source of a configuration into tokens, skipping whitespace and comments.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        let start = offset;
+        let byte = bytes[offset];
+
+        let kind = match byte {
+            _ if byte.is_ascii_whitespace() => {
+                offset += 1;
+                continue;
+            }
+            b'-' if bytes.get(offset + 1) == Some(&b'-') => {
+                offset += 2;
+                offset = match long_bracket_level(bytes, offset) {
+                    Some(level) => skip_long_bracket(bytes, offset, level),
+                    None => bytes[offset..]
+                        .iter()
+                        .position(|byte| *byte == b'\n')
+                        .map_or(bytes.len(), |position| offset + position),
+                };
+                continue;
+            }
+            b'"' | b'\'' => {
+                offset += 1;
+                while offset < bytes.len() && bytes[offset] != byte && bytes[offset] != b'\n' {
+                    offset += if bytes[offset] == b'\\' { 2 } else { 1 };
+                }
+                offset = (offset + 1).min(bytes.len());
+                TokenKind::String
+            }
+            b'[' if long_bracket_level(bytes, offset).is_some() => {
+                let level = long_bracket_level(bytes, offset).unwrap_or_default();
+                offset = skip_long_bracket(bytes, offset, level);
+                TokenKind::String
+            }
+            _ if byte.is_ascii_alphabetic() || byte == b'_' => {
+                while offset < bytes.len()
+                    && (bytes[offset].is_ascii_alphanumeric() || bytes[offset] == b'_')
+                {
+                    offset += 1;
+                }
+                TokenKind::Name
+            }
+            _ if byte.is_ascii_digit() => {
+                while offset < bytes.len()
+                    && (bytes[offset].is_ascii_alphanumeric() || bytes[offset] == b'.')
+                {
+                    offset += 1;
+                }
+                TokenKind::Number
+            }
+            b'=' | b'~' | b'<' | b'>' if bytes.get(offset + 1) == Some(&b'=') => {
+                offset += 2;
+                TokenKind::Symbol
+            }
+            _ => {
+                offset += source[offset..].chars().next().map_or(1, char::len_utf8);
+                TokenKind::Symbol
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            text: &source[start..offset.min(bytes.len())],
+            offset: start,
+        });
+    }
+
+    tokens
+}
+
+/// Returns the level of the long bracket opening at an offset, such as 2 for `[==[`.
+fn long_bracket_level(bytes: &[u8], offset: usize) -> Option<usize> {
+    if bytes.get(offset) != Some(&b'[') {
+        return None;
+    }
+
+    let level = bytes[offset + 1..]
+        .iter()
+        .take_while(|byte| **byte == b'=')
+        .count();
+    (bytes.get(offset + 1 + level) == Some(&b'[')).then_some(level)
+}
+
+/// Skips past the long bracket of a level opening at an offset, returning the offset after it.
+fn skip_long_bracket(bytes: &[u8], offset: usize, level: usize) -> usize {
+    let close = format!("]{}]", "=".repeat(level)).into_bytes();
+
+    let content = offset + level + 2;
+    bytes
+        .get(content..)
+        .and_then(|rest| rest.windows(close.len()).position(|window| window == close))
+        .map_or(bytes.len(), |position| content + position + close.len())
+}
@@ -0,0 +1,230 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Configuration Migrations
+//!
+//! Configurations declare the version of the configuration schema they are written for with a
+//! top-level `version` field. Configurations written for older versions are upgraded to the
+//! current version before being converted into a configuration object, and deprecated fields are
+//! replaced with their replacements. Each upgrade and replacement is reported as a warning rather
+//! than failing the evaluation.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use hartex_discord_configuration_models::error::ConfigurationError;
+use hartex_discord_configuration_models::error::PathSegment;
+use mlua::Lua;
+use mlua::Result;
+use mlua::Table;
+use mlua::Value;
+
+/// The current version of the configuration schema.
+pub const CURRENT_VERSION: u32 = 10;
+
+/// The earliest version of the configuration schema that can still be upgraded.
+pub const MINIMUM_VERSION: u32 = 10;
+
+/// The history of the configuration schema configurations are upgraded along.
+///
+/// No version of the configuration schema has been superseded yet, hence there are neither
+/// migrations nor deprecated fields.
+pub const SCHEMA: Schema = Schema {
+    current_version: CURRENT_VERSION,
+    deprecated_fields: &[],
+    migrations: &[],
+    minimum_version: MINIMUM_VERSION,
+};
+
+/// The history of a configuration schema.
+pub struct Schema {
+    /// The current version of the schema.
+    pub current_version: u32,
+    /// The fields that have been deprecated, regardless of the version of the schema.
+    pub deprecated_fields: &'static [DeprecatedField],
+    /// The migrations between versions of the schema, in ascending order.
+    pub migrations: &'static [Migration],
+    /// The earliest version of the schema that can still be upgraded.
+    pub minimum_version: u32,
+}
+
+/// A warning about a configuration that does not prevent it from being evaluated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Warning {
+    /// The path to the field the warning concerns.
+    pub path: String,
+    /// The message of the warning.
+    pub message: String,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.path, self.message)
+    }
+}
+
+/// A migration upgrading a configuration from a version of the configuration schema to the next.
+pub struct Migration {
+    /// The version the migration upgrades from.
+    pub from: u32,
+    /// The function performing the migration on the configuration table.
+    pub migrate: fn(&Lua, &Table, &mut Vec<Warning>) -> Result<()>,
+}
+
+/// A field that has been replaced by another field.
+pub struct DeprecatedField {
+    /// The keys leading to the table containing the field.
+    pub parent: &'static [&'static str],
+    /// The name of the deprecated field.
+    pub name: &'static str,
+    /// The name of the field replacing the deprecated field.
+    pub replacement: &'static str,
+}
+
+/// Upgrades a configuration table to the current version of the configuration schema, returning
+/// the warnings produced.
+#[allow(clippy::missing_errors_doc)]
+pub fn migrate(lua: &Lua, table: &Table) -> Result<Vec<Warning>> {
+    migrate_along(&SCHEMA, lua, table)
+}
+
+/// Upgrades a configuration table to the current version of the given schema, returning the
+/// warnings produced.
+#[allow(clippy::missing_errors_doc)]
+pub fn migrate_along(schema: &Schema, lua: &Lua, table: &Table) -> Result<Vec<Warning>> {
+    let current_version = schema.current_version;
+    let minimum_version = schema.minimum_version;
+    let mut warnings = Vec::new();
+
+    let declared = table.get::<_, Option<u32>>("version").map_err(|error| {
+        ConfigurationError::from_lua_error(&error)
+            .within(PathSegment::Field(String::from("version")))
+    })?;
+    let version = match declared {
+        Some(version) => version,
+        None => {
+            warnings.push(Warning {
+                path: String::from("version"),
+                message: format!(
+                    "configuration does not declare its version, assuming version {current_version}"
+                ),
+            });
+
+            current_version
+        }
+    };
+
+    if version > current_version {
+        return Err(ConfigurationError::new(format!(
+            "version {version} is newer than the current version {current_version}"
+        ))
+        .within(PathSegment::Field(String::from("version")))
+        .into());
+    }
+    if version < minimum_version {
+        return Err(ConfigurationError::new(format!(
+            "version {version} is no longer supported, the earliest supported version is {minimum_version}"
+        ))
+        .within(PathSegment::Field(String::from("version")))
+        .into());
+    }
+    if version < current_version {
+        warnings.push(Warning {
+            path: String::from("version"),
+            message: format!(
+                "configuration is written for version {version} and has been upgraded to version {current_version}; consider upgrading it"
+            ),
+        });
+    }
+
+    for migration in schema
+        .migrations
+        .iter()
+        .filter(|migration| migration.from >= version)
+    {
+        (migration.migrate)(lua, table, &mut warnings)?;
+    }
+
+    for field in schema.deprecated_fields {
+        replace_deprecated_field(table, field, &mut warnings)?;
+    }
+
+    table.set("version", current_version)?;
+
+    Ok(warnings)
+}
+
+/// Replaces a deprecated field with its replacement, unless the replacement is already present.
+fn replace_deprecated_field(
+    table: &Table,
+    field: &DeprecatedField,
+    warnings: &mut Vec<Warning>,
+) -> Result<()> {
+    let Some(parent) = subtable(table, field.parent)? else {
+        return Ok(());
+    };
+
+    let value = parent.get::<_, Value>(field.name)?;
+    if value.is_nil() {
+        return Ok(());
+    }
+
+    let path = field
+        .parent
+        .iter()
+        .chain([&field.name])
+        .copied()
+        .collect::<Vec<_>>()
+        .join(".");
+    let message = if parent.contains_key(field.replacement)? {
+        format!(
+            "field is deprecated and ignored as `{}` is also present",
+            field.replacement
+        )
+    } else {
+        parent.set(field.replacement, value)?;
+        format!(
+            "field is deprecated, consider renaming it to `{}`",
+            field.replacement
+        )
+    };
+
+    parent.set(field.name, Value::Nil)?;
+    warnings.push(Warning { path, message });
+
+    Ok(())
+}
+
+/// Follows a sequence of keys into nested tables, if every key leads to a table.
+fn subtable<'lua>(table: &Table<'lua>, keys: &[&str]) -> Result<Option<Table<'lua>>> {
+    let mut current = table.clone();
+    for key in keys {
+        let Value::Table(next) = current.get::<_, Value>(*key)? else {
+            return Ok(None);
+        };
+
+        current = next;
+    }
+
+    Ok(Some(current))
+}
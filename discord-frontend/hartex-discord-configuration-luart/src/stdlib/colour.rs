@@ -0,0 +1,133 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Colour Helpers
+//!
+//! - `hartexconf.colour.rgb(0x768EE5)`
+//! - `hartexconf.colour.hex("#768EE5")`
+//! - `hartexconf.colour.hsl(227, 68, 68)`
+
+use mlua::Error;
+use mlua::Lua;
+use mlua::Result;
+use mlua::Table;
+
+/// The largest value of a colour.
+const COLOUR_MAXIMUM: u32 = 0xFF_FF_FF;
+
+/// Constructs the `hartexconf.colour` table.
+pub(crate) fn table(lua: &Lua) -> Result<Table> {
+    let table = lua.create_table()?;
+    table.set(
+        "hex",
+        lua.create_function(|_, hex: String| hex_to_colour(&hex))?,
+    )?;
+    table.set(
+        "hsl",
+        lua.create_function(|_, (hue, saturation, lightness): (f64, f64, f64)| {
+            hsl_to_colour(hue, saturation, lightness)
+        })?,
+    )?;
+    table.set(
+        "rgb",
+        lua.create_function(|_, colour: i64| rgb_to_colour(colour))?,
+    )?;
+
+    Ok(table)
+}
+
+/// Parses a colour from its hexadecimal notation, with an optional leading `#`, in either the
+/// six-digit form or the three-digit shorthand.
+pub fn hex_to_colour(hex: &str) -> Result<u32> {
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+    if !digits.chars().all(|char| char.is_ascii_hexdigit()) {
+        return Err(invalid_hex(hex));
+    }
+
+    let expanded = match digits.len() {
+        3 => digits.chars().flat_map(|char| [char, char]).collect(),
+        6 => digits.to_string(),
+        _ => return Err(invalid_hex(hex)),
+    };
+
+    u32::from_str_radix(&expanded, 16).map_err(|_| invalid_hex(hex))
+}
+
+/// Converts a colour from its hue (in degrees), saturation and lightness (in percentages).
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+pub fn hsl_to_colour(hue: f64, saturation: f64, lightness: f64) -> Result<u32> {
+    if !(0.0..360.0).contains(&hue) {
+        return Err(Error::RuntimeError(format!(
+            "hartexconf.colour.hsl: hue must be at least 0 and less than 360, found: {hue}"
+        )));
+    }
+    if !(0.0..=100.0).contains(&saturation) {
+        return Err(Error::RuntimeError(format!(
+            "hartexconf.colour.hsl: saturation must be between 0 and 100, found: {saturation}"
+        )));
+    }
+    if !(0.0..=100.0).contains(&lightness) {
+        return Err(Error::RuntimeError(format!(
+            "hartexconf.colour.hsl: lightness must be between 0 and 100, found: {lightness}"
+        )));
+    }
+
+    let saturation = saturation / 100.0;
+    let lightness = lightness / 100.0;
+
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue / 60.0;
+    let intermediate = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (red, green, blue) = match sector as u32 {
+        0 => (chroma, intermediate, 0.0),
+        1 => (intermediate, chroma, 0.0),
+        2 => (0.0, chroma, intermediate),
+        3 => (0.0, intermediate, chroma),
+        4 => (intermediate, 0.0, chroma),
+        _ => (chroma, 0.0, intermediate),
+    };
+
+    let offset = lightness - chroma / 2.0;
+    let channel = |value: f64| ((value + offset) * 255.0).round() as u32;
+
+    Ok((channel(red) << 16) | (channel(green) << 8) | channel(blue))
+}
+
+/// Validates a colour given as an integer.
+pub fn rgb_to_colour(colour: i64) -> Result<u32> {
+    u32::try_from(colour)
+        .ok()
+        .filter(|colour| *colour <= COLOUR_MAXIMUM)
+        .ok_or_else(|| {
+            Error::RuntimeError(format!(
+                "hartexconf.colour.rgb: colour must be between 0x000000 and 0xFFFFFF, found: {colour}"
+            ))
+        })
+}
+
+/// Constructs the error for an invalid hexadecimal colour.
+fn invalid_hex(hex: &str) -> Error {
+    Error::RuntimeError(format!(
+        "hartexconf.colour.hex: expected a colour of the form #RRGGBB or #RGB, found: {hex}"
+    ))
+}
@@ -0,0 +1,90 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Duration Helpers
+//!
+//! - `hartexconf.duration("1h30m")`
+//!
+//! Durations are composed of one or more amounts, each followed by a unit of `s` (seconds), `m`
+//! (minutes), `h` (hours), `d` (days) or `w` (weeks), and evaluate to a number of seconds.
+
+use mlua::Error;
+use mlua::Function;
+use mlua::Lua;
+use mlua::Result;
+
+/// Constructs the `hartexconf.duration` function.
+pub(crate) fn function(lua: &Lua) -> Result<Function> {
+    lua.create_function(|_, duration: String| parse_duration(&duration))
+}
+
+/// Parses a duration into a number of seconds.
+pub fn parse_duration(duration: &str) -> Result<u64> {
+    let invalid = |reason: &str| {
+        Error::RuntimeError(format!(
+            "hartexconf.duration: invalid duration `{duration}`: {reason}"
+        ))
+    };
+
+    if duration.is_empty() {
+        return Err(invalid("duration is empty"));
+    }
+
+    let mut seconds = 0u64;
+    let mut amount = String::new();
+    for char in duration.chars() {
+        if char.is_ascii_digit() {
+            amount.push(char);
+            continue;
+        }
+
+        let multiplier = match char {
+            's' => 1,
+            'm' => 60,
+            'h' => 60 * 60,
+            'd' => 24 * 60 * 60,
+            'w' => 7 * 24 * 60 * 60,
+            _ => return Err(invalid(&format!("unknown unit `{char}`"))),
+        };
+        if amount.is_empty() {
+            return Err(invalid(&format!(
+                "unit `{char}` is not preceded by an amount"
+            )));
+        }
+
+        seconds = amount
+            .parse::<u64>()
+            .ok()
+            .and_then(|amount| amount.checked_mul(multiplier))
+            .and_then(|amount| seconds.checked_add(amount))
+            .ok_or_else(|| invalid("duration is too long"))?;
+        amount.clear();
+    }
+
+    if !amount.is_empty() {
+        return Err(invalid(&format!(
+            "amount `{amount}` is not followed by a unit"
+        )));
+    }
+
+    Ok(seconds)
+}
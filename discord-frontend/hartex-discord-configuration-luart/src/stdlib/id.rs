@@ -0,0 +1,74 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Snowflake Helpers
+//!
+//! - `hartexconf.id.channel("1000000000000000")`
+//! - `hartexconf.id.guild("1000000000000000")`
+//! - `hartexconf.id.role("1000000000000000")`
+//! - `hartexconf.id.user("1000000000000000")`
+//!
+//! Each helper accepts a snowflake as a string or an integer and evaluates to its string form.
+
+use mlua::Error;
+use mlua::Lua;
+use mlua::Result;
+use mlua::Table;
+use mlua::Value;
+
+/// The kinds of snowflakes with a helper in the `hartexconf.id` table.
+const KINDS: [&str; 4] = ["channel", "guild", "role", "user"];
+
+/// Constructs the `hartexconf.id` table.
+pub(crate) fn table(lua: &Lua) -> Result<Table> {
+    let table = lua.create_table()?;
+    for kind in KINDS {
+        table.set(
+            kind,
+            lua.create_function(move |_, value: Value| snowflake(kind, &value))?,
+        )?;
+    }
+
+    Ok(table)
+}
+
+/// Validates a snowflake of a specific kind, given as a string or an integer.
+pub fn snowflake(kind: &str, value: &Value) -> Result<String> {
+    let id = match value {
+        Value::String(string) => string.to_str()?.to_string(),
+        Value::Integer(integer) => integer.to_string(),
+        _ => {
+            return Err(Error::RuntimeError(format!(
+                "hartexconf.id.{kind}: expected a string or an integer, found: {}",
+                value.type_name()
+            )));
+        }
+    };
+
+    if !id.parse::<u64>().is_ok_and(|id| id != 0) {
+        return Err(Error::RuntimeError(format!(
+            "hartexconf.id.{kind}: `{id}` is not a valid {kind} ID"
+        )));
+    }
+
+    Ok(id)
+}
@@ -0,0 +1,47 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Configuration Standard Library
+//!
+//! The `hartexconf` table exposed to configurations, containing helpers for constructing and
+//! validating configuration values.
+
+use mlua::Lua;
+use mlua::Result;
+
+pub mod colour;
+pub mod duration;
+pub mod id;
+pub mod permissions;
+pub mod table;
+
+/// Registers the `hartexconf` table as a global of the runtime.
+pub(crate) fn register(lua: &Lua) -> Result<()> {
+    let hartexconf_table = lua.create_table()?;
+    hartexconf_table.set("colour", colour::table(lua)?)?;
+    hartexconf_table.set("duration", duration::function(lua)?)?;
+    hartexconf_table.set("id", id::table(lua)?)?;
+    hartexconf_table.set("permissions", permissions::function(lua)?)?;
+    hartexconf_table.set("table", table::table(lua)?)?;
+
+    lua.globals().set("hartexconf", hartexconf_table)
+}
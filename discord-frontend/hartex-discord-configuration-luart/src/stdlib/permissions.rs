@@ -0,0 +1,60 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Permission Helpers
+//!
+//! - `hartexconf.permissions("BAN_MEMBERS", "KICK_MEMBERS")`
+//!
+//! Evaluates to the permission bits of the named permissions.
+
+use hartex_discord_core::discord::model::guild::Permissions;
+use mlua::Error;
+use mlua::Function;
+use mlua::Lua;
+use mlua::Result;
+use mlua::Variadic;
+
+/// Constructs the `hartexconf.permissions` function.
+pub(crate) fn function(lua: &Lua) -> Result<Function> {
+    lua.create_function(|_, names: Variadic<String>| permissions(&names))
+}
+
+/// Combines permissions from their names.
+pub fn permissions(names: &[String]) -> Result<u64> {
+    if names.is_empty() {
+        return Err(Error::RuntimeError(String::from(
+            "hartexconf.permissions: expected at least one permission name",
+        )));
+    }
+
+    names
+        .iter()
+        .map(|name| {
+            Permissions::from_name(name).ok_or_else(|| {
+                Error::RuntimeError(format!("hartexconf.permissions: unknown permission {name}"))
+            })
+        })
+        .try_fold(Permissions::empty(), |permissions, permission| {
+            Ok(permissions | permission?)
+        })
+        .map(|permissions| permissions.bits())
+}
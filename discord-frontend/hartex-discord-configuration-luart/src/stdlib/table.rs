@@ -0,0 +1,94 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Table Helpers
+//!
+//! - `hartexconf.table.concat({ 1, 2 }, { 3 })`: a new sequence of the elements of both sequences
+//! - `hartexconf.table.contains({ 1, 2 }, 2)`: whether a sequence contains a value
+//! - `hartexconf.table.keys({ a = 1 })`: a new sequence of the keys of a table
+//! - `hartexconf.table.merge({ a = 1 }, { b = 2 })`: a new table with the entries of both tables,
+//!   preferring the second table when both contain a key
+
+use mlua::Lua;
+use mlua::Result;
+use mlua::Table;
+use mlua::Value;
+
+/// Constructs the `hartexconf.table` table.
+pub(crate) fn table(lua: &Lua) -> Result<Table> {
+    let table = lua.create_table()?;
+    table.set(
+        "concat",
+        lua.create_function(|lua, (first, second): (Table, Table)| {
+            let concatenated = lua.create_sequence_from(
+                first
+                    .sequence_values::<Value>()
+                    .collect::<Result<Vec<_>>>()?,
+            )?;
+            for value in second.sequence_values::<Value>() {
+                concatenated.push(value?)?;
+            }
+
+            Ok(concatenated)
+        })?,
+    )?;
+    table.set(
+        "contains",
+        lua.create_function(|_, (sequence, needle): (Table, Value)| {
+            for value in sequence.sequence_values::<Value>() {
+                if value? == needle {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        })?,
+    )?;
+    table.set(
+        "keys",
+        lua.create_function(|lua, table: Table| {
+            lua.create_sequence_from(
+                table
+                    .pairs::<Value, Value>()
+                    .map(|pair| pair.map(|(key, _)| key))
+                    .collect::<Result<Vec<_>>>()?,
+            )
+        })?,
+    )?;
+    table.set(
+        "merge",
+        lua.create_function(|lua, (first, second): (Table, Table)| {
+            let merged = lua.create_table()?;
+            for pair in first
+                .pairs::<Value, Value>()
+                .chain(second.pairs::<Value, Value>())
+            {
+                let (key, value) = pair?;
+                merged.set(key, value)?;
+            }
+
+            Ok(merged)
+        })?,
+    )?;
+
+    Ok(table)
+}
@@ -0,0 +1,124 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_configuration_luart::evaluate_config;
+use hartex_discord_configuration_luart::locate::locate;
+use hartex_discord_configuration_models::error::ConfigurationError;
+
+const MODLOG_CONFIG: &'static str = r#"return {
+    version = 10,
+
+    dashboard = {
+        admins = { "1000000000000000" }
+    },
+
+    plugins = {
+        modlog = {
+            enabled = true,
+            loggers = {
+                {
+                    channel = "1000000000000006",
+                    events = { "MESSAGE_DELETED", "MESSAGE_UPDATED" },
+                    format = "pretty"
+                }
+            }
+        }
+    }
+}
+"#;
+
+fn configuration_error(config: &str) -> ConfigurationError {
+    evaluate_config(config)
+        .unwrap_err()
+        .configuration_error()
+        .cloned()
+        .unwrap()
+}
+
+fn located<'a>(config: &'a str, path: &str) -> &'a str {
+    let (offset, length) = locate(config, path).unwrap();
+
+    &config[offset..offset + length]
+}
+
+#[test]
+pub fn unknown_format_test() {
+    let config = MODLOG_CONFIG.replace("\"pretty\"", "\"prety\"");
+    let error = configuration_error(&config);
+
+    assert_eq!(error.path(), "plugins.modlog.loggers[1].format");
+    assert_eq!(
+        error.message,
+        "unknown value `prety`, expected one of `default`, `pretty`"
+    );
+    assert_eq!(error.suggestion.as_deref(), Some("did you mean `pretty`?"));
+    assert_eq!(located(&config, &error.path()), "\"prety\"");
+}
+
+#[test]
+pub fn unknown_event_flag_test() {
+    let config = MODLOG_CONFIG.replace("\"MESSAGE_UPDATED\"", "\"MESSAGE_UPDATE\"");
+    let error = configuration_error(&config);
+
+    assert_eq!(error.path(), "plugins.modlog.loggers[1].events[2]");
+    assert_eq!(
+        error.suggestion.as_deref(),
+        Some("did you mean `MESSAGE_UPDATED`?")
+    );
+    assert_eq!(located(&config, &error.path()), "\"MESSAGE_UPDATE\"");
+}
+
+#[test]
+pub fn unrelated_value_has_no_suggestion_test() {
+    let config = MODLOG_CONFIG.replace("\"pretty\"", "\"markdown\"");
+    let error = configuration_error(&config);
+
+    assert_eq!(error.suggestion, None);
+}
+
+#[test]
+pub fn mismatched_type_test() {
+    let config = MODLOG_CONFIG.replace("{ \"1000000000000000\" }", "\"1000000000000000\"");
+    let error = configuration_error(&config);
+
+    assert_eq!(error.path(), "dashboard.admins");
+    assert_eq!(error.message, "expected table, found string");
+    assert_eq!(located(&config, &error.path()), "\"1000000000000000\"");
+}
+
+#[test]
+pub fn missing_loggers_test() {
+    let config = MODLOG_CONFIG.replace("loggers =", "logger =");
+    let error = configuration_error(&config);
+
+    assert_eq!(error.path(), "plugins.modlog.loggers");
+    assert!(located(&config, &error.path()).starts_with("{\n            enabled = true,"));
+}
+
+#[test]
+pub fn unsupported_version_test() {
+    let config = MODLOG_CONFIG.replace("version = 10", "version = 1");
+    let error = configuration_error(&config);
+
+    assert_eq!(error.path(), "version");
+    assert_eq!(located(&config, &error.path()), "1");
+}
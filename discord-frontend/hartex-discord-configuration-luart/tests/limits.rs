@@ -0,0 +1,170 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use hartex_discord_configuration_luart::error::EvaluationError;
+use hartex_discord_configuration_luart::evaluate_config;
+use hartex_discord_configuration_luart::evaluate_config_with_limits;
+use hartex_discord_configuration_luart::limits::EvaluationLimits;
+use hartex_discord_configuration_luart::limits::LimitExceeded;
+
+const MINIMAL_CONFIG: &'static str = r#"return {
+    dashboard = {
+        admins = { "1000000000000000" }
+    }
+}
+"#;
+
+#[test]
+pub fn infinite_loop_test() {
+    let error = evaluate_config("while true do end").unwrap_err();
+
+    assert!(matches!(
+        error,
+        EvaluationError::LimitExceeded(LimitExceeded::Instructions(_))
+    ));
+}
+
+#[test]
+pub fn memory_exhaustion_test() {
+    let config = r#"local t = {}
+for i = 1, 100000000 do
+    t[i] = "HarTex" .. i
+end
+"#;
+    let error = evaluate_config(config).unwrap_err();
+
+    assert!(matches!(
+        error,
+        EvaluationError::LimitExceeded(LimitExceeded::Memory(_))
+    ));
+}
+
+#[test]
+pub fn string_growth_test() {
+    let config = r#"local s = "HarTex"
+while true do
+    s = s .. s
+end
+"#;
+    let error = evaluate_config(config).unwrap_err();
+
+    assert!(matches!(
+        error,
+        EvaluationError::LimitExceeded(LimitExceeded::Memory(_))
+    ));
+}
+
+#[test]
+pub fn unbounded_recursion_test() {
+    let config = r#"local function recurse(n)
+    return 1 + recurse(n + 1)
+end
+
+return recurse(1)
+"#;
+
+    assert!(evaluate_config(config).is_err());
+}
+
+#[test]
+pub fn time_limit_test() {
+    let limits = EvaluationLimits {
+        instructions: u64::MAX,
+        time: Duration::from_millis(50),
+        ..Default::default()
+    };
+    let error = evaluate_config_with_limits("while true do end", limits).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "configuration exceeded the time limit of 50ms"
+    );
+}
+
+#[test]
+pub fn custom_instruction_limit_test() {
+    let limits = EvaluationLimits {
+        instructions: 100_000,
+        ..Default::default()
+    };
+    let config = format!("for i = 1, 1000000 do end\n{MINIMAL_CONFIG}");
+    let error = evaluate_config_with_limits(&config, limits).unwrap_err();
+
+    assert!(matches!(
+        error,
+        EvaluationError::LimitExceeded(LimitExceeded::Instructions(100_000))
+    ));
+}
+
+#[test]
+pub fn within_limits_test() {
+    let config = format!("for i = 1, 1000 do end\n{MINIMAL_CONFIG}");
+
+    assert!(evaluate_config(&config).is_ok());
+}
+
+#[test]
+pub fn removed_globals_test() {
+    for name in ["dofile", "load", "loadfile", "pcall", "print", "xpcall"] {
+        let config = format!("{name}(function() end)\n{MINIMAL_CONFIG}");
+
+        assert!(
+            evaluate_config(&config).is_err(),
+            "{name} is available to configurations"
+        );
+    }
+}
+
+#[test]
+pub fn protected_infinite_loop_test() {
+    let config = r#"while true do
+    pcall(function()
+        while true do end
+    end)
+end
+"#;
+
+    assert!(evaluate_config(config).is_err());
+}
+
+#[test]
+pub fn protected_error_handler_loop_test() {
+    let config = r#"while true do
+    xpcall(function()
+        while true do end
+    end, function() end)
+end
+"#;
+
+    assert!(evaluate_config(config).is_err());
+}
+
+#[test]
+pub fn load_code_test() {
+    let config = r#"local chunk = load("while true do end")
+chunk()
+"#;
+
+    assert!(evaluate_config(config).is_err());
+}
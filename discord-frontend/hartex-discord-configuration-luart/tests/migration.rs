@@ -0,0 +1,160 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use expect_test::expect;
+use hartex_discord_configuration_luart::evaluate;
+use hartex_discord_configuration_luart::limits::EvaluationLimits;
+use hartex_discord_configuration_luart::migration::migrate_along;
+use hartex_discord_configuration_luart::migration::DeprecatedField;
+use hartex_discord_configuration_luart::migration::Migration;
+use hartex_discord_configuration_luart::migration::Schema;
+use hartex_discord_configuration_luart::migration::Warning;
+use hartex_discord_configuration_luart::migration::CURRENT_VERSION;
+use mlua::Lua;
+use mlua::Result;
+use mlua::Table;
+use mlua::Value;
+
+const TEST_SCHEMA: Schema = Schema {
+    current_version: 2,
+    deprecated_fields: &[DeprecatedField {
+        parent: &["section"],
+        name: "old",
+        replacement: "new",
+    }],
+    migrations: &[Migration {
+        from: 1,
+        migrate: migrate_greeting,
+    }],
+    minimum_version: 1,
+};
+
+const VERSION_1_CONFIG: &'static str = r#"return {
+    version = 1,
+
+    greeting = "hello",
+
+    section = {
+        old = 1
+    }
+}
+"#;
+
+const CONFIG: &'static str = r#"return {
+    version = 10,
+
+    dashboard = {
+        admins = { "1000000000000000" }
+    },
+
+    appearance = {
+        colour = 0x768EE5
+    }
+}
+"#;
+
+/// Version 1 to 2: `greeting` is moved into the `messages` table.
+fn migrate_greeting(lua: &Lua, table: &Table, warnings: &mut Vec<Warning>) -> Result<()> {
+    let greeting = table.get::<_, Value>("greeting")?;
+    if greeting.is_nil() {
+        return Ok(());
+    }
+
+    let messages = lua.create_table()?;
+    messages.set("greeting", greeting)?;
+    table.set("messages", messages)?;
+    table.set("greeting", Value::Nil)?;
+
+    warnings.push(Warning {
+        path: String::from("greeting"),
+        message: String::from("`greeting` has been moved into `messages`"),
+    });
+
+    Ok(())
+}
+
+#[test]
+pub fn migrate_test() {
+    let lua = Lua::new();
+    let table = lua.load(VERSION_1_CONFIG).eval::<Table>().unwrap();
+    let warnings = migrate_along(&TEST_SCHEMA, &lua, &table).unwrap();
+
+    let messages = table.get::<_, Table>("messages").unwrap();
+    let section = table.get::<_, Table>("section").unwrap();
+    assert_eq!(table.get::<_, u32>("version").unwrap(), 2);
+    assert_eq!(messages.get::<_, String>("greeting").unwrap(), "hello");
+    assert!(table.get::<_, Value>("greeting").unwrap().is_nil());
+    assert_eq!(section.get::<_, u32>("new").unwrap(), 1);
+    assert!(section.get::<_, Value>("old").unwrap().is_nil());
+
+    let expected = expect![[r#"
+        [
+            "`version`: configuration is written for version 1 and has been upgraded to version 2; consider upgrading it",
+            "`greeting`: `greeting` has been moved into `messages`",
+            "`section.old`: field is deprecated, consider renaming it to `new`",
+        ]
+    "#]];
+    expected.assert_debug_eq(&warnings.iter().map(ToString::to_string).collect::<Vec<_>>());
+}
+
+#[test]
+pub fn deprecated_field_ignored_test() {
+    let lua = Lua::new();
+    let config = VERSION_1_CONFIG.replace("old = 1", "old = 1, new = 2");
+    let table = lua.load(&config).eval::<Table>().unwrap();
+    let warnings = migrate_along(&TEST_SCHEMA, &lua, &table).unwrap();
+
+    let section = table.get::<_, Table>("section").unwrap();
+    assert_eq!(section.get::<_, u32>("new").unwrap(), 2);
+    assert!(warnings
+        .iter()
+        .any(|warning| warning.path == "section.old" && warning.message.contains("ignored")));
+}
+
+#[test]
+pub fn current_version_test() {
+    let evaluation = evaluate(CONFIG, EvaluationLimits::default()).unwrap();
+
+    assert_eq!(evaluation.configuration.version, CURRENT_VERSION);
+    assert!(evaluation.warnings.is_empty());
+}
+
+#[test]
+pub fn missing_version_test() {
+    let config = CONFIG.replace("version = 10,", "");
+    let evaluation = evaluate(&config, EvaluationLimits::default()).unwrap();
+
+    assert_eq!(evaluation.configuration.version, CURRENT_VERSION);
+    assert_eq!(evaluation.warnings[0].path, "version");
+}
+
+#[test]
+pub fn unsupported_version_test() {
+    let newer = CONFIG.replace("version = 10", "version = 11");
+    let older = VERSION_1_CONFIG.replace("version = 1", "version = 0");
+
+    assert!(evaluate(&newer, EvaluationLimits::default()).is_err());
+
+    let lua = Lua::new();
+    let table = lua.load(&older).eval::<Table>().unwrap();
+    assert!(migrate_along(&TEST_SCHEMA, &lua, &table).is_err());
+}
@@ -24,6 +24,8 @@ use expect_test::expect;
 use hartex_discord_configuration_luart::evaluate_config;
 
 const SAMPLE_CONFIG: &'static str = r#"return {
+    version = 10,
+
     dashboard = {
         admins = { "1000000000000000", "1000000000000001" },
         editors = { "1000000000000002", "1000000000000003" },
@@ -118,9 +120,60 @@ Configuration {
             ),
         },
     ),
+    version: 10,
 }
 "#
     ];
 
     expected.assert_debug_eq(&config);
 }
+
+const EVENT_FLAGS_CONFIG: &'static str = r#"return {
+    dashboard = {
+        admins = { "1000000000000000" }
+    },
+
+    plugins = {
+        modlog = {
+            enabled = true,
+            loggers = {
+                {
+                    channel = "1000000000000006",
+                    events = {
+                        "MEMBER_JOINED",
+                        "MEMBER_LEFT",
+                        "ROLE_UPDATED",
+                        "VOICE_MOVED",
+                        "EMOJI_DELETED",
+                        "AUTOMOD_ACTION_EXECUTED",
+                        "THREAD_CREATED"
+                    },
+                    format = "default"
+                }
+            }
+        }
+    }
+}
+"#;
+
+#[test]
+pub fn parse_event_flags_test() {
+    let config = evaluate_config(EVENT_FLAGS_CONFIG).unwrap();
+    let loggers = config.plugins.unwrap().modlog.unwrap().loggers;
+    let expected = expect![
+        r#"
+EventFlags(
+    MEMBER_JOINED | MEMBER_LEFT | ROLE_UPDATED | VOICE_MOVED | EMOJI_DELETED | AUTOMOD_ACTION_EXECUTED | THREAD_CREATED,
+)
+"#
+    ];
+
+    expected.assert_debug_eq(&loggers[0].events);
+}
+
+#[test]
+pub fn parse_unknown_event_flag_test() {
+    let config = EVENT_FLAGS_CONFIG.replace("VOICE_MOVED", "VOICE_TELEPORTED");
+
+    assert!(evaluate_config(&config).is_err());
+}
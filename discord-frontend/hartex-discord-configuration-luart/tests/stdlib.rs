@@ -0,0 +1,113 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_configuration_luart::evaluate_config;
+use hartex_discord_configuration_luart::stdlib::colour::hex_to_colour;
+use hartex_discord_configuration_luart::stdlib::colour::hsl_to_colour;
+use hartex_discord_configuration_luart::stdlib::colour::rgb_to_colour;
+use hartex_discord_configuration_luart::stdlib::duration::parse_duration;
+use hartex_discord_configuration_luart::stdlib::permissions::permissions;
+
+const STDLIB_CONFIG: &'static str = r##"local admins = { hartexconf.id.user("1000000000000000") }
+local editors = hartexconf.table.concat(admins, { hartexconf.id.user(1000000000000001) })
+
+local defaults = { enabled = false }
+
+return {
+    dashboard = {
+        admins = admins,
+        editors = editors
+    },
+
+    appearance = {
+        colour = hartexconf.colour.hex("#768EE5")
+    },
+
+    plugins = {
+        management = hartexconf.table.merge(defaults, { enabled = true }),
+        utilities = defaults
+    }
+}
+"##;
+
+#[test]
+pub fn stdlib_config_test() {
+    let config = evaluate_config(STDLIB_CONFIG).unwrap();
+    let plugins = config.plugins.unwrap();
+
+    assert_eq!(
+        config.dashboard.editors.unwrap(),
+        ["1000000000000000", "1000000000000001"]
+    );
+    assert_eq!(config.appearance.unwrap().colour, Some(0x76_8E_E5));
+    assert!(plugins.management.unwrap().enabled);
+    assert!(!plugins.utilities.unwrap().enabled);
+}
+
+#[test]
+pub fn invalid_id_test() {
+    let config = STDLIB_CONFIG.replace("1000000000000001", "\"HarTex\"");
+
+    assert!(evaluate_config(&config)
+        .unwrap_err()
+        .to_string()
+        .contains("hartexconf.id.user: `HarTex` is not a valid user ID"));
+}
+
+#[test]
+pub fn colour_test() {
+    assert_eq!(hex_to_colour("#768EE5").unwrap(), 0x76_8E_E5);
+    assert_eq!(hex_to_colour("768ee5").unwrap(), 0x76_8E_E5);
+    assert_eq!(hex_to_colour("#FFF").unwrap(), 0xFF_FF_FF);
+    assert!(hex_to_colour("#768EE").is_err());
+    assert!(hex_to_colour("#HARTEX").is_err());
+
+    assert_eq!(hsl_to_colour(227.0, 68.0, 68.0).unwrap(), 0x76_8E_E5);
+    assert_eq!(hsl_to_colour(0.0, 100.0, 50.0).unwrap(), 0xFF_00_00);
+    assert!(hsl_to_colour(360.0, 100.0, 50.0).is_err());
+    assert!(hsl_to_colour(0.0, 101.0, 50.0).is_err());
+
+    assert_eq!(rgb_to_colour(0x76_8E_E5).unwrap(), 0x76_8E_E5);
+    assert!(rgb_to_colour(0x1_00_00_00).is_err());
+    assert!(rgb_to_colour(-1).is_err());
+}
+
+#[test]
+pub fn duration_test() {
+    assert_eq!(parse_duration("10m").unwrap(), 600);
+    assert_eq!(parse_duration("1h30m").unwrap(), 5400);
+    assert_eq!(parse_duration("1w1d").unwrap(), 691_200);
+    assert!(parse_duration("").is_err());
+    assert!(parse_duration("10").is_err());
+    assert!(parse_duration("m").is_err());
+    assert!(parse_duration("10y").is_err());
+    assert!(parse_duration("99999999999999999999s").is_err());
+}
+
+#[test]
+pub fn permissions_test() {
+    let names = [String::from("BAN_MEMBERS"), String::from("KICK_MEMBERS")];
+
+    assert_eq!(permissions(&names).unwrap(), 0b110);
+    assert!(permissions(&[String::from("BAN_EVERYONE")]).is_err());
+    assert!(permissions(&[]).is_err());
+}
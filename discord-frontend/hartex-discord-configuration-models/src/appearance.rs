@@ -22,12 +22,14 @@
 
 //! # Appearance Configuration Object
 
-use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
 use mlua::Value;
 use serde::Serialize;
 
+use crate::error::field;
+use crate::error::table;
+
 /// The appearance configuration object.
 #[derive(Debug, Serialize)]
 pub struct Appearance {
@@ -39,15 +41,10 @@ pub struct Appearance {
 
 impl<'lua> FromLua<'lua> for Appearance {
     fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        let Value::Table(table) = lua_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "Appearance: mismatched value type, expected table, found: {}",
-                lua_value.type_name()
-            )));
-        };
+        let table = table(lua_value)?;
 
-        let colour = table.get("colour")?;
-        let nickname = table.get("nickname")?;
+        let colour = field(&table, "colour")?;
+        let nickname = field(&table, "nickname")?;
 
         Ok(Self { colour, nickname })
     }
@@ -22,12 +22,15 @@
 
 //! # Dashboard Configuration Object
 
-use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
 use mlua::Value;
 use serde::Serialize;
 
+use crate::error::optional_sequence;
+use crate::error::sequence;
+use crate::error::table;
+
 /// The dashboard configuration object.
 #[derive(Debug, Serialize)]
 pub struct Dashboard {
@@ -42,17 +45,12 @@ pub struct Dashboard {
 }
 
 impl<'lua> FromLua<'lua> for Dashboard {
-    fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        let Value::Table(table) = lua_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "Dashboard: mismatched value type, expected table, found: {}",
-                lua_value.type_name()
-            )));
-        };
+    fn from_lua(lua_value: Value<'lua>, lua: &'lua Lua) -> mlua::Result<Self> {
+        let table = table(lua_value)?;
 
-        let admins = table.get("admins")?;
-        let editors = table.get("editors")?;
-        let viewers = table.get("viewers")?;
+        let admins = sequence(lua, &table, "admins")?;
+        let editors = optional_sequence(lua, &table, "editors")?;
+        let viewers = optional_sequence(lua, &table, "viewers")?;
 
         Ok(Self {
             admins,
@@ -0,0 +1,262 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Configuration Errors
+//!
+//! Errors converting evaluated configurations into configuration objects. Each error records the
+//! path to the value it concerns, which is built up as the error propagates from the value to the
+//! root of the configuration.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use mlua::Error;
+use mlua::FromLua;
+use mlua::Lua;
+use mlua::Table;
+use mlua::Value;
+
+/// A segment of the path to a value in a configuration.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PathSegment {
+    /// A field of a table.
+    Field(String),
+    /// A 1-based index into a sequence.
+    Index(usize),
+}
+
+/// An error converting a value of a configuration.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigurationError {
+    /// The path to the value, from the root of the configuration.
+    pub path: Vec<PathSegment>,
+    /// The message of the error.
+    pub message: String,
+    /// A suggestion for fixing the value, if any.
+    pub suggestion: Option<String>,
+}
+
+impl ConfigurationError {
+    /// Constructs an error for the current value.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            path: Vec::new(),
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+
+    /// Constructs an error for a value of an unexpected type.
+    #[must_use]
+    pub fn mismatched_type(expected: &str, found: &Value) -> Self {
+        Self::new(format!("expected {expected}, found {}", found.type_name()))
+    }
+
+    /// Constructs an error for a value that is none of the expected values, suggesting the most
+    /// similar expected value if it is similar enough.
+    #[must_use]
+    pub fn unknown_variant(found: &str, expected: &[&str]) -> Self {
+        let expected_list = expected
+            .iter()
+            .map(|variant| format!("`{variant}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Self {
+            path: Vec::new(),
+            message: format!("unknown value `{found}`, expected one of {expected_list}"),
+            suggestion: suggest(found, expected)
+                .map(|variant| format!("did you mean `{variant}`?")),
+        }
+    }
+
+    /// Converts an error raised by the runtime into a configuration error, preserving it if it is
+    /// already one.
+    #[must_use]
+    pub fn from_lua_error(error: &Error) -> Self {
+        if let Some(error) = Self::find(error) {
+            return error.clone();
+        }
+
+        match error {
+            Error::FromLuaConversionError { from, to, .. } => {
+                Self::new(format!("expected {to}, found {from}"))
+            }
+            _ => Self::new(error.to_string()),
+        }
+    }
+
+    /// Finds the configuration error that caused an error raised by the runtime, if any.
+    #[must_use]
+    pub fn find(error: &Error) -> Option<&Self> {
+        match error {
+            Error::CallbackError { cause, .. } | Error::WithContext { cause, .. } => {
+                Self::find(cause)
+            }
+            Error::ExternalError(error) => error.downcast_ref::<Self>(),
+            _ => None,
+        }
+    }
+
+    /// Prepends a segment to the path of the error, as it propagates to the containing value.
+    #[must_use]
+    pub fn within(mut self, segment: PathSegment) -> Self {
+        self.path.insert(0, segment);
+        self
+    }
+
+    /// Returns the path of the error in its textual form, such as `plugins.modlog.loggers[1]`.
+    #[must_use]
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in &self.path {
+            match segment {
+                PathSegment::Field(field) if path.is_empty() => path.push_str(field),
+                PathSegment::Field(field) => {
+                    path.push('.');
+                    path.push_str(field);
+                }
+                PathSegment::Index(index) => path.push_str(&format!("[{index}]")),
+            }
+        }
+
+        path
+    }
+}
+
+impl Display for ConfigurationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            return write!(f, "{}", self.message);
+        }
+
+        write!(f, "invalid value at `{}`: {}", self.path(), self.message)
+    }
+}
+
+impl std::error::Error for ConfigurationError {}
+
+impl From<ConfigurationError> for Error {
+    fn from(error: ConfigurationError) -> Self {
+        Error::external(error)
+    }
+}
+
+/// Converts a field of a table, recording the field in the path of any error.
+pub(crate) fn field<'lua, T: FromLua<'lua>>(table: &Table<'lua>, key: &str) -> mlua::Result<T> {
+    table.get(key).map_err(|error| {
+        ConfigurationError::from_lua_error(&error)
+            .within(PathSegment::Field(key.to_string()))
+            .into()
+    })
+}
+
+/// Converts a field of a table that is a sequence, recording the field and the index of the
+/// element in the path of any error.
+pub(crate) fn sequence<'lua, T: FromLua<'lua>>(
+    lua: &'lua Lua,
+    table: &Table<'lua>,
+    key: &str,
+) -> mlua::Result<Vec<T>> {
+    optional_sequence(lua, table, key)?.ok_or_else(|| {
+        ConfigurationError::mismatched_type("table", &Value::Nil)
+            .within(PathSegment::Field(key.to_string()))
+            .into()
+    })
+}
+
+/// Converts an optional field of a table that is a sequence, recording the field and the index
+/// of the element in the path of any error.
+pub(crate) fn optional_sequence<'lua, T: FromLua<'lua>>(
+    lua: &'lua Lua,
+    table: &Table<'lua>,
+    key: &str,
+) -> mlua::Result<Option<Vec<T>>> {
+    let within_field = |error: ConfigurationError| -> Error {
+        error.within(PathSegment::Field(key.to_string())).into()
+    };
+
+    let sequence = match table.get::<_, Value>(key)? {
+        Value::Nil => return Ok(None),
+        Value::Table(sequence) => sequence,
+        value => {
+            return Err(within_field(ConfigurationError::mismatched_type(
+                "table", &value,
+            )))
+        }
+    };
+
+    sequence
+        .sequence_values::<Value>()
+        .enumerate()
+        .map(|(index, value)| {
+            T::from_lua(value?, lua).map_err(|error| {
+                within_field(
+                    ConfigurationError::from_lua_error(&error)
+                        .within(PathSegment::Index(index + 1)),
+                )
+            })
+        })
+        .collect::<mlua::Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Returns the table of a value that is expected to be a table.
+pub(crate) fn table(lua_value: Value) -> mlua::Result<Table> {
+    match lua_value {
+        Value::Table(table) => Ok(table),
+        value => Err(ConfigurationError::mismatched_type("table", &value).into()),
+    }
+}
+
+/// Suggests the expected value most similar to a value, if it is similar enough.
+fn suggest<'a>(found: &str, expected: &[&'a str]) -> Option<&'a str> {
+    let found = found.to_lowercase();
+    let threshold = (found.chars().count() / 3).max(1);
+
+    expected
+        .iter()
+        .map(|variant| (*variant, distance(&found, &variant.to_lowercase())))
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(variant, _)| variant)
+}
+
+/// Computes the edit distance between two strings.
+fn distance(first: &str, second: &str) -> usize {
+    let second = second.chars().collect::<Vec<_>>();
+    let mut previous = (0..=second.len()).collect::<Vec<_>>();
+
+    for (i, first_char) in first.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, second_char) in second.iter().enumerate() {
+            let substitution = previous[j] + usize::from(first_char != *second_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[second.len()]
+}
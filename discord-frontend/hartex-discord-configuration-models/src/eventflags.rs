@@ -24,47 +24,179 @@
 
 use bitflags::Flags;
 use hartex_bitflags_utils::FlagsExt;
+use hartex_discord_core::discord::model::gateway::event::EventType;
+use hartex_discord_core::discord::model::gateway::Intents;
 use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
 use mlua::Value;
+use serde::Deserialize;
 use serde::Serialize;
 
+use crate::error::table;
+use crate::error::ConfigurationError;
+use crate::error::PathSegment;
+
 bitflags::bitflags! {
-    #[derive(Debug, Serialize)]
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct EventFlags: u128 {
         const MESSAGE_DELETED = 1;
         const MESSAGE_UPDATED = 1 << 1;
+        const MEMBER_JOINED = 1 << 2;
+        const MEMBER_LEFT = 1 << 3;
+        const MEMBER_BANNED = 1 << 4;
+        const MEMBER_UNBANNED = 1 << 5;
+        const MEMBER_NICKNAME_UPDATED = 1 << 6;
+        const ROLE_CREATED = 1 << 7;
+        const ROLE_UPDATED = 1 << 8;
+        const ROLE_DELETED = 1 << 9;
+        const CHANNEL_CREATED = 1 << 10;
+        const CHANNEL_UPDATED = 1 << 11;
+        const CHANNEL_DELETED = 1 << 12;
+        const VOICE_JOINED = 1 << 13;
+        const VOICE_LEFT = 1 << 14;
+        const VOICE_MOVED = 1 << 15;
+        const INVITE_CREATED = 1 << 16;
+        const INVITE_DELETED = 1 << 17;
+        const EMOJI_CREATED = 1 << 18;
+        const EMOJI_UPDATED = 1 << 19;
+        const EMOJI_DELETED = 1 << 20;
+        const AUTOMOD_ACTION_EXECUTED = 1 << 21;
+        const THREAD_CREATED = 1 << 22;
+        const THREAD_UPDATED = 1 << 23;
+        const THREAD_DELETED = 1 << 24;
+    }
+}
+
+impl EventFlags {
+    /// The flags of the events that are logged by the modlog plugin.
+    ///
+    /// Loggers cannot listen to the remaining events until they are logged.
+    pub const LOGGED: Self = Self::MESSAGE_DELETED.union(Self::MESSAGE_UPDATED);
+
+    /// Returns the flags of the events a gateway event may be logged as.
+    ///
+    /// Some gateway events may be logged as one of several events, depending on the state they
+    /// change; for instance, a voice state update may be logged as a member joining, leaving or
+    /// moving between voice channels. All of those events are returned for such gateway events.
+    #[must_use]
+    pub fn from_event_type(event_type: EventType) -> Self {
+        match event_type {
+            EventType::AutoModerationActionExecution => Self::AUTOMOD_ACTION_EXECUTED,
+            EventType::BanAdd => Self::MEMBER_BANNED,
+            EventType::BanRemove => Self::MEMBER_UNBANNED,
+            EventType::ChannelCreate => Self::CHANNEL_CREATED,
+            EventType::ChannelDelete => Self::CHANNEL_DELETED,
+            EventType::ChannelUpdate => Self::CHANNEL_UPDATED,
+            EventType::GuildEmojisUpdate => {
+                Self::EMOJI_CREATED | Self::EMOJI_UPDATED | Self::EMOJI_DELETED
+            }
+            EventType::InviteCreate => Self::INVITE_CREATED,
+            EventType::InviteDelete => Self::INVITE_DELETED,
+            EventType::MemberAdd => Self::MEMBER_JOINED,
+            EventType::MemberRemove => Self::MEMBER_LEFT,
+            EventType::MemberUpdate => Self::MEMBER_NICKNAME_UPDATED,
+            EventType::MessageDelete | EventType::MessageDeleteBulk => Self::MESSAGE_DELETED,
+            EventType::MessageUpdate => Self::MESSAGE_UPDATED,
+            EventType::RoleCreate => Self::ROLE_CREATED,
+            EventType::RoleDelete => Self::ROLE_DELETED,
+            EventType::RoleUpdate => Self::ROLE_UPDATED,
+            EventType::ThreadCreate => Self::THREAD_CREATED,
+            EventType::ThreadDelete => Self::THREAD_DELETED,
+            EventType::ThreadUpdate => Self::THREAD_UPDATED,
+            EventType::VoiceStateUpdate => {
+                Self::VOICE_JOINED | Self::VOICE_LEFT | Self::VOICE_MOVED
+            }
+            _ => Self::empty(),
+        }
+    }
+
+    /// Returns the gateway intents required to receive the events of the flags.
+    #[must_use]
+    pub fn intents(self) -> Intents {
+        let mut intents = Intents::empty();
+
+        if self.intersects(Self::MESSAGE_DELETED | Self::MESSAGE_UPDATED) {
+            intents |= Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT;
+        }
+
+        if self.intersects(Self::MEMBER_JOINED | Self::MEMBER_LEFT | Self::MEMBER_NICKNAME_UPDATED)
+        {
+            intents |= Intents::GUILD_MEMBERS;
+        }
+
+        if self.intersects(Self::MEMBER_BANNED | Self::MEMBER_UNBANNED) {
+            intents |= Intents::GUILD_MODERATION;
+        }
+
+        if self.intersects(
+            Self::ROLE_CREATED
+                | Self::ROLE_UPDATED
+                | Self::ROLE_DELETED
+                | Self::CHANNEL_CREATED
+                | Self::CHANNEL_UPDATED
+                | Self::CHANNEL_DELETED
+                | Self::THREAD_CREATED
+                | Self::THREAD_UPDATED
+                | Self::THREAD_DELETED,
+        ) {
+            intents |= Intents::GUILDS;
+        }
+
+        if self.intersects(Self::VOICE_JOINED | Self::VOICE_LEFT | Self::VOICE_MOVED) {
+            intents |= Intents::GUILD_VOICE_STATES;
+        }
+
+        if self.intersects(Self::INVITE_CREATED | Self::INVITE_DELETED) {
+            intents |= Intents::GUILD_INVITES;
+        }
+
+        if self.intersects(Self::EMOJI_CREATED | Self::EMOJI_UPDATED | Self::EMOJI_DELETED) {
+            intents |= Intents::GUILD_EMOJIS_AND_STICKERS;
+        }
+
+        if self.contains(Self::AUTOMOD_ACTION_EXECUTED) {
+            intents |= Intents::AUTO_MODERATION_EXECUTION;
+        }
+
+        intents
     }
 }
 
 impl<'lua> FromLua<'lua> for EventFlags {
     fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        let Value::Table(table) = lua_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "EventFlags: mismatched value type, expected table, found: {}",
-                lua_value.type_name()
-            )));
-        };
+        let table = table(lua_value)?;
 
         let known = EventFlags::FLAGS
             .iter()
-            .map(|flag| flag.name().to_string())
+            .map(|flag| flag.name())
             .collect::<Vec<_>>();
 
         let flags = table
-            .sequence_values::<String>()
-            .map(|flag| {
-                if known.contains(&flag.clone()?) {
-                    flag
+            .sequence_values::<Value>()
+            .enumerate()
+            .map(|(index, flag)| {
+                let within_index = |error: ConfigurationError| -> Error {
+                    error.within(PathSegment::Index(index + 1)).into()
+                };
+
+                let flag = flag?;
+                let Value::String(string) = &flag else {
+                    return Err(within_index(ConfigurationError::mismatched_type(
+                        "string", &flag,
+                    )));
+                };
+
+                let name = string.to_str()?.to_string();
+                if known.contains(&name.as_str()) {
+                    Ok(name)
                 } else {
-                    Err(Error::RuntimeError(format!(
-                        "EventFlags: unknown flag {}",
-                        flag?
+                    Err(within_index(ConfigurationError::unknown_variant(
+                        &name, &known,
                     )))
                 }
             })
-            .collect::<Result<Vec<_>, _>>()?;
+            .collect::<mlua::Result<Vec<_>>>()?;
         Ok(Self::from_names(flags))
     }
 }
@@ -30,15 +30,18 @@
 #![deny(warnings)]
 #![feature(result_flattening)]
 
-use mlua::Error;
 use mlua::FromLuaMulti;
 use mlua::Lua;
 use mlua::MultiValue;
 use mlua::Value;
 use serde::Serialize;
 
+use crate::error::field;
+use crate::error::ConfigurationError;
+
 pub mod appearance;
 pub mod dashboard;
+pub mod error;
 pub mod eventflags;
 pub mod plugins;
 
@@ -50,32 +53,31 @@ pub struct Configuration {
     pub dashboard: dashboard::Dashboard,
     /// An optional plugins configuration object.
     pub plugins: Option<plugins::Plugins>,
+    /// The version of the configuration schema the configuration is written for.
+    pub version: u32,
 }
 
 impl<'lua> FromLuaMulti<'lua> for Configuration {
     fn from_lua_multi(values: MultiValue<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        if values.is_empty() {
-            return Err(Error::RuntimeError(String::from(
-                "Configuration: multi value is empty",
-            )));
-        }
-
-        let next_value = values.into_iter().next().unwrap();
-        let Value::Table(value) = next_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "Configuration: mismatched value type, expected table, found: {}",
+        let next_value = values.into_iter().next().unwrap_or(Value::Nil);
+        let Value::Table(value) = next_value else {
+            return Err(ConfigurationError::new(format!(
+                "configuration must evaluate to a table, found: {}",
                 next_value.type_name()
-            )));
+            ))
+            .into());
         };
 
-        let appearance = value.get("appearance")?;
-        let dashboard = value.get("dashboard")?;
-        let plugins = value.get("plugins")?;
+        let appearance = field(&value, "appearance")?;
+        let dashboard = field(&value, "dashboard")?;
+        let plugins = field(&value, "plugins")?;
+        let version = field(&value, "version")?;
 
         Ok(Self {
             appearance,
             dashboard,
             plugins,
+            version,
         })
     }
 }
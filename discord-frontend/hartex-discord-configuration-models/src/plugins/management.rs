@@ -22,12 +22,14 @@
 
 //! # Management Plugin Configuration Object
 
-use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
 use mlua::Value;
 use serde::Serialize;
 
+use crate::error::field;
+use crate::error::table;
+
 /// The management plugin configuration object.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Serialize)]
@@ -38,14 +40,9 @@ pub struct ManagementPlugin {
 
 impl<'lua> FromLua<'lua> for ManagementPlugin {
     fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        let Value::Table(table) = lua_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "ManagementPlugin: mismatched value type, expected table, found: {}",
-                lua_value.type_name()
-            )));
-        };
+        let table = table(lua_value)?;
 
-        let enabled = table.get("enabled")?;
+        let enabled = field(&table, "enabled")?;
 
         Ok(Self { enabled })
     }
@@ -22,12 +22,14 @@
 
 //! # Plugins Configuration Object
 
-use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
 use mlua::Value;
 use serde::Serialize;
 
+use crate::error::field;
+use crate::error::table;
+
 pub mod management;
 pub mod modlog;
 pub mod utilities;
@@ -45,16 +47,11 @@ pub struct Plugins {
 
 impl<'lua> FromLua<'lua> for Plugins {
     fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        let Value::Table(table) = lua_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "Dashboard: mismatched value type, expected table, found: {}",
-                lua_value.type_name()
-            )));
-        };
+        let table = table(lua_value)?;
 
-        let management = table.get("management")?;
-        let modlog = table.get("modlog")?;
-        let utilities = table.get("utilities")?;
+        let management = field(&table, "management")?;
+        let modlog = field(&table, "modlog")?;
+        let utilities = field(&table, "utilities")?;
 
         Ok(Self {
             management,
@@ -22,17 +22,20 @@
 
 //! # Modlog Logger Configuration Object
 
-use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
 use mlua::Value;
+use serde::Deserialize;
 use serde::Serialize;
 
+use crate::error::field;
+use crate::error::table;
+use crate::error::ConfigurationError;
 use crate::eventflags::EventFlags;
 
 /// The modlog logger configuration object.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ModlogLogger {
     /// The channel ID associated with this logger.
     pub channel: String,
@@ -44,16 +47,11 @@ pub struct ModlogLogger {
 
 impl<'lua> FromLua<'lua> for ModlogLogger {
     fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        let Value::Table(table) = lua_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "ModlogPlugin: mismatched value type, expected table, found: {}",
-                lua_value.type_name()
-            )));
-        };
+        let table = table(lua_value)?;
 
-        let channel = table.get("channel")?;
-        let events = table.get("events")?;
-        let format = table.get("format")?;
+        let channel = field(&table, "channel")?;
+        let events = field(&table, "events")?;
+        let format = field(&table, "format")?;
 
         Ok(Self {
             channel,
@@ -64,7 +62,7 @@ impl<'lua> FromLua<'lua> for ModlogLogger {
 }
 
 /// Configures modlog formats.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub enum ModlogFormat {
     /// Default, text-only format.
     Default,
@@ -80,27 +78,23 @@ impl Default for ModlogFormat {
 
 impl<'lua> FromLua<'lua> for ModlogFormat {
     fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        let Value::String(string) = lua_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "ModlogFormat: mismatched value type, expected string, found: {}",
-                lua_value.type_name()
-            )));
+        let Value::String(string) = lua_value else {
+            return Err(ConfigurationError::mismatched_type("string", &lua_value).into());
         };
 
         let Ok(rust_string) = string.to_str() else {
-            return Err(Error::RuntimeError(String::from(
-                "ModlogFormat: string contains invalid UTF-8",
-            )));
+            return Err(ConfigurationError::new("string contains invalid UTF-8").into());
         };
 
         Ok(match rust_string {
             "default" => Self::Default,
             "pretty" => Self::Pretty,
             _ => {
-                return Err(Error::RuntimeError(format!(
-                    "ModlogFormat: unexpected variant: {}, expected either default or pretty",
-                    lua_value.type_name()
-                )))
+                return Err(ConfigurationError::unknown_variant(
+                    rust_string,
+                    &["default", "pretty"],
+                )
+                .into())
             }
         })
     }
@@ -22,12 +22,15 @@
 
 //! # Modlog Plugin Configuration Object
 
-use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
 use mlua::Value;
 use serde::Serialize;
 
+use crate::error::field;
+use crate::error::sequence;
+use crate::error::table;
+
 pub mod logger;
 
 /// The modlog plugin configuration object.
@@ -41,16 +44,11 @@ pub struct ModlogPlugin {
 }
 
 impl<'lua> FromLua<'lua> for ModlogPlugin {
-    fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
-        let Value::Table(table) = lua_value.clone() else {
-            return Err(Error::RuntimeError(format!(
-                "ModlogPlugin: mismatched value type, expected table, found: {}",
-                lua_value.type_name()
-            )));
-        };
-
-        let enabled = table.get("enabled")?;
-        let loggers = table.get("loggers")?;
+    fn from_lua(lua_value: Value<'lua>, lua: &'lua Lua) -> mlua::Result<Self> {
+        let table = table(lua_value)?;
+
+        let enabled = field(&table, "enabled")?;
+        let loggers = sequence(lua, &table, "loggers")?;
 
         Ok(Self { enabled, loggers })
     }
@@ -0,0 +1,98 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_configuration_models::eventflags::EventFlags;
+use hartex_discord_core::discord::model::gateway::event::EventType;
+use hartex_discord_core::discord::model::gateway::Intents;
+
+#[test]
+pub fn from_event_type_message_test() {
+    assert_eq!(
+        EventFlags::from_event_type(EventType::MessageDelete),
+        EventFlags::MESSAGE_DELETED
+    );
+    assert_eq!(
+        EventFlags::from_event_type(EventType::MessageDeleteBulk),
+        EventFlags::MESSAGE_DELETED
+    );
+    assert_eq!(
+        EventFlags::from_event_type(EventType::MessageUpdate),
+        EventFlags::MESSAGE_UPDATED
+    );
+}
+
+#[test]
+pub fn from_event_type_several_test() {
+    assert_eq!(
+        EventFlags::from_event_type(EventType::VoiceStateUpdate),
+        EventFlags::VOICE_JOINED | EventFlags::VOICE_LEFT | EventFlags::VOICE_MOVED
+    );
+    assert_eq!(
+        EventFlags::from_event_type(EventType::GuildEmojisUpdate),
+        EventFlags::EMOJI_CREATED | EventFlags::EMOJI_UPDATED | EventFlags::EMOJI_DELETED
+    );
+}
+
+#[test]
+pub fn from_event_type_unlogged_test() {
+    assert!(EventFlags::from_event_type(EventType::Ready).is_empty());
+    assert!(EventFlags::from_event_type(EventType::TypingStart).is_empty());
+    assert!(EventFlags::from_event_type(EventType::InteractionCreate).is_empty());
+}
+
+#[test]
+pub fn intents_test() {
+    assert_eq!(EventFlags::empty().intents(), Intents::empty());
+    assert_eq!(
+        EventFlags::MESSAGE_UPDATED.intents(),
+        Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT
+    );
+    assert_eq!(
+        (EventFlags::MEMBER_JOINED | EventFlags::MEMBER_BANNED).intents(),
+        Intents::GUILD_MEMBERS | Intents::GUILD_MODERATION
+    );
+    assert_eq!(
+        (EventFlags::ROLE_CREATED | EventFlags::THREAD_DELETED).intents(),
+        Intents::GUILDS
+    );
+    assert_eq!(
+        EventFlags::VOICE_MOVED.intents(),
+        Intents::GUILD_VOICE_STATES
+    );
+    assert_eq!(EventFlags::INVITE_CREATED.intents(), Intents::GUILD_INVITES);
+    assert_eq!(
+        EventFlags::EMOJI_DELETED.intents(),
+        Intents::GUILD_EMOJIS_AND_STICKERS
+    );
+    assert_eq!(
+        EventFlags::AUTOMOD_ACTION_EXECUTED.intents(),
+        Intents::AUTO_MODERATION_EXECUTION
+    );
+}
+
+#[test]
+pub fn logged_intents_test() {
+    assert_eq!(
+        EventFlags::LOGGED.intents(),
+        Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT
+    );
+}
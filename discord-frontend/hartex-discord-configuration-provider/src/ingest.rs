@@ -0,0 +1,330 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Configuration Ingestion
+//!
+//! Evaluates the Lua source of a guild configuration, validates the evaluated configuration,
+//! and stores the source along with the normalized configuration.
+//!
+//! Configurations written for older versions of the configuration schema are upgraded before
+//! being validated, with the warnings produced returned alongside the configuration.
+
+use std::pin::Pin;
+
+use hartex_database_queries::configuration::queries::guild_configuration_upsert::guild_configuration_upsert;
+use hartex_discord_configuration_luart::limits::EvaluationLimits;
+use hartex_discord_configuration_luart::locate::locate;
+use hartex_discord_configuration_luart::Evaluation;
+use hartex_discord_configuration_luart::CHUNK_NAME;
+use hartex_discord_configuration_models::eventflags::EventFlags;
+use hartex_discord_configuration_models::Configuration;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::tokio::task::spawn_blocking;
+use hartex_discord_utils::DATABASE_POOL;
+use hartex_errors::configuration::EvaluationError;
+use hartex_errors::configuration::InvalidValueError;
+use hartex_errors::configuration::ValidationError;
+use miette::IntoDiagnostic;
+use miette::SourceSpan;
+use tokio_postgres::GenericClient;
+
+/// The largest colour value that can be configured.
+const COLOUR_MAXIMUM: u32 = 0xFF_FF_FF;
+
+/// The longest nickname that can be configured, in characters.
+const NICKNAME_MAXIMUM_LENGTH: usize = 32;
+
+/// Evaluates and validates the configuration of a guild, and stores it when valid.
+///
+/// Any configuration previously stored for the guild is replaced.
+///
+/// As evaluating a configuration can take up to the evaluation time budget, it is run on the
+/// blocking thread pool rather than on the asynchronous runtime.
+#[allow(clippy::missing_errors_doc)]
+pub async fn ingest(guild_id: Id<GuildMarker>, source: &str) -> miette::Result<Evaluation> {
+    let owned_source = source.to_string();
+    let evaluation = spawn_blocking(move || evaluate(&owned_source))
+        .await
+        .into_diagnostic()??;
+    store(guild_id, source, &evaluation.configuration).await?;
+
+    Ok(evaluation)
+}
+
+/// Evaluates and validates a configuration, without storing it.
+#[allow(clippy::missing_errors_doc)]
+pub fn evaluate(source: &str) -> miette::Result<Evaluation> {
+    let result = hartex_discord_configuration_luart::evaluate(source, EvaluationLimits::default());
+    let evaluation = result.map_err(|error| evaluation_error(source, &error))?;
+    validate(source, &evaluation.configuration)?;
+
+    Ok(evaluation)
+}
+
+/// Validates an evaluated configuration, collecting every invalid value found.
+#[allow(clippy::missing_errors_doc)]
+pub fn validate(source: &str, configuration: &Configuration) -> miette::Result<()> {
+    let mut errors = Vec::new();
+
+    let dashboard = &configuration.dashboard;
+    if dashboard.admins.is_empty() {
+        errors.push(invalid_value(
+            source,
+            "dashboard.admins",
+            "at least one admin is required",
+        ));
+    }
+    validate_user_ids(source, "dashboard.admins", &dashboard.admins, &mut errors);
+    if let Some(editors) = &dashboard.editors {
+        validate_user_ids(source, "dashboard.editors", editors, &mut errors);
+    }
+    if let Some(viewers) = &dashboard.viewers {
+        validate_user_ids(source, "dashboard.viewers", viewers, &mut errors);
+    }
+
+    if let Some(appearance) = &configuration.appearance {
+        if let Some(colour) = appearance.colour.filter(|colour| *colour > COLOUR_MAXIMUM) {
+            errors.push(invalid_value(
+                source,
+                "appearance.colour",
+                format!("colour {colour:#08X} is greater than {COLOUR_MAXIMUM:#08X}"),
+            ));
+        }
+
+        if let Some(nickname) = &appearance.nickname {
+            let length = nickname.chars().count();
+            if length == 0 || length > NICKNAME_MAXIMUM_LENGTH {
+                errors.push(invalid_value(
+                    source,
+                    "appearance.nickname",
+                    format!(
+                        "nickname must be between 1 and {NICKNAME_MAXIMUM_LENGTH} characters long, but is {length}"
+                    ),
+                ));
+            }
+        }
+    }
+
+    if let Some(modlog) = configuration
+        .plugins
+        .as_ref()
+        .and_then(|plugins| plugins.modlog.as_ref())
+    {
+        for (index, logger) in modlog.loggers.iter().enumerate() {
+            let path = format!("plugins.modlog.loggers[{}]", index + 1);
+
+            if !is_snowflake(&logger.channel) {
+                errors.push(invalid_value(
+                    source,
+                    format!("{path}.channel"),
+                    format!("`{}` is not a valid channel ID", logger.channel),
+                ));
+            }
+
+            if logger.events.is_empty() {
+                errors.push(invalid_value(
+                    source,
+                    format!("{path}.events"),
+                    "at least one event is required",
+                ));
+            }
+
+            let unlogged = logger.events.difference(EventFlags::LOGGED);
+            if !unlogged.is_empty() {
+                let names = unlogged
+                    .iter_names()
+                    .map(|(name, _)| format!("`{name}`"))
+                    .collect::<Vec<_>>();
+                errors.push(invalid_value(
+                    source,
+                    format!("{path}.events"),
+                    format!("events {} are not logged yet", names.join(", ")),
+                ));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(ValidationError { errors }.into())
+}
+
+/// Stores the source and the normalized form of a configuration for a guild.
+async fn store(
+    guild_id: Id<GuildMarker>,
+    source: &str,
+    configuration: &Configuration,
+) -> miette::Result<()> {
+    let dashboard = &configuration.dashboard;
+    let appearance = configuration.appearance.as_ref();
+    let loggers = configuration
+        .plugins
+        .as_ref()
+        .and_then(|plugins| plugins.modlog.as_ref())
+        .map_or(&[][..], |modlog| modlog.loggers.as_slice());
+    let loggers = serde_json::to_value(loggers).into_diagnostic()?;
+
+    let pinned = Pin::static_ref(&DATABASE_POOL).await;
+    let pooled = pinned.get().await.into_diagnostic()?;
+    let client = pooled.client();
+
+    guild_configuration_upsert()
+        .bind(
+            client,
+            &guild_id.to_string(),
+            &source,
+            &dashboard.admins,
+            &dashboard.editors.clone().unwrap_or_default(),
+            &dashboard.viewers.clone().unwrap_or_default(),
+            &appearance.and_then(|appearance| appearance.nickname.clone()),
+            &appearance
+                .and_then(|appearance| appearance.colour)
+                .map(i64::from),
+            &enabled_plugins(configuration),
+            &loggers,
+        )
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
+
+/// Returns the names of the plugins enabled in a configuration.
+#[must_use]
+pub fn enabled_plugins(configuration: &Configuration) -> Vec<String> {
+    let Some(plugins) = &configuration.plugins else {
+        return Vec::new();
+    };
+
+    let mut enabled = Vec::new();
+    if plugins
+        .management
+        .as_ref()
+        .is_some_and(|plugin| plugin.enabled)
+    {
+        enabled.push(String::from("management"));
+    }
+    if plugins.modlog.as_ref().is_some_and(|plugin| plugin.enabled) {
+        enabled.push(String::from("modlog"));
+    }
+    if plugins
+        .utilities
+        .as_ref()
+        .is_some_and(|plugin| plugin.enabled)
+    {
+        enabled.push(String::from("utilities"));
+    }
+
+    enabled
+}
+
+/// Returns the span of the line an evaluation error occurs at, if the error message locates it.
+#[must_use]
+pub fn error_span(source: &str, message: &str) -> Option<SourceSpan> {
+    let prefix = format!("{CHUNK_NAME}:");
+    let start = message.find(&prefix)? + prefix.len();
+    let line = message[start..]
+        .split(|char: char| !char.is_ascii_digit())
+        .next()?
+        .parse::<usize>()
+        .ok()?
+        .checked_sub(1)?;
+
+    let offset = source
+        .split_inclusive('\n')
+        .take(line)
+        .map(str::len)
+        .sum::<usize>();
+    let length = source.lines().nth(line).map_or(0, str::len);
+
+    Some((offset, length).into())
+}
+
+/// Constructs the diagnostic for an error evaluating a configuration, pointing at the invalid
+/// value when the error concerns one.
+fn evaluation_error(
+    source: &str,
+    error: &hartex_discord_configuration_luart::error::EvaluationError,
+) -> miette::Report {
+    if let Some(error) = error
+        .configuration_error()
+        .filter(|error| !error.path.is_empty())
+    {
+        let mut invalid = invalid_value(source, error.path(), error.message.clone());
+        invalid.help.clone_from(&error.suggestion);
+
+        return invalid.into();
+    }
+
+    let message = error.to_string();
+    EvaluationError {
+        src: source.to_string(),
+        err_span: error_span(source, &message),
+        message,
+    }
+    .into()
+}
+
+/// Constructs an invalid value error at a path of the configuration, pointing at the value in the
+/// source where it can be located.
+fn invalid_value(
+    source: &str,
+    path: impl Into<String>,
+    message: impl Into<String>,
+) -> InvalidValueError {
+    let path = path.into();
+
+    InvalidValueError {
+        src: source.to_string(),
+        err_span: locate(source, &path).map(Into::into),
+        help: None,
+        path,
+        message: message.into(),
+    }
+}
+
+/// Returns whether a string is a valid Discord snowflake.
+#[must_use]
+pub fn is_snowflake(id: &str) -> bool {
+    id.parse::<u64>().is_ok_and(|id| id != 0)
+}
+
+/// Validates a list of user IDs, reporting each invalid ID with its position in the list.
+fn validate_user_ids(
+    source: &str,
+    path: &str,
+    ids: &[String],
+    errors: &mut Vec<InvalidValueError>,
+) {
+    for (index, id) in ids.iter().enumerate() {
+        if !is_snowflake(id) {
+            errors.push(invalid_value(
+                source,
+                format!("{path}[{}]", index + 1),
+                format!("`{id}` is not a valid user ID"),
+            ));
+        }
+    }
+}
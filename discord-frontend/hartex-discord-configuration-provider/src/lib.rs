@@ -28,9 +28,13 @@
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+pub mod ingest;
+
 use std::pin::Pin;
 
+use hartex_database_queries::configuration::queries::modlog_loggers::modlog_loggers;
 use hartex_database_queries::configuration::queries::plugin_enabled::plugin_enabled;
+use hartex_discord_configuration_models::plugins::modlog::logger::ModlogLogger;
 use hartex_discord_core::discord::model::id::marker::GuildMarker;
 use hartex_discord_core::discord::model::id::Id;
 use hartex_discord_utils::DATABASE_POOL;
@@ -41,6 +45,32 @@ use tokio_postgres::GenericClient;
 pub struct ConfigurationProvider;
 
 impl ConfigurationProvider {
+    /// Queries the modlog loggers configured for a certain guild.
+    ///
+    /// No loggers are returned if the guild has not been configured, or if the modlog plugin is
+    /// not enabled for the guild.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn modlog_loggers(guild_id: Id<GuildMarker>) -> miette::Result<Vec<ModlogLogger>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let Some(data) = modlog_loggers()
+            .bind(client, &guild_id.to_string())
+            .opt()
+            .await
+            .into_diagnostic()?
+        else {
+            return Ok(Vec::new());
+        };
+
+        if !data.enabled_plugins.iter().any(|plugin| plugin == "modlog") {
+            return Ok(Vec::new());
+        }
+
+        serde_json::from_value(data.plugins_modlog_loggers).into_diagnostic()
+    }
+
     /// Queries whether a specific plugin is enabled for a certain guild.
     #[allow(clippy::missing_errors_doc)]
     pub async fn plugin_enabled(
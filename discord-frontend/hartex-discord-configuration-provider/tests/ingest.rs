@@ -0,0 +1,244 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_configuration_provider::ingest::enabled_plugins;
+use hartex_discord_configuration_provider::ingest::error_span;
+use hartex_discord_configuration_provider::ingest::evaluate;
+use hartex_discord_configuration_provider::ingest::is_snowflake;
+use hartex_errors::configuration::ValidationError;
+use miette::SourceSpan;
+
+/// Builds a configuration with the given dashboard admins, appearance and modlog loggers.
+fn configuration(admins: &str, appearance: &str, loggers: &str) -> String {
+    format!(
+        r#"return {{
+    version = 10,
+
+    dashboard = {{
+        admins = {{ {admins} }}
+    }},
+
+    appearance = {{ {appearance} }},
+
+    plugins = {{
+        modlog = {{
+            enabled = true,
+            loggers = {{ {loggers} }}
+        }}
+    }}
+}}
+"#
+    )
+}
+
+/// Evaluates a configuration expected to be invalid, returning the paths and messages of the
+/// invalid values.
+fn invalid_values(source: &str) -> Vec<(String, String)> {
+    let report = evaluate(source).unwrap_err();
+    let error = report.downcast_ref::<ValidationError>().unwrap();
+
+    error
+        .errors
+        .iter()
+        .map(|error| (error.path.clone(), error.message.clone()))
+        .collect()
+}
+
+#[test]
+pub fn valid_test() {
+    let source = configuration(
+        r#""1000000000000000""#,
+        r#"nickname = "HarTex Nightly", colour = 0xFFFFFF"#,
+        r#"{ channel = "1000000000000001", events = { "MESSAGE_DELETED" }, format = "default" }"#,
+    );
+
+    assert!(evaluate(&source).is_ok());
+}
+
+#[test]
+pub fn empty_admins_test() {
+    let source = configuration("", "", "");
+
+    assert_eq!(
+        invalid_values(&source),
+        vec![(
+            String::from("dashboard.admins"),
+            String::from("at least one admin is required")
+        )]
+    );
+}
+
+#[test]
+pub fn invalid_snowflake_test() {
+    let source = configuration(r#""1000000000000000", "not a snowflake""#, "", "");
+
+    assert_eq!(
+        invalid_values(&source),
+        vec![(
+            String::from("dashboard.admins[2]"),
+            String::from("`not a snowflake` is not a valid user ID")
+        )]
+    );
+}
+
+#[test]
+pub fn nickname_too_long_test() {
+    let source = configuration(
+        r#""1000000000000000""#,
+        &format!(r#"nickname = "{}""#, "a".repeat(33)),
+        "",
+    );
+
+    assert_eq!(
+        invalid_values(&source),
+        vec![(
+            String::from("appearance.nickname"),
+            String::from("nickname must be between 1 and 32 characters long, but is 33")
+        )]
+    );
+}
+
+#[test]
+pub fn colour_too_large_test() {
+    let source = configuration(r#""1000000000000000""#, "colour = 0x1000000", "");
+
+    assert_eq!(
+        invalid_values(&source),
+        vec![(
+            String::from("appearance.colour"),
+            String::from("colour 0x1000000 is greater than 0xFFFFFF")
+        )]
+    );
+}
+
+#[test]
+pub fn logger_without_events_test() {
+    let source = configuration(
+        r#""1000000000000000""#,
+        "",
+        r#"{ channel = "1000000000000001", events = {}, format = "default" }"#,
+    );
+
+    assert_eq!(
+        invalid_values(&source),
+        vec![(
+            String::from("plugins.modlog.loggers[1].events"),
+            String::from("at least one event is required")
+        )]
+    );
+}
+
+#[test]
+pub fn logger_unlogged_events_test() {
+    let source = configuration(
+        r#""1000000000000000""#,
+        "",
+        r#"{ channel = "1000000000000001", events = { "MESSAGE_DELETED", "MEMBER_JOINED" }, format = "default" }"#,
+    );
+
+    assert_eq!(
+        invalid_values(&source),
+        vec![(
+            String::from("plugins.modlog.loggers[1].events"),
+            String::from("events `MEMBER_JOINED` are not logged yet")
+        )]
+    );
+}
+
+#[test]
+pub fn multiple_invalid_values_test() {
+    let source = configuration("", "colour = 0x1000000", "");
+
+    let paths = invalid_values(&source)
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["dashboard.admins", "appearance.colour"]);
+}
+
+#[test]
+pub fn error_span_test() {
+    let source = "return {\n    version = nil + 1\n}\n";
+    let message = "runtime error: configuration:2: attempt to perform arithmetic on a nil value";
+
+    assert_eq!(error_span(source, message), Some(SourceSpan::from((9, 21))));
+}
+
+#[test]
+pub fn error_span_unlocated_test() {
+    let source = "return {}\n";
+
+    assert_eq!(error_span(source, "out of memory"), None);
+    assert_eq!(error_span(source, "configuration:0: invalid line"), None);
+    assert_eq!(error_span(source, "configuration: no line"), None);
+}
+
+#[test]
+pub fn enabled_plugins_test() {
+    let source = r#"return {
+    version = 10,
+
+    dashboard = {
+        admins = { "1000000000000000" }
+    },
+
+    plugins = {
+        management = {
+            enabled = true
+        },
+        modlog = {
+            enabled = false,
+            loggers = {}
+        },
+        utilities = {
+            enabled = true
+        }
+    }
+}
+"#;
+    let evaluation = evaluate(source).unwrap();
+
+    assert_eq!(
+        enabled_plugins(&evaluation.configuration),
+        vec!["management", "utilities"]
+    );
+}
+
+#[test]
+pub fn enabled_plugins_none_test() {
+    let source = configuration(r#""1000000000000000""#, "", "");
+    let mut evaluation = evaluate(&source).unwrap();
+    evaluation.configuration.plugins = None;
+
+    assert!(enabled_plugins(&evaluation.configuration).is_empty());
+}
+
+#[test]
+pub fn is_snowflake_test() {
+    assert!(is_snowflake("1000000000000000"));
+    assert!(is_snowflake("18446744073709551615"));
+    assert!(!is_snowflake("0"));
+    assert!(!is_snowflake("-1"));
+    assert!(!is_snowflake(""));
+    assert!(!is_snowflake("not a snowflake"));
+    assert!(!is_snowflake("18446744073709551616"));
+}
@@ -25,34 +25,123 @@
 //! An implementation of a cache updater for the guild create event.
 
 use hartex_discord_core::discord::model::gateway::payload::incoming::GuildCreate;
+use hartex_discord_core::discord::model::gateway::Intents;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
 use hartex_discord_entitycache_entities::emoji::EmojiEntity;
 use hartex_discord_entitycache_entities::guild::GuildEntity;
 use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_entities::sticker::StickerEntity;
+use hartex_discord_entitycache_entities::thread::ThreadEntity;
+use hartex_discord_entitycache_entities::voice_state::VoiceStateEntity;
+use hartex_discord_entitycache_repositories::channel::CachedChannelRepository;
 use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
 use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
 use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+use hartex_discord_entitycache_repositories::sticker::CachedStickerRepository;
+use hartex_discord_entitycache_repositories::thread::CachedThreadRepository;
+use hartex_discord_entitycache_repositories::voice_state::CachedVoiceStateRepository;
 
 use crate::CacheUpdater;
 
 impl CacheUpdater for GuildCreate {
+    const INTENTS: Intents = Intents::GUILDS;
+
     async fn update(&self) -> CacheResult<()> {
         let entity = GuildEntity::from(self.0.clone());
 
         CachedGuildRepository.upsert(entity).await?;
 
-        for role in &self.0.roles {
-            CachedRoleRepository
-                .upsert(RoleEntity::from((self.0.id, role.clone())))
-                .await?;
-        }
-
-        for emoji in &self.0.emojis {
-            CachedEmojiRepository
-                .upsert(EmojiEntity::from((self.0.id, emoji.clone())))
-                .await?;
-        }
+        CachedRoleRepository
+            .upsert_many(
+                self.0
+                    .roles
+                    .iter()
+                    .map(|role| RoleEntity::from((self.0.id, role.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedRoleRepository
+            .delete_stale_in_guild(self.0.id, self.0.roles.iter().map(|role| role.id))
+            .await?;
+
+        CachedEmojiRepository
+            .upsert_many(
+                self.0
+                    .emojis
+                    .iter()
+                    .map(|emoji| EmojiEntity::from((self.0.id, emoji.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedEmojiRepository
+            .delete_stale_in_guild(self.0.id, self.0.emojis.iter().map(|emoji| emoji.id))
+            .await?;
+
+        CachedChannelRepository
+            .upsert_many(
+                self.0
+                    .channels
+                    .iter()
+                    .map(|channel| ChannelEntity::from((self.0.id, channel.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedChannelRepository
+            .delete_stale_in_guild(self.0.id, self.0.channels.iter().map(|channel| channel.id))
+            .await?;
+
+        CachedThreadRepository
+            .upsert_many(
+                self.0
+                    .threads
+                    .iter()
+                    .map(|thread| ThreadEntity::from((self.0.id, thread.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedThreadRepository
+            .delete_stale_in_guild(self.0.id, self.0.threads.iter().map(|thread| thread.id))
+            .await?;
+
+        CachedStickerRepository
+            .upsert_many(
+                self.0
+                    .stickers
+                    .iter()
+                    .map(|sticker| StickerEntity::from((self.0.id, sticker.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedStickerRepository
+            .delete_stale_in_guild(self.0.id, self.0.stickers.iter().map(|sticker| sticker.id))
+            .await?;
+
+        CachedVoiceStateRepository
+            .upsert_many(
+                self.0
+                    .voice_states
+                    .iter()
+                    .map(|voice_state| VoiceStateEntity::from((self.0.id, voice_state.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedVoiceStateRepository
+            .delete_stale_in_guild(
+                self.0.id,
+                self.0
+                    .voice_states
+                    .iter()
+                    .map(|voice_state| voice_state.user_id),
+            )
+            .await?;
 
         Ok(())
     }
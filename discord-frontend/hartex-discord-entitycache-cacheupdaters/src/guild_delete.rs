@@ -0,0 +1,68 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Guild Delete Cache Updater
+//!
+//! An implementation of a cache updater for the guild delete event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildDelete;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::channel::CachedChannelRepository;
+use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_entitycache_repositories::member::CachedMemberRepository;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+use hartex_discord_entitycache_repositories::sticker::CachedStickerRepository;
+use hartex_discord_entitycache_repositories::thread::CachedThreadRepository;
+use hartex_discord_entitycache_repositories::user::CachedUserRepository;
+use hartex_discord_entitycache_repositories::voice_state::CachedVoiceStateRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for GuildDelete {
+    const INTENTS: Intents = Intents::GUILDS;
+
+    async fn update(&self) -> CacheResult<()> {
+        // an unavailable guild is merely experiencing an outage; the bot is still in the guild
+        if self.unavailable == Some(true) {
+            return Ok(());
+        }
+
+        CachedVoiceStateRepository
+            .delete_by_guild_id(self.id)
+            .await?;
+        CachedMessageRepository.delete_by_guild_id(self.id).await?;
+        CachedMemberRepository.delete_by_guild_id(self.id).await?;
+        CachedRoleRepository.delete_by_guild_id(self.id).await?;
+        CachedEmojiRepository.delete_by_guild_id(self.id).await?;
+        CachedStickerRepository.delete_by_guild_id(self.id).await?;
+        CachedThreadRepository.delete_by_guild_id(self.id).await?;
+        CachedChannelRepository.delete_by_guild_id(self.id).await?;
+        CachedGuildRepository.delete(self.id).await?;
+        CachedUserRepository.delete_unreferenced().await?;
+
+        Ok(())
+    }
+}
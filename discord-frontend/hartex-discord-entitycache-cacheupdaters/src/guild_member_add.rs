@@ -0,0 +1,51 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Guild Member Add Cache Updater
+//!
+//! An implementation of a cache updater for the guild member add event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::MemberAdd;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::member::MemberEntity;
+use hartex_discord_entitycache_entities::user::UserEntity;
+use hartex_discord_entitycache_repositories::member::CachedMemberRepository;
+use hartex_discord_entitycache_repositories::user::CachedUserRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for MemberAdd {
+    const INTENTS: Intents = Intents::GUILD_MEMBERS;
+
+    async fn update(&self) -> CacheResult<()> {
+        let member_entity =
+            MemberEntity::from((self.guild_id, self.member.user.id, self.member.clone()));
+        let user_entity = UserEntity::from(self.member.user.clone());
+
+        CachedUserRepository.upsert(user_entity).await?;
+        CachedMemberRepository.upsert(member_entity).await?;
+
+        Ok(())
+    }
+}
@@ -21,6 +21,7 @@
  */
 
 use hartex_discord_core::discord::model::gateway::payload::incoming::MemberChunk;
+use hartex_discord_core::discord::model::gateway::Intents;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Repository;
 use hartex_discord_entitycache_entities::member::MemberEntity;
@@ -31,14 +32,22 @@ use hartex_discord_entitycache_repositories::user::CachedUserRepository;
 use crate::CacheUpdater;
 
 impl CacheUpdater for MemberChunk {
+    const INTENTS: Intents = Intents::GUILD_MEMBERS;
+
     async fn update(&self) -> CacheResult<()> {
-        for member in &self.members {
-            let member_entity = MemberEntity::from((self.guild_id, member.user.id, member.clone()));
-            let user_entity = UserEntity::from(member.user.clone());
+        let (member_entities, user_entities): (Vec<_>, Vec<_>) = self
+            .members
+            .iter()
+            .map(|member| {
+                (
+                    MemberEntity::from((self.guild_id, member.user.id, member.clone())),
+                    UserEntity::from(member.user.clone()),
+                )
+            })
+            .unzip();
 
-            CachedMemberRepository.upsert(member_entity).await?;
-            CachedUserRepository.upsert(user_entity).await?;
-        }
+        CachedUserRepository.upsert_many(user_entities).await?;
+        CachedMemberRepository.upsert_many(member_entities).await?;
 
         Ok(())
     }
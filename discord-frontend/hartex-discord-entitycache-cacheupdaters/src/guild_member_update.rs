@@ -0,0 +1,65 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Guild Member Update Cache Updater
+//!
+//! An implementation of a cache updater for the guild member update event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::MemberUpdate;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_core::discord::model::guild::MemberFlags;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::error::CacheResultExt;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::member::MemberEntity;
+use hartex_discord_entitycache_entities::user::UserEntity;
+use hartex_discord_entitycache_repositories::member::CachedMemberRepository;
+use hartex_discord_entitycache_repositories::user::CachedUserRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for MemberUpdate {
+    const INTENTS: Intents = Intents::GUILD_MEMBERS;
+
+    async fn update(&self) -> CacheResult<()> {
+        let flags = CachedMemberRepository
+            .get((self.guild_id, self.user.id))
+            .await
+            .optional()?
+            .map_or(MemberFlags::empty(), |member| member.flags);
+
+        let member_entity = MemberEntity {
+            flags,
+            joined_at: self.joined_at,
+            nick: self.nick.clone(),
+            roles: self.roles.clone(),
+            guild_id: self.guild_id,
+            user_id: self.user.id,
+        };
+        let user_entity = UserEntity::from(self.user.clone());
+
+        CachedUserRepository.upsert(user_entity).await?;
+        CachedMemberRepository.upsert(member_entity).await?;
+
+        Ok(())
+    }
+}
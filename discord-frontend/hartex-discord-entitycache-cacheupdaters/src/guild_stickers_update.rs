@@ -0,0 +1,58 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Guild Stickers Update Cache Updater
+//!
+//! An implementation of a cache updater for the guild stickers update event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildStickersUpdate;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::sticker::StickerEntity;
+use hartex_discord_entitycache_repositories::sticker::CachedStickerRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for GuildStickersUpdate {
+    const INTENTS: Intents = Intents::GUILD_EMOJIS_AND_STICKERS;
+
+    async fn update(&self) -> CacheResult<()> {
+        CachedStickerRepository
+            .upsert_many(
+                self.stickers
+                    .iter()
+                    .map(|sticker| StickerEntity::from((self.guild_id, sticker.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedStickerRepository
+            .delete_stale_in_guild(
+                self.guild_id,
+                self.stickers.iter().map(|sticker| sticker.id),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
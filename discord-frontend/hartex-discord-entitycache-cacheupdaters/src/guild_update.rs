@@ -0,0 +1,101 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Guild Update Cache Updater
+//!
+//! An implementation of a cache updater for the guild update event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildUpdate;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::error::CacheResultExt;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::emoji::EmojiEntity;
+use hartex_discord_entitycache_entities::guild::GuildEntity;
+use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for GuildUpdate {
+    const INTENTS: Intents = Intents::GUILDS;
+
+    async fn update(&self) -> CacheResult<()> {
+        // the partial guild does not carry whether the guild is large, so the previously
+        // cached value is kept
+        let large = CachedGuildRepository
+            .get(self.0.id)
+            .await
+            .optional()?
+            .is_some_and(|guild| guild.large);
+
+        let entity = GuildEntity {
+            default_message_notifications: self.0.default_message_notifications,
+            explicit_content_filter: self.0.explicit_content_filter,
+            features: self.0.features.clone(),
+            icon: self.0.icon,
+            large,
+            mfa_level: self.0.mfa_level,
+            name: self.0.name.clone(),
+            premium_subscription_count: self.0.premium_subscription_count,
+            premium_tier: self.0.premium_tier,
+            owner_id: self.0.owner_id,
+            preferred_locale: self.0.preferred_locale.clone(),
+            verification_level: self.0.verification_level,
+            id: self.0.id,
+        };
+
+        CachedGuildRepository.upsert(entity).await?;
+
+        CachedRoleRepository
+            .upsert_many(
+                self.0
+                    .roles
+                    .iter()
+                    .map(|role| RoleEntity::from((self.0.id, role.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedRoleRepository
+            .delete_stale_in_guild(self.0.id, self.0.roles.iter().map(|role| role.id))
+            .await?;
+
+        CachedEmojiRepository
+            .upsert_many(
+                self.0
+                    .emojis
+                    .iter()
+                    .map(|emoji| EmojiEntity::from((self.0.id, emoji.clone())))
+                    .collect(),
+            )
+            .await?;
+
+        CachedEmojiRepository
+            .delete_stale_in_guild(self.0.id, self.0.emojis.iter().map(|emoji| emoji.id))
+            .await?;
+
+        Ok(())
+    }
+}
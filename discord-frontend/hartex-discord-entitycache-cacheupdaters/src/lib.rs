@@ -29,14 +29,96 @@
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+use hartex_discord_core::discord::model::gateway::payload::incoming::ChannelCreate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::ChannelDelete;
+use hartex_discord_core::discord::model::gateway::payload::incoming::ChannelUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildCreate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildDelete;
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildEmojisUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildStickersUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MemberAdd;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MemberChunk;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MemberRemove;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MemberUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageCreate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageDelete;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageDeleteBulk;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::RoleCreate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::RoleDelete;
+use hartex_discord_core::discord::model::gateway::payload::incoming::RoleUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::ThreadCreate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::ThreadDelete;
+use hartex_discord_core::discord::model::gateway::payload::incoming::ThreadListSync;
+use hartex_discord_core::discord::model::gateway::payload::incoming::ThreadUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::UserUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::VoiceStateUpdate;
+use hartex_discord_core::discord::model::gateway::Intents;
 use hartex_discord_entitycache_core::error::CacheResult;
 
+pub mod channel_create;
+pub mod channel_delete;
+pub mod channel_update;
 pub mod guild_create;
+pub mod guild_delete;
+pub mod guild_emojis_update;
+pub mod guild_member_add;
 pub mod guild_member_chunk;
+pub mod guild_member_remove;
+pub mod guild_member_update;
+pub mod guild_role_create;
+pub mod guild_role_delete;
+pub mod guild_role_update;
+pub mod guild_stickers_update;
+pub mod guild_update;
+pub mod message_create;
+pub mod message_delete;
+pub mod message_delete_bulk;
+pub mod message_update;
+pub mod thread_create;
+pub mod thread_delete;
+pub mod thread_list_sync;
+pub mod thread_update;
+pub mod user_update;
+pub mod voice_state_update;
 
 /// A trait for all cache updaters to implement.
 pub trait CacheUpdater {
+    /// The gateway intents required for receiving the event of this cache updater.
+    const INTENTS: Intents;
+
     /// Update the cache.
     #[allow(async_fn_in_trait)]
     async fn update(&self) -> CacheResult<()>;
 }
+
+/// The gateway intents required by all cache updaters.
+#[must_use]
+pub fn intents() -> Intents {
+    ChannelCreate::INTENTS
+        | ChannelDelete::INTENTS
+        | ChannelUpdate::INTENTS
+        | GuildCreate::INTENTS
+        | GuildDelete::INTENTS
+        | GuildEmojisUpdate::INTENTS
+        | GuildStickersUpdate::INTENTS
+        | GuildUpdate::INTENTS
+        | MemberAdd::INTENTS
+        | MemberChunk::INTENTS
+        | MemberRemove::INTENTS
+        | MemberUpdate::INTENTS
+        | MessageCreate::INTENTS
+        | MessageDelete::INTENTS
+        | MessageDeleteBulk::INTENTS
+        | MessageUpdate::INTENTS
+        | RoleCreate::INTENTS
+        | RoleDelete::INTENTS
+        | RoleUpdate::INTENTS
+        | ThreadCreate::INTENTS
+        | ThreadDelete::INTENTS
+        | ThreadListSync::INTENTS
+        | ThreadUpdate::INTENTS
+        | UserUpdate::INTENTS
+        | VoiceStateUpdate::INTENTS
+}
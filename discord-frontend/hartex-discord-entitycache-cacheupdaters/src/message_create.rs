@@ -0,0 +1,56 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Create Cache Updater
+//!
+//! An implementation of a cache updater for the message create event.
+//!
+//! This cache updater is invoked by the worker rather than the entitycache update service, such
+//! that a message is cached on the same lane as its updates and deletions.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageCreate;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for MessageCreate {
+    const INTENTS: Intents = Intents::GUILD_MESSAGES.union(Intents::MESSAGE_CONTENT);
+
+    async fn update(&self) -> CacheResult<()> {
+        // only guild messages are cached
+        let Some(guild_id) = self.0.guild_id else {
+            return Ok(());
+        };
+
+        CachedMessageRepository
+            .upsert(MessageEntity::from((
+                self.0.author.id,
+                guild_id,
+                self.0.clone(),
+            )))
+            .await
+    }
+}
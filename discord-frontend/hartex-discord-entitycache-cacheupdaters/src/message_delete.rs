@@ -0,0 +1,44 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Delete Cache Updater
+//!
+//! An implementation of a cache updater for the message delete event.
+//!
+//! This cache updater is invoked by the worker rather than the entitycache update service, such
+//! that the moderation log can read the content of the message before it is evicted.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageDelete;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for MessageDelete {
+    const INTENTS: Intents = Intents::GUILD_MESSAGES;
+
+    async fn update(&self) -> CacheResult<()> {
+        CachedMessageRepository.delete(self.id).await
+    }
+}
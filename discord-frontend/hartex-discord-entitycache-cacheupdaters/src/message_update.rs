@@ -0,0 +1,87 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Update Cache Updater
+//!
+//! An implementation of a cache updater for the message update event.
+//!
+//! This cache updater is invoked by the worker rather than the entitycache update service, such
+//! that the moderation log can read the previous content of the message before it is updated.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageUpdate;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for MessageUpdate {
+    const INTENTS: Intents = Intents::GUILD_MESSAGES.union(Intents::MESSAGE_CONTENT);
+
+    async fn update(&self) -> CacheResult<()> {
+        let Some(guild_id) = self.guild_id else {
+            return Ok(());
+        };
+
+        let entity = match CachedMessageRepository.get_many(vec![self.id]).await?.pop() {
+            Some(mut entity) => {
+                if let Some(attachments) = &self.attachments {
+                    entity.attachments.clone_from(attachments);
+                }
+                if let Some(content) = &self.content {
+                    entity.content.clone_from(content);
+                }
+                if self.edited_timestamp.is_some() {
+                    entity.edited_timestamp = self.edited_timestamp;
+                }
+
+                entity
+            }
+            None => {
+                // a message that is not cached is only cached if the update carries all of it
+                let (Some(attachments), Some(author), Some(content), Some(timestamp)) = (
+                    self.attachments.clone(),
+                    self.author.as_ref(),
+                    self.content.clone(),
+                    self.timestamp,
+                ) else {
+                    return Ok(());
+                };
+
+                MessageEntity {
+                    attachments,
+                    author_id: author.id,
+                    channel_id: self.channel_id,
+                    content,
+                    edited_timestamp: self.edited_timestamp,
+                    guild_id,
+                    id: self.id,
+                    timestamp,
+                }
+            }
+        };
+
+        CachedMessageRepository.upsert(entity).await
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Thread List Sync Cache Updater
+//!
+//! An implementation of a cache updater for the thread list sync event.
+//!
+//! Threads of the synced channels that are missing from the event are no longer active and are
+//! removed from the cache. When no channels are specified, the event covers the entire guild.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::ThreadListSync;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::thread::ThreadEntity;
+use hartex_discord_entitycache_repositories::thread::CachedThreadRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for ThreadListSync {
+    const INTENTS: Intents = Intents::GUILDS;
+
+    async fn update(&self) -> CacheResult<()> {
+        let mut retained = self
+            .threads
+            .iter()
+            .map(|thread| thread.id)
+            .collect::<Vec<_>>();
+        if !self.channel_ids.is_empty() {
+            retained.extend(
+                CachedThreadRepository
+                    .get_by_guild_id(self.guild_id)
+                    .await?
+                    .into_iter()
+                    .filter(|thread| {
+                        !thread
+                            .parent_id
+                            .is_some_and(|parent_id| self.channel_ids.contains(&parent_id))
+                    })
+                    .map(|thread| thread.id),
+            );
+        }
+
+        CachedThreadRepository
+            .delete_stale_in_guild(self.guild_id, retained.into_iter())
+            .await?;
+
+        CachedThreadRepository
+            .upsert_many(
+                self.threads
+                    .iter()
+                    .map(|thread| ThreadEntity::from((self.guild_id, thread.clone())))
+                    .collect(),
+            )
+            .await
+    }
+}
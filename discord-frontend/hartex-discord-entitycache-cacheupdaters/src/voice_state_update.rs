@@ -0,0 +1,55 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Voice State Update Cache Updater
+//!
+//! An implementation of a cache updater for the voice state update event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::VoiceStateUpdate;
+use hartex_discord_core::discord::model::gateway::Intents;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::voice_state::VoiceStateEntity;
+use hartex_discord_entitycache_repositories::voice_state::CachedVoiceStateRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for VoiceStateUpdate {
+    const INTENTS: Intents = Intents::GUILD_VOICE_STATES;
+
+    async fn update(&self) -> CacheResult<()> {
+        let Some(guild_id) = self.0.guild_id else {
+            return Ok(());
+        };
+
+        // a voice state without a channel means the user has left the voice channel
+        if self.0.channel_id.is_none() {
+            return CachedVoiceStateRepository
+                .delete((guild_id, self.0.user_id))
+                .await;
+        }
+
+        CachedVoiceStateRepository
+            .upsert(VoiceStateEntity::from((guild_id, self.0.clone())))
+            .await
+    }
+}
@@ -0,0 +1,202 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # In-Memory Key-Value Store
+//!
+//! A key-value store held purely in the memory of the process. Its contents are lost when the
+//! process exits, hence it is only suitable for tests and small deployments running all
+//! components within a single process.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use crate::backend::keyvalue::KeyValueStore;
+use crate::error::CacheResult;
+
+/// A key-value store held in memory.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Default)]
+pub struct InMemoryStore {
+    sets: Mutex<HashMap<String, HashSet<String>>>,
+    sorted_sets: Mutex<HashMap<String, SortedSet>>,
+    values: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryStore {
+    /// Create an empty in-memory store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KeyValueStore for InMemoryStore {
+    async fn delete(&self, keys: Vec<String>) -> CacheResult<()> {
+        let mut values = self.values.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut sets = self.sets.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut sorted_sets = self
+            .sorted_sets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        for key in keys {
+            values.remove(&key);
+            sets.remove(&key);
+            sorted_sets.remove(&key);
+        }
+
+        Ok(())
+    }
+
+    async fn exists(&self, key: String) -> CacheResult<bool> {
+        let values = self.values.lock().unwrap_or_else(PoisonError::into_inner);
+
+        Ok(values.contains_key(&key))
+    }
+
+    async fn get(&self, keys: Vec<String>) -> CacheResult<Vec<Option<Vec<u8>>>> {
+        let values = self.values.lock().unwrap_or_else(PoisonError::into_inner);
+
+        Ok(keys.iter().map(|key| values.get(key).cloned()).collect())
+    }
+
+    async fn set(&self, entries: Vec<(String, Vec<u8>)>) -> CacheResult<()> {
+        let mut values = self.values.lock().unwrap_or_else(PoisonError::into_inner);
+        values.extend(entries);
+
+        Ok(())
+    }
+
+    async fn set_add(&self, set: String, members: Vec<String>) -> CacheResult<()> {
+        let mut sets = self.sets.lock().unwrap_or_else(PoisonError::into_inner);
+        sets.entry(set).or_default().extend(members);
+
+        Ok(())
+    }
+
+    async fn set_members(&self, set: String) -> CacheResult<Vec<String>> {
+        let sets = self.sets.lock().unwrap_or_else(PoisonError::into_inner);
+
+        Ok(sets
+            .get(&set)
+            .map(|members| members.iter().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn set_remove(&self, set: String, members: Vec<String>) -> CacheResult<()> {
+        let mut sets = self.sets.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(existing) = sets.get_mut(&set) {
+            for member in &members {
+                existing.remove(member);
+            }
+
+            if existing.is_empty() {
+                sets.remove(&set);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn sorted_set_add(&self, set: String, members: Vec<(String, i64)>) -> CacheResult<()> {
+        let mut sorted_sets = self
+            .sorted_sets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let existing = sorted_sets.entry(set).or_default();
+        for (member, score) in members {
+            if let Some(previous) = existing.scores.insert(member.clone(), score) {
+                existing.ordered.remove(&(previous, member.clone()));
+            }
+            existing.ordered.insert((score, member));
+        }
+
+        Ok(())
+    }
+
+    async fn sorted_set_below(&self, set: String, score: i64) -> CacheResult<Vec<String>> {
+        let sorted_sets = self
+            .sorted_sets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // the empty string precedes every other member with the same score
+        Ok(sorted_sets
+            .get(&set)
+            .map(|existing| {
+                existing
+                    .ordered
+                    .range(..(score, String::new()))
+                    .map(|(_, member)| member.clone())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn sorted_set_lowest(&self, set: String, keep: usize) -> CacheResult<Vec<String>> {
+        let sorted_sets = self
+            .sorted_sets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        Ok(sorted_sets
+            .get(&set)
+            .map(|existing| {
+                existing
+                    .ordered
+                    .iter()
+                    .take(existing.ordered.len().saturating_sub(keep))
+                    .map(|(_, member)| member.clone())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn sorted_set_remove(&self, set: String, members: Vec<String>) -> CacheResult<()> {
+        let mut sorted_sets = self
+            .sorted_sets
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(existing) = sorted_sets.get_mut(&set) {
+            for member in members {
+                if let Some(score) = existing.scores.remove(&member) {
+                    existing.ordered.remove(&(score, member));
+                }
+            }
+
+            if existing.scores.is_empty() {
+                sorted_sets.remove(&set);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A set whose members are ordered by their scores.
+#[derive(Debug, Default)]
+struct SortedSet {
+    ordered: BTreeSet<(i64, String)>,
+    scores: HashMap<String, i64>,
+}
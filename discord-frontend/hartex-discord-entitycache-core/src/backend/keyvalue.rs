@@ -0,0 +1,497 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Key-Value Storage Backend
+//!
+//! A storage backend storing entities serialized as JSON in a key-value store.
+//!
+//! Every entity is stored under the key `entitycache:<entity>:<id>`. The keys of all entities of a
+//! type are indexed in the set `entitycache:<entity>:all`, and the keys of the entities belonging
+//! to a guild in the set `entitycache:<entity>:guild:<guild id>`. Likewise, the keys of the
+//! entities belonging to a user are indexed in the set `entitycache:<entity>:user:<user id>`.
+//!
+//! Entities with a timestamp (see [`TimestampedEntity`]) are additionally indexed by their
+//! timestamps in the sorted sets `entitycache:<entity>:timestamps` and
+//! `entitycache:<entity>:guild:<guild id>:timestamps`, such that the oldest entities can be deleted
+//! without retrieving every entity. These indices are only maintained by the `*_timestamped`
+//! operations of [`KeyValueBackend`], which must therefore be used for such entities instead of
+//! the operations of [`Backend`].
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+
+use crate::backend::Backend;
+use crate::backend::GuildScopedBackend;
+use crate::backend::StoredEntity;
+use crate::backend::TimestampedEntity;
+use crate::error::CacheError;
+use crate::error::CacheResult;
+use crate::memory::CacheKey;
+use crate::traits::Entity;
+
+/// A key-value store supporting sets and sorted sets.
+pub trait KeyValueStore {
+    /// Deletes the given keys.
+    #[allow(async_fn_in_trait)]
+    async fn delete(&self, keys: Vec<String>) -> CacheResult<()>;
+
+    /// Returns whether the given key exists.
+    #[allow(async_fn_in_trait)]
+    async fn exists(&self, key: String) -> CacheResult<bool>;
+
+    /// Retrieves the values of the given keys, in the same order as the keys.
+    #[allow(async_fn_in_trait)]
+    async fn get(&self, keys: Vec<String>) -> CacheResult<Vec<Option<Vec<u8>>>>;
+
+    /// Sets the values of the given keys.
+    #[allow(async_fn_in_trait)]
+    async fn set(&self, entries: Vec<(String, Vec<u8>)>) -> CacheResult<()>;
+
+    /// Adds members to a set.
+    #[allow(async_fn_in_trait)]
+    async fn set_add(&self, set: String, members: Vec<String>) -> CacheResult<()>;
+
+    /// Retrieves the members of a set.
+    #[allow(async_fn_in_trait)]
+    async fn set_members(&self, set: String) -> CacheResult<Vec<String>>;
+
+    /// Removes members from a set.
+    #[allow(async_fn_in_trait)]
+    async fn set_remove(&self, set: String, members: Vec<String>) -> CacheResult<()>;
+
+    /// Adds members to a sorted set with the given scores, replacing the scores of existing
+    /// members.
+    #[allow(async_fn_in_trait)]
+    async fn sorted_set_add(&self, set: String, members: Vec<(String, i64)>) -> CacheResult<()>;
+
+    /// Retrieves the members of a sorted set with a score lower than the given score.
+    #[allow(async_fn_in_trait)]
+    async fn sorted_set_below(&self, set: String, score: i64) -> CacheResult<Vec<String>>;
+
+    /// Retrieves the members of a sorted set in ascending order of score, except for the given
+    /// number of members with the highest scores.
+    #[allow(async_fn_in_trait)]
+    async fn sorted_set_lowest(&self, set: String, keep: usize) -> CacheResult<Vec<String>>;
+
+    /// Removes members from a sorted set.
+    #[allow(async_fn_in_trait)]
+    async fn sorted_set_remove(&self, set: String, members: Vec<String>) -> CacheResult<()>;
+}
+
+/// A storage backend backed by a key-value store.
+#[allow(clippy::module_name_repetitions)]
+pub struct KeyValueBackend<S> {
+    store: S,
+}
+
+impl<S: KeyValueStore> KeyValueBackend<S> {
+    /// Create a storage backend backed by the given key-value store.
+    #[must_use]
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    /// Retrieves all entities of a type from the backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store operation fails or if an entity cannot be deserialized.
+    pub async fn all<T: StoredEntity>(&self) -> CacheResult<Vec<T>> {
+        let keys = self.store.set_members(all_key::<T>()).await?;
+
+        self.get_by_keys(keys).await
+    }
+
+    /// Returns whether any entity belonging to the given user exists in the backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store operation fails.
+    pub async fn any_by_user_id<T: StoredEntity>(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> CacheResult<bool> {
+        let keys = self.store.set_members(user_key::<T>(user_id)).await?;
+
+        Ok(!keys.is_empty())
+    }
+
+    /// Deletes all entities of a guild from the backend, returning the deleted entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store operation fails or if an entity cannot be deserialized.
+    pub async fn delete_by_guild_id_returning<T: StoredEntity>(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> CacheResult<Vec<T>> {
+        let keys = self.store.set_members(guild_key::<T>(guild_id)).await?;
+        let entities = self.delete_by_keys::<T>(keys).await?;
+
+        self.store.delete(vec![guild_key::<T>(guild_id)]).await?;
+
+        Ok(entities)
+    }
+
+    /// Deletes entities indexed by their timestamps from the backend, returning the deleted
+    /// entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store operation fails or if an entity cannot be deserialized.
+    pub async fn delete_timestamped<T: TimestampedEntity>(
+        &self,
+        entity_ids: Vec<T::Id>,
+    ) -> CacheResult<Vec<T>> {
+        self.delete_timestamped_by_keys(entity_ids.iter().map(CacheKey::cache_key).collect())
+            .await
+    }
+
+    /// Deletes the entities indexed by their timestamps with a timestamp before the given one, in
+    /// microseconds since the Unix epoch, returning the deleted entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store operation fails or if an entity cannot be deserialized.
+    pub async fn delete_timestamped_before<T: TimestampedEntity>(
+        &self,
+        before_micros: i64,
+    ) -> CacheResult<Vec<T>> {
+        let keys = self
+            .store
+            .sorted_set_below(timestamps_key::<T>(), before_micros)
+            .await?;
+
+        self.delete_timestamped_by_keys(keys).await
+    }
+
+    /// Deletes all entities of a guild indexed by their timestamps from the backend, returning the
+    /// deleted entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store operation fails or if an entity cannot be deserialized.
+    pub async fn delete_timestamped_by_guild_id<T: TimestampedEntity>(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> CacheResult<Vec<T>> {
+        let keys = self.store.set_members(guild_key::<T>(guild_id)).await?;
+        let entities = self.delete_timestamped_by_keys(keys).await?;
+
+        self.store
+            .delete(vec![
+                guild_key::<T>(guild_id),
+                guild_timestamps_key::<T>(guild_id),
+            ])
+            .await?;
+
+        Ok(entities)
+    }
+
+    /// Deletes the oldest entities of a guild indexed by their timestamps such that at most the
+    /// given number of entities of the guild remain, returning the deleted entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store operation fails or if an entity cannot be deserialized.
+    pub async fn delete_timestamped_excess_in_guild<T: TimestampedEntity>(
+        &self,
+        guild_id: Id<GuildMarker>,
+        capacity: usize,
+    ) -> CacheResult<Vec<T>> {
+        let keys = self
+            .store
+            .sorted_set_lowest(guild_timestamps_key::<T>(guild_id), capacity)
+            .await?;
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let entities = self.delete_timestamped_by_keys(keys.clone()).await?;
+
+        // the keys of entities that no longer exist would otherwise remain in the index forever
+        self.store
+            .sorted_set_remove(guild_timestamps_key::<T>(guild_id), keys)
+            .await?;
+
+        Ok(entities)
+    }
+
+    /// Upserts entities into the backend, indexing them by their timestamps.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store operation fails or if an entity cannot be serialized.
+    pub async fn upsert_timestamped<T: TimestampedEntity>(
+        &self,
+        entities: Vec<T>,
+    ) -> CacheResult<()> {
+        let mut members = Vec::with_capacity(entities.len());
+        let mut by_guild = HashMap::<_, Vec<_>>::new();
+        for entity in &entities {
+            let member = (entity.id().cache_key(), entity.timestamp_micros());
+            if let Some(guild_id) = entity.guild_id() {
+                by_guild.entry(guild_id).or_default().push(member.clone());
+            }
+            members.push(member);
+        }
+
+        Backend::<T>::upsert_many(self, entities).await?;
+        self.store
+            .sorted_set_add(timestamps_key::<T>(), members)
+            .await?;
+        for (guild_id, members) in by_guild {
+            self.store
+                .sorted_set_add(guild_timestamps_key::<T>(guild_id), members)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the entities with the given keys from the backend, returning the deleted entities.
+    async fn delete_by_keys<T: StoredEntity>(&self, keys: Vec<String>) -> CacheResult<Vec<T>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let entities = self.get_by_keys::<T>(keys.clone()).await?;
+
+        let mut by_guild = HashMap::<_, Vec<_>>::new();
+        let mut by_user = HashMap::<_, Vec<_>>::new();
+        for entity in &entities {
+            if let Some(guild_id) = entity.guild_id() {
+                by_guild
+                    .entry(guild_id)
+                    .or_default()
+                    .push(entity.id().cache_key());
+            }
+            if let Some(user_id) = entity.user_id() {
+                by_user
+                    .entry(user_id)
+                    .or_default()
+                    .push(entity.id().cache_key());
+            }
+        }
+
+        self.store
+            .delete(keys.iter().map(|key| entity_key::<T>(key)).collect())
+            .await?;
+        self.store.set_remove(all_key::<T>(), keys).await?;
+        for (guild_id, keys) in by_guild {
+            self.store
+                .set_remove(guild_key::<T>(guild_id), keys)
+                .await?;
+        }
+        for (user_id, keys) in by_user {
+            self.store.set_remove(user_key::<T>(user_id), keys).await?;
+        }
+
+        Ok(entities)
+    }
+
+    /// Deletes the entities indexed by their timestamps with the given keys from the backend,
+    /// returning the deleted entities.
+    async fn delete_timestamped_by_keys<T: TimestampedEntity>(
+        &self,
+        keys: Vec<String>,
+    ) -> CacheResult<Vec<T>> {
+        let entities = self.delete_by_keys::<T>(keys.clone()).await?;
+
+        let mut by_guild = HashMap::<_, Vec<_>>::new();
+        for entity in &entities {
+            if let Some(guild_id) = entity.guild_id() {
+                by_guild
+                    .entry(guild_id)
+                    .or_default()
+                    .push(entity.id().cache_key());
+            }
+        }
+
+        self.store
+            .sorted_set_remove(timestamps_key::<T>(), keys)
+            .await?;
+        for (guild_id, keys) in by_guild {
+            self.store
+                .sorted_set_remove(guild_timestamps_key::<T>(guild_id), keys)
+                .await?;
+        }
+
+        Ok(entities)
+    }
+
+    /// Retrieves the entities with the given keys from the backend.
+    async fn get_by_keys<T: StoredEntity>(&self, keys: Vec<String>) -> CacheResult<Vec<T>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let values = self
+            .store
+            .get(keys.iter().map(|key| entity_key::<T>(key)).collect())
+            .await?;
+
+        values
+            .into_iter()
+            .flatten()
+            .map(|bytes| serde_json::from_slice(&bytes).map_err(CacheError::from))
+            .collect()
+    }
+}
+
+impl<S: KeyValueStore, T: StoredEntity> Backend<T> for KeyValueBackend<S> {
+    async fn delete(&self, entity_id: T::Id) -> CacheResult<()> {
+        self.delete_by_keys::<T>(vec![entity_id.cache_key()])
+            .await
+            .map(|_| ())
+    }
+
+    async fn exists(&self, entity_id: T::Id) -> CacheResult<bool> {
+        self.store
+            .exists(entity_key::<T>(&entity_id.cache_key()))
+            .await
+    }
+
+    async fn get(&self, entity_id: T::Id) -> CacheResult<T> {
+        let key = entity_id.cache_key();
+
+        self.get_by_keys(vec![key.clone()])
+            .await?
+            .pop()
+            .ok_or(CacheError::NotFound {
+                entity: T::NAME,
+                key,
+            })
+    }
+
+    async fn get_many(&self, entity_ids: Vec<T::Id>) -> CacheResult<Vec<T>> {
+        self.get_by_keys(entity_ids.iter().map(CacheKey::cache_key).collect())
+            .await
+    }
+
+    async fn upsert(&self, entity: T) -> CacheResult<()> {
+        self.upsert_many(vec![entity]).await
+    }
+
+    async fn upsert_many(&self, entities: Vec<T>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let mut entries = Vec::with_capacity(entities.len());
+        let mut keys = Vec::with_capacity(entities.len());
+        let mut by_guild = HashMap::<_, Vec<_>>::new();
+        let mut by_user = HashMap::<_, Vec<_>>::new();
+        for entity in &entities {
+            let key = entity.id().cache_key();
+
+            entries.push((entity_key::<T>(&key), serde_json::to_vec(entity)?));
+            if let Some(guild_id) = entity.guild_id() {
+                by_guild.entry(guild_id).or_default().push(key.clone());
+            }
+            if let Some(user_id) = entity.user_id() {
+                by_user.entry(user_id).or_default().push(key.clone());
+            }
+            keys.push(key);
+        }
+
+        self.store.set(entries).await?;
+        self.store.set_add(all_key::<T>(), keys).await?;
+        for (guild_id, keys) in by_guild {
+            self.store.set_add(guild_key::<T>(guild_id), keys).await?;
+        }
+        for (user_id, keys) in by_user {
+            self.store.set_add(user_key::<T>(user_id), keys).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: KeyValueStore, T: StoredEntity> GuildScopedBackend<T> for KeyValueBackend<S> {
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        self.delete_by_guild_id_returning::<T>(guild_id)
+            .await
+            .map(|_| ())
+    }
+
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<T::Id>,
+    ) -> CacheResult<()> {
+        let keep = entity_ids
+            .iter()
+            .map(CacheKey::cache_key)
+            .collect::<HashSet<_>>();
+        let stale = self
+            .store
+            .set_members(guild_key::<T>(guild_id))
+            .await?
+            .into_iter()
+            .filter(|key| !keep.contains(key))
+            .collect();
+
+        self.delete_by_keys::<T>(stale).await.map(|_| ())
+    }
+
+    async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<T>> {
+        let keys = self.store.set_members(guild_key::<T>(guild_id)).await?;
+
+        self.get_by_keys(keys).await
+    }
+}
+
+/// The key an entity is stored under.
+fn entity_key<T: StoredEntity>(key: &str) -> String {
+    format!("entitycache:{}:{key}", T::NAME)
+}
+
+/// The key of the set indexing all entities of a type.
+fn all_key<T: StoredEntity>() -> String {
+    format!("entitycache:{}:all", T::NAME)
+}
+
+/// The key of the set indexing the entities of a type belonging to a guild.
+fn guild_key<T: StoredEntity>(guild_id: Id<GuildMarker>) -> String {
+    format!("entitycache:{}:guild:{guild_id}", T::NAME)
+}
+
+/// The key of the set indexing the entities of a type belonging to a user.
+fn user_key<T: StoredEntity>(user_id: Id<UserMarker>) -> String {
+    format!("entitycache:{}:user:{user_id}", T::NAME)
+}
+
+/// The key of the sorted set indexing all entities of a type by their timestamps.
+fn timestamps_key<T: StoredEntity>() -> String {
+    format!("entitycache:{}:timestamps", T::NAME)
+}
+
+/// The key of the sorted set indexing the entities of a type belonging to a guild by their
+/// timestamps.
+fn guild_timestamps_key<T: StoredEntity>(guild_id: Id<GuildMarker>) -> String {
+    format!("entitycache:{}:guild:{guild_id}:timestamps", T::NAME)
+}
@@ -0,0 +1,112 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Storage Backends
+//!
+//! The storage backends that entities can be stored in.
+//!
+//! Entities are stored in PostgreSQL by default. Alternatively, they can be stored in a key-value
+//! store (see [`keyvalue`]), either in a Redis-compatible server or purely in memory, such that
+//! tests and small deployments can run without PostgreSQL.
+
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::CacheResult;
+use crate::memory::CacheKey;
+use crate::traits::Entity;
+
+pub mod inmemory;
+pub mod keyvalue;
+pub mod redis;
+
+/// An entity that can be stored in any storage backend.
+pub trait StoredEntity:
+    Entity<Id: CacheKey + Clone + Send> + Clone + DeserializeOwned + Serialize + Send + Sync
+{
+    /// The name of the entity type.
+    const NAME: &'static str;
+
+    /// Returns the identifier of the guild the entity belongs to, if any.
+    fn guild_id(&self) -> Option<Id<GuildMarker>>;
+
+    /// Returns the identifier of the user the entity belongs to, if any.
+    fn user_id(&self) -> Option<Id<UserMarker>>;
+}
+
+/// An entity that is retained in order of its timestamp, such that the oldest entities can be
+/// found without retrieving every entity.
+pub trait TimestampedEntity: StoredEntity {
+    /// Returns the timestamp of the entity, in microseconds since the Unix epoch.
+    fn timestamp_micros(&self) -> i64;
+}
+
+/// A storage backend holding entities.
+pub trait Backend<T: StoredEntity> {
+    /// Deletes an entity from the backend.
+    #[allow(async_fn_in_trait)]
+    async fn delete(&self, entity_id: T::Id) -> CacheResult<()>;
+
+    /// Returns whether an entity exists in the backend.
+    #[allow(async_fn_in_trait)]
+    async fn exists(&self, entity_id: T::Id) -> CacheResult<bool>;
+
+    /// Retrieves an entity from the backend.
+    #[allow(async_fn_in_trait)]
+    async fn get(&self, entity_id: T::Id) -> CacheResult<T>;
+
+    /// Retrieves multiple entities from the backend.
+    ///
+    /// Entities that do not exist in the backend are omitted from the result.
+    #[allow(async_fn_in_trait)]
+    async fn get_many(&self, entity_ids: Vec<T::Id>) -> CacheResult<Vec<T>>;
+
+    /// Upserts an entity into the backend.
+    #[allow(async_fn_in_trait)]
+    async fn upsert(&self, entity: T) -> CacheResult<()>;
+
+    /// Upserts multiple entities into the backend at once.
+    #[allow(async_fn_in_trait)]
+    async fn upsert_many(&self, entities: Vec<T>) -> CacheResult<()>;
+}
+
+/// A storage backend holding entities that belong to a guild.
+pub trait GuildScopedBackend<T: StoredEntity>: Backend<T> {
+    /// Deletes all entities of a guild from the backend.
+    #[allow(async_fn_in_trait)]
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()>;
+
+    /// Deletes the entities of a guild that are not among the given entities from the backend.
+    #[allow(async_fn_in_trait)]
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<T::Id>,
+    ) -> CacheResult<()>;
+
+    /// Retrieves all entities of a guild from the backend.
+    #[allow(async_fn_in_trait)]
+    async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<T>>;
+}
@@ -0,0 +1,187 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Redis Key-Value Store
+//!
+//! A key-value store backed by a Redis-compatible server, such as Redis, Valkey or KeyDB.
+
+use hartex_discord_core::tokio::sync::OnceCell;
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+use redis::Client;
+
+use crate::backend::keyvalue::KeyValueStore;
+use crate::error::CacheResult;
+
+/// A key-value store backed by a Redis-compatible server.
+///
+/// The connection to the server is established upon first use, and is shared by all operations.
+#[allow(clippy::module_name_repetitions)]
+pub struct RedisStore {
+    client: Client,
+    connection: OnceCell<MultiplexedConnection>,
+}
+
+impl RedisStore {
+    /// Create a store backed by the server at the given URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid.
+    pub fn new(url: &str) -> CacheResult<Self> {
+        Ok(Self {
+            client: Client::open(url)?,
+            connection: OnceCell::new(),
+        })
+    }
+
+    /// Obtain a handle to the shared connection, establishing it if necessary.
+    async fn connection(&self) -> CacheResult<MultiplexedConnection> {
+        let connection = self
+            .connection
+            .get_or_try_init(|| self.client.get_multiplexed_async_connection())
+            .await?;
+
+        Ok(connection.clone())
+    }
+}
+
+impl KeyValueStore for RedisStore {
+    async fn delete(&self, keys: Vec<String>) -> CacheResult<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = self.connection().await?;
+        connection.del::<_, ()>(keys).await?;
+
+        Ok(())
+    }
+
+    async fn exists(&self, key: String) -> CacheResult<bool> {
+        let mut connection = self.connection().await?;
+
+        Ok(connection.exists(key).await?)
+    }
+
+    async fn get(&self, keys: Vec<String>) -> CacheResult<Vec<Option<Vec<u8>>>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut connection = self.connection().await?;
+
+        // MGET always replies with an array, even for a single key
+        Ok(redis::cmd("MGET")
+            .arg(keys)
+            .query_async(&mut connection)
+            .await?)
+    }
+
+    async fn set(&self, entries: Vec<(String, Vec<u8>)>) -> CacheResult<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = self.connection().await?;
+        connection.mset::<_, _, ()>(&entries).await?;
+
+        Ok(())
+    }
+
+    async fn set_add(&self, set: String, members: Vec<String>) -> CacheResult<()> {
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = self.connection().await?;
+        connection.sadd::<_, _, ()>(set, members).await?;
+
+        Ok(())
+    }
+
+    async fn set_members(&self, set: String) -> CacheResult<Vec<String>> {
+        let mut connection = self.connection().await?;
+
+        Ok(connection.smembers(set).await?)
+    }
+
+    async fn set_remove(&self, set: String, members: Vec<String>) -> CacheResult<()> {
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = self.connection().await?;
+        connection.srem::<_, _, ()>(set, members).await?;
+
+        Ok(())
+    }
+
+    async fn sorted_set_add(&self, set: String, members: Vec<(String, i64)>) -> CacheResult<()> {
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let members = members
+            .into_iter()
+            .map(|(member, score)| (score, member))
+            .collect::<Vec<_>>();
+
+        let mut connection = self.connection().await?;
+        connection
+            .zadd_multiple::<_, _, _, ()>(set, &members)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn sorted_set_below(&self, set: String, score: i64) -> CacheResult<Vec<String>> {
+        let mut connection = self.connection().await?;
+
+        // the leading parenthesis makes the upper bound exclusive
+        Ok(connection
+            .zrangebyscore(set, "-inf", format!("({score}"))
+            .await?)
+    }
+
+    async fn sorted_set_lowest(&self, set: String, keep: usize) -> CacheResult<Vec<String>> {
+        let keep = isize::try_from(keep).unwrap_or(isize::MAX);
+
+        let mut connection = self.connection().await?;
+
+        // negative indices count from the member with the highest score
+        Ok(connection
+            .zrange(set, 0, (-1isize).saturating_sub(keep))
+            .await?)
+    }
+
+    async fn sorted_set_remove(&self, set: String, members: Vec<String>) -> CacheResult<()> {
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = self.connection().await?;
+        connection.zrem::<_, _, ()>(set, members).await?;
+
+        Ok(())
+    }
+}
@@ -29,6 +29,8 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 
 use bb8::RunError;
+use redis::RedisError;
+use serde_json::Error as JsonError;
 use tokio_postgres::Error as PostgresError;
 
 /// A cache error..
@@ -38,8 +40,19 @@ pub enum CacheError {
     Bb8(RunError<PostgresError>),
     /// Error related to environment variables.
     Env(VarError),
+    /// An entity could not be serialized or deserialized.
+    Json(JsonError),
+    /// An entity does not exist in the storage backend.
+    NotFound {
+        /// The name of the entity type.
+        entity: &'static str,
+        /// The key of the entity.
+        key: String,
+    },
     /// A postgres error occurred.
     Postgres(PostgresError),
+    /// A redis error occurred.
+    Redis(RedisError),
 }
 
 impl Display for CacheError {
@@ -47,7 +60,10 @@ impl Display for CacheError {
         match self {
             Self::Bb8(error) => writeln!(f, "bb8 postgres error: {error}"),
             Self::Env(error) => writeln!(f, "env error: {error}"),
+            Self::Json(error) => writeln!(f, "json error: {error}"),
+            Self::NotFound { entity, key } => writeln!(f, "{entity} entity `{key}` not found"),
             Self::Postgres(error) => writeln!(f, "postgres error: {error}"),
+            Self::Redis(error) => writeln!(f, "redis error: {error}"),
         }
     }
 }
@@ -60,12 +76,24 @@ impl From<RunError<PostgresError>> for CacheError {
     }
 }
 
+impl From<JsonError> for CacheError {
+    fn from(error: JsonError) -> Self {
+        Self::Json(error)
+    }
+}
+
 impl From<PostgresError> for CacheError {
     fn from(error: PostgresError) -> Self {
         Self::Postgres(error)
     }
 }
 
+impl From<RedisError> for CacheError {
+    fn from(error: RedisError) -> Self {
+        Self::Redis(error)
+    }
+}
+
 impl From<VarError> for CacheError {
     fn from(error: VarError) -> Self {
         Self::Env(error)
@@ -73,3 +101,24 @@ impl From<VarError> for CacheError {
 }
 
 pub type CacheResult<T> = Result<T, CacheError>;
+
+/// Extensions for the results of cache operations.
+#[allow(clippy::module_name_repetitions)]
+pub trait CacheResultExt<T> {
+    /// Converts the result into `None` if the entity does not exist, keeping any other error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the result if it is not a not found error.
+    fn optional(self) -> CacheResult<Option<T>>;
+}
+
+impl<T> CacheResultExt<T> for CacheResult<T> {
+    fn optional(self) -> CacheResult<Option<T>> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(CacheError::NotFound { .. }) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
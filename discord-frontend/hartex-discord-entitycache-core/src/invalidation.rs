@@ -0,0 +1,66 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Entity Cache Invalidations
+//!
+//! Invalidations performed by the in-memory caches of one process, to be applied to the
+//! in-memory caches of other processes.
+//!
+//! The entitycache update service collects the invalidations performed while handling an event
+//! with [`collect`] and broadcasts them once the event has been handled.
+
+use std::cell::RefCell;
+use std::future::Future;
+
+use hartex_discord_core::tokio::task_local;
+use serde::Deserialize;
+use serde::Serialize;
+
+task_local! {
+    static PENDING: RefCell<Vec<Invalidation>>;
+}
+
+/// An invalidation of an in-memory cache.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Invalidation {
+    /// The name of the entity type the invalidation applies to.
+    pub entity: String,
+    /// The key of the invalidated entity, or `None` if every entity of the type is invalidated.
+    pub key: Option<String>,
+}
+
+/// Runs a future to completion, collecting the invalidations performed by it.
+pub async fn collect<F: Future>(future: F) -> (F::Output, Vec<Invalidation>) {
+    PENDING
+        .scope(RefCell::new(Vec::new()), async {
+            let output = future.await;
+            let invalidations = PENDING.with(RefCell::take);
+
+            (output, invalidations)
+        })
+        .await
+}
+
+/// Records an invalidation, if invalidations are being collected by the current task.
+pub(crate) fn record(invalidation: Invalidation) {
+    let _ = PENDING.try_with(|pending| pending.borrow_mut().push(invalidation));
+}
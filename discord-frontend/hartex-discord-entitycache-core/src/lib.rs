@@ -36,5 +36,8 @@ extern crate hartex_discord_entitycache_macros;
 #[cfg(feature = "derive")]
 pub use hartex_discord_entitycache_macros::*;
 
+pub mod backend;
 pub mod error;
+pub mod invalidation;
+pub mod memory;
 pub mod traits;
@@ -0,0 +1,263 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # In-Memory Entity Cache
+//!
+//! An in-process tier in front of the database, holding recently retrieved entities such that
+//! repeated lookups do not round-trip to the database.
+//!
+//! Each entity type has its own least-recently-used cache with its own capacity and time-to-live,
+//! configured with the following environment variables, all of which are optional:
+//!
+//! - `ENTITYCACHE_MEMORY_<ENTITY>_CAPACITY`: the maximum number of entities held; a capacity of 0
+//!   disables the in-memory tier for the entity type
+//! - `ENTITYCACHE_MEMORY_<ENTITY>_TTL_SECONDS`: the time an entity is held for before it has to be
+//!   retrieved from the database again
+//!
+//! Entities are invalidated whenever they are written to the database. As the entitycache update
+//! service and the workers are usually separate processes, the update service broadcasts the
+//! invalidations it performs (see [`crate::invalidation`]) for the workers to apply to their own
+//! in-memory caches.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Display;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::Instant;
+
+use hartex_discord_core::discord::model::id::Id;
+
+use crate::invalidation;
+use crate::invalidation::Invalidation;
+
+/// The configuration of an in-memory cache.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryCacheConfig {
+    /// The maximum number of entities held.
+    pub capacity: usize,
+    /// The time an entity is held for.
+    pub ttl: Duration,
+}
+
+impl MemoryCacheConfig {
+    /// Obtain the configuration of the in-memory cache of an entity type from the environment.
+    ///
+    /// Any environment variable that is not set or cannot be parsed falls back to the
+    /// corresponding value of the default configuration.
+    #[must_use]
+    pub fn from_env(entity: &str, default: Self) -> Self {
+        let prefix = format!("ENTITYCACHE_MEMORY_{}", entity.to_uppercase());
+
+        Self {
+            capacity: env::var(format!("{prefix}_CAPACITY"))
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(default.capacity),
+            ttl: env::var(format!("{prefix}_TTL_SECONDS"))
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .map_or(default.ttl, Duration::from_secs),
+        }
+    }
+}
+
+/// A key identifying an entity within an in-memory cache.
+pub trait CacheKey {
+    /// Returns the key of the entity identifier.
+    fn cache_key(&self) -> String;
+}
+
+impl<T> CacheKey for Id<T> {
+    fn cache_key(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<A: Display, B: Display> CacheKey for (A, B) {
+    fn cache_key(&self) -> String {
+        format!("{}:{}", self.0, self.1)
+    }
+}
+
+/// A least-recently-used in-memory cache of entities of a single type.
+#[allow(clippy::module_name_repetitions)]
+pub struct MemoryCache<V> {
+    config: MemoryCacheConfig,
+    entity: &'static str,
+    state: Mutex<MemoryCacheState<V>>,
+}
+
+/// The mutable state of an in-memory cache.
+struct MemoryCacheState<V> {
+    entries: HashMap<String, MemoryCacheSlot<V>>,
+    generation: u64,
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+/// An entity held by an in-memory cache.
+struct MemoryCacheSlot<V> {
+    expires_at: Instant,
+    tick: u64,
+    value: V,
+}
+
+impl<V: Clone> MemoryCache<V> {
+    /// Create an in-memory cache for an entity type with the given configuration.
+    #[must_use]
+    pub fn new(entity: &'static str, config: MemoryCacheConfig) -> Self {
+        Self {
+            config,
+            entity,
+            state: Mutex::new(MemoryCacheState {
+                entries: HashMap::new(),
+                generation: 0,
+                recency: BTreeMap::new(),
+                tick: 0,
+            }),
+        }
+    }
+
+    /// Create an in-memory cache for an entity type with the configuration obtained from the
+    /// environment.
+    #[must_use]
+    pub fn from_env(entity: &'static str, default: MemoryCacheConfig) -> Self {
+        Self::new(entity, MemoryCacheConfig::from_env(entity, default))
+    }
+
+    /// Returns the name of the entity type held by the cache.
+    #[must_use]
+    pub fn entity(&self) -> &'static str {
+        self.entity
+    }
+
+    /// Retrieves an entity from the cache, if it is held and has not expired.
+    pub fn get(&self, key: &str) -> Option<V> {
+        let mut guard = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let state = &mut *guard;
+        state.tick += 1;
+        let tick = state.tick;
+
+        let slot = state.entries.get_mut(key)?;
+        if slot.expires_at <= Instant::now() {
+            let previous_tick = slot.tick;
+            state.entries.remove(key);
+            state.recency.remove(&previous_tick);
+
+            return None;
+        }
+
+        let previous_tick = slot.tick;
+        slot.tick = tick;
+        let value = slot.value.clone();
+
+        state.recency.remove(&previous_tick);
+        state.recency.insert(tick, key.to_string());
+
+        Some(value)
+    }
+
+    /// Returns the current generation of the cache.
+    ///
+    /// The generation changes whenever an entity is invalidated. It must be obtained before
+    /// retrieving an entity from the database and be passed to [`MemoryCache::insert`], such that
+    /// an entity retrieved before an invalidation is not inserted after the invalidation.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .generation
+    }
+
+    /// Inserts an entity retrieved from the database into the cache, evicting the least recently
+    /// used entity if the cache is full.
+    ///
+    /// The entity is not inserted if the cache has been invalidated since the given generation.
+    pub fn insert(&self, generation: u64, key: String, value: V) {
+        if self.config.capacity == 0 || self.config.ttl.is_zero() {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.generation != generation {
+            return;
+        }
+
+        state.tick += 1;
+        let tick = state.tick;
+
+        let slot = MemoryCacheSlot {
+            expires_at: Instant::now() + self.config.ttl,
+            tick,
+            value,
+        };
+        if let Some(previous) = state.entries.insert(key.clone(), slot) {
+            state.recency.remove(&previous.tick);
+        }
+        state.recency.insert(tick, key);
+
+        while state.entries.len() > self.config.capacity {
+            let Some((_, evicted)) = state.recency.pop_first() else {
+                break;
+            };
+
+            state.entries.remove(&evicted);
+        }
+    }
+
+    /// Invalidates an entity, or every entity if no key is given, recording the invalidation such
+    /// that it can be broadcast to other processes.
+    pub fn invalidate(&self, key: Option<&str>) {
+        self.apply(key);
+
+        invalidation::record(Invalidation {
+            entity: self.entity.to_string(),
+            key: key.map(String::from),
+        });
+    }
+
+    /// Invalidates an entity, or every entity if no key is given, without recording the
+    /// invalidation.
+    ///
+    /// This is used for applying invalidations broadcast by other processes.
+    pub fn apply(&self, key: Option<&str>) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.generation += 1;
+
+        match key {
+            Some(key) => {
+                if let Some(slot) = state.entries.remove(key) {
+                    state.recency.remove(&slot.tick);
+                }
+            }
+            None => {
+                state.entries.clear();
+                state.recency.clear();
+            }
+        }
+    }
+}
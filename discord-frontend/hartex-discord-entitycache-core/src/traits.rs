@@ -35,11 +35,29 @@ pub trait Entity {
 
 /// A cache repository holding entities.
 pub trait Repository<T: Entity> {
+    /// Deletes an entity from the repository.
+    #[allow(async_fn_in_trait)]
+    async fn delete(&self, entity_id: T::Id) -> CacheResult<()>;
+
+    /// Returns whether an entity exists in the repository.
+    #[allow(async_fn_in_trait)]
+    async fn exists(&self, entity_id: T::Id) -> CacheResult<bool>;
+
     /// Retrieves an entity from the repository.
     #[allow(async_fn_in_trait)]
     async fn get(&self, entity_id: T::Id) -> CacheResult<T>;
 
+    /// Retrieves multiple entities from the repository in a single query.
+    ///
+    /// Entities that do not exist in the repository are omitted from the result.
+    #[allow(async_fn_in_trait)]
+    async fn get_many(&self, entity_ids: Vec<T::Id>) -> CacheResult<Vec<T>>;
+
     /// Upserts an entity into the repository.
     #[allow(async_fn_in_trait)]
     async fn upsert(&self, entity: T) -> CacheResult<()>;
+
+    /// Upserts multiple entities into the repository in a single transaction.
+    #[allow(async_fn_in_trait)]
+    async fn upsert_many(&self, entities: Vec<T>) -> CacheResult<()>;
 }
@@ -0,0 +1,354 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_core::discord::model::id::marker::GenericMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::tokio;
+use hartex_discord_entitycache_core::backend::inmemory::InMemoryStore;
+use hartex_discord_entitycache_core::backend::keyvalue::KeyValueBackend;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::backend::StoredEntity;
+use hartex_discord_entitycache_core::backend::TimestampedEntity;
+use hartex_discord_entitycache_core::error::CacheError;
+use hartex_discord_entitycache_core::error::CacheResultExt;
+use hartex_discord_entitycache_core::traits::Entity;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct TestEntity {
+    guild_id: Id<GuildMarker>,
+    id: Id<GenericMarker>,
+    timestamp: i64,
+    user_id: Id<UserMarker>,
+}
+
+impl Entity for TestEntity {
+    type Id = Id<GenericMarker>;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
+
+impl StoredEntity for TestEntity {
+    const NAME: &'static str = "test";
+
+    fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        Some(self.guild_id)
+    }
+
+    fn user_id(&self) -> Option<Id<UserMarker>> {
+        Some(self.user_id)
+    }
+}
+
+impl TimestampedEntity for TestEntity {
+    fn timestamp_micros(&self) -> i64 {
+        self.timestamp
+    }
+}
+
+fn entity(id: u64, guild_id: u64, user_id: u64, timestamp: i64) -> TestEntity {
+    TestEntity {
+        guild_id: Id::new(guild_id),
+        id: Id::new(id),
+        timestamp,
+        user_id: Id::new(user_id),
+    }
+}
+
+fn ids(entities: Vec<TestEntity>) -> Vec<u64> {
+    let mut ids = entities
+        .into_iter()
+        .map(|entity| entity.id.get())
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+
+    ids
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn upsert_get_delete_test() {
+    let backend = KeyValueBackend::new(InMemoryStore::new());
+    backend.upsert(entity(1, 10, 100, 0)).await.unwrap();
+    backend
+        .upsert_many(vec![entity(2, 10, 100, 0), entity(3, 20, 200, 0)])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Backend::<TestEntity>::get(&backend, Id::new(1))
+            .await
+            .unwrap(),
+        entity(1, 10, 100, 0)
+    );
+    assert!(Backend::<TestEntity>::exists(&backend, Id::new(3))
+        .await
+        .unwrap());
+    assert_eq!(
+        ids(
+            Backend::<TestEntity>::get_many(&backend, vec![Id::new(1), Id::new(3), Id::new(4)])
+                .await
+                .unwrap()
+        ),
+        vec![1, 3]
+    );
+    assert_eq!(
+        ids(backend.all::<TestEntity>().await.unwrap()),
+        vec![1, 2, 3]
+    );
+
+    Backend::<TestEntity>::delete(&backend, Id::new(1))
+        .await
+        .unwrap();
+
+    assert!(!Backend::<TestEntity>::exists(&backend, Id::new(1))
+        .await
+        .unwrap());
+    assert!(matches!(
+        Backend::<TestEntity>::get(&backend, Id::new(1)).await,
+        Err(CacheError::NotFound { entity: "test", .. })
+    ));
+    assert_eq!(ids(backend.all::<TestEntity>().await.unwrap()), vec![2, 3]);
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn upsert_replaces_test() {
+    let backend = KeyValueBackend::new(InMemoryStore::new());
+    backend.upsert(entity(1, 10, 100, 0)).await.unwrap();
+    backend.upsert(entity(1, 10, 100, 5)).await.unwrap();
+
+    assert_eq!(
+        Backend::<TestEntity>::get(&backend, Id::new(1))
+            .await
+            .unwrap(),
+        entity(1, 10, 100, 5)
+    );
+    assert_eq!(ids(backend.all::<TestEntity>().await.unwrap()), vec![1]);
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn guild_scoped_test() {
+    let backend = KeyValueBackend::new(InMemoryStore::new());
+    backend
+        .upsert_many(vec![
+            entity(1, 10, 100, 0),
+            entity(2, 10, 100, 0),
+            entity(3, 10, 100, 0),
+            entity(4, 20, 100, 0),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        ids(
+            GuildScopedBackend::<TestEntity>::get_by_guild_id(&backend, Id::new(10))
+                .await
+                .unwrap()
+        ),
+        vec![1, 2, 3]
+    );
+
+    GuildScopedBackend::<TestEntity>::delete_stale_in_guild(
+        &backend,
+        Id::new(10),
+        vec![Id::new(2)],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        ids(
+            GuildScopedBackend::<TestEntity>::get_by_guild_id(&backend, Id::new(10))
+                .await
+                .unwrap()
+        ),
+        vec![2]
+    );
+    assert_eq!(ids(backend.all::<TestEntity>().await.unwrap()), vec![2, 4]);
+
+    GuildScopedBackend::<TestEntity>::delete_by_guild_id(&backend, Id::new(10))
+        .await
+        .unwrap();
+
+    assert!(
+        GuildScopedBackend::<TestEntity>::get_by_guild_id(&backend, Id::new(10))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(ids(backend.all::<TestEntity>().await.unwrap()), vec![4]);
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn user_index_test() {
+    let backend = KeyValueBackend::new(InMemoryStore::new());
+    backend
+        .upsert_many(vec![
+            entity(1, 10, 100, 0),
+            entity(2, 20, 100, 0),
+            entity(3, 10, 200, 0),
+        ])
+        .await
+        .unwrap();
+
+    assert!(backend
+        .any_by_user_id::<TestEntity>(Id::new(100))
+        .await
+        .unwrap());
+    assert!(!backend
+        .any_by_user_id::<TestEntity>(Id::new(300))
+        .await
+        .unwrap());
+
+    Backend::<TestEntity>::delete(&backend, Id::new(1))
+        .await
+        .unwrap();
+
+    assert!(backend
+        .any_by_user_id::<TestEntity>(Id::new(100))
+        .await
+        .unwrap());
+
+    GuildScopedBackend::<TestEntity>::delete_by_guild_id(&backend, Id::new(20))
+        .await
+        .unwrap();
+
+    assert!(!backend
+        .any_by_user_id::<TestEntity>(Id::new(100))
+        .await
+        .unwrap());
+    assert!(backend
+        .any_by_user_id::<TestEntity>(Id::new(200))
+        .await
+        .unwrap());
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn timestamped_excess_test() {
+    let backend = KeyValueBackend::new(InMemoryStore::new());
+    backend
+        .upsert_timestamped(vec![
+            entity(1, 10, 100, 30),
+            entity(2, 10, 100, 10),
+            entity(3, 10, 100, 20),
+            entity(4, 20, 100, 0),
+        ])
+        .await
+        .unwrap();
+
+    let deleted = backend
+        .delete_timestamped_excess_in_guild::<TestEntity>(Id::new(10), 1)
+        .await
+        .unwrap();
+
+    assert_eq!(ids(deleted), vec![2, 3]);
+    assert_eq!(ids(backend.all::<TestEntity>().await.unwrap()), vec![1, 4]);
+    assert!(backend
+        .delete_timestamped_excess_in_guild::<TestEntity>(Id::new(10), 1)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn timestamped_before_test() {
+    let backend = KeyValueBackend::new(InMemoryStore::new());
+    backend
+        .upsert_timestamped(vec![
+            entity(1, 10, 100, 10),
+            entity(2, 20, 100, 20),
+            entity(3, 10, 100, 30),
+        ])
+        .await
+        .unwrap();
+
+    // a later upsert moves the entity within the index
+    backend
+        .upsert_timestamped(vec![entity(1, 10, 100, 40)])
+        .await
+        .unwrap();
+
+    let deleted = backend
+        .delete_timestamped_before::<TestEntity>(30)
+        .await
+        .unwrap();
+
+    assert_eq!(ids(deleted), vec![2]);
+    assert_eq!(ids(backend.all::<TestEntity>().await.unwrap()), vec![1, 3]);
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn timestamped_delete_test() {
+    let backend = KeyValueBackend::new(InMemoryStore::new());
+    backend
+        .upsert_timestamped(vec![entity(1, 10, 100, 10), entity(2, 10, 100, 20)])
+        .await
+        .unwrap();
+
+    let deleted = backend
+        .delete_timestamped::<TestEntity>(vec![Id::new(2)])
+        .await
+        .unwrap();
+
+    assert_eq!(ids(deleted), vec![2]);
+
+    // the deleted entity no longer counts towards the capacity of its guild
+    assert!(backend
+        .delete_timestamped_excess_in_guild::<TestEntity>(Id::new(10), 1)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let deleted = backend
+        .delete_timestamped_by_guild_id::<TestEntity>(Id::new(10))
+        .await
+        .unwrap();
+
+    assert_eq!(ids(deleted), vec![1]);
+    assert!(backend.all::<TestEntity>().await.unwrap().is_empty());
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn get_optional_test() {
+    let backend = KeyValueBackend::new(InMemoryStore::new());
+    backend.upsert(entity(1, 10, 100, 0)).await.unwrap();
+
+    assert_eq!(
+        Backend::<TestEntity>::get(&backend, Id::new(1))
+            .await
+            .optional()
+            .unwrap(),
+        Some(entity(1, 10, 100, 0))
+    );
+    assert_eq!(
+        Backend::<TestEntity>::get(&backend, Id::new(2))
+            .await
+            .optional()
+            .unwrap(),
+        None
+    );
+}
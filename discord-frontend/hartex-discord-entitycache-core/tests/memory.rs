@@ -0,0 +1,124 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::thread;
+use std::time::Duration;
+
+use hartex_discord_entitycache_core::memory::MemoryCache;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
+
+fn cache(capacity: usize, ttl: Duration) -> MemoryCache<u32> {
+    MemoryCache::new("test", MemoryCacheConfig { capacity, ttl })
+}
+
+#[test]
+pub fn insert_get_test() {
+    let cache = cache(2, Duration::from_secs(60));
+    cache.insert(cache.generation(), String::from("a"), 1);
+
+    assert_eq!(cache.get("a"), Some(1));
+    assert_eq!(cache.get("b"), None);
+}
+
+#[test]
+pub fn least_recently_used_eviction_test() {
+    let cache = cache(2, Duration::from_secs(60));
+    cache.insert(cache.generation(), String::from("a"), 1);
+    cache.insert(cache.generation(), String::from("b"), 2);
+
+    // retrieving an entity makes it the most recently used one
+    assert_eq!(cache.get("a"), Some(1));
+
+    cache.insert(cache.generation(), String::from("c"), 3);
+
+    assert_eq!(cache.get("a"), Some(1));
+    assert_eq!(cache.get("b"), None);
+    assert_eq!(cache.get("c"), Some(3));
+}
+
+#[test]
+pub fn reinsert_does_not_evict_test() {
+    let cache = cache(2, Duration::from_secs(60));
+    cache.insert(cache.generation(), String::from("a"), 1);
+    cache.insert(cache.generation(), String::from("b"), 2);
+    cache.insert(cache.generation(), String::from("a"), 3);
+
+    assert_eq!(cache.get("a"), Some(3));
+    assert_eq!(cache.get("b"), Some(2));
+}
+
+#[test]
+pub fn ttl_expiry_test() {
+    let cache = cache(2, Duration::from_millis(20));
+    cache.insert(cache.generation(), String::from("a"), 1);
+
+    assert_eq!(cache.get("a"), Some(1));
+
+    thread::sleep(Duration::from_millis(50));
+
+    assert_eq!(cache.get("a"), None);
+}
+
+#[test]
+pub fn disabled_test() {
+    let no_capacity = cache(0, Duration::from_secs(60));
+    no_capacity.insert(no_capacity.generation(), String::from("a"), 1);
+
+    assert_eq!(no_capacity.get("a"), None);
+
+    let no_ttl = cache(2, Duration::ZERO);
+    no_ttl.insert(no_ttl.generation(), String::from("a"), 1);
+
+    assert_eq!(no_ttl.get("a"), None);
+}
+
+#[test]
+pub fn invalidate_test() {
+    let cache = cache(2, Duration::from_secs(60));
+    cache.insert(cache.generation(), String::from("a"), 1);
+    cache.insert(cache.generation(), String::from("b"), 2);
+
+    cache.invalidate(Some("a"));
+
+    assert_eq!(cache.get("a"), None);
+    assert_eq!(cache.get("b"), Some(2));
+
+    cache.apply(None);
+
+    assert_eq!(cache.get("b"), None);
+}
+
+#[test]
+pub fn stale_generation_test() {
+    let cache = cache(2, Duration::from_secs(60));
+
+    // an entity retrieved before an invalidation must not be inserted after it
+    let generation = cache.generation();
+    cache.invalidate(Some("a"));
+    cache.insert(generation, String::from("a"), 1);
+
+    assert_eq!(cache.get("a"), None);
+
+    cache.insert(cache.generation(), String::from("a"), 2);
+
+    assert_eq!(cache.get("a"), Some(2));
+}
@@ -0,0 +1,52 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Channel Entities
+
+use hartex_discord_entitycache_core::entity;
+
+/// A channel entity.
+#[allow(clippy::module_name_repetitions)]
+#[entity(
+    from = "twilight_model::channel::Channel",
+    assume = ["CachedChannelSelectByGuildId", "CachedChannelSelectById", "CachedChannelSelectByIds"],
+    id = ["id"],
+    include = [
+        "kind",
+        "name",
+        "nsfw",
+        "parent_id",
+        "permission_overwrites",
+        "position",
+        "rate_limit_per_user",
+        "topic",
+    ],
+    extra = [
+        "guild_id": "Id<GuildMarker>",
+    ],
+    overrides = [
+        "ChannelType": "twilight_model::channel::ChannelType",
+        "PermissionOverwrite": "twilight_model::channel::permission_overwrite::PermissionOverwrite",
+    ],
+    relates = [],
+)]
+pub struct ChannelEntity;
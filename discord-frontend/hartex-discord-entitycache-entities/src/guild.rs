@@ -28,7 +28,7 @@ use hartex_discord_entitycache_core::entity;
 #[allow(clippy::module_name_repetitions)]
 #[entity(
     from = "twilight_model::guild::Guild",
-    assume = ["CachedGuildSelectById"],
+    assume = ["CachedGuildSelectById", "CachedGuildSelectByIds"],
     id = ["id"],
     include = [
         "default_message_notifications",
@@ -41,6 +41,7 @@ use hartex_discord_entitycache_core::entity;
         "premium_subscription_count",
         "premium_tier",
         "owner_id",
+        "preferred_locale",
         "verification_level"
     ],
     extra = [],
@@ -53,9 +54,12 @@ use hartex_discord_entitycache_core::entity;
         "VerificationLevel": "twilight_model::guild::VerificationLevel"
     ],
     relates = [
+        multiple "ChannelEntity": via "id" as "guild_id",
         multiple "EmojiEntity": via "id" as "guild_id",
         multiple "MemberEntity": via "id" as "guild_id",
         multiple "RoleEntity": via "id" as "guild_id",
+        multiple "StickerEntity": via "id" as "guild_id",
+        multiple "ThreadEntity": via "id" as "guild_id",
     ],
 )]
 pub struct GuildEntity;
@@ -26,7 +26,7 @@ use hartex_discord_entitycache_core::entity;
 #[allow(clippy::module_name_repetitions)]
 #[entity(
     from = "twilight_model::guild::Member",
-    assume = ["CachedMemberSelectByGuildId", "CachedMemberSelectByUserIdAndGuildId"],
+    assume = ["CachedMemberSelectByGuildId", "CachedMemberSelectByUserIdAndGuildId", "CachedMemberSelectByUserIdsAndGuildIds"],
     id = ["guild_id", "user_id"],
     include = ["flags", "joined_at", "nick", "roles"],
     extra = [
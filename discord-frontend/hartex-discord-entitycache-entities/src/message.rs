@@ -0,0 +1,61 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Entities
+
+use hartex_discord_entitycache_core::backend::TimestampedEntity;
+use hartex_discord_entitycache_core::entity;
+
+/// A message entity.
+///
+/// Messages are only cached for a limited time and up to a limited number per guild, such that
+/// the content of deleted and updated messages can be looked up by the moderation log.
+#[allow(clippy::module_name_repetitions)]
+#[entity(
+    from = "twilight_model::channel::message::Message",
+    assume = ["CachedMessageSelectByGuildId", "CachedMessageSelectById", "CachedMessageSelectByIds"],
+    id = ["id"],
+    include = [
+        "attachments",
+        "channel_id",
+        "content",
+        "edited_timestamp",
+        "timestamp",
+    ],
+    extra = [
+        "author_id": "Id<UserMarker>",
+        "guild_id": "Id<GuildMarker>",
+    ],
+    overrides = [
+        "Attachment": "twilight_model::channel::Attachment",
+    ],
+    relates = [
+        unique "ChannelEntity": via "channel_id" as "id",
+    ],
+)]
+pub struct MessageEntity;
+
+impl TimestampedEntity for MessageEntity {
+    fn timestamp_micros(&self) -> i64 {
+        self.timestamp.as_micros()
+    }
+}
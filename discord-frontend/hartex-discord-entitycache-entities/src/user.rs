@@ -26,7 +26,7 @@ use hartex_discord_entitycache_core::entity;
 #[allow(clippy::module_name_repetitions)]
 #[entity(
     from = "twilight_model::user::User",
-    assume = ["CachedUserSelectById"],
+    assume = ["CachedUserSelectById", "CachedUserSelectByIds"],
     id = ["id"],
     include = [
         "avatar",
@@ -0,0 +1,54 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Voice State Entities
+
+use hartex_discord_entitycache_core::entity;
+
+/// A voice state entity.
+#[allow(clippy::module_name_repetitions)]
+#[entity(
+    from = "twilight_model::voice::VoiceState",
+    assume = ["CachedVoiceStateSelectByGuildId", "CachedVoiceStateSelectByUserIdAndGuildId", "CachedVoiceStateSelectByUserIdsAndGuildIds"],
+    id = ["guild_id", "user_id"],
+    include = [
+        "channel_id",
+        "deaf",
+        "mute",
+        "request_to_speak_timestamp",
+        "self_deaf",
+        "self_mute",
+        "self_stream",
+        "self_video",
+        "session_id",
+        "suppress",
+    ],
+    extra = [
+        "guild_id": "Id<GuildMarker>",
+    ],
+    overrides = [],
+    relates = [
+        unique "GuildEntity": via "guild_id" as "id",
+        unique "UserEntity": via "user_id" as "id",
+    ],
+)]
+pub struct VoiceStateEntity;
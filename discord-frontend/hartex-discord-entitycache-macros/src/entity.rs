@@ -35,7 +35,6 @@ use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::quote;
 use quote::ToTokens;
-use quote::TokenStreamExt;
 use syn::ItemStruct;
 use syn::LitStr;
 use syn::Token;
@@ -52,12 +51,17 @@ const PRELUDE_AND_PRIMITIVES: [&str; 21] = [
 ];
 
 /// Valid entities for inter-entity relationships.
-const VALID_ENTITIES: [(&str, &str); 5] = [
+const VALID_ENTITIES: [(&str, &str); 10] = [
+    ("ChannelEntity", "crate::channel::ChannelEntity"),
     ("EmojiEntity", "crate::emoji::EmojiEntity"),
     ("GuildEntity", "crate::guild::GuildEntity"),
     ("MemberEntity", "crate::member::MemberEntity"),
+    ("MessageEntity", "crate::message::MessageEntity"),
     ("RoleEntity", "crate::role::RoleEntity"),
+    ("StickerEntity", "crate::sticker::StickerEntity"),
+    ("ThreadEntity", "crate::thread::ThreadEntity"),
     ("UserEntity", "crate::user::UserEntity"),
+    ("VoiceStateEntity", "crate::voice_state::VoiceStateEntity"),
 ];
 
 impl_parse!(
@@ -273,11 +277,16 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
         )?,
     };
 
+    // extra fields take precedence over fields of the same name in the type
+    let is_extra = |name: &str| {
+        (input.extra_fields_array.elements.iter()).any(|element| element.key.value() == name)
+    };
     let (
         mut field_tokens_to_append,
         mut field_assignments_to_append,
         mut field_assignments_to_append_with_necessary_casts,
     ): (Vec<_>, Vec<_>, Vec<_>) = (type_metadata.fields.iter())
+        .filter(|field| !is_extra(&field.name))
         .filter_map(|field| (id_fields.iter().find(|&x| x == &field.name)).map(|_| maker(field)))
         .multiunzip();
 
@@ -294,14 +303,13 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
         type_of(&field.ty, input).to_token_stream()
     } else {
         let vec = id_fields.iter().map(|name| {
-            (type_metadata.fields.iter().cloned())
-                .chain(
-                    (input.extra_fields_array.elements.iter()).map(|element| Field {
-                        name: element.key.value(),
-                        vis: "pub".to_string(),
-                        ty: element.value.value(),
-                    }),
-                )
+            (input.extra_fields_array.elements.iter())
+                .map(|element| Field {
+                    name: element.key.value(),
+                    vis: "pub".to_string(),
+                    ty: element.value.value(),
+                })
+                .chain(type_metadata.fields.iter().cloned())
                 .find(|field| &field.name == name)
                 .unwrap()
         });
@@ -330,7 +338,7 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
 
     let fields_for_function_decls =
         (type_metadata.fields.iter()).map(|field| (field.name.clone(), field.ty.clone()));
-    let fields_for_function_decls = fields_for_function_decls.merge(
+    let fields_for_function_decls = fields_for_function_decls.chain(
         (input.extra_fields_array.elements.iter())
             .map(|element| (element.key.value(), element.value.value())),
     );
@@ -368,48 +376,36 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
         };
         let ret_type = syn::parse_str::<Type>(hashmap.get(&*entity).unwrap()).unwrap();
 
-        let query_function_name = make_query_function_name(first, &element.as_value.value());
-        // FIXME: bad assumption of always calling .to_string() here (mostly just that should suffice, but...)
-        let mut full_query_function_call = quote! {
-            let data = hartex_database_queries::discord_frontend::queries::#query_function_name::#query_function_name().bind(client, &#param_name.to_string())
-        };
-
         let function = match &*element.unique_or_multiple.to_string() {
             "multiple" => {
-                let ident = Ident::new(&pluralize(first, 2, false), Span::call_site());
+                if element.as_value.value() != "guild_id" {
+                    bail(
+                        &element.as_value,
+                        "expected `guild_id` for relationships with multiple entities",
+                    )?;
+                }
 
-                full_query_function_call.append_all(quote! {
-                    .all().await?;
-                });
+                let ident = Ident::new(&pluralize(first, 2, false), Span::call_site());
 
                 quote! {
-                    pub async fn #ident(&self, #param_decl) -> hartex_discord_entitycache_core::error::CacheResult<Vec<#ret_type>> {
-                        let pinned = std::pin::Pin::static_ref(&hartex_discord_utils::DATABASE_POOL).await;
-                        let pooled = pinned.get().await?;
-                        let client = pooled.client();
-
-                        #full_query_function_call
-
-                        Ok(data.into_iter().map(|thing| #ret_type::from(thing)).collect())
+                    pub async fn #ident<B: hartex_discord_entitycache_core::backend::GuildScopedBackend<#ret_type>>(&self, backend: &B, #param_decl) -> hartex_discord_entitycache_core::error::CacheResult<Vec<#ret_type>> {
+                        backend.get_by_guild_id(#param_name).await
                     }
                 }
             }
             "unique" => {
-                let ident = Ident::new(first, Span::call_site());
+                if element.as_value.value() != "id" {
+                    bail(
+                        &element.as_value,
+                        "expected `id` for relationships with a unique entity",
+                    )?;
+                }
 
-                full_query_function_call.append_all(quote! {
-                    .one().await?;
-                });
+                let ident = Ident::new(first, Span::call_site());
 
                 quote! {
-                    pub async fn #ident(&self, #param_decl) -> hartex_discord_entitycache_core::error::CacheResult<#ret_type> {
-                        let pinned = std::pin::Pin::static_ref(&hartex_discord_utils::DATABASE_POOL).await;
-                        let pooled = pinned.get().await?;
-                        let client = pooled.client();
-
-                        #full_query_function_call
-
-                        Ok(#ret_type::from(data))
+                    pub async fn #ident<B: hartex_discord_entitycache_core::backend::Backend<#ret_type>>(&self, backend: &B, #param_decl) -> hartex_discord_entitycache_core::error::CacheResult<#ret_type> {
+                        backend.get(#param_name).await
                     }
                 }
             }
@@ -418,6 +414,32 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
         function_decls.push(quote! {#function});
     }
 
+    let entity_name = item_struct_name.to_string();
+    let entity_name = entity_name.strip_suffix("Entity").unwrap_or(&entity_name);
+    let entity_name = entity_name.to_case(Case::Snake);
+    let guild_id_expr_tokens = if is_extra("guild_id") {
+        quote! { Some(self.guild_id) }
+    } else {
+        quote! { None }
+    };
+    let user_id_expr_tokens = if is_extra("user_id") {
+        quote! { Some(self.user_id) }
+    } else {
+        quote! { None }
+    };
+    let stored_entity_impl = quote! {
+        #[automatically_derived]
+        impl hartex_discord_entitycache_core::backend::StoredEntity for #item_struct_name {
+            const NAME: &'static str = #entity_name;
+            fn guild_id(&self) -> Option<twilight_model::id::Id<twilight_model::id::marker::GuildMarker>> {
+                #guild_id_expr_tokens
+            }
+            fn user_id(&self) -> Option<twilight_model::id::Id<twilight_model::id::marker::UserMarker>> {
+                #user_id_expr_tokens
+            }
+        }
+    };
+
     let assumed_extra_impls = (input.assume_lits.elements.iter()).map(LitStr::value);
 
     if input.extra_fields_array.elements.is_empty() {
@@ -439,9 +461,8 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
             .collect::<Vec<_>>();
 
         return Some(quote! {
-            use tokio_postgres::GenericClient;
-
             #(#attrs)*
+            #[derive(Clone, serde::Deserialize, serde::Serialize)]
             #item_struct_vis struct #item_struct_name {
                 #(#fields_tokens),*
             }
@@ -460,6 +481,7 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
                     Self { #(#fields_assignments),* }
                 }
             }
+            #stored_entity_impl
             #(#extra)*
         });
     }
@@ -500,9 +522,8 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
         .collect::<Vec<_>>();
 
     Some(quote! {
-        use tokio_postgres::GenericClient;
-
         #(#attrs)*
+        #[derive(Clone, serde::Deserialize, serde::Serialize)]
         #item_struct_vis struct #item_struct_name {
             #(#fields_tokens),*,
             #(#extra_fields_tokens),*
@@ -522,6 +543,7 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
                 Self { #(#fields_assignments),*, #(#extra_fields_tokens2),* }
             }
         }
+        #stored_entity_impl
         #(#extra)*
     })
 }
@@ -573,10 +595,12 @@ fn make_field_decl_and_assignments(
     }
 
     // Field type special case
-    if field_type.is_enum("DefaultMessageNotificationLevel")
+    if field_type.is_enum("ChannelType")
+        || field_type.is_enum("DefaultMessageNotificationLevel")
         || field_type.is_enum("ExplicitContentFilter")
         || field_type.is_enum("MfaLevel")
         || field_type.is_enum("PremiumTier")
+        || field_type.is_enum("StickerFormatType")
         || field_type.is_enum("VerificationLevel")
     {
         (
@@ -602,6 +626,12 @@ fn make_field_decl_and_assignments(
             quote! {#field_name: model.#field_name},
             quote! {#field_name: twilight_model::guild::RoleFlags::from_bits(model.#field_name as u64).unwrap()},
         )
+    } else if field_type.is_option_of("Id") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name.as_deref().map(|str| std::str::FromStr::from_str(str).unwrap())},
+        )
     } else if field_type.is_option_of("ImageHash") {
         (
             quote! {pub #field_name: #field_type},
@@ -614,6 +644,12 @@ fn make_field_decl_and_assignments(
             quote! {#field_name: model.#field_name},
             quote! {#field_name: model.#field_name.map(|timestamp| twilight_model::util::Timestamp::from_secs(timestamp.unix_timestamp()).unwrap())},
         )
+    } else if field_type.is("Timestamp") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: twilight_model::util::Timestamp::from_secs(model.#field_name.unix_timestamp()).unwrap()},
+        )
     } else if field_type.is("i64") {
         (
             quote! {pub #field_name: #field_type},
@@ -626,12 +662,32 @@ fn make_field_decl_and_assignments(
             quote! {#field_name: model.#field_name},
             quote! {#field_name: model.#field_name as u32},
         )
+    } else if field_type.is_option_of("u16") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name.map(|i| i as u16)},
+        )
     } else if field_type.is_option_of("u64") {
         (
             quote! {pub #field_name: #field_type},
             quote! {#field_name: model.#field_name},
             quote! {#field_name: model.#field_name.map(|i| i as u64)},
         )
+    } else if field_type.is_option_of("ThreadMetadata") || field_type.is_option_of("Vec") {
+        // nested structures are stored as JSONB
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name.map(|value| serde_json::from_value(value).unwrap())},
+        )
+    } else if field_type.is_vec_of("Attachment") {
+        // nested structures are stored as JSONB
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: serde_json::from_value(model.#field_name).unwrap()},
+        )
     } else if field_type.is_vec_of("GuildFeature") {
         (
             quote! {pub #field_name: #field_type},
@@ -652,15 +708,3 @@ fn make_field_decl_and_assignments(
         )
     }
 }
-
-// FIXME: may need to generalize for multiple fields
-/// Construct an identifier containing the database query function name.
-fn make_query_function_name(target_entity: &str, by_field: &str) -> Ident {
-    let name = format!(
-        "cached_{}_select_by_{}",
-        target_entity.to_lowercase(),
-        by_field.to_lowercase()
-    );
-
-    Ident::new(&name, Span::call_site())
-}
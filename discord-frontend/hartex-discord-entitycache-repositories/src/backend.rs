@@ -0,0 +1,228 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Storage Backend
+//!
+//! The storage backend the repositories store entities in, configured with the following
+//! environment variables:
+//!
+//! - `ENTITYCACHE_BACKEND`: one of `postgres` (the default), `redis` or `memory`
+//! - `ENTITYCACHE_REDIS_URL`: the URL of the Redis-compatible server, required for the `redis`
+//!   backend
+//!
+//! The configuration is checked once, when the backend is initialized at startup (see [`init`]).
+//!
+//! The `memory` backend does not persist any entities, and does not share them between processes;
+//! it is therefore only suitable for tests and for small deployments running all components within
+//! the all-in-one process.
+
+use std::env;
+use std::ops::Deref;
+
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::inmemory::InMemoryStore;
+use hartex_discord_entitycache_core::backend::keyvalue::KeyValueBackend;
+use hartex_discord_entitycache_core::backend::redis::RedisStore;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::backend::StoredEntity;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_errors::entitycache::InvalidRedisUrlError;
+use hartex_errors::entitycache::MissingRedisUrlError;
+use hartex_errors::entitycache::UnsupportedBackendError;
+use once_cell::sync::OnceCell;
+
+use crate::postgres::PostgresBackend;
+
+/// The storage backend configured by the environment.
+///
+/// The backend must be initialized with [`init`] before any repository is used.
+pub static BACKEND: ConfiguredBackend = ConfiguredBackend(OnceCell::new());
+
+/// Initializes the storage backend configured by the environment, if it has not been initialized
+/// yet.
+///
+/// # Errors
+///
+/// Returns an error if the configured backend is not supported, or if the configuration of the
+/// Redis-compatible server is missing or invalid.
+pub fn init() -> miette::Result<()> {
+    BACKEND.0.get_or_try_init(StorageBackend::from_env)?;
+
+    Ok(())
+}
+
+/// The storage backend configured by the environment, once initialized.
+pub struct ConfiguredBackend(OnceCell<StorageBackend>);
+
+impl Deref for ConfiguredBackend {
+    type Target = StorageBackend;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+            .get()
+            .expect("the entity cache backend is initialized at startup")
+    }
+}
+
+/// A discriminated union of supported storage backends.
+#[allow(clippy::module_name_repetitions)]
+pub enum StorageBackend {
+    /// A backend holding entities in the memory of the process.
+    InMemory(KeyValueBackend<InMemoryStore>),
+    /// A backend storing entities in PostgreSQL.
+    Postgres(PostgresBackend),
+    /// A backend storing entities in a Redis-compatible server.
+    Redis(KeyValueBackend<RedisStore>),
+}
+
+impl StorageBackend {
+    /// Obtain the storage backend configured by the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured backend is not supported, or if the configuration of the
+    /// Redis-compatible server is missing or invalid.
+    pub fn from_env() -> miette::Result<Self> {
+        let backend = env::var("ENTITYCACHE_BACKEND").unwrap_or_else(|_| String::from("postgres"));
+
+        match backend.as_str() {
+            "memory" => Ok(Self::InMemory(KeyValueBackend::new(InMemoryStore::new()))),
+            "postgres" => Ok(Self::Postgres(PostgresBackend)),
+            "redis" => {
+                let url = env::var("ENTITYCACHE_REDIS_URL").map_err(|_| MissingRedisUrlError)?;
+                let store = RedisStore::new(&url).map_err(|error| InvalidRedisUrlError {
+                    url: url.clone(),
+                    message: error.to_string().trim_end().to_string(),
+                })?;
+
+                Ok(Self::Redis(KeyValueBackend::new(store)))
+            }
+            _ => Err(UnsupportedBackendError { backend }.into()),
+        }
+    }
+}
+
+impl<T: StoredEntity> Backend<T> for StorageBackend
+where
+    PostgresBackend: Backend<T>,
+{
+    async fn delete(&self, entity_id: T::Id) -> CacheResult<()> {
+        match self {
+            Self::InMemory(backend) => Backend::<T>::delete(backend, entity_id).await,
+            Self::Postgres(backend) => Backend::<T>::delete(backend, entity_id).await,
+            Self::Redis(backend) => Backend::<T>::delete(backend, entity_id).await,
+        }
+    }
+
+    async fn exists(&self, entity_id: T::Id) -> CacheResult<bool> {
+        match self {
+            Self::InMemory(backend) => Backend::<T>::exists(backend, entity_id).await,
+            Self::Postgres(backend) => Backend::<T>::exists(backend, entity_id).await,
+            Self::Redis(backend) => Backend::<T>::exists(backend, entity_id).await,
+        }
+    }
+
+    async fn get(&self, entity_id: T::Id) -> CacheResult<T> {
+        match self {
+            Self::InMemory(backend) => Backend::<T>::get(backend, entity_id).await,
+            Self::Postgres(backend) => Backend::<T>::get(backend, entity_id).await,
+            Self::Redis(backend) => Backend::<T>::get(backend, entity_id).await,
+        }
+    }
+
+    async fn get_many(&self, entity_ids: Vec<T::Id>) -> CacheResult<Vec<T>> {
+        match self {
+            Self::InMemory(backend) => Backend::<T>::get_many(backend, entity_ids).await,
+            Self::Postgres(backend) => Backend::<T>::get_many(backend, entity_ids).await,
+            Self::Redis(backend) => Backend::<T>::get_many(backend, entity_ids).await,
+        }
+    }
+
+    async fn upsert(&self, entity: T) -> CacheResult<()> {
+        match self {
+            Self::InMemory(backend) => Backend::<T>::upsert(backend, entity).await,
+            Self::Postgres(backend) => Backend::<T>::upsert(backend, entity).await,
+            Self::Redis(backend) => Backend::<T>::upsert(backend, entity).await,
+        }
+    }
+
+    async fn upsert_many(&self, entities: Vec<T>) -> CacheResult<()> {
+        match self {
+            Self::InMemory(backend) => Backend::<T>::upsert_many(backend, entities).await,
+            Self::Postgres(backend) => Backend::<T>::upsert_many(backend, entities).await,
+            Self::Redis(backend) => Backend::<T>::upsert_many(backend, entities).await,
+        }
+    }
+}
+
+impl<T: StoredEntity> GuildScopedBackend<T> for StorageBackend
+where
+    PostgresBackend: GuildScopedBackend<T>,
+{
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        match self {
+            Self::InMemory(backend) => {
+                GuildScopedBackend::<T>::delete_by_guild_id(backend, guild_id).await
+            }
+            Self::Postgres(backend) => {
+                GuildScopedBackend::<T>::delete_by_guild_id(backend, guild_id).await
+            }
+            Self::Redis(backend) => {
+                GuildScopedBackend::<T>::delete_by_guild_id(backend, guild_id).await
+            }
+        }
+    }
+
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<T::Id>,
+    ) -> CacheResult<()> {
+        match self {
+            Self::InMemory(backend) => {
+                GuildScopedBackend::<T>::delete_stale_in_guild(backend, guild_id, entity_ids).await
+            }
+            Self::Postgres(backend) => {
+                GuildScopedBackend::<T>::delete_stale_in_guild(backend, guild_id, entity_ids).await
+            }
+            Self::Redis(backend) => {
+                GuildScopedBackend::<T>::delete_stale_in_guild(backend, guild_id, entity_ids).await
+            }
+        }
+    }
+
+    async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<T>> {
+        match self {
+            Self::InMemory(backend) => {
+                GuildScopedBackend::<T>::get_by_guild_id(backend, guild_id).await
+            }
+            Self::Postgres(backend) => {
+                GuildScopedBackend::<T>::get_by_guild_id(backend, guild_id).await
+            }
+            Self::Redis(backend) => {
+                GuildScopedBackend::<T>::get_by_guild_id(backend, guild_id).await
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Channel Repository
+
+use std::ops::Deref;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
+
+use crate::backend::BACKEND;
+use crate::repository::CachedRepository;
+
+/// Repository for channel entities.
+pub struct CachedChannelRepository;
+
+/// The repository of channel entities.
+static REPOSITORY: CachedRepository<ChannelEntity> = CachedRepository::new(MemoryCacheConfig {
+    capacity: 10_000,
+    ttl: Duration::from_secs(300),
+});
+
+impl Deref for CachedChannelRepository {
+    type Target = CachedRepository<ChannelEntity>;
+
+    fn deref(&self) -> &Self::Target {
+        &REPOSITORY
+    }
+}
+
+impl CachedChannelRepository {
+    /// Deletes all channels of a guild from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        GuildScopedBackend::<ChannelEntity>::delete_by_guild_id(&*BACKEND, guild_id).await?;
+
+        self.cache().invalidate(None);
+
+        Ok(())
+    }
+
+    /// Deletes the channels of a guild that are not among the given channels from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: impl Iterator<Item = Id<ChannelMarker>>,
+    ) -> CacheResult<()> {
+        GuildScopedBackend::<ChannelEntity>::delete_stale_in_guild(
+            &*BACKEND,
+            guild_id,
+            ids.collect(),
+        )
+        .await?;
+
+        self.cache().invalidate(None);
+
+        Ok(())
+    }
+}
@@ -20,49 +20,71 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use std::pin::Pin;
+//! # Emoji Repository
 
-use hartex_database_queries::discord_frontend::queries::cached_emoji_select_by_id::cached_emoji_select_by_id;
-use hartex_database_queries::discord_frontend::queries::cached_emoji_upsert::cached_emoji_upsert;
+use std::ops::Deref;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::id::marker::EmojiMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
 use hartex_discord_entitycache_core::error::CacheResult;
-use hartex_discord_entitycache_core::traits::Entity;
-use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
 use hartex_discord_entitycache_entities::emoji::EmojiEntity;
-use hartex_discord_utils::DATABASE_POOL;
-use tokio_postgres::GenericClient;
+
+use crate::backend::BACKEND;
+use crate::repository::CachedRepository;
 
 /// Repository for emoji entities.
 pub struct CachedEmojiRepository;
 
-impl Repository<EmojiEntity> for CachedEmojiRepository {
-    async fn get(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<EmojiEntity> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
+/// The repository of emoji entities.
+static REPOSITORY: CachedRepository<EmojiEntity> = CachedRepository::new(MemoryCacheConfig {
+    capacity: 5000,
+    ttl: Duration::from_secs(600),
+});
+
+impl Deref for CachedEmojiRepository {
+    type Target = CachedRepository<EmojiEntity>;
+
+    fn deref(&self) -> &Self::Target {
+        &REPOSITORY
+    }
+}
 
-        let data = cached_emoji_select_by_id()
-            .bind(client, &id.to_string())
-            .one()
-            .await?;
+impl CachedEmojiRepository {
+    /// Deletes all emojis of a guild from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        GuildScopedBackend::<EmojiEntity>::delete_by_guild_id(&*BACKEND, guild_id).await?;
 
-        Ok(EmojiEntity::from(data))
+        self.cache().invalidate(None);
+
+        Ok(())
     }
 
-    async fn upsert(&self, entity: EmojiEntity) -> CacheResult<()> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
+    /// Deletes the emojis of a guild that are not among the given emojis from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: impl Iterator<Item = Id<EmojiMarker>>,
+    ) -> CacheResult<()> {
+        GuildScopedBackend::<EmojiEntity>::delete_stale_in_guild(
+            &*BACKEND,
+            guild_id,
+            ids.collect(),
+        )
+        .await?;
 
-        cached_emoji_upsert()
-            .bind(
-                client,
-                &entity.animated,
-                &entity.id.to_string(),
-                &entity.guild_id.to_string(),
-                &entity.name,
-                &entity.managed,
-            )
-            .await?;
+        self.cache().invalidate(None);
 
         Ok(())
     }
@@ -22,76 +22,27 @@
 
 //! # Guild Repository
 
-use std::borrow::Cow;
-use std::pin::Pin;
+use std::ops::Deref;
+use std::time::Duration;
 
-use hartex_database_queries::discord_frontend::queries::cached_guild_select_by_id::cached_guild_select_by_id;
-use hartex_database_queries::discord_frontend::queries::cached_guild_upsert::cached_guild_upsert;
-use hartex_discord_core::discord::model::guild::DefaultMessageNotificationLevel;
-use hartex_discord_core::discord::model::guild::ExplicitContentFilter;
-use hartex_discord_core::discord::model::guild::MfaLevel;
-use hartex_discord_core::discord::model::guild::PremiumTier;
-use hartex_discord_core::discord::model::guild::VerificationLevel;
-use hartex_discord_entitycache_core::error::CacheResult;
-use hartex_discord_entitycache_core::traits::Entity;
-use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
 use hartex_discord_entitycache_entities::guild::GuildEntity;
-use hartex_discord_utils::DATABASE_POOL;
-use tokio_postgres::GenericClient;
+
+use crate::repository::CachedRepository;
 
 /// Repository for guild entities.
 pub struct CachedGuildRepository;
 
-impl Repository<GuildEntity> for CachedGuildRepository {
-    #[allow(clippy::cast_possible_truncation)]
-    #[allow(clippy::cast_sign_loss)]
-    async fn get(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<GuildEntity> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
-
-        let data = cached_guild_select_by_id()
-            .bind(client, &id.to_string())
-            .one()
-            .await?;
-
-        Ok(GuildEntity::from(data))
-    }
-
-    #[allow(clippy::cast_possible_wrap)]
-    async fn upsert(&self, entity: GuildEntity) -> CacheResult<()> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
+/// The repository of guild entities.
+static REPOSITORY: CachedRepository<GuildEntity> = CachedRepository::new(MemoryCacheConfig {
+    capacity: 1000,
+    ttl: Duration::from_secs(300),
+});
 
-        cached_guild_upsert()
-            .bind(
-                client,
-                &i16::from(<DefaultMessageNotificationLevel as Into<u8>>::into(
-                    entity.default_message_notifications,
-                )),
-                &i16::from(<ExplicitContentFilter as Into<u8>>::into(
-                    entity.explicit_content_filter,
-                )),
-                &entity
-                    .features
-                    .iter()
-                    .map(|feature| feature.clone().into())
-                    .collect::<Vec<Cow<'static, str>>>(),
-                &entity.icon.map(|hash| hash.to_string()),
-                &entity.large,
-                &entity.name,
-                &entity.owner_id.to_string(),
-                &entity.id.to_string(),
-                &i16::from(<MfaLevel as Into<u8>>::into(entity.mfa_level)),
-                &entity.premium_subscription_count.map(|id| id as i64),
-                &i16::from(<PremiumTier as Into<u8>>::into(entity.premium_tier)),
-                &i16::from(<VerificationLevel as Into<u8>>::into(
-                    entity.verification_level,
-                )),
-            )
-            .await?;
+impl Deref for CachedGuildRepository {
+    type Target = CachedRepository<GuildEntity>;
 
-        Ok(())
+    fn deref(&self) -> &Self::Target {
+        &REPOSITORY
     }
 }
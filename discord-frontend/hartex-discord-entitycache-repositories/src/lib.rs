@@ -21,14 +21,47 @@
  */
 
 //! # Entity Cache Repositories
+//!
+//! Every repository holds the entities it has retrieved in an in-memory cache, in front of the
+//! configured storage backend (see [`hartex_discord_entitycache_core::memory`] and [`backend`]).
 
 #![allow(incomplete_features)]
 #![deny(clippy::pedantic)]
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+use hartex_discord_entitycache_core::invalidation::Invalidation;
+
+pub mod backend;
+pub mod channel;
 pub mod emoji;
 pub mod guild;
 pub mod member;
+pub mod message;
+pub mod postgres;
+pub mod repository;
 pub mod role;
+pub mod sticker;
+pub mod thread;
 pub mod user;
+pub mod voice_state;
+
+/// Applies an invalidation broadcast by another process to the in-memory cache of the entity type
+/// it applies to.
+pub fn apply_invalidation(invalidation: &Invalidation) {
+    let key = invalidation.key.as_deref();
+
+    match invalidation.entity.as_str() {
+        "channel" => channel::CachedChannelRepository.cache().apply(key),
+        "emoji" => emoji::CachedEmojiRepository.cache().apply(key),
+        "guild" => guild::CachedGuildRepository.cache().apply(key),
+        "member" => member::CachedMemberRepository.cache().apply(key),
+        "message" => message::CachedMessageRepository.cache().apply(key),
+        "role" => role::CachedRoleRepository.cache().apply(key),
+        "sticker" => sticker::CachedStickerRepository.cache().apply(key),
+        "thread" => thread::CachedThreadRepository.cache().apply(key),
+        "user" => user::CachedUserRepository.cache().apply(key),
+        "voice_state" => voice_state::CachedVoiceStateRepository.cache().apply(key),
+        _ => (),
+    }
+}
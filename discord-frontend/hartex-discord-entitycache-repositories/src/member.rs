@@ -20,63 +20,126 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use std::pin::Pin;
+//! # Member Repository
 
-use hartex_database_queries::discord_frontend::queries::cached_member_select_by_user_id_and_guild_id::cached_member_select_by_user_id_and_guild_id;
-use hartex_database_queries::discord_frontend::queries::cached_member_upsert::cached_member_upsert;
+use std::ops::Deref;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::keyvalue::KeyValueBackend;
+use hartex_discord_entitycache_core::backend::keyvalue::KeyValueStore;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
 use hartex_discord_entitycache_core::error::CacheResult;
-use hartex_discord_entitycache_core::traits::Entity;
-use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_core::memory::CacheKey;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
 use hartex_discord_entitycache_entities::member::MemberEntity;
-use hartex_discord_utils::DATABASE_POOL;
-use time::OffsetDateTime;
-use tokio_postgres::GenericClient;
+
+use crate::backend::StorageBackend;
+use crate::backend::BACKEND;
+use crate::repository::CachedRepository;
 
 /// Repository for member entities.
 pub struct CachedMemberRepository;
 
-impl Repository<MemberEntity> for CachedMemberRepository {
-    #[allow(clippy::cast_sign_loss)]
-    async fn get(
-        &self,
-        (guild_id, user_id): <MemberEntity as Entity>::Id,
-    ) -> CacheResult<MemberEntity> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
-
-        let data = cached_member_select_by_user_id_and_guild_id()
-            .bind(client, &user_id.to_string(), &guild_id.to_string())
-            .one()
-            .await?;
-
-        Ok(MemberEntity::from(data))
+/// The repository of member entities.
+static REPOSITORY: CachedRepository<MemberEntity> = CachedRepository::new(MemoryCacheConfig {
+    capacity: 50_000,
+    ttl: Duration::from_secs(120),
+});
+
+impl Deref for CachedMemberRepository {
+    type Target = CachedRepository<MemberEntity>;
+
+    fn deref(&self) -> &Self::Target {
+        &REPOSITORY
+    }
+}
+
+impl CachedMemberRepository {
+    /// Deletes all members of a guild from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let user_ids = match &*BACKEND {
+            StorageBackend::InMemory(backend) => {
+                user_ids(backend.delete_by_guild_id_returning(guild_id).await?)
+            }
+            StorageBackend::Postgres(backend) => {
+                backend.delete_members_by_guild_id(guild_id).await?
+            }
+            StorageBackend::Redis(backend) => {
+                user_ids(backend.delete_by_guild_id_returning(guild_id).await?)
+            }
+        };
+
+        invalidate(guild_id, &user_ids);
+
+        Ok(())
     }
 
-    #[allow(clippy::cast_possible_wrap)]
-    async fn upsert(&self, entity: MemberEntity) -> CacheResult<()> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
-
-        cached_member_upsert()
-            .bind(
-                client,
-                &(entity.flags.bits() as i64),
-                &entity.joined_at.map(|timestamp| {
-                    OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
-                }),
-                &entity.nick,
-                &entity.user_id.to_string(),
-                &entity.guild_id.to_string(),
-                &entity
-                    .roles
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>(),
-            )
-            .await?;
+    /// Removes a role from all members of a guild that have the role.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn remove_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> CacheResult<()> {
+        let user_ids = match &*BACKEND {
+            StorageBackend::InMemory(backend) => remove_role(backend, guild_id, role_id).await?,
+            StorageBackend::Postgres(backend) => {
+                backend.remove_member_role(guild_id, role_id).await?
+            }
+            StorageBackend::Redis(backend) => remove_role(backend, guild_id, role_id).await?,
+        };
+
+        invalidate(guild_id, &user_ids);
 
         Ok(())
     }
 }
+
+/// Removes a role from all members of a guild that have the role, for backends without a dedicated
+/// operation for doing so, returning the identifiers of the users of the updated members.
+async fn remove_role<S: KeyValueStore>(
+    backend: &KeyValueBackend<S>,
+    guild_id: Id<GuildMarker>,
+    role_id: Id<RoleMarker>,
+) -> CacheResult<Vec<Id<UserMarker>>> {
+    let members = GuildScopedBackend::<MemberEntity>::get_by_guild_id(backend, guild_id)
+        .await?
+        .into_iter()
+        .filter(|member| member.roles.contains(&role_id))
+        .map(|mut member| {
+            member.roles.retain(|id| *id != role_id);
+            member
+        })
+        .collect::<Vec<_>>();
+    let user_ids = members.iter().map(|member| member.user_id).collect();
+
+    Backend::<MemberEntity>::upsert_many(backend, members).await?;
+
+    Ok(user_ids)
+}
+
+/// Returns the identifiers of the users of the given members.
+fn user_ids(members: Vec<MemberEntity>) -> Vec<Id<UserMarker>> {
+    members.into_iter().map(|member| member.user_id).collect()
+}
+
+/// Invalidates the given members of a guild in the in-memory cache.
+fn invalidate(guild_id: Id<GuildMarker>, user_ids: &[Id<UserMarker>]) {
+    for user_id in user_ids {
+        REPOSITORY
+            .cache()
+            .invalidate(Some(&(guild_id, *user_id).cache_key()));
+    }
+}
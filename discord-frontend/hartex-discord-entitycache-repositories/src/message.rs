@@ -0,0 +1,315 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Repository
+//!
+//! Messages are retained for a limited time and up to a limited number per guild, configured with
+//! the following environment variables:
+//!
+//! - `ENTITYCACHE_MESSAGE_GUILD_CAPACITY`: the maximum number of messages retained per guild
+//! - `ENTITYCACHE_MESSAGE_RETENTION_SECONDS`: the time for which a message is retained after it
+//!   has been sent
+//!
+//! The messages exceeding the capacity of a guild are deleted periodically together with the
+//! expired messages, rather than upon every upsert.
+//!
+//! In key-value backends, messages are indexed by their timestamps, such that the oldest messages
+//! of a guild and the expired messages can be deleted without retrieving every message.
+
+use std::collections::HashSet;
+use std::env;
+use std::iter;
+use std::mem;
+use std::ops::Deref;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::MessageMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::memory::CacheKey;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use once_cell::sync::Lazy;
+use time::OffsetDateTime;
+
+use crate::backend::StorageBackend;
+use crate::backend::BACKEND;
+use crate::repository::CachedRepository;
+
+/// Repository for message entities.
+pub struct CachedMessageRepository;
+
+/// The repository of message entities.
+static REPOSITORY: CachedRepository<MessageEntity> = CachedRepository::new(MemoryCacheConfig {
+    capacity: 10_000,
+    ttl: Duration::from_secs(60),
+});
+
+impl Deref for CachedMessageRepository {
+    type Target = CachedRepository<MessageEntity>;
+
+    fn deref(&self) -> &Self::Target {
+        &REPOSITORY
+    }
+}
+
+/// The guilds with messages upserted since the number of their messages was last limited.
+static PENDING_GUILDS: Lazy<Mutex<HashSet<Id<GuildMarker>>>> = Lazy::new(Mutex::default);
+
+/// The limits on the messages retained in the repository.
+pub static RETENTION: Lazy<MessageRetention> = Lazy::new(MessageRetention::from_env);
+
+/// The limits on the messages retained in the repository.
+#[derive(Clone, Copy, Debug)]
+pub struct MessageRetention {
+    /// The maximum number of messages retained per guild.
+    pub capacity_per_guild: usize,
+    /// The time for which a message is retained after it has been sent.
+    pub retention: Duration,
+}
+
+impl MessageRetention {
+    /// Obtain the limits from the environment.
+    ///
+    /// Any environment variable that is not set or cannot be parsed falls back to a default of
+    /// 1000 messages per guild, retained for a day.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self {
+            capacity_per_guild: env::var("ENTITYCACHE_MESSAGE_GUILD_CAPACITY")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(1000),
+            retention: env::var("ENTITYCACHE_MESSAGE_RETENTION_SECONDS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .map_or(Duration::from_secs(86_400), Duration::from_secs),
+        }
+    }
+}
+
+impl CachedMessageRepository {
+    /// Deletes all messages of a guild from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let ids = match &*BACKEND {
+            StorageBackend::InMemory(backend) => {
+                message_ids(backend.delete_timestamped_by_guild_id(guild_id).await?)
+            }
+            StorageBackend::Postgres(backend) => {
+                backend.delete_messages_by_guild_id(guild_id).await?
+            }
+            StorageBackend::Redis(backend) => {
+                message_ids(backend.delete_timestamped_by_guild_id(guild_id).await?)
+            }
+        };
+
+        invalidate(&ids);
+
+        Ok(())
+    }
+
+    /// Deletes all messages that have been retained for longer than the configured retention from
+    /// the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_expired(&self) -> CacheResult<()> {
+        let before = OffsetDateTime::now_utc() - RETENTION.retention;
+        let before_micros = before.unix_timestamp().saturating_mul(1_000_000);
+
+        let ids = match &*BACKEND {
+            StorageBackend::InMemory(backend) => {
+                message_ids(backend.delete_timestamped_before(before_micros).await?)
+            }
+            StorageBackend::Postgres(backend) => backend.delete_expired_messages(before).await?,
+            StorageBackend::Redis(backend) => {
+                message_ids(backend.delete_timestamped_before(before_micros).await?)
+            }
+        };
+
+        invalidate(&ids);
+
+        Ok(())
+    }
+
+    /// Deletes multiple messages from the repository at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_many(&self, ids: Vec<Id<MessageMarker>>) -> CacheResult<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        match &*BACKEND {
+            StorageBackend::InMemory(backend) => {
+                backend
+                    .delete_timestamped::<MessageEntity>(ids.clone())
+                    .await?;
+            }
+            StorageBackend::Postgres(backend) => backend.delete_many_messages(ids.clone()).await?,
+            StorageBackend::Redis(backend) => {
+                backend
+                    .delete_timestamped::<MessageEntity>(ids.clone())
+                    .await?;
+            }
+        }
+
+        invalidate(&ids);
+
+        Ok(())
+    }
+
+    /// Deletes the oldest messages of every guild with messages upserted since this was last
+    /// called, such that no more than the configured number of messages is retained per guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_excess(&self) -> CacheResult<()> {
+        let guild_ids = mem::take(
+            &mut *PENDING_GUILDS
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+
+        let mut guild_ids = guild_ids.into_iter();
+        while let Some(guild_id) = guild_ids.next() {
+            if let Err(error) = self.enforce_capacity(guild_id).await {
+                // the remaining guilds are limited upon the next call instead
+                PENDING_GUILDS
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .extend(iter::once(guild_id).chain(guild_ids));
+
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the oldest messages of a guild from the repository, such that no more than the
+    /// configured number of messages is retained for the guild.
+    async fn enforce_capacity(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let capacity = RETENTION.capacity_per_guild;
+
+        let ids = match &*BACKEND {
+            StorageBackend::InMemory(backend) => message_ids(
+                backend
+                    .delete_timestamped_excess_in_guild(guild_id, capacity)
+                    .await?,
+            ),
+            StorageBackend::Postgres(backend) => {
+                backend.delete_excess_messages(guild_id, capacity).await?
+            }
+            StorageBackend::Redis(backend) => message_ids(
+                backend
+                    .delete_timestamped_excess_in_guild(guild_id, capacity)
+                    .await?,
+            ),
+        };
+
+        invalidate(&ids);
+
+        Ok(())
+    }
+}
+
+impl Repository<MessageEntity> for CachedMessageRepository {
+    async fn delete(&self, entity_id: <MessageEntity as Entity>::Id) -> CacheResult<()> {
+        self.delete_many(vec![entity_id]).await
+    }
+
+    async fn exists(&self, entity_id: <MessageEntity as Entity>::Id) -> CacheResult<bool> {
+        REPOSITORY.exists(entity_id).await
+    }
+
+    async fn get(&self, entity_id: <MessageEntity as Entity>::Id) -> CacheResult<MessageEntity> {
+        REPOSITORY.get(entity_id).await
+    }
+
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<MessageEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<MessageEntity>> {
+        REPOSITORY.get_many(entity_ids).await
+    }
+
+    async fn upsert(&self, entity: MessageEntity) -> CacheResult<()> {
+        self.upsert_many(vec![entity]).await
+    }
+
+    async fn upsert_many(&self, entities: Vec<MessageEntity>) -> CacheResult<()> {
+        let keys = entities
+            .iter()
+            .map(|entity| entity.id().cache_key())
+            .collect::<Vec<_>>();
+        let guild_ids = entities
+            .iter()
+            .map(|entity| entity.guild_id)
+            .collect::<HashSet<_>>();
+
+        match &*BACKEND {
+            StorageBackend::InMemory(backend) => backend.upsert_timestamped(entities).await?,
+            StorageBackend::Postgres(backend) => {
+                Backend::<MessageEntity>::upsert_many(backend, entities).await?;
+            }
+            StorageBackend::Redis(backend) => backend.upsert_timestamped(entities).await?,
+        }
+
+        for key in keys {
+            self.cache().invalidate(Some(&key));
+        }
+
+        PENDING_GUILDS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(guild_ids);
+
+        Ok(())
+    }
+}
+
+/// Returns the identifiers of the given messages.
+fn message_ids(messages: Vec<MessageEntity>) -> Vec<Id<MessageMarker>> {
+    messages.into_iter().map(|message| message.id).collect()
+}
+
+/// Invalidates the given messages in the in-memory cache.
+fn invalidate(ids: &[Id<MessageMarker>]) {
+    for id in ids {
+        REPOSITORY.cache().invalidate(Some(&id.cache_key()));
+    }
+}
@@ -0,0 +1,200 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Emoji PostgreSQL Backend
+
+use std::pin::Pin;
+
+use hartex_database_queries::discord_frontend::queries::cached_emoji_delete_by_guild_id::cached_emoji_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_delete_by_id::cached_emoji_delete_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_delete_stale_by_guild_id::cached_emoji_delete_stale_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_exists_by_id::cached_emoji_exists_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_select_by_guild_id::cached_emoji_select_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_select_by_id::cached_emoji_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_select_by_ids::cached_emoji_select_by_ids;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_upsert::cached_emoji_upsert;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_upsert_many::cached_emoji_upsert_many;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::emoji::EmojiEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use tokio_postgres::GenericClient;
+
+use crate::postgres::column;
+use crate::postgres::not_found;
+use crate::postgres::PostgresBackend;
+
+impl Backend<EmojiEntity> for PostgresBackend {
+    async fn delete(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_emoji_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        Ok(cached_emoji_exists_by_id()
+            .bind(client, &id.to_string())
+            .one()
+            .await?)
+    }
+
+    async fn get(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<EmojiEntity> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_emoji_select_by_id()
+            .bind(client, &id.to_string())
+            .opt()
+            .await?
+            .ok_or_else(|| not_found::<EmojiEntity>(&id))?;
+
+        Ok(EmojiEntity::from(data))
+    }
+
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<EmojiEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<EmojiEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = entity_ids
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+
+        let data = cached_emoji_select_by_ids()
+            .bind(client, &ids)
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(EmojiEntity::from).collect())
+    }
+
+    async fn upsert(&self, entity: EmojiEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_emoji_upsert()
+            .bind(
+                client,
+                &entity.animated,
+                &entity.name,
+                &entity.id.to_string(),
+                &entity.guild_id.to_string(),
+                &entity.managed,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<EmojiEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_emoji_upsert_many()
+            .bind(
+                client,
+                &column(&entities, |entity| entity.animated),
+                &column(&entities, |entity| entity.name.as_str()),
+                &column(&entities, |entity| entity.id.to_string()),
+                &column(&entities, |entity| entity.guild_id.to_string()),
+                &column(&entities, |entity| entity.managed),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl GuildScopedBackend<EmojiEntity> for PostgresBackend {
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_emoji_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<<EmojiEntity as Entity>::Id>,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_emoji_delete_stale_by_guild_id()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &entity_ids
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<EmojiEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_emoji_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(EmojiEntity::from).collect())
+    }
+}
@@ -0,0 +1,202 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Guild PostgreSQL Backend
+
+use std::borrow::Cow;
+use std::pin::Pin;
+
+use hartex_database_queries::discord_frontend::queries::cached_guild_delete_by_id::cached_guild_delete_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_guild_exists_by_id::cached_guild_exists_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_guild_select_by_id::cached_guild_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_guild_select_by_ids::cached_guild_select_by_ids;
+use hartex_database_queries::discord_frontend::queries::cached_guild_upsert::cached_guild_upsert;
+use hartex_database_queries::discord_frontend::queries::cached_guild_upsert_many::cached_guild_upsert_many;
+use hartex_discord_core::discord::model::guild::DefaultMessageNotificationLevel;
+use hartex_discord_core::discord::model::guild::ExplicitContentFilter;
+use hartex_discord_core::discord::model::guild::MfaLevel;
+use hartex_discord_core::discord::model::guild::PremiumTier;
+use hartex_discord_core::discord::model::guild::VerificationLevel;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::guild::GuildEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use tokio_postgres::GenericClient;
+
+use crate::postgres::column;
+use crate::postgres::not_found;
+use crate::postgres::PostgresBackend;
+
+impl Backend<GuildEntity> for PostgresBackend {
+    async fn delete(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_guild_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        Ok(cached_guild_exists_by_id()
+            .bind(client, &id.to_string())
+            .one()
+            .await?)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    async fn get(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<GuildEntity> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_guild_select_by_id()
+            .bind(client, &id.to_string())
+            .opt()
+            .await?
+            .ok_or_else(|| not_found::<GuildEntity>(&id))?;
+
+        Ok(GuildEntity::from(data))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<GuildEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<GuildEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = entity_ids
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+
+        let data = cached_guild_select_by_ids()
+            .bind(client, &ids)
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(GuildEntity::from).collect())
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    async fn upsert(&self, entity: GuildEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_guild_upsert()
+            .bind(
+                client,
+                &i16::from(<DefaultMessageNotificationLevel as Into<u8>>::into(
+                    entity.default_message_notifications,
+                )),
+                &i16::from(<ExplicitContentFilter as Into<u8>>::into(
+                    entity.explicit_content_filter,
+                )),
+                &entity
+                    .features
+                    .iter()
+                    .map(|feature| feature.clone().into())
+                    .collect::<Vec<Cow<'static, str>>>(),
+                &entity.icon.map(|hash| hash.to_string()),
+                &entity.large,
+                &entity.name,
+                &entity.owner_id.to_string(),
+                &entity.id.to_string(),
+                &i16::from(<MfaLevel as Into<u8>>::into(entity.mfa_level)),
+                &entity.premium_subscription_count.map(|id| id as i64),
+                &i16::from(<PremiumTier as Into<u8>>::into(entity.premium_tier)),
+                &i16::from(<VerificationLevel as Into<u8>>::into(
+                    entity.verification_level,
+                )),
+                &entity.preferred_locale,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    async fn upsert_many(&self, entities: Vec<GuildEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_guild_upsert_many()
+            .bind(
+                client,
+                &column(&entities, |entity| {
+                    i16::from(<DefaultMessageNotificationLevel as Into<u8>>::into(
+                        entity.default_message_notifications,
+                    ))
+                }),
+                &column(&entities, |entity| {
+                    i16::from(<ExplicitContentFilter as Into<u8>>::into(
+                        entity.explicit_content_filter,
+                    ))
+                }),
+                &column(&entities, |entity| {
+                    serde_json::to_value(&entity.features).unwrap()
+                }),
+                &column(&entities, |entity| entity.icon.map(|hash| hash.to_string())),
+                &column(&entities, |entity| entity.large),
+                &column(&entities, |entity| entity.name.as_str()),
+                &column(&entities, |entity| entity.owner_id.to_string()),
+                &column(&entities, |entity| entity.id.to_string()),
+                &column(&entities, |entity| {
+                    i16::from(<MfaLevel as Into<u8>>::into(entity.mfa_level))
+                }),
+                &column(&entities, |entity| {
+                    entity.premium_subscription_count.map(|id| id as i64)
+                }),
+                &column(&entities, |entity| {
+                    i16::from(<PremiumTier as Into<u8>>::into(entity.premium_tier))
+                }),
+                &column(&entities, |entity| {
+                    i16::from(<VerificationLevel as Into<u8>>::into(
+                        entity.verification_level,
+                    ))
+                }),
+                &column(&entities, |entity| entity.preferred_locale.as_str()),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
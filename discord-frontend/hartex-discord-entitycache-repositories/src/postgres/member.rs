@@ -0,0 +1,266 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Member PostgreSQL Backend
+
+use std::pin::Pin;
+use std::str::FromStr;
+
+use hartex_database_queries::discord_frontend::queries::cached_member_delete_by_guild_id::cached_member_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_delete_by_user_id_and_guild_id::cached_member_delete_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_delete_stale_by_guild_id::cached_member_delete_stale_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_exists_by_user_id_and_guild_id::cached_member_exists_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_remove_role_by_guild_id::cached_member_remove_role_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_select_by_guild_id::cached_member_select_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_select_by_user_id_and_guild_id::cached_member_select_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_select_by_user_ids_and_guild_ids::cached_member_select_by_user_ids_and_guild_ids;
+use hartex_database_queries::discord_frontend::queries::cached_member_upsert::cached_member_upsert;
+use hartex_database_queries::discord_frontend::queries::cached_member_upsert_many::cached_member_upsert_many;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::member::MemberEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use time::OffsetDateTime;
+use tokio_postgres::GenericClient;
+
+use crate::postgres::column;
+use crate::postgres::not_found;
+use crate::postgres::PostgresBackend;
+
+impl PostgresBackend {
+    /// Deletes all members of a guild, returning the identifiers of the users of the deleted
+    /// members.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn delete_members_by_guild_id(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> CacheResult<Vec<Id<UserMarker>>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_member_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(ids.iter().map(|id| Id::from_str(id).unwrap()).collect())
+    }
+
+    /// Removes a role from all members of a guild that have the role, returning the identifiers of
+    /// the users of the updated members.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn remove_member_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> CacheResult<Vec<Id<UserMarker>>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_member_remove_role_by_guild_id()
+            .bind(client, &role_id.to_string(), &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(ids.iter().map(|id| Id::from_str(id).unwrap()).collect())
+    }
+}
+
+impl Backend<MemberEntity> for PostgresBackend {
+    async fn delete(&self, (guild_id, user_id): <MemberEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_member_delete_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn exists(&self, (guild_id, user_id): <MemberEntity as Entity>::Id) -> CacheResult<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        Ok(cached_member_exists_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .one()
+            .await?)
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    async fn get(
+        &self,
+        (guild_id, user_id): <MemberEntity as Entity>::Id,
+    ) -> CacheResult<MemberEntity> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_member_select_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .opt()
+            .await?
+            .ok_or_else(|| not_found::<MemberEntity>(&(guild_id, user_id)))?;
+
+        Ok(MemberEntity::from(data))
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<MemberEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<MemberEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let (guild_ids, user_ids): (Vec<_>, Vec<_>) = entity_ids
+            .into_iter()
+            .map(|(guild_id, user_id)| (guild_id.to_string(), user_id.to_string()))
+            .unzip();
+
+        let data = cached_member_select_by_user_ids_and_guild_ids()
+            .bind(client, &user_ids, &guild_ids)
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(MemberEntity::from).collect())
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    async fn upsert(&self, entity: MemberEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_member_upsert()
+            .bind(
+                client,
+                &(entity.flags.bits() as i64),
+                &entity.joined_at.map(|timestamp| {
+                    OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
+                }),
+                &entity.nick,
+                &entity.user_id.to_string(),
+                &entity.guild_id.to_string(),
+                &entity
+                    .roles
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    async fn upsert_many(&self, entities: Vec<MemberEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_member_upsert_many()
+            .bind(
+                client,
+                &column(&entities, |entity| entity.flags.bits() as i64),
+                &column(&entities, |entity| {
+                    entity.joined_at.map(|timestamp| {
+                        OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
+                    })
+                }),
+                &column(&entities, |entity| entity.nick.as_deref()),
+                &column(&entities, |entity| entity.user_id.to_string()),
+                &column(&entities, |entity| entity.guild_id.to_string()),
+                &column(&entities, |entity| {
+                    serde_json::to_value(&entity.roles).unwrap()
+                }),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl GuildScopedBackend<MemberEntity> for PostgresBackend {
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        self.delete_members_by_guild_id(guild_id).await.map(|_| ())
+    }
+
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<<MemberEntity as Entity>::Id>,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_member_delete_stale_by_guild_id()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &entity_ids
+                    .iter()
+                    .map(|(_, user_id)| user_id.to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<MemberEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_member_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(MemberEntity::from).collect())
+    }
+}
@@ -0,0 +1,306 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message PostgreSQL Backend
+
+use std::pin::Pin;
+use std::str::FromStr;
+
+use hartex_database_queries::discord_frontend::queries::cached_message_delete_by_guild_id::cached_message_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_delete_by_id::cached_message_delete_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_delete_by_ids::cached_message_delete_by_ids;
+use hartex_database_queries::discord_frontend::queries::cached_message_delete_excess_by_guild_id::cached_message_delete_excess_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_delete_expired::cached_message_delete_expired;
+use hartex_database_queries::discord_frontend::queries::cached_message_delete_stale_by_guild_id::cached_message_delete_stale_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_exists_by_id::cached_message_exists_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_select_by_guild_id::cached_message_select_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_select_by_id::cached_message_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_select_by_ids::cached_message_select_by_ids;
+use hartex_database_queries::discord_frontend::queries::cached_message_upsert::cached_message_upsert;
+use hartex_database_queries::discord_frontend::queries::cached_message_upsert_many::cached_message_upsert_many;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::MessageMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use time::OffsetDateTime;
+use tokio_postgres::GenericClient;
+
+use crate::postgres::column;
+use crate::postgres::not_found;
+use crate::postgres::PostgresBackend;
+
+impl PostgresBackend {
+    /// Deletes all messages of a guild, returning the identifiers of the deleted messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn delete_messages_by_guild_id(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> CacheResult<Vec<Id<MessageMarker>>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_message_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(ids.iter().map(|id| Id::from_str(id).unwrap()).collect())
+    }
+
+    /// Deletes multiple messages at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn delete_many_messages(&self, ids: Vec<Id<MessageMarker>>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_message_delete_by_ids()
+            .bind(
+                client,
+                &ids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the oldest messages of a guild such that at most the given number of messages of
+    /// the guild remain, returning the identifiers of the deleted messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn delete_excess_messages(
+        &self,
+        guild_id: Id<GuildMarker>,
+        capacity: usize,
+    ) -> CacheResult<Vec<Id<MessageMarker>>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_message_delete_excess_by_guild_id()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &i64::try_from(capacity).unwrap_or(i64::MAX),
+            )
+            .all()
+            .await?;
+
+        Ok(ids.iter().map(|id| Id::from_str(id).unwrap()).collect())
+    }
+
+    /// Deletes all messages sent before the given point in time, returning the identifiers of the
+    /// deleted messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn delete_expired_messages(
+        &self,
+        before: OffsetDateTime,
+    ) -> CacheResult<Vec<Id<MessageMarker>>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_message_delete_expired()
+            .bind(client, &before)
+            .all()
+            .await?;
+
+        Ok(ids.iter().map(|id| Id::from_str(id).unwrap()).collect())
+    }
+}
+
+impl Backend<MessageEntity> for PostgresBackend {
+    async fn delete(&self, id: <MessageEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_message_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <MessageEntity as Entity>::Id) -> CacheResult<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        Ok(cached_message_exists_by_id()
+            .bind(client, &id.to_string())
+            .one()
+            .await?)
+    }
+
+    async fn get(&self, id: <MessageEntity as Entity>::Id) -> CacheResult<MessageEntity> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_message_select_by_id()
+            .bind(client, &id.to_string())
+            .opt()
+            .await?
+            .ok_or_else(|| not_found::<MessageEntity>(&id))?;
+
+        Ok(MessageEntity::from(data))
+    }
+
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<MessageEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<MessageEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = entity_ids
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+
+        let data = cached_message_select_by_ids()
+            .bind(client, &ids)
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(MessageEntity::from).collect())
+    }
+
+    async fn upsert(&self, entity: MessageEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_message_upsert()
+            .bind(
+                client,
+                &serde_json::to_value(&entity.attachments).unwrap(),
+                &entity.author_id.to_string(),
+                &entity.channel_id.to_string(),
+                &entity.content,
+                &entity.edited_timestamp.map(|timestamp| {
+                    OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
+                }),
+                &entity.guild_id.to_string(),
+                &entity.id.to_string(),
+                &OffsetDateTime::from_unix_timestamp(entity.timestamp.as_secs()).unwrap(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<MessageEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_message_upsert_many()
+            .bind(
+                client,
+                &column(&entities, |entity| {
+                    serde_json::to_value(&entity.attachments).unwrap()
+                }),
+                &column(&entities, |entity| entity.author_id.to_string()),
+                &column(&entities, |entity| entity.channel_id.to_string()),
+                &column(&entities, |entity| entity.content.as_str()),
+                &column(&entities, |entity| {
+                    entity.edited_timestamp.map(|timestamp| {
+                        OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
+                    })
+                }),
+                &column(&entities, |entity| entity.guild_id.to_string()),
+                &column(&entities, |entity| entity.id.to_string()),
+                &column(&entities, |entity| {
+                    OffsetDateTime::from_unix_timestamp(entity.timestamp.as_secs()).unwrap()
+                }),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl GuildScopedBackend<MessageEntity> for PostgresBackend {
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        self.delete_messages_by_guild_id(guild_id).await.map(|_| ())
+    }
+
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<<MessageEntity as Entity>::Id>,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_message_delete_stale_by_guild_id()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &entity_ids
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<MessageEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_message_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(MessageEntity::from).collect())
+    }
+}
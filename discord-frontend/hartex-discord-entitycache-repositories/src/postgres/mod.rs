@@ -0,0 +1,58 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # PostgreSQL Storage Backend
+//!
+//! The storage backend storing entities in the PostgreSQL database of the bot.
+
+use hartex_discord_entitycache_core::backend::StoredEntity;
+use hartex_discord_entitycache_core::error::CacheError;
+use hartex_discord_entitycache_core::memory::CacheKey;
+
+pub mod channel;
+pub mod emoji;
+pub mod guild;
+pub mod member;
+pub mod message;
+pub mod role;
+pub mod sticker;
+pub mod thread;
+pub mod user;
+pub mod voice_state;
+
+/// A storage backend storing entities in PostgreSQL.
+#[allow(clippy::module_name_repetitions)]
+pub struct PostgresBackend;
+
+/// Collects a value of each of the given entities, such that the entities can be bound to a query
+/// as one array per column.
+fn column<'a, T, C>(entities: &'a [T], value: impl FnMut(&'a T) -> C) -> Vec<C> {
+    entities.iter().map(value).collect()
+}
+
+/// Constructs the error returned when an entity does not exist in the database.
+fn not_found<T: StoredEntity>(id: &T::Id) -> CacheError {
+    CacheError::NotFound {
+        entity: T::NAME,
+        key: id.cache_key(),
+    }
+}
@@ -0,0 +1,223 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Role PostgreSQL Backend
+
+use std::pin::Pin;
+
+use hartex_database_queries::discord_frontend::queries::cached_role_delete_by_guild_id::cached_role_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_role_delete_by_id_and_guild_id::cached_role_delete_by_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_role_delete_stale_by_guild_id::cached_role_delete_stale_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_role_exists_by_id_and_guild_id::cached_role_exists_by_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_role_select_by_guild_id::cached_role_select_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_role_select_by_id_and_guild_id::cached_role_select_by_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_role_select_by_ids_and_guild_ids::cached_role_select_by_ids_and_guild_ids;
+use hartex_database_queries::discord_frontend::queries::cached_role_upsert::cached_role_upsert;
+use hartex_database_queries::discord_frontend::queries::cached_role_upsert_many::cached_role_upsert_many;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use tokio_postgres::GenericClient;
+
+use crate::postgres::column;
+use crate::postgres::not_found;
+use crate::postgres::PostgresBackend;
+
+impl Backend<RoleEntity> for PostgresBackend {
+    async fn delete(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_role_delete_by_id_and_guild_id()
+            .bind(client, &id.to_string(), &guild_id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn exists(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        Ok(cached_role_exists_by_id_and_guild_id()
+            .bind(client, &id.to_string(), &guild_id.to_string())
+            .one()
+            .await?)
+    }
+
+    #[allow(clippy::cast_lossless)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    async fn get(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<RoleEntity> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_role_select_by_id_and_guild_id()
+            .bind(client, &id.to_string(), &guild_id.to_string())
+            .opt()
+            .await?
+            .ok_or_else(|| not_found::<RoleEntity>(&(guild_id, id)))?;
+
+        Ok(RoleEntity::from(data))
+    }
+
+    #[allow(clippy::cast_lossless)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<RoleEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<RoleEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let (guild_ids, ids): (Vec<_>, Vec<_>) = entity_ids
+            .into_iter()
+            .map(|(guild_id, id)| (guild_id.to_string(), id.to_string()))
+            .unzip();
+
+        let data = cached_role_select_by_ids_and_guild_ids()
+            .bind(client, &ids, &guild_ids)
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(RoleEntity::from).collect())
+    }
+
+    #[allow(clippy::cast_lossless)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    async fn upsert(&self, entity: RoleEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_role_upsert()
+            .bind(
+                client,
+                &(entity.color as i64),
+                &entity.icon.map(|hash| hash.to_string()),
+                &entity.id.to_string(),
+                &entity.guild_id.to_string(),
+                &(entity.flags.bits() as i32),
+                &entity.hoist,
+                &entity.managed,
+                &entity.mentionable,
+                &(entity.position as i32),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_lossless)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    async fn upsert_many(&self, entities: Vec<RoleEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_role_upsert_many()
+            .bind(
+                client,
+                &column(&entities, |entity| entity.color as i64),
+                &column(&entities, |entity| entity.icon.map(|hash| hash.to_string())),
+                &column(&entities, |entity| entity.id.to_string()),
+                &column(&entities, |entity| entity.guild_id.to_string()),
+                &column(&entities, |entity| entity.flags.bits() as i32),
+                &column(&entities, |entity| entity.hoist),
+                &column(&entities, |entity| entity.managed),
+                &column(&entities, |entity| entity.mentionable),
+                &column(&entities, |entity| entity.position as i32),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl GuildScopedBackend<RoleEntity> for PostgresBackend {
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_role_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<<RoleEntity as Entity>::Id>,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_role_delete_stale_by_guild_id()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &entity_ids
+                    .iter()
+                    .map(|(_, id)| id.to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::cast_lossless)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<RoleEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_role_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(RoleEntity::from).collect())
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Sticker PostgreSQL Backend
+
+use std::pin::Pin;
+
+use hartex_database_queries::discord_frontend::queries::cached_sticker_delete_by_guild_id::cached_sticker_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_sticker_delete_by_id::cached_sticker_delete_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_sticker_delete_stale_by_guild_id::cached_sticker_delete_stale_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_sticker_exists_by_id::cached_sticker_exists_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_sticker_select_by_guild_id::cached_sticker_select_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_sticker_select_by_id::cached_sticker_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_sticker_select_by_ids::cached_sticker_select_by_ids;
+use hartex_database_queries::discord_frontend::queries::cached_sticker_upsert::cached_sticker_upsert;
+use hartex_database_queries::discord_frontend::queries::cached_sticker_upsert_many::cached_sticker_upsert_many;
+use hartex_discord_core::discord::model::channel::message::sticker::StickerFormatType;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::sticker::StickerEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use tokio_postgres::GenericClient;
+
+use crate::postgres::column;
+use crate::postgres::not_found;
+use crate::postgres::PostgresBackend;
+
+impl Backend<StickerEntity> for PostgresBackend {
+    async fn delete(&self, id: <StickerEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_sticker_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <StickerEntity as Entity>::Id) -> CacheResult<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        Ok(cached_sticker_exists_by_id()
+            .bind(client, &id.to_string())
+            .one()
+            .await?)
+    }
+
+    async fn get(&self, id: <StickerEntity as Entity>::Id) -> CacheResult<StickerEntity> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_sticker_select_by_id()
+            .bind(client, &id.to_string())
+            .opt()
+            .await?
+            .ok_or_else(|| not_found::<StickerEntity>(&id))?;
+
+        Ok(StickerEntity::from(data))
+    }
+
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<StickerEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<StickerEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = entity_ids
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+
+        let data = cached_sticker_select_by_ids()
+            .bind(client, &ids)
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(StickerEntity::from).collect())
+    }
+
+    async fn upsert(&self, entity: StickerEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_sticker_upsert()
+            .bind(
+                client,
+                &entity.available,
+                &entity.description,
+                &i16::from(<StickerFormatType as Into<u8>>::into(entity.format_type)),
+                &entity.guild_id.to_string(),
+                &entity.id.to_string(),
+                &entity.name,
+                &entity.tags,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<StickerEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_sticker_upsert_many()
+            .bind(
+                client,
+                &column(&entities, |entity| entity.available),
+                &column(&entities, |entity| entity.description.as_deref()),
+                &column(&entities, |entity| {
+                    i16::from(<StickerFormatType as Into<u8>>::into(entity.format_type))
+                }),
+                &column(&entities, |entity| entity.guild_id.to_string()),
+                &column(&entities, |entity| entity.id.to_string()),
+                &column(&entities, |entity| entity.name.as_str()),
+                &column(&entities, |entity| entity.tags.as_str()),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl GuildScopedBackend<StickerEntity> for PostgresBackend {
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_sticker_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<<StickerEntity as Entity>::Id>,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_sticker_delete_stale_by_guild_id()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &entity_ids
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<StickerEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_sticker_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(StickerEntity::from).collect())
+    }
+}
@@ -0,0 +1,183 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # User PostgreSQL Backend
+
+use std::pin::Pin;
+
+use hartex_database_queries::discord_frontend::queries::cached_user_delete_by_id::cached_user_delete_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_user_delete_unreferenced::cached_user_delete_unreferenced;
+use hartex_database_queries::discord_frontend::queries::cached_user_delete_unreferenced_by_id::cached_user_delete_unreferenced_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_user_exists_by_id::cached_user_exists_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_user_select_by_id::cached_user_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_user_select_by_ids::cached_user_select_by_ids;
+use hartex_database_queries::discord_frontend::queries::cached_user_upsert::cached_user_upsert;
+use hartex_database_queries::discord_frontend::queries::cached_user_upsert_many::cached_user_upsert_many;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::user::UserEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use tokio_postgres::GenericClient;
+
+use crate::postgres::column;
+use crate::postgres::not_found;
+use crate::postgres::PostgresBackend;
+
+impl PostgresBackend {
+    /// Deletes a user from the backend if the user is no longer a member of any cached guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn delete_user_if_unreferenced(&self, id: Id<UserMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_user_delete_unreferenced_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes all users that are no longer a member of any cached guild from the backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub async fn delete_unreferenced_users(&self) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_user_delete_unreferenced().bind(client).await?;
+
+        Ok(())
+    }
+}
+
+impl Backend<UserEntity> for PostgresBackend {
+    async fn delete(&self, id: <UserEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_user_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <UserEntity as Entity>::Id) -> CacheResult<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        Ok(cached_user_exists_by_id()
+            .bind(client, &id.to_string())
+            .one()
+            .await?)
+    }
+
+    async fn get(&self, id: <UserEntity as Entity>::Id) -> CacheResult<UserEntity> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_user_select_by_id()
+            .bind(client, &id.to_string())
+            .opt()
+            .await?
+            .ok_or_else(|| not_found::<UserEntity>(&id))?;
+
+        Ok(UserEntity::from(data))
+    }
+
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<UserEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<UserEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = entity_ids
+            .into_iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+
+        let data = cached_user_select_by_ids().bind(client, &ids).all().await?;
+
+        Ok(data.into_iter().map(UserEntity::from).collect())
+    }
+
+    async fn upsert(&self, entity: UserEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_user_upsert()
+            .bind(
+                client,
+                &entity.avatar.map(|hash| hash.to_string()),
+                &entity.id.to_string(),
+                &entity.bot,
+                &entity.name,
+                &entity.discriminator.to_string(),
+                &entity.global_name,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<UserEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_user_upsert_many()
+            .bind(
+                client,
+                &column(&entities, |entity| {
+                    entity.avatar.map(|hash| hash.to_string())
+                }),
+                &column(&entities, |entity| entity.id.to_string()),
+                &column(&entities, |entity| entity.bot),
+                &column(&entities, |entity| entity.name.as_str()),
+                &column(&entities, |entity| entity.discriminator.to_string()),
+                &column(&entities, |entity| entity.global_name.as_deref()),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
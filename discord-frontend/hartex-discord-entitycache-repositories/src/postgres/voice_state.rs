@@ -0,0 +1,235 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Voice State PostgreSQL Backend
+
+use std::pin::Pin;
+
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_delete_by_guild_id::cached_voice_state_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_delete_by_user_id_and_guild_id::cached_voice_state_delete_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_delete_stale_by_guild_id::cached_voice_state_delete_stale_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_exists_by_user_id_and_guild_id::cached_voice_state_exists_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_select_by_guild_id::cached_voice_state_select_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_select_by_user_id_and_guild_id::cached_voice_state_select_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_select_by_user_ids_and_guild_ids::cached_voice_state_select_by_user_ids_and_guild_ids;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_upsert::cached_voice_state_upsert;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_upsert_many::cached_voice_state_upsert_many;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::voice_state::VoiceStateEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use time::OffsetDateTime;
+use tokio_postgres::GenericClient;
+
+use crate::postgres::column;
+use crate::postgres::not_found;
+use crate::postgres::PostgresBackend;
+
+impl Backend<VoiceStateEntity> for PostgresBackend {
+    async fn delete(
+        &self,
+        (guild_id, user_id): <VoiceStateEntity as Entity>::Id,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_voice_state_delete_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        (guild_id, user_id): <VoiceStateEntity as Entity>::Id,
+    ) -> CacheResult<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        Ok(cached_voice_state_exists_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .one()
+            .await?)
+    }
+
+    async fn get(
+        &self,
+        (guild_id, user_id): <VoiceStateEntity as Entity>::Id,
+    ) -> CacheResult<VoiceStateEntity> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_voice_state_select_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .opt()
+            .await?
+            .ok_or_else(|| not_found::<VoiceStateEntity>(&(guild_id, user_id)))?;
+
+        Ok(VoiceStateEntity::from(data))
+    }
+
+    async fn get_many(
+        &self,
+        entity_ids: Vec<<VoiceStateEntity as Entity>::Id>,
+    ) -> CacheResult<Vec<VoiceStateEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let (guild_ids, user_ids): (Vec<_>, Vec<_>) = entity_ids
+            .into_iter()
+            .map(|(guild_id, user_id)| (guild_id.to_string(), user_id.to_string()))
+            .unzip();
+
+        let data = cached_voice_state_select_by_user_ids_and_guild_ids()
+            .bind(client, &user_ids, &guild_ids)
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(VoiceStateEntity::from).collect())
+    }
+
+    async fn upsert(&self, entity: VoiceStateEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_voice_state_upsert()
+            .bind(
+                client,
+                &entity.channel_id.map(|id| id.to_string()),
+                &entity.deaf,
+                &entity.guild_id.to_string(),
+                &entity.mute,
+                &entity.request_to_speak_timestamp.map(|timestamp| {
+                    OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
+                }),
+                &entity.self_deaf,
+                &entity.self_mute,
+                &entity.self_stream,
+                &entity.self_video,
+                &entity.session_id,
+                &entity.suppress,
+                &entity.user_id.to_string(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<VoiceStateEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_voice_state_upsert_many()
+            .bind(
+                client,
+                &column(&entities, |entity| {
+                    entity.channel_id.map(|id| id.to_string())
+                }),
+                &column(&entities, |entity| entity.deaf),
+                &column(&entities, |entity| entity.guild_id.to_string()),
+                &column(&entities, |entity| entity.mute),
+                &column(&entities, |entity| {
+                    entity.request_to_speak_timestamp.map(|timestamp| {
+                        OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
+                    })
+                }),
+                &column(&entities, |entity| entity.self_deaf),
+                &column(&entities, |entity| entity.self_mute),
+                &column(&entities, |entity| entity.self_stream),
+                &column(&entities, |entity| entity.self_video),
+                &column(&entities, |entity| entity.session_id.as_str()),
+                &column(&entities, |entity| entity.suppress),
+                &column(&entities, |entity| entity.user_id.to_string()),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl GuildScopedBackend<VoiceStateEntity> for PostgresBackend {
+    async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_voice_state_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entity_ids: Vec<<VoiceStateEntity as Entity>::Id>,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_voice_state_delete_stale_by_guild_id()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &entity_ids
+                    .iter()
+                    .map(|(_, user_id)| user_id.to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_by_guild_id(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> CacheResult<Vec<VoiceStateEntity>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_voice_state_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        Ok(data.into_iter().map(VoiceStateEntity::from).collect())
+    }
+}
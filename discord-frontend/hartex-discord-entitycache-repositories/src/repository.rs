@@ -0,0 +1,178 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Cached Repository
+//!
+//! The repository shared by every entity type, holding the entities it has retrieved in an
+//! in-memory cache in front of the configured storage backend.
+//!
+//! The repository of every entity type dereferences to a [`CachedRepository`] of the entity type,
+//! and only implements the operations specific to the entity type itself.
+
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::Backend;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::backend::StoredEntity;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::memory::CacheKey;
+use hartex_discord_entitycache_core::memory::MemoryCache;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Repository;
+use once_cell::sync::OnceCell;
+
+use crate::backend::StorageBackend;
+use crate::backend::BACKEND;
+
+/// A repository of entities, held in an in-memory cache in front of the storage backend.
+#[allow(clippy::module_name_repetitions)]
+pub struct CachedRepository<T> {
+    cache: OnceCell<MemoryCache<T>>,
+    config: MemoryCacheConfig,
+}
+
+impl<T: StoredEntity> CachedRepository<T> {
+    /// Creates a repository whose in-memory cache has the given default configuration, which
+    /// can be overridden by the environment.
+    #[must_use]
+    pub const fn new(config: MemoryCacheConfig) -> Self {
+        Self {
+            cache: OnceCell::new(),
+            config,
+        }
+    }
+
+    /// Returns the in-memory cache of the repository.
+    pub(crate) fn cache(&self) -> &MemoryCache<T> {
+        self.cache
+            .get_or_init(|| MemoryCache::from_env(T::NAME, self.config))
+    }
+}
+
+impl<T: StoredEntity> CachedRepository<T>
+where
+    StorageBackend: GuildScopedBackend<T>,
+{
+    /// Retrieves all entities of a guild from the repository, holding them in the in-memory cache
+    /// for subsequent retrievals.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn get_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<Vec<T>> {
+        let generation = self.cache().generation();
+
+        let entities = GuildScopedBackend::<T>::get_by_guild_id(&*BACKEND, guild_id).await?;
+        for entity in &entities {
+            self.cache()
+                .insert(generation, entity.id().cache_key(), entity.clone());
+        }
+
+        Ok(entities)
+    }
+}
+
+impl<T: StoredEntity> Repository<T> for CachedRepository<T>
+where
+    StorageBackend: Backend<T>,
+{
+    async fn delete(&self, entity_id: T::Id) -> CacheResult<()> {
+        let key = entity_id.cache_key();
+
+        Backend::<T>::delete(&*BACKEND, entity_id).await?;
+
+        self.cache().invalidate(Some(&key));
+
+        Ok(())
+    }
+
+    async fn exists(&self, entity_id: T::Id) -> CacheResult<bool> {
+        if self.cache().get(&entity_id.cache_key()).is_some() {
+            return Ok(true);
+        }
+
+        Backend::<T>::exists(&*BACKEND, entity_id).await
+    }
+
+    async fn get(&self, entity_id: T::Id) -> CacheResult<T> {
+        let key = entity_id.cache_key();
+        if let Some(entity) = self.cache().get(&key) {
+            return Ok(entity);
+        }
+        let generation = self.cache().generation();
+
+        let entity = Backend::<T>::get(&*BACKEND, entity_id).await?;
+        self.cache().insert(generation, key, entity.clone());
+
+        Ok(entity)
+    }
+
+    async fn get_many(&self, entity_ids: Vec<T::Id>) -> CacheResult<Vec<T>> {
+        let mut entities = Vec::new();
+        let mut missing = Vec::new();
+        for entity_id in entity_ids {
+            match self.cache().get(&entity_id.cache_key()) {
+                Some(entity) => entities.push(entity),
+                None => missing.push(entity_id),
+            }
+        }
+
+        if missing.is_empty() {
+            return Ok(entities);
+        }
+        let generation = self.cache().generation();
+
+        for entity in Backend::<T>::get_many(&*BACKEND, missing).await? {
+            self.cache()
+                .insert(generation, entity.id().cache_key(), entity.clone());
+            entities.push(entity);
+        }
+
+        Ok(entities)
+    }
+
+    async fn upsert(&self, entity: T) -> CacheResult<()> {
+        let key = entity.id().cache_key();
+
+        Backend::<T>::upsert(&*BACKEND, entity).await?;
+
+        self.cache().invalidate(Some(&key));
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<T>) -> CacheResult<()> {
+        let keys = entities
+            .iter()
+            .map(|entity| entity.id().cache_key())
+            .collect::<Vec<_>>();
+
+        Backend::<T>::upsert_many(&*BACKEND, entities).await?;
+
+        for key in keys {
+            self.cache().invalidate(Some(&key));
+        }
+
+        Ok(())
+    }
+}
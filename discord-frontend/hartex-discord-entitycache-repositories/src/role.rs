@@ -20,88 +20,85 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use std::pin::Pin;
-use std::str::FromStr;
+//! # Role Repository
+
+use std::ops::Deref;
+use std::time::Duration;
 
-use hartex_database_queries::discord_frontend::queries::cached_role_select_by_guild_id::cached_role_select_by_guild_id;
-use hartex_database_queries::discord_frontend::queries::cached_role_select_by_id_and_guild_id::cached_role_select_by_id_and_guild_id;
-use hartex_database_queries::discord_frontend::queries::cached_role_upsert::cached_role_upsert;
 use hartex_discord_core::discord::model::id::marker::GuildMarker;
 use hartex_discord_core::discord::model::id::marker::RoleMarker;
 use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
 use hartex_discord_entitycache_core::error::CacheResult;
-use hartex_discord_entitycache_core::traits::Entity;
-use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
 use hartex_discord_entitycache_entities::role::RoleEntity;
-use hartex_discord_utils::DATABASE_POOL;
-use tokio_postgres::GenericClient;
+
+use crate::backend::BACKEND;
+use crate::repository::CachedRepository;
 
 /// Repository for role entities.
 pub struct CachedRoleRepository;
 
+/// The repository of role entities.
+static REPOSITORY: CachedRepository<RoleEntity> = CachedRepository::new(MemoryCacheConfig {
+    capacity: 10_000,
+    ttl: Duration::from_secs(300),
+});
+
+impl Deref for CachedRoleRepository {
+    type Target = CachedRepository<RoleEntity>;
+
+    fn deref(&self) -> &Self::Target {
+        &REPOSITORY
+    }
+}
+
 impl CachedRoleRepository {
-    // todo: add relationship to get all roles from a guild
-    #[allow(clippy::missing_errors_doc)]
-    #[allow(clippy::missing_panics_doc)]
+    /// Returns the identifiers of all roles of a guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
     pub async fn role_ids_in_guild(
         &self,
         guild_id: Id<GuildMarker>,
     ) -> CacheResult<Vec<Id<RoleMarker>>> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
-
-        let roles = cached_role_select_by_guild_id()
-            .bind(client, &guild_id.to_string())
-            .all()
-            .await?;
-
-        Ok(roles
-            .into_iter()
-            .map(|role| Id::<RoleMarker>::from_str(&role.id).unwrap())
-            .collect())
+        let roles = GuildScopedBackend::<RoleEntity>::get_by_guild_id(&*BACKEND, guild_id).await?;
+
+        Ok(roles.into_iter().map(|role| role.id).collect())
     }
-}
 
-impl Repository<RoleEntity> for CachedRoleRepository {
-    #[allow(clippy::cast_lossless)]
-    #[allow(clippy::cast_possible_truncation)]
-    #[allow(clippy::cast_sign_loss)]
-    async fn get(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<RoleEntity> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
-
-        let data = cached_role_select_by_id_and_guild_id()
-            .bind(client, &id.to_string(), &guild_id.to_string())
-            .one()
-            .await?;
-
-        Ok(RoleEntity::from(data))
+    /// Deletes all roles of a guild from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        GuildScopedBackend::<RoleEntity>::delete_by_guild_id(&*BACKEND, guild_id).await?;
+
+        self.cache().invalidate(None);
+
+        Ok(())
     }
 
-    #[allow(clippy::cast_lossless)]
-    #[allow(clippy::cast_possible_truncation)]
-    #[allow(clippy::cast_sign_loss)]
-    async fn upsert(&self, entity: RoleEntity) -> CacheResult<()> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
-
-        cached_role_upsert()
-            .bind(
-                client,
-                &(entity.color as i64),
-                &entity.icon.map(|hash| hash.to_string()),
-                &entity.id.to_string(),
-                &entity.guild_id.to_string(),
-                &(entity.flags.bits() as i32),
-                &entity.hoist,
-                &entity.managed,
-                &entity.mentionable,
-                &(entity.position as i32),
-            )
-            .await?;
+    /// Deletes the roles of a guild that are not among the given roles from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: impl Iterator<Item = Id<RoleMarker>>,
+    ) -> CacheResult<()> {
+        GuildScopedBackend::<RoleEntity>::delete_stale_in_guild(
+            &*BACKEND,
+            guild_id,
+            ids.map(|id| (guild_id, id)).collect(),
+        )
+        .await?;
+
+        self.cache().invalidate(None);
 
         Ok(())
     }
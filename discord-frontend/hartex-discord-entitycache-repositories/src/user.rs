@@ -20,51 +20,103 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use std::pin::Pin;
+//! # User Repository
 
-use hartex_database_queries::discord_frontend::queries::cached_user_select_by_id::cached_user_select_by_id;
-use hartex_database_queries::discord_frontend::queries::cached_user_upsert::cached_user_upsert;
+use std::ops::Deref;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::keyvalue::KeyValueBackend;
+use hartex_discord_entitycache_core::backend::keyvalue::KeyValueStore;
+use hartex_discord_entitycache_core::backend::Backend;
 use hartex_discord_entitycache_core::error::CacheResult;
-use hartex_discord_entitycache_core::traits::Entity;
-use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_core::memory::CacheKey;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
+use hartex_discord_entitycache_entities::member::MemberEntity;
 use hartex_discord_entitycache_entities::user::UserEntity;
-use hartex_discord_utils::DATABASE_POOL;
-use tokio_postgres::GenericClient;
+
+use crate::backend::StorageBackend;
+use crate::backend::BACKEND;
+use crate::repository::CachedRepository;
 
 /// Repository for user entities.
 pub struct CachedUserRepository;
 
-impl Repository<UserEntity> for CachedUserRepository {
-    async fn get(&self, id: <UserEntity as Entity>::Id) -> CacheResult<UserEntity> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
+/// The repository of user entities.
+static REPOSITORY: CachedRepository<UserEntity> = CachedRepository::new(MemoryCacheConfig {
+    capacity: 50_000,
+    ttl: Duration::from_secs(300),
+});
+
+impl Deref for CachedUserRepository {
+    type Target = CachedRepository<UserEntity>;
+
+    fn deref(&self) -> &Self::Target {
+        &REPOSITORY
+    }
+}
+
+impl CachedUserRepository {
+    /// Deletes a user from the repository if the user is no longer a member of any cached guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_if_unreferenced(&self, id: Id<UserMarker>) -> CacheResult<()> {
+        match &*BACKEND {
+            StorageBackend::InMemory(backend) => delete_unreferenced(backend, Some(id)).await?,
+            StorageBackend::Postgres(backend) => backend.delete_user_if_unreferenced(id).await?,
+            StorageBackend::Redis(backend) => delete_unreferenced(backend, Some(id)).await?,
+        }
 
-        let data = cached_user_select_by_id()
-            .bind(client, &id.to_string())
-            .one()
-            .await?;
+        self.cache().invalidate(Some(&id.cache_key()));
 
-        Ok(UserEntity::from(data))
+        Ok(())
     }
 
-    async fn upsert(&self, entity: UserEntity) -> CacheResult<()> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await?;
-        let client = pooled.client();
-
-        cached_user_upsert()
-            .bind(
-                client,
-                &entity.avatar.map(|hash| hash.to_string()),
-                &entity.id.to_string(),
-                &entity.bot,
-                &entity.name,
-                &entity.discriminator.to_string(),
-                &entity.global_name,
-            )
-            .await?;
+    /// Deletes all users that are no longer a member of any cached guild from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_unreferenced(&self) -> CacheResult<()> {
+        match &*BACKEND {
+            StorageBackend::InMemory(backend) => delete_unreferenced(backend, None).await?,
+            StorageBackend::Postgres(backend) => backend.delete_unreferenced_users().await?,
+            StorageBackend::Redis(backend) => delete_unreferenced(backend, None).await?,
+        }
+
+        self.cache().invalidate(None);
 
         Ok(())
     }
 }
+
+/// Deletes the given user, or all users, that are no longer a member of any cached guild, for
+/// backends without a dedicated operation for doing so.
+///
+/// The memberships of each user are looked up in the index of the members belonging to the user,
+/// such that the members of other users need not be retrieved.
+async fn delete_unreferenced<S: KeyValueStore>(
+    backend: &KeyValueBackend<S>,
+    id: Option<Id<UserMarker>>,
+) -> CacheResult<()> {
+    let candidates = match id {
+        Some(id) => vec![id],
+        None => backend
+            .all::<UserEntity>()
+            .await?
+            .into_iter()
+            .map(|user| user.id)
+            .collect(),
+    };
+
+    for id in candidates {
+        if !backend.any_by_user_id::<MemberEntity>(id).await? {
+            Backend::<UserEntity>::delete(backend, id).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Voice State Repository
+
+use std::ops::Deref;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::backend::GuildScopedBackend;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::memory::MemoryCacheConfig;
+use hartex_discord_entitycache_entities::voice_state::VoiceStateEntity;
+
+use crate::backend::BACKEND;
+use crate::repository::CachedRepository;
+
+/// Repository for voice state entities.
+pub struct CachedVoiceStateRepository;
+
+/// The repository of voice state entities.
+static REPOSITORY: CachedRepository<VoiceStateEntity> = CachedRepository::new(MemoryCacheConfig {
+    capacity: 10_000,
+    ttl: Duration::from_secs(60),
+});
+
+impl Deref for CachedVoiceStateRepository {
+    type Target = CachedRepository<VoiceStateEntity>;
+
+    fn deref(&self) -> &Self::Target {
+        &REPOSITORY
+    }
+}
+
+impl CachedVoiceStateRepository {
+    /// Deletes all voice states of a guild from the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        GuildScopedBackend::<VoiceStateEntity>::delete_by_guild_id(&*BACKEND, guild_id).await?;
+
+        self.cache().invalidate(None);
+
+        Ok(())
+    }
+
+    /// Deletes the voice states of a guild that do not belong to any of the given users from the
+    /// repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage backend operation fails.
+    pub async fn delete_stale_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_ids: impl Iterator<Item = Id<UserMarker>>,
+    ) -> CacheResult<()> {
+        GuildScopedBackend::<VoiceStateEntity>::delete_stale_in_guild(
+            &*BACKEND,
+            guild_id,
+            user_ids.map(|user_id| (guild_id, user_id)).collect(),
+        )
+        .await?;
+
+        self.cache().invalidate(None);
+
+        Ok(())
+    }
+}
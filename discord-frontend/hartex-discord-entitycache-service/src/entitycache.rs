@@ -35,14 +35,92 @@ pub async fn update(event: GatewayEvent) -> miette::Result<()> {
     };
 
     match dispatch {
+        DispatchEvent::ChannelCreate(channel_create) => {
+            log::trace!("updating cache using CHANNEL_CREATE event");
+            channel_create.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ChannelDelete(channel_delete) => {
+            log::trace!("updating cache using CHANNEL_DELETE event");
+            channel_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ChannelUpdate(channel_update) => {
+            log::trace!("updating cache using CHANNEL_UPDATE event");
+            channel_update.update().await.into_diagnostic()?;
+        }
         DispatchEvent::GuildCreate(guild_create) => {
             log::trace!("updating cache using GUILD_CREATE event");
             guild_create.update().await.into_diagnostic()?;
         }
+        DispatchEvent::GuildDelete(guild_delete) => {
+            log::trace!("updating cache using GUILD_DELETE event");
+            guild_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::GuildEmojisUpdate(guild_emojis_update) => {
+            log::trace!("updating cache using GUILD_EMOJIS_UPDATE event");
+            guild_emojis_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::GuildStickersUpdate(guild_stickers_update) => {
+            log::trace!("updating cache using GUILD_STICKERS_UPDATE event");
+            guild_stickers_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::GuildUpdate(guild_update) => {
+            log::trace!("updating cache using GUILD_UPDATE event");
+            guild_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::MemberAdd(member_add) => {
+            log::trace!("updating cache using GUILD_MEMBER_ADD event");
+            member_add.update().await.into_diagnostic()?;
+        }
         DispatchEvent::MemberChunk(member_chunk) => {
             log::trace!("updating cache using GUILD_MEMBER_CHUNK event");
             member_chunk.update().await.into_diagnostic()?;
         }
+        DispatchEvent::MemberRemove(member_remove) => {
+            log::trace!("updating cache using GUILD_MEMBER_REMOVE event");
+            member_remove.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::MemberUpdate(member_update) => {
+            log::trace!("updating cache using GUILD_MEMBER_UPDATE event");
+            member_update.update().await.into_diagnostic()?;
+        }
+        // messages are cached by the worker, such that the creation, update and deletion of a
+        // message are applied in order on the lane of its guild
+        DispatchEvent::RoleCreate(role_create) => {
+            log::trace!("updating cache using GUILD_ROLE_CREATE event");
+            role_create.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::RoleDelete(role_delete) => {
+            log::trace!("updating cache using GUILD_ROLE_DELETE event");
+            role_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::RoleUpdate(role_update) => {
+            log::trace!("updating cache using GUILD_ROLE_UPDATE event");
+            role_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ThreadCreate(thread_create) => {
+            log::trace!("updating cache using THREAD_CREATE event");
+            thread_create.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ThreadDelete(thread_delete) => {
+            log::trace!("updating cache using THREAD_DELETE event");
+            thread_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ThreadListSync(thread_list_sync) => {
+            log::trace!("updating cache using THREAD_LIST_SYNC event");
+            thread_list_sync.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ThreadUpdate(thread_update) => {
+            log::trace!("updating cache using THREAD_UPDATE event");
+            thread_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::UserUpdate(user_update) => {
+            log::trace!("updating cache using USER_UPDATE event");
+            user_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::VoiceStateUpdate(voice_state_update) => {
+            log::trace!("updating cache using VOICE_STATE_UPDATE event");
+            voice_state_update.update().await.into_diagnostic()?;
+        }
         _ => (),
     }
 
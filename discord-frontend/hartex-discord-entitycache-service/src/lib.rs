@@ -0,0 +1,274 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Entitycache Update Service
+//!
+//! A separate service for updating the entitycache when event payloads are received from Discord
+//! via the websocket gateway.
+//!
+//! The service can either be run as its own process, or within the all-in-one process together
+//! with the leader and the worker.
+//!
+//! Once an event has been handled, the invalidations of the in-memory entity caches performed
+//! while handling it are broadcast to the workers, such that they no longer serve entities that
+//! have since been updated.
+//!
+//! The service also periodically deletes the cached messages that have been retained for longer
+//! than the configured retention.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use std::env;
+use std::future::Future;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::gateway::event::GatewayEvent;
+use hartex_discord_core::discord::model::gateway::event::GatewayEventDeserializer;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::time;
+use hartex_discord_entitycache_core::invalidation;
+use hartex_discord_entitycache_core::invalidation::Invalidation;
+use hartex_discord_entitycache_repositories::backend;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+use hartex_discord_internal_events::deadletter::DeadLetterQueue;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_internal_events::envelope::EnvelopeError;
+use hartex_discord_internal_events::retry::RetryPolicy;
+use hartex_discord_transport::Delivery;
+use hartex_discord_transport::Producer;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use serde::de::DeserializeSeed;
+
+mod entitycache;
+
+/// The maximum number of invalidations carried by a single message.
+const INVALIDATIONS_PER_MESSAGE: usize = 1000;
+
+/// The interval at which expired and excess messages are deleted from the entity cache.
+const MESSAGE_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Run the entitycache update service with the given transport until the shutdown future
+/// completes.
+///
+/// # Errors
+///
+/// Returns an error if the environment is misconfigured, if the transport cannot be set up, or if
+/// an event can neither be handled nor be sent to the dead-letter topic.
+pub async fn run(transport: Transport, shutdown: impl Future<Output = ()>) -> miette::Result<()> {
+    backend::init()?;
+
+    let topic = env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_CACHE").into_diagnostic()?;
+    let invalidation_topic =
+        env::var("KAFKA_TOPIC_INTERNAL_ENTITYCACHE_INVALIDATION").into_diagnostic()?;
+
+    let producer = transport.producer()?;
+    let retry_policy = RetryPolicy::from_env()?;
+    let dead_letters = DeadLetterQueue::new("entitycache", producer.clone())?;
+    let consumer = transport.consumer(
+        "com.github.teamhartex.hartex.inbound.gateway.payload.consumer",
+        &[&topic],
+    )?;
+
+    let mut message_sweep_interval = time::interval(MESSAGE_SWEEP_INTERVAL);
+
+    tokio::pin!(shutdown);
+
+    loop {
+        let result = tokio::select! {
+            biased;
+            () = &mut shutdown => {
+                log::warn!("shutdown signal received, no longer polling for new events");
+                break;
+            }
+            _ = message_sweep_interval.tick() => {
+                sweep_messages(&producer, &invalidation_topic).await;
+
+                continue;
+            }
+            result = consumer.recv() => result,
+        };
+
+        let delivery = match result {
+            Ok(delivery) => delivery,
+            Err(error) => {
+                println!("{error:?}");
+
+                continue;
+            }
+        };
+
+        // the cache update is fully performed, or sent to the dead-letter topic, before the
+        // shutdown signal is checked again, hence no in-flight updates are lost upon shutdown
+        handle(
+            &delivery,
+            &retry_policy,
+            &dead_letters,
+            &producer,
+            &invalidation_topic,
+        )
+        .await?;
+        consumer.store_offset(&delivery)?;
+    }
+
+    log::trace!("flushing pending dead letters");
+    producer.flush(Duration::from_secs(30))?;
+
+    log::trace!("committing consumer offsets");
+    if let Err(error) = consumer.commit() {
+        println!("{error:?}");
+    }
+
+    log::warn!("shut down gracefully");
+
+    Ok(())
+}
+
+/// Handle a message received from the leader.
+///
+/// Messages that cannot be decoded are sent to the dead-letter topic right away, whereas cache
+/// updates that fail are retried according to the retry policy first.
+async fn handle(
+    delivery: &Delivery,
+    retry_policy: &RetryPolicy,
+    dead_letters: &DeadLetterQueue,
+    producer: &Producer,
+    invalidation_topic: &str,
+) -> miette::Result<()> {
+    let Some(bytes) = delivery.payload.as_deref() else {
+        return Ok(());
+    };
+
+    let envelope: Envelope = match Envelope::from_bytes(bytes).into_diagnostic() {
+        Ok(envelope) => envelope,
+        Err(error) => {
+            println!("{error:?}");
+
+            return dead_letters.send(delivery, None, &error, 1).await;
+        }
+    };
+
+    log::trace!(
+        "[shard {shard_id}] received {event_type} event (schema v{version}); attempting to deserialize",
+        shard_id = envelope.shard_id,
+        event_type = envelope.event_type,
+        version = envelope.version,
+    );
+    let event = match decode(&envelope) {
+        Ok(event) => event,
+        Err(error) => {
+            println!("{error:?}");
+
+            return dead_letters
+                .send(delivery, Some(&envelope), &error, 1)
+                .await;
+        }
+    };
+
+    let (result, invalidations) =
+        invalidation::collect(retry_policy.run(|| entitycache::update(event.clone()))).await;
+
+    // failed attempts may have partially updated the cache, hence the invalidations are broadcast
+    // regardless of whether the update eventually succeeded
+    broadcast(
+        producer,
+        invalidation_topic,
+        envelope.shard_id,
+        &invalidations,
+    )
+    .await;
+
+    if let Err((error, attempts)) = result {
+        println!("{error:?}");
+
+        return dead_letters
+            .send(delivery, Some(&envelope), &error, attempts)
+            .await;
+    }
+
+    Ok(())
+}
+
+/// Delete the cached messages that have been retained for longer than the configured retention,
+/// as well as the oldest messages of guilds with more messages than the configured capacity.
+async fn sweep_messages(producer: &Producer, invalidation_topic: &str) {
+    log::trace!("deleting expired and excess messages from the entity cache");
+
+    let (result, invalidations) = invalidation::collect(async {
+        CachedMessageRepository.delete_expired().await?;
+        CachedMessageRepository.delete_excess().await
+    })
+    .await;
+
+    broadcast(producer, invalidation_topic, 0, &invalidations).await;
+
+    if let Err(error) = result.into_diagnostic() {
+        println!("{error:?}");
+    }
+}
+
+/// Broadcast invalidations of the in-memory entity caches to the workers.
+///
+/// The invalidations are split across multiple messages if there are many of them, such as after
+/// a large guild has been created.
+async fn broadcast(
+    producer: &Producer,
+    topic: &str,
+    shard_id: u32,
+    invalidations: &[Invalidation],
+) {
+    for chunk in invalidations.chunks(INVALIDATIONS_PER_MESSAGE) {
+        let bytes = match Envelope::new(shard_id, "ENTITYCACHE_INVALIDATION", chunk).to_bytes() {
+            Ok(bytes) => bytes,
+            Err(error) => {
+                println!("{:?}", Err::<(), EnvelopeError>(error).into_diagnostic());
+
+                return;
+            }
+        };
+
+        if let Err(error) = producer
+            .send(topic, "INTERNAL_EVENT_ENTITYCACHE_INVALIDATION", &bytes)
+            .await
+        {
+            println!("{error:?}");
+        }
+    }
+}
+
+/// Decode the gateway event carried by an envelope.
+fn decode(envelope: &Envelope) -> miette::Result<GatewayEvent> {
+    let payload = envelope.payload.get();
+    let gateway_deserializer = GatewayEventDeserializer::from_json(payload)
+        .ok_or(Error::new(ErrorKind::Other, "invalid gateway payload"))
+        .into_diagnostic()?;
+    let mut json_deserializer = serde_json::Deserializer::from_str(payload);
+
+    gateway_deserializer
+        .deserialize(&mut json_deserializer)
+        .into_diagnostic()
+}
@@ -0,0 +1,131 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Gateway Command Bus
+//!
+//! The command bus sends gateway commands to the leader, routed to the shard responsible for the
+//! guild the command is targeted at.
+//!
+//! This is used by the workers as well as by the operational tooling.
+
+use std::env;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+
+use hartex_discord_transport::Producer;
+use miette::IntoDiagnostic;
+
+use crate::command::shard_id_for_guild;
+use crate::command::GatewayCommand;
+use crate::command::OutboundCommand;
+use crate::envelope::Envelope;
+
+/// Counter for generating unique command IDs within this process.
+static COMMAND_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A bus for sending gateway commands to the leader.
+#[derive(Clone)]
+pub struct CommandBus {
+    producer: Producer,
+    shard_total: u32,
+    topic: String,
+}
+
+impl CommandBus {
+    /// Create a new command bus with the given producer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `SHARD_TOTAL` or `KAFKA_TOPIC_OUTBOUND_COMMUNICATION` environment
+    /// variables are missing or invalid.
+    pub fn new(producer: Producer) -> miette::Result<Self> {
+        let shard_total = env::var("SHARD_TOTAL")
+            .into_diagnostic()?
+            .parse()
+            .into_diagnostic()?;
+        let topic = env::var("KAFKA_TOPIC_OUTBOUND_COMMUNICATION").into_diagnostic()?;
+
+        Ok(Self {
+            producer,
+            shard_total,
+            topic,
+        })
+    }
+
+    /// Send a gateway command, returning its unique ID.
+    ///
+    /// Commands targeted at a guild are routed to the shard responsible for the guild; other
+    /// commands are sent to every shard. Member requests without a nonce are assigned the ID of
+    /// the command as their nonce.
+    ///
+    /// Commands are produced to the partition of the outbound topic matching the ID of the shard,
+    /// hence the topic must have at least as many partitions as there are shards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be serialized or produced.
+    pub async fn send(&self, mut command: GatewayCommand) -> miette::Result<String> {
+        let id = next_command_id();
+
+        if let GatewayCommand::RequestGuildMembers(request) = &mut command {
+            request.d.nonce.get_or_insert_with(|| id.clone());
+        }
+
+        let shard_ids = match command.guild_id() {
+            Some(guild_id) => vec![shard_id_for_guild(guild_id, self.shard_total)],
+            None => (0..self.shard_total).collect(),
+        };
+
+        let outbound = OutboundCommand {
+            command,
+            id: id.clone(),
+        };
+
+        for shard_id in shard_ids {
+            let bytes = Envelope::new(shard_id, "GATEWAY_COMMAND", &outbound)
+                .to_bytes()
+                .into_diagnostic()?;
+
+            self.producer
+                .send_to_partition(
+                    &self.topic,
+                    shard_id,
+                    &format!("OUTBOUND_GATEWAY_COMMAND_SHARD_{shard_id}"),
+                    &bytes,
+                )
+                .await?;
+        }
+
+        Ok(id)
+    }
+}
+
+/// Generate a unique ID for a command.
+fn next_command_id() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis());
+    let counter = COMMAND_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    format!("{timestamp:x}-{counter:x}")
+}
@@ -0,0 +1,103 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Gateway Commands
+//!
+//! Gateway commands sent by the workers to the leader, to be sent to Discord through the
+//! appropriate shard.
+
+use hartex_discord_core::discord::model::gateway::payload::outgoing::RequestGuildMembers;
+use hartex_discord_core::discord::model::gateway::payload::outgoing::UpdatePresence;
+use hartex_discord_core::discord::model::gateway::payload::outgoing::UpdateVoiceState;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::presence::PresenceTemplate;
+
+/// A gateway command.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "kind", content = "command", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GatewayCommand {
+    /// Request members of a guild.
+    RequestGuildMembers(RequestGuildMembers),
+    /// Set the presence of the bot from a template, rendered by each shard.
+    SetPresence(PresenceTemplate),
+    /// Update the presence of the bot.
+    UpdatePresence(UpdatePresence),
+    /// Update the voice state of the bot in a guild.
+    UpdateVoiceState(UpdateVoiceState),
+}
+
+impl GatewayCommand {
+    /// The guild the command is targeted at, if any.
+    #[must_use]
+    pub fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        match self {
+            Self::RequestGuildMembers(command) => Some(command.d.guild_id),
+            Self::SetPresence(_) | Self::UpdatePresence(_) => None,
+            Self::UpdateVoiceState(command) => Some(command.d.guild_id),
+        }
+    }
+
+    /// The name of the kind of the command.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::RequestGuildMembers(_) => "REQUEST_GUILD_MEMBERS",
+            Self::SetPresence(_) => "SET_PRESENCE",
+            Self::UpdatePresence(_) => "UPDATE_PRESENCE",
+            Self::UpdateVoiceState(_) => "UPDATE_VOICE_STATE",
+        }
+    }
+}
+
+/// A gateway command sent to a shard, identified by a unique ID.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OutboundCommand {
+    /// The command itself.
+    pub command: GatewayCommand,
+    /// The unique ID of the command, used for correlating delivery failures.
+    pub id: String,
+}
+
+/// Emitted when a gateway command cannot be delivered by a shard.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommandDeliveryFailed {
+    /// The ID of the command.
+    pub command_id: Option<String>,
+    /// The kind of the command, if known.
+    pub kind: Option<String>,
+    /// The reason of the failure.
+    pub reason: String,
+    /// The ID of the shard the command was destined for.
+    pub shard_id: u32,
+}
+
+/// Obtain the ID of the shard that receives events for a given guild.
+#[allow(clippy::cast_possible_truncation)]
+#[must_use]
+pub fn shard_id_for_guild(guild_id: Id<GuildMarker>, shard_total: u32) -> u32 {
+    ((guild_id.get() >> 22) % u64::from(shard_total)) as u32
+}
@@ -0,0 +1,129 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Dead Letters
+//!
+//! Events that cannot be processed, either because they cannot be decoded or because processing
+//! them keeps failing after all retries, are sent to the dead-letter topic instead of being
+//! dropped. Dead letters carry the original payload, such that they can be inspected and re-driven
+//! into the topic they originate from.
+
+use std::env;
+
+use hartex_discord_transport::Delivery;
+use hartex_discord_transport::Producer;
+use miette::IntoDiagnostic;
+use miette::Report;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::envelope::Envelope;
+
+/// An event that could not be processed.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeadLetter {
+    /// The number of attempts made at processing the event.
+    pub attempts: u32,
+    /// The name of the consumer that failed to process the event.
+    pub consumer: String,
+    /// The error that occurred when the event was last processed.
+    pub error: String,
+    /// The type of the event, if the event could be decoded.
+    pub event_type: Option<String>,
+    /// The key of the original message, if any.
+    pub key: Option<String>,
+    /// The original payload of the message.
+    pub payload: String,
+    /// The topic the original message was consumed from.
+    pub topic: String,
+}
+
+/// A queue for sending dead letters.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
+pub struct DeadLetterQueue {
+    consumer: String,
+    producer: Producer,
+    topic: String,
+}
+
+impl DeadLetterQueue {
+    /// Create a new dead-letter queue for the given consumer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `KAFKA_TOPIC_DEAD_LETTER` environment variable is missing.
+    pub fn new(consumer: impl Into<String>, producer: Producer) -> miette::Result<Self> {
+        let topic = env::var("KAFKA_TOPIC_DEAD_LETTER").into_diagnostic()?;
+
+        Ok(Self {
+            consumer: consumer.into(),
+            producer,
+            topic,
+        })
+    }
+
+    /// Send a delivery that could not be processed to the dead-letter topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dead letter cannot be serialized or produced.
+    pub async fn send(
+        &self,
+        delivery: &Delivery,
+        envelope: Option<&Envelope>,
+        error: &Report,
+        attempts: u32,
+    ) -> miette::Result<()> {
+        let dead_letter = DeadLetter {
+            attempts,
+            consumer: self.consumer.clone(),
+            error: error
+                .chain()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(": "),
+            event_type: envelope.map(|envelope| envelope.event_type.clone()),
+            key: delivery.key.clone(),
+            payload: delivery
+                .payload
+                .as_deref()
+                .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+                .unwrap_or_default(),
+            topic: delivery.topic.clone(),
+        };
+
+        let shard_id = envelope.map_or(0, |envelope| envelope.shard_id);
+        let bytes = Envelope::new(shard_id, "DEAD_LETTER", &dead_letter)
+            .to_bytes()
+            .into_diagnostic()?;
+
+        self.producer
+            .send(
+                &self.topic,
+                &format!("DEAD_LETTER_{consumer}", consumer = self.consumer),
+                &bytes,
+            )
+            .await
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Envelopes
+//!
+//! All messages exchanged between the leader, the worker and the entity cache service over Kafka
+//! are wrapped in a versioned envelope, carrying metadata about the message alongside its
+//! payload.
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::time::SystemTime;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::value::RawValue;
+
+/// The current schema version of envelopes.
+pub const SCHEMA_VERSION: u16 = 1;
+
+/// A versioned envelope wrapping a payload.
+///
+/// By default, the payload is kept as raw JSON such that it can be deserialized lazily once the
+/// event type is known.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Envelope<P = Box<RawValue>> {
+    /// The type of the event carried by this envelope.
+    pub event_type: String,
+    /// The ID of the guild the event belongs to, if any, such that consumers can route the event
+    /// without deserializing its payload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<String>,
+    /// The payload.
+    pub payload: P,
+    /// The ID of the shard the event originates from or is destined for.
+    pub shard_id: u32,
+    /// The time the envelope was created, in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The schema version of the envelope.
+    pub version: u16,
+}
+
+impl<P> Envelope<P> {
+    /// Create a new envelope with the current schema version and timestamp.
+    pub fn new(shard_id: u32, event_type: impl Into<String>, payload: P) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |duration| {
+                u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+            });
+
+        Self {
+            event_type: event_type.into(),
+            guild_id: None,
+            payload,
+            shard_id,
+            timestamp,
+            version: SCHEMA_VERSION,
+        }
+    }
+}
+
+impl<P: Serialize> Envelope<P> {
+    /// Serialize the envelope into bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be serialized.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EnvelopeError> {
+        serde_json::to_vec(self).map_err(EnvelopeError::Json)
+    }
+}
+
+impl<P: DeserializeOwned> Envelope<P> {
+    /// Deserialize an envelope from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the envelope has an unsupported schema version, or if the envelope
+    /// cannot be deserialized.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EnvelopeError> {
+        let probe = serde_json::from_slice::<VersionProbe>(bytes).map_err(EnvelopeError::Json)?;
+        if probe.version != SCHEMA_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(probe.version));
+        }
+
+        serde_json::from_slice(bytes).map_err(EnvelopeError::Json)
+    }
+}
+
+impl Envelope {
+    /// Create a new envelope from a raw JSON payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is not valid JSON.
+    pub fn from_json(
+        shard_id: u32,
+        event_type: impl Into<String>,
+        json: String,
+    ) -> Result<Self, EnvelopeError> {
+        let payload = RawValue::from_string(json).map_err(EnvelopeError::Json)?;
+
+        Ok(Self::new(shard_id, event_type, payload))
+    }
+
+    /// Deserialize the raw payload into a concrete type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload cannot be deserialized into the given type.
+    pub fn payload_as<T: DeserializeOwned>(&self) -> Result<T, EnvelopeError> {
+        serde_json::from_str(self.payload.get()).map_err(EnvelopeError::Json)
+    }
+}
+
+/// An error occurred when serializing or deserializing an envelope.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub enum EnvelopeError {
+    /// A JSON error occurred.
+    Json(serde_json::Error),
+    /// The envelope has a schema version that is not supported.
+    UnsupportedVersion(u16),
+}
+
+impl Display for EnvelopeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => write!(f, "json error: {error}"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported envelope schema version {version} (expected {SCHEMA_VERSION})"
+            ),
+        }
+    }
+}
+
+impl Error for EnvelopeError {}
+
+/// Used for checking the schema version of an envelope before deserializing it fully.
+#[derive(Deserialize)]
+struct VersionProbe {
+    version: u16,
+}
@@ -21,3 +21,16 @@
  */
 
 //! # Payloads for internal events used within HarTex.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+pub mod bus;
+pub mod command;
+pub mod deadletter;
+pub mod envelope;
+pub mod presence;
+pub mod retry;
+pub mod shard;
+pub mod worker;
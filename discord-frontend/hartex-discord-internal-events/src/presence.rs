@@ -0,0 +1,134 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Presence Templates
+//!
+//! Presences are configured as templates, which are rendered for each shard with the following
+//! placeholders:
+//!
+//! - `{shard}`: the ID of the shard
+//! - `{shard_total}`: the total number of shards
+//! - `{guild_count}`: the number of guilds the bot is in
+
+use hartex_discord_core::discord::model::gateway::payload::outgoing::update_presence::UpdatePresencePayload;
+use hartex_discord_core::discord::model::gateway::payload::outgoing::UpdatePresence;
+use hartex_discord_core::discord::model::gateway::presence::Activity;
+use hartex_discord_core::discord::model::gateway::presence::ActivityType;
+use hartex_discord_core::discord::model::gateway::presence::Status;
+use hartex_discord_core::discord::model::gateway::OpCode;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A presence template.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PresenceTemplate {
+    /// The type of the activity.
+    pub activity_type: ActivityType,
+    /// The status.
+    pub status: Status,
+    /// The template of the activity text.
+    pub template: String,
+}
+
+impl PresenceTemplate {
+    /// Render the template into a presence payload for a given shard.
+    #[must_use]
+    pub fn render(
+        &self,
+        shard_id: u32,
+        shard_total: u32,
+        guild_count: u64,
+    ) -> UpdatePresencePayload {
+        let text = self
+            .template
+            .replace("{shard}", &shard_id.to_string())
+            .replace("{shard_total}", &shard_total.to_string())
+            .replace("{guild_count}", &guild_count.to_string());
+
+        // custom statuses display the state rather than the name of the activity
+        let state = (self.activity_type == ActivityType::Custom).then(|| text.clone());
+
+        UpdatePresencePayload {
+            activities: vec![Activity {
+                application_id: None,
+                assets: None,
+                buttons: vec![],
+                created_at: None,
+                details: None,
+                emoji: None,
+                flags: None,
+                id: None,
+                instance: None,
+                kind: self.activity_type,
+                name: text,
+                party: None,
+                secrets: None,
+                state,
+                timestamps: None,
+                url: None,
+            }],
+            afk: false,
+            since: None,
+            status: self.status,
+        }
+    }
+
+    /// Render the template into a presence update command for a given shard.
+    #[must_use]
+    pub fn render_command(
+        &self,
+        shard_id: u32,
+        shard_total: u32,
+        guild_count: u64,
+    ) -> UpdatePresence {
+        UpdatePresence {
+            d: self.render(shard_id, shard_total, guild_count),
+            op: OpCode::PresenceUpdate,
+        }
+    }
+}
+
+/// Parse an activity type from its name.
+#[must_use]
+pub fn parse_activity_type(name: &str) -> Option<ActivityType> {
+    Some(match name.to_lowercase().as_str() {
+        "competing" => ActivityType::Competing,
+        "custom" => ActivityType::Custom,
+        "listening" => ActivityType::Listening,
+        "playing" => ActivityType::Playing,
+        "streaming" => ActivityType::Streaming,
+        "watching" => ActivityType::Watching,
+        _ => return None,
+    })
+}
+
+/// Parse a status from its name.
+#[must_use]
+pub fn parse_status(name: &str) -> Option<Status> {
+    Some(match name.to_lowercase().as_str() {
+        "dnd" => Status::DoNotDisturb,
+        "idle" => Status::Idle,
+        "invisible" => Status::Invisible,
+        "online" => Status::Online,
+        _ => return None,
+    })
+}
@@ -0,0 +1,149 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Retry Policy
+//!
+//! Processing an event is retried with exponential backoff before the event is given up on and
+//! sent to the dead-letter topic.
+//!
+//! The policy is configured with the following environment variables, all of which are optional:
+//!
+//! - `RETRY_MAX_ATTEMPTS`: the maximum number of attempts, defaults to 3
+//! - `RETRY_BACKOFF_INITIAL_MS`: the delay before the first retry, defaults to 500
+//! - `RETRY_BACKOFF_MAX_MS`: the maximum delay between retries, defaults to 30000
+//! - `RETRY_BACKOFF_MULTIPLIER`: the factor the delay grows by after each retry, defaults to 2
+
+use std::env;
+use std::error::Error;
+use std::future::Future;
+use std::str::FromStr;
+use std::time::Duration;
+
+use hartex_discord_core::tokio::time::sleep;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use miette::Report;
+
+/// A policy for retrying failed operations.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// The maximum delay between retries.
+    pub max_backoff: Duration,
+    /// The factor the delay grows by after each retry.
+    pub multiplier: f64,
+}
+
+impl RetryPolicy {
+    /// Obtain the retry policy configured by the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the environment variables is invalid.
+    pub fn from_env() -> miette::Result<Self> {
+        let max_attempts = var_or("RETRY_MAX_ATTEMPTS", 3u32)?;
+        if max_attempts == 0 {
+            return Err(Report::msg("RETRY_MAX_ATTEMPTS must be at least 1"));
+        }
+
+        let multiplier = var_or("RETRY_BACKOFF_MULTIPLIER", 2f64)?;
+        if !multiplier.is_finite() || multiplier < 1.0 {
+            return Err(Report::msg(format!(
+                "RETRY_BACKOFF_MULTIPLIER must be a finite number of at least 1, got {multiplier}"
+            )));
+        }
+
+        Ok(Self {
+            initial_backoff: Duration::from_millis(var_or("RETRY_BACKOFF_INITIAL_MS", 500u64)?),
+            max_attempts,
+            max_backoff: Duration::from_millis(var_or("RETRY_BACKOFF_MAX_MS", 30000u64)?),
+            multiplier,
+        })
+    }
+
+    /// The delay before the retry following the given attempt, starting at 1.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let millis = (self.initial_backoff.as_millis() as f64 * self.multiplier.powi(exponent))
+            .min(self.max_backoff.as_millis() as f64);
+
+        Duration::from_millis(millis as u64)
+    }
+
+    /// Run an operation until it succeeds or the maximum number of attempts is reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt along with the number of attempts made, if all
+    /// attempts have failed.
+    pub async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T, (Report, u32)>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = miette::Result<T>>,
+    {
+        let mut attempt = 1;
+
+        loop {
+            match operation().await {
+                Ok(value) => return Ok(value),
+                Err(error) if attempt < self.max_attempts => {
+                    let backoff = self.backoff(attempt);
+                    log::warn!(
+                        "attempt {attempt} of {max_attempts} failed, retrying in {backoff:?}: {error}",
+                        max_attempts = self.max_attempts,
+                    );
+
+                    sleep(backoff).await;
+                    attempt += 1;
+                }
+                Err(error) => return Err((error, attempt)),
+            }
+        }
+    }
+}
+
+/// Read an optional environment variable, falling back to a default if it is not set.
+///
+/// # Errors
+///
+/// Returns an error if the environment variable is set but cannot be parsed, or is not valid
+/// unicode.
+pub fn var_or<T>(name: &str, default: T) -> miette::Result<T>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    match env::var(name) {
+        Ok(value) => value.parse().into_diagnostic(),
+        Err(env::VarError::NotPresent) => Ok(default),
+        Err(error) => Err(error).into_diagnostic(),
+    }
+}
@@ -0,0 +1,45 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Shard Events
+//!
+//! Events emitted by the leader regarding the lifecycle of its shards.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Emitted when the gateway connection of a shard is closed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShardClosed {
+    /// The close code received, if any.
+    pub code: Option<u16>,
+    /// Whether the close code is fatal, in which case the shard will not reconnect.
+    pub fatal: bool,
+    /// The reason of closing the connection, if any.
+    pub reason: Option<String>,
+    /// Whether the session of the shard can still be resumed after this close.
+    pub resumable: bool,
+    /// The ID of the shard.
+    pub shard_id: u32,
+    /// The total number of shards.
+    pub shard_total: u32,
+}
@@ -0,0 +1,41 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Worker Events
+//!
+//! Events emitted by the workers regarding their own operation.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Emitted periodically with the state of the event executor of a worker.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WorkerMetrics {
+    /// The number of events received but not yet fully processed.
+    pub in_flight: usize,
+    /// The number of events processed since the worker started.
+    pub processed: u64,
+    /// The capacity of the queue of each lane.
+    pub queue_capacity: usize,
+    /// The number of events waiting in the queue of each lane.
+    pub queue_depths: Vec<usize>,
+}
@@ -0,0 +1,111 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Close Frame Handling
+//!
+//! Close frames received from the gateway are classified, logged and published as structured
+//! internal events; the persisted session of the shard is invalidated if it cannot be resumed.
+
+use std::env;
+
+use hartex_discord_core::discord::gateway::CloseFrame;
+use hartex_discord_core::discord::gateway::ShardId;
+use hartex_discord_core::discord::model::gateway::CloseCode;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_internal_events::shard::ShardClosed;
+use hartex_discord_transport::Producer;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+use crate::session;
+
+/// Close codes upon which Discord invalidates the session, even though reconnecting is possible.
+const SESSION_INVALIDATING_CODES: [u16; 4] = [1000, 1001, 4007, 4009];
+
+/// Handle a close frame received from the gateway for a given shard.
+pub async fn handle(
+    shard_id: ShardId,
+    frame: Option<&CloseFrame<'_>>,
+    producer: &Producer,
+) -> miette::Result<()> {
+    let event = classify(shard_id, frame);
+
+    if event.fatal {
+        log::error!(
+            "[shard {shard_id}] gateway connection closed with fatal close code {code:?}: {reason}",
+            shard_id = event.shard_id,
+            code = event.code,
+            reason = event.reason.as_deref().unwrap_or_default(),
+        );
+    } else {
+        log::warn!(
+            "[shard {shard_id}] gateway connection closed with close code {code:?} (resumable: {resumable}): {reason}",
+            shard_id = event.shard_id,
+            code = event.code,
+            resumable = event.resumable,
+            reason = event.reason.as_deref().unwrap_or_default(),
+        );
+    }
+
+    if !event.resumable {
+        log::trace!(
+            "[shard {shard_id}] invalidating persisted session",
+            shard_id = event.shard_id
+        );
+        session::invalidate(shard_id).await?;
+    }
+
+    let topic = env::var("KAFKA_TOPIC_INTERNAL_EVENT_PAYLOAD").into_diagnostic()?;
+    let bytes = Envelope::new(event.shard_id, "SHARD_CLOSED", &event)
+        .to_bytes()
+        .into_diagnostic()?;
+
+    let key = format!(
+        "INTERNAL_EVENT_SHARD_CLOSED_{shard_id}",
+        shard_id = event.shard_id
+    );
+    if let Err(error) = producer.send(&topic, &key, &bytes).await {
+        println!("{error:?}");
+    }
+
+    Ok(())
+}
+
+/// Classify a close frame into a structured event.
+fn classify(shard_id: ShardId, frame: Option<&CloseFrame<'_>>) -> ShardClosed {
+    let code = frame.map(CloseFrame::code);
+    let fatal = code
+        .and_then(|code| CloseCode::try_from(code).ok())
+        .is_some_and(|code| !code.can_reconnect());
+    let resumable = !fatal && !code.is_some_and(|code| SESSION_INVALIDATING_CODES.contains(&code));
+
+    ShardClosed {
+        code,
+        fatal,
+        reason: frame
+            .map(|frame| frame.reason().to_string())
+            .filter(|reason| !reason.is_empty()),
+        resumable,
+        shard_id: shard_id.number(),
+        shard_total: shard_id.total(),
+    }
+}
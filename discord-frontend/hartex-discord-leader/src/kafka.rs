@@ -21,49 +21,76 @@
  */
 
 use std::env;
-use std::str;
-use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use futures_util::StreamExt as FutureStreamExt;
 use hartex_discord_core::discord::gateway::queue::Queue;
 use hartex_discord_core::discord::gateway::Message as GatewayMessage;
 use hartex_discord_core::discord::gateway::MessageSender;
 use hartex_discord_core::discord::gateway::Shard;
-use hartex_discord_core::discord::model::gateway::payload::outgoing::RequestGuildMembers;
+use hartex_discord_core::discord::gateway::ShardId;
+use hartex_discord_core::discord::model::gateway::event::GatewayEventDeserializer;
 use hartex_discord_core::tokio;
+use hartex_discord_internal_events::command::CommandDeliveryFailed;
+use hartex_discord_internal_events::command::GatewayCommand;
+use hartex_discord_internal_events::command::OutboundCommand;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_internal_events::envelope::EnvelopeError;
+use hartex_discord_transport::Consumer;
+use hartex_discord_transport::Delivery;
+use hartex_discord_transport::Producer;
+use hartex_discord_transport::Transport;
 use hartex_log::log;
 use miette::IntoDiagnostic;
-use rdkafka::consumer::StreamConsumer;
-use rdkafka::error::KafkaError;
-use rdkafka::producer::FutureProducer;
-use rdkafka::producer::FutureRecord;
-use rdkafka::util::Timeout;
-use rdkafka::Message;
-use serde_scan::scan;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::close;
+use crate::presence;
+use crate::session;
+
+/// The interval at which the sequence of a session is persisted.
+const SESSION_PERSIST_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Create a consumer for the outbound commands of a given shard.
+///
+/// Outbound commands are produced to the partition matching the ID of the shard they are destined
+/// for; the consumer is assigned that partition only, such that each shard receives its own
+/// commands exactly once, regardless of which leader process the shard runs in.
+pub fn outbound_consumer(
+    transport: &Transport,
+    topic: &str,
+    shard_id: u32,
+) -> miette::Result<Consumer> {
+    transport.partition_consumer(
+        "com.github.teamhartex.hartex.inbound.gateway.command.consumer",
+        topic,
+        shard_id,
+    )
+}
 
 /// Handle inbound AND outbound messages for a given shard.
 pub async fn handle<'a, Q>(
     shard: &mut Shard<Q>,
-    producer: FutureProducer,
-    consumer: Arc<StreamConsumer>,
+    producer: Producer,
+    consumer: Consumer,
 ) -> miette::Result<()>
 where
     Q: Queue + Send + Sync + Sized + Unpin + 'static,
 {
-    let shard_id = shard.id().number();
+    let shard_id = shard.id();
     let sender = shard.sender();
     tokio::select! {
-        _ = inbound(shard, producer) => {},
-        _ = outbound((shard_id, sender), consumer) => {}
+        _ = inbound(shard, producer.clone()) => {},
+        _ = outbound((shard_id, sender), consumer, producer) => {}
     }
 
     Ok(())
 }
 
 /// Handle inbound traffic.
-#[allow(clippy::match_wildcard_for_single_variants)]
-async fn inbound<Q>(shard: &mut Shard<Q>, producer: FutureProducer) -> miette::Result<()>
+async fn inbound<Q>(shard: &mut Shard<Q>, producer: Producer) -> miette::Result<()>
 where
     Q: Queue + Send + Sync + Sized + Unpin + 'static,
 {
@@ -71,56 +98,63 @@ where
     let topic_2 =
         env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_CACHE").into_diagnostic()?;
 
+    let mut last_persisted = Instant::now();
+
     while let Some(result) = shard.next().await {
         match result {
             Ok(message) => {
-                let Some(bytes) = (match message {
-                    // todo: handle close frame
-                    GatewayMessage::Text(string) => Some(string.into_bytes()),
-                    _ => None,
-                }) else {
-                    continue;
+                let string = match message {
+                    GatewayMessage::Close(frame) => {
+                        if let Err(error) =
+                            close::handle(shard.id(), frame.as_ref(), &producer).await
+                        {
+                            println!("{error:?}");
+                        }
+
+                        continue;
+                    }
+                    GatewayMessage::Text(string) => string,
                 };
 
-                log::trace!(
-                    "[shard {shard_id}] received binary payload from gateway",
-                    shard_id = shard.id().number()
-                );
+                let event_type = event_type(&string);
+                let ready = event_type == "READY";
+                if ready || last_persisted.elapsed() >= SESSION_PERSIST_INTERVAL {
+                    let resume_url = if ready {
+                        serde_json::from_str::<Value>(&string)
+                            .ok()
+                            .and_then(|value| {
+                                value["d"]["resume_gateway_url"].as_str().map(String::from)
+                            })
+                    } else {
+                        None
+                    };
 
-                // send payload to worker process
-                if let Err((error, _)) = producer
-                    .send(
-                        FutureRecord::to(&topic)
-                            .key(&format!(
-                                "INBOUND_GATEWAY_PAYLOAD_SHARD_{shard_id}",
-                                shard_id = shard.id().number()
-                            ))
-                            .payload(&bytes),
-                        Timeout::After(Duration::from_secs(0)),
-                    )
-                    .await
-                {
-                    println!("{:?}", Err::<(), KafkaError>(error).into_diagnostic());
+                    if let Some(session) = shard.session().cloned() {
+                        if let Err(error) =
+                            session::persist(shard.id(), &session, resume_url.as_deref()).await
+                        {
+                            println!("{error:?}");
+                        }
+                    }
 
-                    continue;
+                    last_persisted = Instant::now();
                 }
 
-                // send payloaad to caching process
-                if let Err((error, _)) = producer
-                    .send(
-                        FutureRecord::to(&topic_2)
-                            .key(&format!(
-                                "INBOUND_GATEWAY_PAYLOAD_SHARD_{shard_id}",
-                                shard_id = shard.id().number()
-                            ))
-                            .payload(&bytes),
-                        Timeout::After(Duration::from_secs(0)),
-                    )
-                    .await
-                {
-                    println!("{:?}", Err::<(), KafkaError>(error).into_diagnostic());
+                log::trace!(
+                    "[shard {shard_id}] received {event_type} payload from gateway",
+                    shard_id = shard.id().number()
+                );
 
-                    continue;
+                if let Err(error) = forward(
+                    &producer,
+                    [&topic, &topic_2],
+                    shard.id().number(),
+                    event_type,
+                    string,
+                )
+                .await
+                {
+                    println!("{error:?}");
                 }
             }
             Err(error) => {
@@ -135,35 +169,214 @@ where
     Ok(())
 }
 
+/// Returns the event type of a gateway payload, or its opcode if it is not a dispatch event.
+pub fn event_type(payload: &str) -> String {
+    GatewayEventDeserializer::from_json(payload).map_or_else(
+        || String::from("UNKNOWN"),
+        |deserializer| {
+            deserializer
+                .event_type()
+                .map_or_else(|| format!("OPCODE_{}", deserializer.op()), String::from)
+        },
+    )
+}
+
+/// Returns the ID of the guild a gateway payload belongs to, if any.
+pub fn guild_id(payload: &str) -> Option<String> {
+    let Ok(GuildProbe { d: Some(data), t }) = serde_json::from_str::<GuildProbe>(payload) else {
+        return None;
+    };
+
+    // the ID of guild events not carrying a separate guild ID is the ID of the guild itself
+    data.guild_id.or_else(|| {
+        data.id
+            .filter(|_| t.as_deref().is_some_and(|t| t.starts_with("GUILD_")))
+    })
+}
+
+/// Forward a gateway payload received by a shard to the worker process and the caching process,
+/// in that order.
+///
+/// The guild the payload belongs to is carried in the envelope, such that the worker can route the
+/// event without deserializing the payload.
+pub async fn forward(
+    producer: &Producer,
+    topics: [&str; 2],
+    shard_id: u32,
+    event_type: String,
+    payload: String,
+) -> miette::Result<()> {
+    let guild_id = guild_id(&payload);
+    let mut envelope = Envelope::from_json(shard_id, event_type, payload).into_diagnostic()?;
+    envelope.guild_id = guild_id;
+
+    let bytes = envelope.to_bytes().into_diagnostic()?;
+    let key = format!("INBOUND_GATEWAY_PAYLOAD_SHARD_{shard_id}");
+
+    for topic in topics {
+        producer.send(topic, &key, &bytes).await?;
+    }
+
+    Ok(())
+}
+
 /// Handle outbound traffic.
 async fn outbound(
-    (shard_id, sender): (u32, MessageSender),
-    consumer: Arc<StreamConsumer>,
+    (shard, sender): (ShardId, MessageSender),
+    consumer: Consumer,
+    producer: Producer,
 ) -> miette::Result<()> {
-    while let Some(result) = consumer.stream().next().await {
-        let Ok(message) = result else {
-            let error = result.unwrap_err();
-            println!("{:?}", Err::<(), KafkaError>(error).into_diagnostic());
+    loop {
+        let delivery = match consumer.recv().await {
+            Ok(delivery) => delivery,
+            Err(error) => {
+                println!("{error:?}");
 
-            continue;
+                continue;
+            }
         };
 
-        let key = str::from_utf8(message.key().unwrap()).unwrap();
+        // commands are not retried, hence the offset is stored once the command has been sent or
+        // its failure has been reported
+        send_command(shard, &sender, &delivery, &producer).await;
 
-        if key.contains("REQUEST_GUILD_MEMBERS") {
-            let bytes = message.payload().unwrap();
+        if let Err(error) = consumer.store_offset(&delivery) {
+            println!("{error:?}");
+        }
+    }
+}
 
-            let command = serde_json::from_slice::<RequestGuildMembers>(bytes).into_diagnostic()?;
-            let scanned: u32 =
-                scan!("OUTBOUND_REQUEST_GUILD_MEMBERS_{}" <- key).into_diagnostic()?;
+/// Send the gateway command carried by a delivery to the gateway, reporting the failure of
+/// delivering it if the command cannot be sent.
+async fn send_command(
+    shard: ShardId,
+    sender: &MessageSender,
+    delivery: &Delivery,
+    producer: &Producer,
+) {
+    let shard_id = shard.number();
 
-            if shard_id != scanned {
-                continue;
-            }
+    let Some(bytes) = delivery.payload.as_deref() else {
+        return;
+    };
+
+    let envelope: Envelope = match Envelope::from_bytes(bytes) {
+        Ok(envelope) => envelope,
+        Err(error) => {
+            println!("{:?}", Err::<(), EnvelopeError>(error).into_diagnostic());
 
-            sender.command(&command).into_diagnostic()?;
+            return;
         }
+    };
+
+    if envelope.event_type != "GATEWAY_COMMAND" {
+        return;
     }
 
-    Ok(())
+    if envelope.shard_id != shard_id {
+        log::warn!(
+            "[shard {shard_id}] received command destined for shard {}; the partition of the command does not match its shard",
+            envelope.shard_id
+        );
+
+        return;
+    }
+
+    let outbound = match envelope.payload_as::<OutboundCommand>() {
+        Ok(outbound) => outbound,
+        Err(error) => {
+            report_delivery_failure(producer, shard_id, None, None, error.to_string()).await;
+
+            return;
+        }
+    };
+
+    log::trace!(
+        "[shard {shard_id}] sending {kind} command {id}",
+        kind = outbound.command.kind(),
+        id = outbound.id
+    );
+
+    let result = match &outbound.command {
+        GatewayCommand::RequestGuildMembers(command) => sender.command(command),
+        GatewayCommand::SetPresence(template) => {
+            let guild_count = presence::guild_count().await.unwrap_or_else(|error| {
+                log::warn!("failed to obtain guild count for presence: {error:?}");
+                0
+            });
+
+            sender.command(&template.render_command(shard_id, shard.total(), guild_count))
+        }
+        GatewayCommand::UpdatePresence(command) => sender.command(command),
+        GatewayCommand::UpdateVoiceState(command) => sender.command(command),
+    };
+
+    if let Err(error) = result {
+        report_delivery_failure(
+            producer,
+            shard_id,
+            Some(outbound.id),
+            Some(outbound.command.kind()),
+            error.to_string(),
+        )
+        .await;
+    }
+}
+
+/// Report the failure of delivering a gateway command.
+async fn report_delivery_failure(
+    producer: &Producer,
+    shard_id: u32,
+    command_id: Option<String>,
+    kind: Option<&str>,
+    reason: String,
+) {
+    log::warn!(
+        "[shard {shard_id}] failed to deliver {kind} command {command_id}: {reason}",
+        kind = kind.unwrap_or("UNKNOWN"),
+        command_id = command_id.as_deref().unwrap_or("<unknown>"),
+    );
+
+    let event = CommandDeliveryFailed {
+        command_id,
+        kind: kind.map(String::from),
+        reason,
+        shard_id,
+    };
+
+    let result = env::var("KAFKA_TOPIC_INTERNAL_EVENT_PAYLOAD")
+        .into_diagnostic()
+        .and_then(|topic| {
+            Envelope::new(shard_id, "COMMAND_DELIVERY_FAILED", &event)
+                .to_bytes()
+                .into_diagnostic()
+                .map(|bytes| (topic, bytes))
+        });
+    let (topic, bytes) = match result {
+        Ok(tuple) => tuple,
+        Err(error) => {
+            println!("{error:?}");
+
+            return;
+        }
+    };
+
+    let key = format!("INTERNAL_EVENT_COMMAND_DELIVERY_FAILED_{shard_id}");
+    if let Err(error) = producer.send(&topic, &key, &bytes).await {
+        println!("{error:?}");
+    }
+}
+
+/// Used for extracting the guild a gateway payload belongs to without deserializing the payload
+/// fully.
+#[derive(Deserialize)]
+struct GuildProbe {
+    d: Option<GuildProbeData>,
+    t: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GuildProbeData {
+    guild_id: Option<String>,
+    id: Option<String>,
 }
@@ -0,0 +1,145 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Leader Process
+//!
+//! The leader process is the process that connects to the Discord API, receives events and
+//! forwards to the workers.
+//!
+//! The leader can either be run as its own process, or within the all-in-one process together
+//! with the worker and the entitycache update service.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use std::env;
+use std::future::Future;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use hartex_discord_core::discord::gateway::CloseFrame;
+use hartex_discord_core::discord::gateway::Message as GatewayMessage;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::sync::watch;
+use hartex_discord_core::tokio::task::JoinSet;
+use hartex_discord_core::tokio::time;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+mod close;
+mod kafka;
+mod presence;
+mod queue;
+mod session;
+mod shards;
+
+/// Run the leader with the given transport until the shutdown future completes.
+///
+/// # Errors
+///
+/// Returns an error if the environment is misconfigured, or if the shards or the transport cannot
+/// be set up.
+pub async fn run(transport: Transport, shutdown: impl Future<Output = ()>) -> miette::Result<()> {
+    let topic = env::var("KAFKA_TOPIC_OUTBOUND_COMMUNICATION").into_diagnostic()?;
+
+    let producer = transport.producer()?;
+    log::trace!("building clusters");
+    let range = shards::ShardRange::from_env()?;
+    let queue = queue::obtain(range.is_partial())?;
+    let shards = shards::obtain(range, queue).await?;
+
+    let (tx, rx) = watch::channel(false);
+
+    log::trace!("launching {} shard(s)", shards.len());
+    let mut set = JoinSet::new();
+    for mut shard in shards {
+        let mut rx = rx.clone();
+        let consumer = kafka::outbound_consumer(&transport, &topic, shard.id().number())?;
+        let producer_clone = producer.clone();
+
+        set.spawn(async move {
+            tokio::select! {
+                _ = kafka::handle(&mut shard, producer_clone, consumer) => {},
+                _ = rx.changed() => {
+                    // persist the session and close with a non-1000 close code, such that the
+                    // session can be resumed when the leader restarts
+                    if let Some(session) = shard.session().cloned() {
+                        if let Err(error) = session::persist(shard.id(), &session, None).await {
+                            println!("{error:?}");
+                        }
+                    }
+
+                    shard.close(CloseFrame::RESUME);
+
+                    // drive the shard until the close frame has been sent
+                    let _ = time::timeout(Duration::from_secs(5), async {
+                        while let Some(result) = shard.next().await {
+                            if matches!(result, Ok(GatewayMessage::Close(_))) {
+                                break;
+                            }
+                        }
+                    })
+                    .await;
+                }
+            }
+        });
+    }
+
+    shutdown.await;
+
+    log::warn!("shutdown signal received, shutting down");
+
+    tx.send(true).into_diagnostic()?;
+
+    // wait for all tasks to complete
+    while set.join_next().await.is_some() {}
+
+    Ok(())
+}
+
+/// Forward a gateway payload to the worker and the entitycache update service as if it had been
+/// received from Discord by a shard.
+///
+/// This allows the components downstream of the leader to be driven without connecting to
+/// Discord, such as in integration tests.
+///
+/// # Errors
+///
+/// Returns an error if the environment is misconfigured, or if the payload cannot be enveloped or
+/// produced.
+pub async fn forward(transport: &Transport, shard_id: u32, payload: String) -> miette::Result<()> {
+    let topic = env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD").into_diagnostic()?;
+    let topic_2 =
+        env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_CACHE").into_diagnostic()?;
+
+    let event_type = kafka::event_type(&payload);
+    kafka::forward(
+        &transport.producer()?,
+        [&topic, &topic_2],
+        shard_id,
+        event_type,
+        payload,
+    )
+    .await
+}
@@ -0,0 +1,88 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Presence Configuration
+//!
+//! The presence of the shards is configured with the following environment variables:
+//!
+//! - `PRESENCE_STATUS`: the status, one of `online`, `idle`, `dnd` and `invisible`
+//! - `PRESENCE_ACTIVITY_TYPE`: the type of the activity, one of `playing`, `streaming`,
+//!   `listening`, `watching`, `custom` and `competing`
+//! - `PRESENCE_ACTIVITY_TEMPLATE`: the template of the activity text, see
+//!   [`hartex_discord_internal_events::presence`] for the supported placeholders
+
+use std::env;
+use std::pin::Pin;
+
+use hartex_database_queries::discord_frontend::queries::cached_guild_count::cached_guild_count;
+use hartex_discord_core::discord::model::gateway::presence::ActivityType;
+use hartex_discord_core::discord::model::gateway::presence::Status;
+use hartex_discord_internal_events::presence::parse_activity_type;
+use hartex_discord_internal_events::presence::parse_status;
+use hartex_discord_internal_events::presence::PresenceTemplate;
+use hartex_discord_utils::DATABASE_POOL;
+use miette::IntoDiagnostic;
+use miette::Report;
+use tokio_postgres::GenericClient;
+
+/// The default template of the activity text.
+const DEFAULT_TEMPLATE: &str = "development | shard {shard}";
+
+/// Obtain the presence template from the environment, falling back to the defaults for any
+/// variable that is not set.
+pub fn template_from_env() -> miette::Result<PresenceTemplate> {
+    let status = match env::var("PRESENCE_STATUS") {
+        Ok(status) => parse_status(&status)
+            .ok_or_else(|| Report::msg(format!("invalid presence status: {status}")))?,
+        Err(_) => Status::Online,
+    };
+    let activity_type = match env::var("PRESENCE_ACTIVITY_TYPE") {
+        Ok(activity_type) => parse_activity_type(&activity_type).ok_or_else(|| {
+            Report::msg(format!("invalid presence activity type: {activity_type}"))
+        })?,
+        Err(_) => ActivityType::Watching,
+    };
+    let template =
+        env::var("PRESENCE_ACTIVITY_TEMPLATE").unwrap_or_else(|_| String::from(DEFAULT_TEMPLATE));
+
+    Ok(PresenceTemplate {
+        activity_type,
+        status,
+        template,
+    })
+}
+
+/// Obtain the number of guilds the bot is in from the cache.
+#[allow(clippy::cast_sign_loss)]
+pub async fn guild_count() -> miette::Result<u64> {
+    let pinned = Pin::static_ref(&DATABASE_POOL).await;
+    let pooled = pinned.get().await.into_diagnostic()?;
+    let client = pooled.client();
+
+    let count = cached_guild_count()
+        .bind(client)
+        .one()
+        .await
+        .into_diagnostic()?;
+
+    Ok(count as u64)
+}
@@ -20,8 +20,10 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::pin::Pin;
 use std::time::Duration;
 
+use hartex_database_queries::discord_frontend::queries::identify_bucket_acquire::identify_bucket_acquire;
 use hartex_discord_core::discord::gateway::queue::Queue;
 use hartex_discord_core::tokio;
 use hartex_discord_core::tokio::sync::mpsc::unbounded_channel;
@@ -31,8 +33,13 @@ use hartex_discord_core::tokio::sync::oneshot;
 use hartex_discord_core::tokio::sync::oneshot::Receiver;
 use hartex_discord_core::tokio::sync::oneshot::Sender;
 use hartex_discord_core::tokio::time::sleep;
+use hartex_discord_utils::DATABASE_POOL;
 use hartex_log::log;
 use miette::IntoDiagnostic;
+use tokio_postgres::GenericClient;
+
+/// The interval between attempts of acquiring an identify bucket.
+const BUCKET_RETRY_INTERVAL: Duration = Duration::from_millis(500);
 
 /// A discriminated union of supported bot queues.
 #[allow(clippy::module_name_repetitions)]
@@ -82,38 +89,74 @@ impl Queue for LocalQueue {
 }
 
 /// A queue for large bots.
+///
+/// The identify buckets are coordinated through the database, such that the identify rate limits
+/// still hold when multiple leader processes start at once.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Debug)]
-pub struct LargeBotQueue(Vec<UnboundedSender<Sender<()>>>);
+pub struct LargeBotQueue {
+    buckets: u32,
+    duration: Duration,
+}
 
 impl LargeBotQueue {
     /// Create a large bot queue.
-    pub fn new(buckets: usize, duration: Duration) -> Self {
-        let mut queues = Vec::with_capacity(buckets);
-        for _ in 0..buckets {
-            let (tx, rx) = unbounded_channel();
-            tokio::spawn(wait_for_while(rx, duration));
-            queues.push(tx);
-        }
-
-        Self(queues)
+    pub fn new(buckets: u32, duration: Duration) -> Self {
+        Self { buckets, duration }
     }
 }
 
 impl Queue for LargeBotQueue {
     #[allow(unused_must_use)]
     fn enqueue(&'_ self, shard_id: u32) -> Receiver<()> {
-        #[allow(clippy::cast_possible_truncation)]
-        let bucket = (shard_id % (self.0.len() as u32)) as usize;
+        let bucket = shard_id % self.buckets;
+        let duration = self.duration;
         let (tx, rx) = oneshot::channel();
-        if let Err(error) = self.0[bucket].clone().send(tx) {
-            log::warn!("skipping, send failed: {:?}", error);
-        }
+
+        tokio::spawn(async move {
+            acquire_bucket(bucket, duration).await;
+
+            if let Err(error) = tx.send(()) {
+                log::warn!("skipping, send failed: {:?}", error);
+            }
+        });
 
         rx
     }
 }
 
+/// Waits until an identify bucket is acquired in the database.
+///
+/// A bucket can be acquired once the duration has elapsed since it was last acquired by any
+/// leader process.
+async fn acquire_bucket(bucket: u32, duration: Duration) {
+    loop {
+        match try_acquire_bucket(bucket, duration).await {
+            Ok(true) => return,
+            Ok(false) => (),
+            Err(error) => log::warn!("failed to acquire identify bucket {bucket}: {error:?}"),
+        }
+
+        sleep(BUCKET_RETRY_INTERVAL).await;
+    }
+}
+
+/// Attempts to acquire an identify bucket in the database.
+#[allow(clippy::cast_possible_wrap)]
+async fn try_acquire_bucket(bucket: u32, duration: Duration) -> miette::Result<bool> {
+    let pinned = Pin::static_ref(&DATABASE_POOL).await;
+    let pooled = pinned.get().await.into_diagnostic()?;
+    let client = pooled.client();
+
+    let acquired = identify_bucket_acquire()
+        .bind(client, &(bucket as i32), &(duration.as_secs() as i64))
+        .opt()
+        .await
+        .into_diagnostic()?;
+
+    Ok(acquired.is_some())
+}
+
 /// Waits for a while until an unbounded receiver receives something.
 async fn wait_for_while(mut rx: UnboundedReceiver<Sender<()>>, duration: Duration) {
     while let Some(tx) = rx.recv().await {
@@ -126,10 +169,13 @@ async fn wait_for_while(mut rx: UnboundedReceiver<Sender<()>>, duration: Duratio
 }
 
 /// Obtain a queue to use for the startup of the bot.
-pub fn obtain() -> miette::Result<BotQueue> {
+///
+/// If the leader only runs a part of the shards, other leader processes may be identifying at the
+/// same time, hence the identify buckets are always coordinated through the database.
+pub fn obtain(clustered: bool) -> miette::Result<BotQueue> {
     let concurrency = std::env::var("SHARD_CONCURRENCY")
         .into_diagnostic()?
-        .parse::<usize>()
+        .parse::<u32>()
         .into_diagnostic()?;
     let wait = Duration::from_secs(
         std::env::var("SHARD_CONCURRENCY_WAIT_SECONDS")
@@ -138,7 +184,7 @@ pub fn obtain() -> miette::Result<BotQueue> {
             .into_diagnostic()?,
     );
 
-    Ok(if concurrency == 1 {
+    Ok(if concurrency == 1 && !clustered {
         BotQueue::Local(LocalQueue::new(wait))
     } else {
         BotQueue::LargeBot(LargeBotQueue::new(concurrency, wait))
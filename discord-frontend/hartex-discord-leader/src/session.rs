@@ -0,0 +1,120 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Gateway Session Persistence
+//!
+//! The sessions of the shards are persisted such that the leader can resume them instead of
+//! identifying again after a restart, avoiding an identify storm upon deployments.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+
+use hartex_database_queries::discord_frontend::queries::gateway_session_delete_by_shard_id::gateway_session_delete_by_shard_id;
+use hartex_database_queries::discord_frontend::queries::gateway_session_select_all::gateway_session_select_all;
+use hartex_database_queries::discord_frontend::queries::gateway_session_upsert::gateway_session_upsert;
+use hartex_discord_core::discord::gateway::Session;
+use hartex_discord_core::discord::gateway::ShardId;
+use hartex_discord_utils::DATABASE_POOL;
+use miette::IntoDiagnostic;
+use tokio_postgres::GenericClient;
+
+/// A gateway session persisted for a shard.
+#[derive(Clone, Debug)]
+pub struct PersistedSession {
+    /// The URL to use for resuming the session, if known.
+    pub resume_url: Option<String>,
+    /// The session itself.
+    pub session: Session,
+    /// The total number of shards when the session was persisted.
+    pub shard_total: u32,
+}
+
+/// Load all persisted sessions, keyed by their shard IDs.
+#[allow(clippy::cast_sign_loss)]
+pub async fn load() -> miette::Result<HashMap<u32, PersistedSession>> {
+    let pinned = Pin::static_ref(&DATABASE_POOL).await;
+    let pooled = pinned.get().await.into_diagnostic()?;
+    let client = pooled.client();
+
+    let sessions = gateway_session_select_all()
+        .bind(client)
+        .all()
+        .await
+        .into_diagnostic()?;
+
+    Ok(sessions
+        .into_iter()
+        .map(|session| {
+            (
+                session.shard_id as u32,
+                PersistedSession {
+                    resume_url: session.resume_url,
+                    session: Session::new(session.sequence as u64, session.session_id),
+                    shard_total: session.shard_total as u32,
+                },
+            )
+        })
+        .collect())
+}
+
+/// Persist the session of a shard.
+///
+/// The previously persisted resume URL is kept if `resume_url` is `None`.
+#[allow(clippy::cast_possible_wrap)]
+pub async fn persist(
+    shard_id: ShardId,
+    session: &Session,
+    resume_url: Option<&str>,
+) -> miette::Result<()> {
+    let pinned = Pin::static_ref(&DATABASE_POOL).await;
+    let pooled = pinned.get().await.into_diagnostic()?;
+    let client = pooled.client();
+
+    gateway_session_upsert()
+        .bind(
+            client,
+            &(shard_id.number() as i32),
+            &(shard_id.total() as i32),
+            &session.id(),
+            &(session.sequence() as i64),
+            &resume_url,
+        )
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
+
+/// Invalidate the persisted session of a shard such that it identifies on its next start.
+#[allow(clippy::cast_possible_wrap)]
+pub async fn invalidate(shard_id: ShardId) -> miette::Result<()> {
+    let pinned = Pin::static_ref(&DATABASE_POOL).await;
+    let pooled = pinned.get().await.into_diagnostic()?;
+    let client = pooled.client();
+
+    gateway_session_delete_by_shard_id()
+        .bind(client, &(shard_id.number() as i32))
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
@@ -20,64 +20,136 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::env;
 use std::ops::Deref;
 
-use hartex_discord_core::discord::gateway::create_recommended;
+use hartex_discord_core::discord::gateway::create_iterator;
 use hartex_discord_core::discord::gateway::queue::Queue;
 use hartex_discord_core::discord::gateway::ConfigBuilder;
 use hartex_discord_core::discord::gateway::Intents;
 use hartex_discord_core::discord::gateway::Shard;
 use hartex_discord_core::discord::gateway::ShardId;
-use hartex_discord_core::discord::model::gateway::payload::outgoing::update_presence::UpdatePresencePayload;
-use hartex_discord_core::discord::model::gateway::presence::Activity;
-use hartex_discord_core::discord::model::gateway::presence::ActivityType;
-use hartex_discord_core::discord::model::gateway::presence::Status;
-use hartex_discord_utils::CLIENT;
 use hartex_discord_utils::TOKEN;
+use hartex_log::log;
 use miette::IntoDiagnostic;
+use miette::Report;
 
-/// Obtain a list of shards.
-pub async fn obtain<Q>(queue: Q) -> miette::Result<Vec<Shard<Q>>>
+use crate::presence;
+use crate::session;
+
+/// The range of shards run by this leader process.
+#[derive(Clone, Copy, Debug)]
+pub struct ShardRange {
+    /// The ID of the first shard to run.
+    pub start: u32,
+    /// The ID of the last shard to run, inclusive.
+    pub end: u32,
+    /// The total number of shards across all leader processes.
+    pub total: u32,
+}
+
+impl ShardRange {
+    /// Obtain the shard range from the environment.
+    ///
+    /// `SHARD_TOTAL` is required; `SHARD_START` and `SHARD_END` default to the first and the last
+    /// shard respectively.
+    pub fn from_env() -> miette::Result<Self> {
+        let total = env::var("SHARD_TOTAL")
+            .into_diagnostic()?
+            .parse::<u32>()
+            .into_diagnostic()?;
+        let start = match env::var("SHARD_START") {
+            Ok(start) => start.parse::<u32>().into_diagnostic()?,
+            Err(_) => 0,
+        };
+        let end = match env::var("SHARD_END") {
+            Ok(end) => end.parse::<u32>().into_diagnostic()?,
+            Err(_) => total.saturating_sub(1),
+        };
+
+        if total == 0 || start > end || end >= total {
+            return Err(Report::msg(format!(
+                "invalid shard range: shards {start} to {end} of {total} shard(s)"
+            )));
+        }
+
+        Ok(Self { start, end, total })
+    }
+
+    /// Whether this leader process only runs a part of the shards.
+    pub fn is_partial(&self) -> bool {
+        self.start != 0 || self.end != self.total - 1
+    }
+}
+
+/// Intents required by the worker regardless of the enabled plugins.
+///
+/// Members are requested along with their presences when a guild becomes available.
+const WORKER_INTENTS: Intents = Intents::GUILDS
+    .union(Intents::GUILD_MEMBERS)
+    .union(Intents::GUILD_PRESENCES);
+
+/// Obtain the gateway intents to identify with, derived from what the worker, the plugins and the
+/// cache updaters need.
+fn intents() -> Intents {
+    WORKER_INTENTS
+        | hartex_discord_commands::intents()
+        | hartex_discord_entitycache_cacheupdaters::intents()
+}
+
+/// Obtain a list of shards within a shard range.
+pub async fn obtain<Q>(range: ShardRange, queue: Q) -> miette::Result<Vec<Shard<Q>>>
 where
     Q: Queue + Clone + Send + Sync + Sized,
 {
-    let config = ConfigBuilder::new(TOKEN.deref().clone(), Intents::all())
+    let sessions = session::load().await?;
+    log::trace!("loaded {} persisted session(s)", sessions.len());
+
+    let presence = presence::template_from_env()?;
+    let guild_count = presence::guild_count().await.unwrap_or_else(|error| {
+        log::warn!("failed to obtain guild count for presence: {error:?}");
+        0
+    });
+
+    let intents = intents();
+    log::trace!("identifying with intents {intents:?}");
+
+    let config = ConfigBuilder::new(TOKEN.deref().clone(), intents)
         .queue(queue.clone())
         .build();
 
-    Ok(create_recommended::<_, Q>(
-        &CLIENT,
+    log::trace!(
+        "running shards {} to {} of {} shard(s)",
+        range.start,
+        range.end,
+        range.total
+    );
+
+    Ok(create_iterator(
+        (range.start..range.end + 1).map(|number| ShardId::new(number, range.total)),
         config,
-        |shard_id: ShardId, builder: ConfigBuilder<Q>| {
+        |shard_id: ShardId, mut builder: ConfigBuilder<Q>| {
+            // sessions persisted under a different shard count cannot be resumed
+            if let Some(persisted) = sessions
+                .get(&shard_id.number())
+                .filter(|persisted| persisted.shard_total == shard_id.total())
+            {
+                log::trace!(
+                    "[shard {shard_id}] resuming persisted session",
+                    shard_id = shard_id.number()
+                );
+                builder = builder.session(persisted.session.clone());
+
+                if let Some(resume_url) = persisted.resume_url.clone() {
+                    builder = builder.resume_url(resume_url);
+                }
+            }
+
             builder
-                .presence(UpdatePresencePayload {
-                    activities: vec![Activity {
-                        application_id: None,
-                        assets: None,
-                        buttons: vec![],
-                        created_at: None,
-                        details: None,
-                        emoji: None,
-                        flags: None,
-                        id: None,
-                        instance: None,
-                        kind: ActivityType::Watching,
-                        name: format!("development | shard {}", shard_id.number()),
-                        party: None,
-                        secrets: None,
-                        state: None,
-                        timestamps: None,
-                        url: None,
-                    }],
-                    afk: false,
-                    since: None,
-                    status: Status::Online,
-                })
+                .presence(presence.render(shard_id.number(), shard_id.total(), guild_count))
                 .queue(queue.clone())
                 .build()
         },
     )
-    .await
-    .into_diagnostic()?
     .collect::<Vec<_>>())
 }
@@ -0,0 +1,201 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Kafka Transport
+//!
+//! Messages are carried by a Kafka cluster.
+
+use std::time::Duration;
+
+use hartex_kafka_utils::traits::ClientConfigUtils;
+use hartex_kafka_utils::types::CompressionType;
+use miette::IntoDiagnostic;
+use rdkafka::consumer::CommitMode;
+use rdkafka::consumer::Consumer;
+use rdkafka::consumer::StreamConsumer;
+use rdkafka::producer::FutureProducer;
+use rdkafka::producer::FutureRecord;
+use rdkafka::producer::Producer;
+use rdkafka::util::Timeout;
+use rdkafka::ClientConfig;
+use rdkafka::Message;
+use rdkafka::Offset;
+use rdkafka::TopicPartitionList;
+
+use crate::Delivery;
+
+/// A transport backed by a Kafka cluster.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct KafkaTransport {
+    bootstrap_servers: Vec<String>,
+}
+
+impl KafkaTransport {
+    /// Create a Kafka transport with the given bootstrap servers.
+    #[must_use]
+    pub fn new(bootstrap_servers: Vec<String>) -> Self {
+        Self { bootstrap_servers }
+    }
+
+    /// Create a producer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the producer cannot be created.
+    pub fn producer(&self) -> miette::Result<FutureProducer> {
+        ClientConfig::new()
+            .bootstrap_servers(self.bootstrap_servers.clone().into_iter())
+            .compression_type(CompressionType::Lz4)
+            .delivery_timeout_ms(30000)
+            .create::<FutureProducer>()
+            .into_diagnostic()
+    }
+
+    /// Create a consumer within a consumer group, subscribed to the given topics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the consumer cannot be created or subscribed.
+    pub fn consumer(&self, group_id: &str, topics: &[&str]) -> miette::Result<StreamConsumer> {
+        let consumer = self.create_consumer(group_id, "latest")?;
+        consumer.subscribe(topics).into_diagnostic()?;
+
+        Ok(consumer)
+    }
+
+    /// Create a consumer within a consumer group, subscribed to the given topics, that starts
+    /// from the earliest offset if the group has no committed offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the consumer cannot be created or subscribed.
+    pub fn earliest_consumer(
+        &self,
+        group_id: &str,
+        topics: &[&str],
+    ) -> miette::Result<StreamConsumer> {
+        let consumer = self.create_consumer(group_id, "earliest")?;
+        consumer.subscribe(topics).into_diagnostic()?;
+
+        Ok(consumer)
+    }
+
+    /// Create a consumer within a consumer group, assigned a single partition of a topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the consumer cannot be created or assigned.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn partition_consumer(
+        &self,
+        group_id: &str,
+        topic: &str,
+        partition: u32,
+    ) -> miette::Result<StreamConsumer> {
+        let consumer = self.create_consumer(group_id, "latest")?;
+
+        let mut assignment = TopicPartitionList::new();
+        assignment
+            .add_partition_offset(topic, partition as i32, Offset::Stored)
+            .into_diagnostic()?;
+        consumer.assign(&assignment).into_diagnostic()?;
+
+        Ok(consumer)
+    }
+
+    fn create_consumer(
+        &self,
+        group_id: &str,
+        offset_reset: &str,
+    ) -> miette::Result<StreamConsumer> {
+        ClientConfig::new()
+            .bootstrap_servers(self.bootstrap_servers.clone().into_iter())
+            .auto_offset_reset(offset_reset)
+            .group_id(group_id)
+            .enable_auto_offset_store(false)
+            .create::<StreamConsumer>()
+            .into_diagnostic()
+    }
+}
+
+/// Send a message with a Kafka producer.
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) async fn send(
+    producer: &FutureProducer,
+    topic: &str,
+    partition: Option<u32>,
+    key: &str,
+    payload: &[u8],
+) -> miette::Result<()> {
+    let mut record = FutureRecord::to(topic).key(key).payload(payload);
+    if let Some(partition) = partition {
+        record = record.partition(partition as i32);
+    }
+
+    producer
+        .send(record, Timeout::After(Duration::from_secs(0)))
+        .await
+        .map(|_| ())
+        .map_err(|(error, _)| error)
+        .into_diagnostic()
+}
+
+/// Flush a Kafka producer.
+pub(crate) fn flush(producer: &FutureProducer, timeout: Duration) -> miette::Result<()> {
+    producer.flush(Timeout::After(timeout)).into_diagnostic()
+}
+
+/// Receive a message with a Kafka consumer.
+pub(crate) async fn recv(consumer: &StreamConsumer) -> miette::Result<Delivery> {
+    let message = consumer.recv().await.into_diagnostic()?;
+
+    Ok(Delivery {
+        key: message
+            .key()
+            .map(|key| String::from_utf8_lossy(key).into_owned()),
+        offset: message.offset(),
+        partition: u32::try_from(message.partition()).ok(),
+        payload: message.payload().map(<[u8]>::to_vec),
+        topic: message.topic().to_string(),
+    })
+}
+
+/// Store the offset of a delivery with a Kafka consumer.
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn store_offset(consumer: &StreamConsumer, delivery: &Delivery) -> miette::Result<()> {
+    let Some(partition) = delivery.partition else {
+        return Ok(());
+    };
+
+    // the stored offset is the offset of the next message to consume
+    consumer
+        .store_offset(&delivery.topic, partition as i32, delivery.offset + 1)
+        .into_diagnostic()
+}
+
+/// Commit the stored offsets of a Kafka consumer.
+pub(crate) fn commit(consumer: &StreamConsumer) -> miette::Result<()> {
+    consumer
+        .commit_consumer_state(CommitMode::Sync)
+        .into_diagnostic()
+}
@@ -0,0 +1,271 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Transport Layer
+//!
+//! The transport layer carries messages between the leader, the workers and the entitycache
+//! update service.
+//!
+//! When the components run as separate processes, messages are carried by Kafka. When they run
+//! within a single process, such as for local development or integration tests, messages are
+//! carried by in-process channels instead, such that no Kafka cluster is required.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use std::env;
+use std::time::Duration;
+
+use miette::IntoDiagnostic;
+use rdkafka::consumer::StreamConsumer;
+use rdkafka::producer::FutureProducer;
+
+use crate::kafka::KafkaTransport;
+use crate::memory::MemoryBroker;
+use crate::memory::MemoryConsumer;
+use crate::memory::MemoryProducer;
+
+pub mod kafka;
+pub mod memory;
+
+/// A message delivered to a consumer.
+#[derive(Clone, Debug)]
+pub struct Delivery {
+    /// The key of the message.
+    pub key: Option<String>,
+    /// The offset of the message within its partition.
+    pub offset: i64,
+    /// The partition the message was produced to.
+    pub partition: Option<u32>,
+    /// The payload of the message.
+    pub payload: Option<Vec<u8>>,
+    /// The topic the message was produced to.
+    pub topic: String,
+}
+
+/// A discriminated union of supported transports.
+#[derive(Clone, Debug)]
+pub enum Transport {
+    /// A transport backed by a Kafka cluster.
+    Kafka(KafkaTransport),
+    /// A transport backed by in-process channels.
+    Memory(MemoryBroker),
+}
+
+impl Transport {
+    /// Obtain the transport configured by the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `KAFKA_BOOTSTRAP_SERVERS` environment variable is missing.
+    pub fn from_env() -> miette::Result<Self> {
+        let bootstrap_servers = env::var("KAFKA_BOOTSTRAP_SERVERS")
+            .into_diagnostic()?
+            .split(';')
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        Ok(Self::Kafka(KafkaTransport::new(bootstrap_servers)))
+    }
+
+    /// Create an in-process transport.
+    #[must_use]
+    pub fn memory() -> Self {
+        Self::Memory(MemoryBroker::new())
+    }
+
+    /// Create a producer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the producer cannot be created.
+    pub fn producer(&self) -> miette::Result<Producer> {
+        match self {
+            Self::Kafka(kafka) => kafka.producer().map(Producer::Kafka),
+            Self::Memory(memory) => Ok(Producer::Memory(memory.producer())),
+        }
+    }
+
+    /// Create a consumer within a consumer group, subscribed to the given topics.
+    ///
+    /// Offsets are not stored automatically; they must be stored with [`Consumer::store_offset`]
+    /// once a delivery has been handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the consumer cannot be created or subscribed.
+    pub fn consumer(&self, group_id: &str, topics: &[&str]) -> miette::Result<Consumer> {
+        match self {
+            Self::Kafka(kafka) => kafka.consumer(group_id, topics).map(Consumer::Kafka),
+            Self::Memory(memory) => Ok(Consumer::Memory(memory.subscribe(group_id, topics, None))),
+        }
+    }
+
+    /// Create a consumer within a consumer group, subscribed to the given topics, that starts from
+    /// the earliest offset if the group has no committed offset.
+    ///
+    /// As the in-process transport does not persist messages, this is equivalent to
+    /// [`Transport::consumer`] for the in-process transport.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the consumer cannot be created or subscribed.
+    pub fn earliest_consumer(&self, group_id: &str, topics: &[&str]) -> miette::Result<Consumer> {
+        match self {
+            Self::Kafka(kafka) => kafka
+                .earliest_consumer(group_id, topics)
+                .map(Consumer::Kafka),
+            Self::Memory(memory) => Ok(Consumer::Memory(memory.subscribe(group_id, topics, None))),
+        }
+    }
+
+    /// Create a consumer within a consumer group, assigned a single partition of a topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the consumer cannot be created or assigned.
+    pub fn partition_consumer(
+        &self,
+        group_id: &str,
+        topic: &str,
+        partition: u32,
+    ) -> miette::Result<Consumer> {
+        match self {
+            Self::Kafka(kafka) => kafka
+                .partition_consumer(group_id, topic, partition)
+                .map(Consumer::Kafka),
+            Self::Memory(memory) => Ok(Consumer::Memory(memory.subscribe(
+                group_id,
+                &[topic],
+                Some(partition),
+            ))),
+        }
+    }
+}
+
+/// A discriminated union of supported producers.
+#[derive(Clone)]
+pub enum Producer {
+    /// A Kafka producer.
+    Kafka(FutureProducer),
+    /// An in-process producer.
+    Memory(MemoryProducer),
+}
+
+impl Producer {
+    /// Send a message to a topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be produced.
+    pub async fn send(&self, topic: &str, key: &str, payload: &[u8]) -> miette::Result<()> {
+        match self {
+            Self::Kafka(producer) => kafka::send(producer, topic, None, key, payload).await,
+            Self::Memory(producer) => {
+                producer.send(topic, None, key, payload);
+                Ok(())
+            }
+        }
+    }
+
+    /// Send a message to a specific partition of a topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be produced.
+    pub async fn send_to_partition(
+        &self,
+        topic: &str,
+        partition: u32,
+        key: &str,
+        payload: &[u8],
+    ) -> miette::Result<()> {
+        match self {
+            Self::Kafka(producer) => {
+                kafka::send(producer, topic, Some(partition), key, payload).await
+            }
+            Self::Memory(producer) => {
+                producer.send(topic, Some(partition), key, payload);
+                Ok(())
+            }
+        }
+    }
+
+    /// Wait until all pending messages have been delivered, or the timeout elapses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pending messages cannot be delivered in time.
+    pub fn flush(&self, timeout: Duration) -> miette::Result<()> {
+        match self {
+            Self::Kafka(producer) => kafka::flush(producer, timeout),
+            Self::Memory(_) => Ok(()),
+        }
+    }
+}
+
+/// A discriminated union of supported consumers.
+pub enum Consumer {
+    /// A Kafka consumer.
+    Kafka(StreamConsumer),
+    /// An in-process consumer.
+    Memory(MemoryConsumer),
+}
+
+impl Consumer {
+    /// Receive the next delivery.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delivery cannot be received.
+    pub async fn recv(&self) -> miette::Result<Delivery> {
+        match self {
+            Self::Kafka(consumer) => kafka::recv(consumer).await,
+            Self::Memory(consumer) => consumer.recv().await,
+        }
+    }
+
+    /// Store the offset of a handled delivery, such that it is committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offset cannot be stored.
+    pub fn store_offset(&self, delivery: &Delivery) -> miette::Result<()> {
+        match self {
+            Self::Kafka(consumer) => kafka::store_offset(consumer, delivery),
+            Self::Memory(_) => Ok(()),
+        }
+    }
+
+    /// Synchronously commit the stored offsets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offsets cannot be committed.
+    pub fn commit(&self) -> miette::Result<()> {
+        match self {
+            Self::Kafka(consumer) => kafka::commit(consumer),
+            Self::Memory(_) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # In-Memory Transport
+//!
+//! Messages are carried by in-process channels.
+//!
+//! Every consumer group subscribed to a topic receives every message produced to the topic after
+//! the group subscribed; consumers assigned a single partition only receive the messages produced
+//! to that partition. Messages are not persisted; messages produced to a topic without any
+//! consumer group subscribed to their partition are held back until a consumer group subscribes,
+//! such that no messages are lost while the components of a process are starting up.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use hartex_discord_core::tokio::sync::mpsc::unbounded_channel;
+use hartex_discord_core::tokio::sync::mpsc::UnboundedReceiver;
+use hartex_discord_core::tokio::sync::mpsc::UnboundedSender;
+use hartex_discord_core::tokio::sync::Mutex as AsyncMutex;
+use miette::Report;
+
+use crate::Delivery;
+
+/// The maximum number of messages held back per topic until a consumer group subscribes to it.
+///
+/// The oldest messages are dropped once the limit is reached, such that topics without any
+/// consumers in a process do not grow without bound.
+const PENDING_LIMIT: usize = 1024;
+
+/// An in-process message broker.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Default)]
+pub struct MemoryBroker {
+    topics: Arc<Mutex<HashMap<String, MemoryTopic>>>,
+}
+
+impl MemoryBroker {
+    /// Create a new in-process broker.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a producer for this broker.
+    #[must_use]
+    pub fn producer(&self) -> MemoryProducer {
+        MemoryProducer {
+            broker: self.clone(),
+        }
+    }
+
+    /// Subscribe a consumer group to the given topics, optionally restricted to a single
+    /// partition.
+    ///
+    /// Subscribing a consumer group to the same topic and partition again replaces the previous
+    /// subscription.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the broker has been poisoned.
+    #[must_use]
+    pub fn subscribe(
+        &self,
+        group_id: &str,
+        topics: &[&str],
+        partition: Option<u32>,
+    ) -> MemoryConsumer {
+        let (tx, rx) = unbounded_channel();
+
+        let mut guard = self.topics.lock().unwrap();
+        for topic in topics {
+            let entry = guard.entry((*topic).to_string()).or_default();
+
+            // messages held back for the partition are delivered to the first subscriber
+            let (matching, pending) = entry
+                .pending
+                .drain(..)
+                .partition::<VecDeque<_>, _>(|delivery| matches(partition, delivery.partition));
+            entry.pending = pending;
+            for delivery in matching {
+                let _ = tx.send(delivery);
+            }
+
+            entry
+                .subscriptions
+                .insert((group_id.to_string(), partition), tx.clone());
+        }
+
+        MemoryConsumer {
+            receiver: AsyncMutex::new(rx),
+        }
+    }
+
+    fn publish(&self, topic: &str, partition: Option<u32>, key: &str, payload: &[u8]) {
+        let mut guard = self.topics.lock().unwrap();
+        let entry = guard.entry(topic.to_string()).or_default();
+
+        let delivery = Delivery {
+            key: Some(key.to_string()),
+            offset: entry.next_offset,
+            partition,
+            payload: Some(payload.to_vec()),
+            topic: topic.to_string(),
+        };
+        entry.next_offset += 1;
+
+        // subscriptions of dropped consumers are removed
+        let mut delivered = false;
+        entry.subscriptions.retain(|(_, subscribed_partition), tx| {
+            if !matches(*subscribed_partition, partition) {
+                return !tx.is_closed();
+            }
+
+            let sent = tx.send(delivery.clone()).is_ok();
+            delivered |= sent;
+
+            sent
+        });
+
+        if !delivered {
+            if entry.pending.len() == PENDING_LIMIT {
+                entry.pending.pop_front();
+            }
+
+            entry.pending.push_back(delivery);
+        }
+    }
+}
+
+/// Whether a message produced to a partition is delivered to a subscription.
+fn matches(subscribed_partition: Option<u32>, partition: Option<u32>) -> bool {
+    subscribed_partition.is_none() || subscribed_partition == partition
+}
+
+/// A topic of the in-process broker.
+#[derive(Debug, Default)]
+struct MemoryTopic {
+    next_offset: i64,
+    pending: VecDeque<Delivery>,
+    subscriptions: HashMap<(String, Option<u32>), UnboundedSender<Delivery>>,
+}
+
+/// A producer for the in-process broker.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct MemoryProducer {
+    broker: MemoryBroker,
+}
+
+impl MemoryProducer {
+    /// Send a message to a topic, optionally to a specific partition.
+    pub fn send(&self, topic: &str, partition: Option<u32>, key: &str, payload: &[u8]) {
+        self.broker.publish(topic, partition, key, payload);
+    }
+}
+
+/// A consumer for the in-process broker.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct MemoryConsumer {
+    receiver: AsyncMutex<UnboundedReceiver<Delivery>>,
+}
+
+impl MemoryConsumer {
+    /// Receive the next delivery.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the broker no longer holds any subscription of the consumer.
+    pub async fn recv(&self) -> miette::Result<Delivery> {
+        self.receiver
+            .lock()
+            .await
+            .recv()
+            .await
+            .ok_or_else(|| Report::msg("the consumer is no longer subscribed to any topic"))
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_core::tokio;
+use hartex_discord_transport::memory::MemoryBroker;
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn deliver_test() {
+    let broker = MemoryBroker::new();
+    let first = broker.subscribe("first", &["topic"], None);
+    let second = broker.subscribe("second", &["topic"], None);
+
+    broker.producer().send("topic", None, "key", b"payload");
+
+    assert_eq!(first.recv().await.unwrap().payload.unwrap(), b"payload");
+    assert_eq!(second.recv().await.unwrap().payload.unwrap(), b"payload");
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn pending_until_subscribed_test() {
+    let broker = MemoryBroker::new();
+
+    broker.producer().send("topic", None, "key", b"first");
+    broker.producer().send("topic", None, "key", b"second");
+
+    let consumer = broker.subscribe("group", &["topic"], None);
+
+    let first = consumer.recv().await.unwrap();
+    let second = consumer.recv().await.unwrap();
+    assert_eq!(first.payload.unwrap(), b"first");
+    assert_eq!(second.payload.unwrap(), b"second");
+    assert!(first.offset < second.offset);
+}
+
+#[tokio::test(crate = "hartex_discord_core::tokio")]
+pub async fn pending_by_partition_test() {
+    let broker = MemoryBroker::new();
+    let producer = broker.producer();
+    let zero = broker.subscribe("group", &["topic"], Some(0));
+
+    producer.send("topic", Some(0), "key", b"zero");
+    producer.send("topic", Some(1), "key", b"one");
+
+    let one = broker.subscribe("group", &["topic"], Some(1));
+
+    assert_eq!(zero.recv().await.unwrap().payload.unwrap(), b"zero");
+    assert_eq!(one.recv().await.unwrap().payload.unwrap(), b"one");
+}
@@ -22,11 +22,11 @@
 
 use std::env;
 use std::panic::AssertUnwindSafe;
-use std::time::Duration;
 use std::time::SystemTime;
 
 use futures_util::FutureExt;
 use hartex_backend_models::uptime::UptimeUpdate;
+use hartex_discord_commands::modlog;
 use hartex_discord_core::discord::model::application::interaction::InteractionType;
 use hartex_discord_core::discord::model::gateway::event::DispatchEvent;
 use hartex_discord_core::discord::model::gateway::event::GatewayEvent;
@@ -35,6 +35,9 @@ use hartex_discord_core::discord::model::gateway::payload::outgoing::RequestGuil
 use hartex_discord_core::discord::model::gateway::OpCode;
 use hartex_discord_core::tokio::net::TcpStream;
 use hartex_discord_core::tokio::spawn;
+use hartex_discord_entitycache_cacheupdaters::CacheUpdater;
+use hartex_discord_internal_events::bus::CommandBus;
+use hartex_discord_internal_events::command::GatewayCommand;
 use hartex_discord_utils::CLIENT;
 use hartex_log::log;
 use hyper::client::conn::http1::handshake;
@@ -44,24 +47,17 @@ use hyper::Method;
 use hyper::Request;
 use hyper_util::rt::TokioIo;
 use miette::IntoDiagnostic;
-use rdkafka::error::KafkaError;
-use rdkafka::producer::FutureProducer;
-use rdkafka::producer::FutureRecord;
-use rdkafka::util::Timeout;
 
 use crate::errorhandler::ErrorPayload;
 
 /// Invoke a corresponding event callback for an event.
+///
+/// The callbacks have side effects that must not be repeated, such as posting to the moderation
+/// log, hence they are invoked once per event and never retried.
 #[allow(clippy::cast_lossless)]
 #[allow(clippy::large_futures)]
 #[allow(clippy::too_many_lines)]
-pub async fn invoke(
-    event: GatewayEvent,
-    shard: u8,
-    producer: FutureProducer,
-) -> miette::Result<()> {
-    let topic = env::var("KAFKA_TOPIC_OUTBOUND_COMMUNICATION").into_diagnostic()?;
-
+pub async fn invoke(event: GatewayEvent, shard: u32, commands: &CommandBus) -> miette::Result<()> {
     #[allow(clippy::collapsible_match)]
     match event {
         GatewayEvent::Dispatch(seq, dispatch) => match dispatch {
@@ -81,17 +77,11 @@ pub async fn invoke(
                     },
                     op: OpCode::RequestGuildMembers,
                 };
-                let string = serde_json::to_string(&request).into_diagnostic()?;
-                if let Err((error, _)) = producer
-                    .send(
-                        FutureRecord::to(&topic)
-                            .key(&format!("OUTBOUND_REQUEST_GUILD_MEMBERS_{shard}"))
-                            .payload(&string),
-                        Timeout::After(Duration::from_secs(0)),
-                    )
+                if let Err(error) = commands
+                    .send(GatewayCommand::RequestGuildMembers(request))
                     .await
                 {
-                    println!("{:?}", Err::<(), KafkaError>(error).into_diagnostic());
+                    println!("{error:?}");
                 }
 
                 Ok(())
@@ -127,6 +117,39 @@ pub async fn invoke(
 
                 Ok(())
             }
+            DispatchEvent::MessageDelete(message_delete) => {
+                log::trace!(
+                    "shard {shard} has received MESSAGE_DELETE payload from Discord (sequence {seq})"
+                );
+
+                if let Err(error) = modlog::message_delete(&message_delete).await {
+                    println!("{error:?}");
+                }
+
+                Ok(())
+            }
+            DispatchEvent::MessageDeleteBulk(message_delete_bulk) => {
+                log::trace!(
+                    "shard {shard} has received MESSAGE_DELETE_BULK payload from Discord (sequence {seq})"
+                );
+
+                if let Err(error) = modlog::message_delete_bulk(&message_delete_bulk).await {
+                    println!("{error:?}");
+                }
+
+                Ok(())
+            }
+            DispatchEvent::MessageUpdate(message_update) => {
+                log::trace!(
+                    "shard {shard} has received MESSAGE_UPDATE payload from Discord (sequence {seq})"
+                );
+
+                if let Err(error) = modlog::message_update(&message_update).await {
+                    println!("{error:?}");
+                }
+
+                Ok(())
+            }
             DispatchEvent::Ready(ready) => {
                 log::info!(
                     "{}#{} (shard {shard}) has received READY payload from Discord (gateway v{}) (sequence {seq})",
@@ -172,3 +195,32 @@ pub async fn invoke(
         _ => Ok(()),
     }
 }
+
+/// Apply the updates of the entity cache that are owned by the worker for an event.
+///
+/// The cached messages are created, updated and evicted by the worker rather than by the
+/// entitycache update service, such that their previous content is available to the modlog plugin
+/// while the event callbacks are invoked. Hence this is run after the callbacks, and can be retried
+/// independently of them. As every event of a guild is handled on the same lane, a message is
+/// always cached before its update or deletion is applied.
+pub async fn update_cache(event: &GatewayEvent) -> miette::Result<()> {
+    let GatewayEvent::Dispatch(_, dispatch) = event else {
+        return Ok(());
+    };
+
+    match dispatch {
+        DispatchEvent::MessageCreate(message_create) => {
+            message_create.update().await.into_diagnostic()
+        }
+        DispatchEvent::MessageDelete(message_delete) => {
+            message_delete.update().await.into_diagnostic()
+        }
+        DispatchEvent::MessageDeleteBulk(message_delete_bulk) => {
+            message_delete_bulk.update().await.into_diagnostic()
+        }
+        DispatchEvent::MessageUpdate(message_update) => {
+            message_update.update().await.into_diagnostic()
+        }
+        _ => Ok(()),
+    }
+}
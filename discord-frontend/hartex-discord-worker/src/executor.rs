@@ -0,0 +1,230 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Event Executor
+//!
+//! Events are processed concurrently by a fixed number of lanes, each processing its events one
+//! after another. Events are routed to a lane by the guild they belong to, such that events of the
+//! same guild are processed in order, while events of different guilds are processed in parallel.
+//!
+//! Each lane has a bounded queue. When the queue of a lane is full, submitting another event to
+//! the lane waits until the lane catches up, which in turn stops the worker from consuming further
+//! events.
+
+use std::collections::hash_map::DefaultHasher;
+use std::future::Future;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use hartex_discord_core::tokio::sync::mpsc::channel;
+use hartex_discord_core::tokio::sync::mpsc::Sender;
+use hartex_discord_core::tokio::sync::mpsc::UnboundedSender;
+use hartex_discord_core::tokio::task::JoinSet;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_internal_events::retry::var_or;
+use hartex_discord_internal_events::worker::WorkerMetrics;
+use hartex_discord_transport::Delivery;
+use miette::Report;
+
+/// The configuration of the event executor.
+///
+/// The configuration is read from the following environment variables, all of which are
+/// optional:
+///
+/// - `WORKER_CONCURRENCY`: the number of lanes, defaults to 16
+/// - `WORKER_QUEUE_CAPACITY`: the capacity of the queue of each lane, defaults to 64
+/// - `WORKER_METRICS_INTERVAL_SECONDS`: the interval between metrics reports, defaults to 30
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug)]
+pub struct ExecutorConfig {
+    /// The number of lanes.
+    pub concurrency: usize,
+    /// The interval between metrics reports.
+    pub metrics_interval: Duration,
+    /// The capacity of the queue of each lane.
+    pub queue_capacity: usize,
+}
+
+impl ExecutorConfig {
+    /// Obtain the executor configuration from the environment.
+    pub fn from_env() -> miette::Result<Self> {
+        let concurrency = var_or("WORKER_CONCURRENCY", 16usize)?;
+        let queue_capacity = var_or("WORKER_QUEUE_CAPACITY", 64usize)?;
+        if concurrency == 0 || queue_capacity == 0 {
+            return Err(Report::msg(
+                "WORKER_CONCURRENCY and WORKER_QUEUE_CAPACITY must be at least 1",
+            ));
+        }
+
+        Ok(Self {
+            concurrency,
+            metrics_interval: Duration::from_secs(var_or(
+                "WORKER_METRICS_INTERVAL_SECONDS",
+                30u64,
+            )?),
+            queue_capacity,
+        })
+    }
+}
+
+/// An event submitted to the executor.
+pub struct Event {
+    /// The delivery carrying the event.
+    pub delivery: Delivery,
+    /// The envelope of the event, decoded once upon receiving the delivery, or `None` if the
+    /// delivery has no payload.
+    pub envelope: Option<miette::Result<Envelope>>,
+}
+
+/// The outcome of processing an event.
+pub struct Completion {
+    /// The position of the processed delivery, without its payload.
+    pub position: Delivery,
+    /// The result of processing the event.
+    pub result: miette::Result<()>,
+}
+
+/// A bounded concurrent executor for events.
+pub struct Executor {
+    lanes: Vec<Sender<Event>>,
+    processed: Arc<AtomicU64>,
+    queue_capacity: usize,
+    tasks: JoinSet<()>,
+}
+
+impl Executor {
+    /// Create an executor processing events with the given handler.
+    ///
+    /// The outcome of every processed event is sent to the given completion sender.
+    pub fn new<F, Fut>(
+        config: &ExecutorConfig,
+        handler: F,
+        completions: &UnboundedSender<Completion>,
+    ) -> Self
+    where
+        F: Fn(Event) -> Fut + Clone + Send + 'static,
+        Fut: Future<Output = miette::Result<()>> + Send + 'static,
+    {
+        let processed = Arc::new(AtomicU64::new(0));
+        let mut lanes = Vec::with_capacity(config.concurrency);
+        let mut tasks = JoinSet::new();
+
+        for _ in 0..config.concurrency {
+            let (tx, mut rx) = channel::<Event>(config.queue_capacity);
+            let completions = completions.clone();
+            let handler = handler.clone();
+            let processed = processed.clone();
+
+            tasks.spawn(async move {
+                while let Some(event) = rx.recv().await {
+                    let position = Delivery {
+                        key: None,
+                        offset: event.delivery.offset,
+                        partition: event.delivery.partition,
+                        payload: None,
+                        topic: event.delivery.topic.clone(),
+                    };
+
+                    let result = handler(event).await;
+                    processed.fetch_add(1, Ordering::Relaxed);
+
+                    if completions.send(Completion { position, result }).is_err() {
+                        break;
+                    }
+                }
+            });
+            lanes.push(tx);
+        }
+
+        Self {
+            lanes,
+            processed,
+            queue_capacity: config.queue_capacity,
+            tasks,
+        }
+    }
+
+    /// Submit an event to the lane responsible for its routing key.
+    ///
+    /// This waits until there is space in the queue of the lane.
+    pub async fn submit(&self, event: Event) -> miette::Result<()> {
+        let mut hasher = DefaultHasher::new();
+        routing_key(&event).hash(&mut hasher);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let lane = (hasher.finish() % self.lanes.len() as u64) as usize;
+
+        self.lanes[lane]
+            .send(event)
+            .await
+            .map_err(|_| Report::msg(format!("lane {lane} of the event executor has stopped")))
+    }
+
+    /// Take a snapshot of the metrics of the executor.
+    pub fn metrics(&self, in_flight: usize) -> WorkerMetrics {
+        WorkerMetrics {
+            in_flight,
+            processed: self.processed.load(Ordering::Relaxed),
+            queue_capacity: self.queue_capacity,
+            queue_depths: self
+                .lanes
+                .iter()
+                .map(|lane| lane.max_capacity() - lane.capacity())
+                .collect(),
+        }
+    }
+
+    /// Stop accepting events and wait until all queued events are processed.
+    pub async fn shutdown(self) {
+        let Self {
+            lanes, mut tasks, ..
+        } = self;
+        drop(lanes);
+
+        while tasks.join_next().await.is_some() {}
+    }
+}
+
+/// The key events are routed to lanes by.
+#[derive(Hash)]
+enum RoutingKey {
+    /// Events belonging to a guild are routed by the guild.
+    Guild(String),
+    /// Other events are routed by the shard they are received from.
+    Shard(u32),
+}
+
+/// Determine the routing key of an event from the header of its envelope.
+fn routing_key(event: &Event) -> RoutingKey {
+    let Some(Ok(envelope)) = &event.envelope else {
+        return RoutingKey::Shard(0);
+    };
+
+    envelope
+        .guild_id
+        .clone()
+        .map_or(RoutingKey::Shard(envelope.shard_id), RoutingKey::Guild)
+}
@@ -24,6 +24,12 @@ use std::collections::HashMap;
 
 use hartex_discord_commands::general::about::About;
 use hartex_discord_commands::general::contributors::Contributors;
+use hartex_discord_commands::management::ban::Ban;
+use hartex_discord_commands::management::kick::Kick;
+use hartex_discord_commands::management::purge::Purge;
+use hartex_discord_commands::management::timeout::Timeout;
+use hartex_discord_commands::management::unban::Unban;
+use hartex_discord_commands::management::warn::Warn;
 use hartex_discord_commands::utilities::info::Info;
 use hartex_discord_commands_core::traits::Command;
 use hartex_discord_commands_core::traits::CommandMetadata;
@@ -49,8 +55,14 @@ pub static COMMAND_LOOKUP: Lazy<HashMap<String, Box<dyn Command + Send + Sync>>>
     Lazy::new(|| {
         let mut map = HashMap::<String, Box<dyn Command + Send + Sync>>::new();
         map.insert(About.name(), Box::new(About));
+        map.insert(Ban.name(), Box::new(Ban));
         map.insert(Contributors.name(), Box::new(Contributors));
         map.insert(Info.name(), Box::new(Info));
+        map.insert(Kick.name(), Box::new(Kick));
+        map.insert(Purge.name(), Box::new(Purge));
+        map.insert(Timeout.name(), Box::new(Timeout));
+        map.insert(Unban.name(), Box::new(Unban));
+        map.insert(Warn.name(), Box::new(Warn));
         map
     });
 
@@ -0,0 +1,387 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Worker Process
+//!
+//! The worker process is the process that receives messages from the leader.
+//!
+//! The worker can either be run as its own process, or within the all-in-one process together
+//! with the leader and the entitycache update service.
+//!
+//! The worker applies the invalidations broadcast by the entitycache update service to its
+//! in-memory entity caches, such that entities that have since been updated are retrieved from the
+//! database again.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use std::env;
+use std::future::Future;
+use std::time::Duration;
+
+use hartex_discord_core::discord::model::gateway::event::EventType;
+use hartex_discord_core::discord::model::gateway::event::GatewayEvent;
+use hartex_discord_core::discord::model::gateway::event::GatewayEventDeserializer;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::sync::mpsc::unbounded_channel;
+use hartex_discord_core::tokio::time;
+use hartex_discord_entitycache_core::invalidation::Invalidation;
+use hartex_discord_entitycache_repositories::apply_invalidation;
+use hartex_discord_entitycache_repositories::backend;
+use hartex_discord_internal_events::bus::CommandBus;
+use hartex_discord_internal_events::deadletter::DeadLetterQueue;
+use hartex_discord_internal_events::envelope::Envelope;
+use hartex_discord_internal_events::envelope::EnvelopeError;
+use hartex_discord_internal_events::retry::RetryPolicy;
+use hartex_discord_internal_events::worker::WorkerMetrics;
+use hartex_discord_transport::Delivery;
+use hartex_discord_transport::Producer;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use once_cell::sync::Lazy;
+use serde::de::DeserializeSeed;
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+
+use crate::error::ConsumerError;
+use crate::error::ConsumerErrorKind;
+use crate::executor::Completion;
+use crate::executor::Event;
+use crate::executor::Executor;
+use crate::executor::ExecutorConfig;
+use crate::interaction::COMMAND_LOOKUP;
+use crate::offsets::OffsetTracker;
+
+mod error;
+mod errorhandler;
+mod eventcallback;
+mod executor;
+mod interaction;
+mod offsets;
+
+/// Run the worker with the given transport until the shutdown future completes.
+///
+/// Events are processed concurrently by the event executor, in order for each guild.
+///
+/// # Errors
+///
+/// Returns an error if the environment is misconfigured, if the transport cannot be set up, or if
+/// an event can neither be handled nor be sent to the dead-letter topic.
+#[allow(clippy::large_futures)]
+#[allow(clippy::too_many_lines)]
+pub async fn run(transport: Transport, shutdown: impl Future<Output = ()>) -> miette::Result<()> {
+    Lazy::force(&COMMAND_LOOKUP);
+    backend::init()?;
+
+    let topic = env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD").into_diagnostic()?;
+    let internal_topic = env::var("KAFKA_TOPIC_INTERNAL_EVENT_PAYLOAD").into_diagnostic()?;
+    let invalidation_topic =
+        env::var("KAFKA_TOPIC_INTERNAL_ENTITYCACHE_INVALIDATION").into_diagnostic()?;
+
+    let producer = transport.producer()?;
+    let retry_policy = RetryPolicy::from_env()?;
+    let dead_letters = DeadLetterQueue::new("worker", producer.clone())?;
+    let commands = CommandBus::new(producer.clone())?;
+    let config = ExecutorConfig::from_env()?;
+    let consumer = transport.consumer(
+        "com.github.teamhartex.hartex.inbound.gateway.payload.consumer",
+        &[&topic],
+    )?;
+
+    // every worker has its own in-memory entity caches, hence every worker subscribes to the
+    // invalidations with its own consumer group such that it receives all of them
+    let invalidations = transport.consumer(&invalidation_group_id()?, &[&invalidation_topic])?;
+
+    let (completions_tx, mut completions_rx) = unbounded_channel();
+    let executor = Executor::new(
+        &config,
+        move |event| {
+            let commands = commands.clone();
+            let dead_letters = dead_letters.clone();
+
+            async move { handle(event, &commands, &retry_policy, &dead_letters).await }
+        },
+        &completions_tx,
+    );
+    drop(completions_tx);
+
+    let mut tracker = OffsetTracker::default();
+    let mut metrics_interval = time::interval(config.metrics_interval);
+    let mut failure = None;
+
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            biased;
+            () = &mut shutdown => {
+                log::warn!("shutdown signal received, no longer polling for new events");
+                break;
+            }
+            Some(Completion { position, result }) = completions_rx.recv() => {
+                // the offset of a failed event is never stored, hence it is consumed again when
+                // the worker restarts
+                if let Err(error) = result {
+                    failure = Some(error);
+                    break;
+                }
+
+                if let Some(position) = tracker.complete(&position) {
+                    consumer.store_offset(&position)?;
+                }
+            }
+            result = invalidations.recv() => {
+                match result {
+                    Ok(delivery) => apply_invalidations(&delivery),
+                    Err(error) => println!("{error:?}"),
+                }
+            }
+            _ = metrics_interval.tick() => {
+                report_metrics(&producer, &internal_topic, executor.metrics(tracker.in_flight())).await;
+            }
+            result = consumer.recv() => {
+                let delivery = match result {
+                    Ok(delivery) => delivery,
+                    Err(error) => {
+                        println!("{error:?}");
+
+                        continue;
+                    }
+                };
+
+                tracker.begin(&delivery);
+
+                // the envelope is decoded once, both for routing the event and for handling it
+                let envelope = delivery
+                    .payload
+                    .as_deref()
+                    .map(|bytes| Envelope::from_bytes(bytes).into_diagnostic());
+
+                // this waits while the queue of the lane is full, hence no further events are
+                // consumed until the lane catches up
+                executor.submit(Event { delivery, envelope }).await?;
+            }
+        }
+    }
+
+    // the queued events are fully handled, or sent to the dead-letter topic, before shutting
+    // down, hence no in-flight events are lost upon shutdown
+    log::trace!("waiting for in-flight events to complete");
+    executor.shutdown().await;
+
+    while let Some(Completion { position, result }) = completions_rx.recv().await {
+        match result {
+            Ok(()) if failure.is_none() => {
+                if let Some(position) = tracker.complete(&position) {
+                    consumer.store_offset(&position)?;
+                }
+            }
+            Ok(()) => (),
+            Err(error) => {
+                println!("{error:?}");
+                failure.get_or_insert(error);
+            }
+        }
+    }
+
+    log::trace!("flushing pending outbound messages");
+    producer.flush(Duration::from_secs(30))?;
+
+    log::trace!("committing consumer offsets");
+    if let Err(error) = consumer.commit() {
+        println!("{error:?}");
+    }
+
+    if let Some(error) = failure {
+        return Err(error);
+    }
+
+    log::warn!("shut down gracefully");
+
+    Ok(())
+}
+
+/// The consumer group of this worker for receiving invalidations of the in-memory entity caches.
+///
+/// The group is named after the `WORKER_INSTANCE_ID` environment variable, falling back to the
+/// `HOSTNAME` environment variable, such that a restarted worker resumes the group of its previous
+/// run instead of leaving an abandoned group behind.
+fn invalidation_group_id() -> miette::Result<String> {
+    let instance_id = env::var("WORKER_INSTANCE_ID")
+        .or_else(|_| env::var("HOSTNAME"))
+        .into_diagnostic()?;
+
+    Ok(format!(
+        "com.github.teamhartex.hartex.internal.entitycache.invalidation.consumer.{instance_id}"
+    ))
+}
+
+/// Apply the invalidations of the in-memory entity caches carried by a delivery.
+fn apply_invalidations(delivery: &Delivery) {
+    let Some(bytes) = delivery.payload.as_deref() else {
+        return;
+    };
+
+    match Envelope::<Vec<Invalidation>>::from_bytes(bytes) {
+        Ok(envelope) => envelope.payload.iter().for_each(apply_invalidation),
+        Err(error) => println!("{:?}", Err::<(), EnvelopeError>(error).into_diagnostic()),
+    }
+}
+
+/// Report the metrics of the event executor.
+async fn report_metrics(producer: &Producer, topic: &str, metrics: WorkerMetrics) {
+    log::debug!(
+        "{in_flight} event(s) in flight, {processed} event(s) processed, queue depths: {queue_depths:?} (capacity {queue_capacity})",
+        in_flight = metrics.in_flight,
+        processed = metrics.processed,
+        queue_depths = metrics.queue_depths,
+        queue_capacity = metrics.queue_capacity,
+    );
+
+    let bytes = match Envelope::new(0, "WORKER_METRICS", &metrics).to_bytes() {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            println!("{:?}", Err::<(), EnvelopeError>(error).into_diagnostic());
+
+            return;
+        }
+    };
+
+    if let Err(error) = producer
+        .send(topic, "INTERNAL_EVENT_WORKER_METRICS", &bytes)
+        .await
+    {
+        println!("{error:?}");
+    }
+}
+
+/// Handle a message received from the leader.
+///
+/// Messages that cannot be decoded are sent to the dead-letter topic right away, whereas dispatch
+/// events of types that are not modelled are skipped.
+///
+/// The event callbacks are invoked once, as their side effects must not be repeated; only the
+/// updates of the entity cache following them are retried according to the retry policy, before
+/// the event is sent to the dead-letter topic.
+#[allow(clippy::large_futures)]
+async fn handle(
+    Event { delivery, envelope }: Event,
+    commands: &CommandBus,
+    retry_policy: &RetryPolicy,
+    dead_letters: &DeadLetterQueue,
+) -> miette::Result<()> {
+    let Some(envelope) = envelope else {
+        return Ok(());
+    };
+
+    let envelope = match envelope {
+        Ok(envelope) => envelope,
+        Err(error) => {
+            println!("{error:?}");
+
+            return dead_letters.send(&delivery, None, &error, 1).await;
+        }
+    };
+
+    log::trace!(
+        "[shard {shard_id}] received {event_type} event (schema v{version}); attempting to deserialize",
+        shard_id = envelope.shard_id,
+        event_type = envelope.event_type,
+        version = envelope.version,
+    );
+    let event = match decode(&envelope) {
+        Ok(Some(event)) => event,
+        Ok(None) => {
+            log::trace!(
+                "[shard {shard_id}] skipping unsupported {event_type} event",
+                shard_id = envelope.shard_id,
+                event_type = envelope.event_type,
+            );
+
+            return Ok(());
+        }
+        Err(error) => {
+            println!("{error:?}");
+
+            return dead_letters
+                .send(&delivery, Some(&envelope), &error, 1)
+                .await;
+        }
+    };
+
+    let callback_result = eventcallback::invoke(event.clone(), envelope.shard_id, commands).await;
+    let cache_result = retry_policy
+        .run(|| eventcallback::update_cache(&event))
+        .await;
+
+    if let Err((error, attempts)) = cache_result {
+        println!("{error:?}");
+
+        return dead_letters
+            .send(&delivery, Some(&envelope), &error, attempts)
+            .await;
+    }
+    if let Err(error) = callback_result {
+        println!("{error:?}");
+
+        return dead_letters
+            .send(&delivery, Some(&envelope), &error, 1)
+            .await;
+    }
+
+    Ok(())
+}
+
+/// Decode the gateway event carried by an envelope.
+///
+/// Returns `None` for dispatch events of types that are not modelled.
+fn decode(envelope: &Envelope) -> miette::Result<Option<GatewayEvent>> {
+    let payload = envelope.payload.get();
+    let gateway_deserializer = GatewayEventDeserializer::from_json(payload)
+        .ok_or(ConsumerError {
+            kind: ConsumerErrorKind::InvalidGatewayPayload,
+        })
+        .into_diagnostic()?;
+
+    if gateway_deserializer
+        .event_type()
+        .is_some_and(|event_type| !is_modelled(event_type))
+    {
+        return Ok(None);
+    }
+
+    let mut json_deserializer = serde_json::Deserializer::from_str(payload);
+
+    gateway_deserializer
+        .deserialize(&mut json_deserializer)
+        .map(Some)
+        .into_diagnostic()
+}
+
+/// Whether a dispatch event type is modelled, such that events of the type can be decoded.
+fn is_modelled(event_type: &str) -> bool {
+    EventType::deserialize(event_type.into_deserializer())
+        .map_err(|_: serde::de::value::Error| ())
+        .is_ok()
+}
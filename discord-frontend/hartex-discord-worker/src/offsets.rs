@@ -0,0 +1,86 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Offset Tracking
+//!
+//! As events are processed concurrently, they may complete in a different order than they were
+//! consumed in. The offset of a partition is therefore only advanced up to the first event of the
+//! partition that has not completed yet, such that no event is skipped when the worker restarts.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use hartex_discord_transport::Delivery;
+
+/// Tracks the in-flight deliveries of each partition.
+#[derive(Default)]
+pub struct OffsetTracker {
+    partitions: HashMap<(String, Option<u32>), BTreeMap<i64, bool>>,
+}
+
+impl OffsetTracker {
+    /// Start tracking a delivery.
+    pub fn begin(&mut self, delivery: &Delivery) {
+        self.partitions
+            .entry((delivery.topic.clone(), delivery.partition))
+            .or_default()
+            .insert(delivery.offset, false);
+    }
+
+    /// Mark a delivery as completed.
+    ///
+    /// Returns the position of the latest delivery of the partition up to which all deliveries
+    /// have completed, if the position has advanced.
+    pub fn complete(&mut self, position: &Delivery) -> Option<Delivery> {
+        let offsets = self
+            .partitions
+            .get_mut(&(position.topic.clone(), position.partition))?;
+        if let Some(completed) = offsets.get_mut(&position.offset) {
+            *completed = true;
+        }
+
+        let mut advanced = None;
+        while let Some(entry) = offsets.first_entry() {
+            if !*entry.get() {
+                break;
+            }
+
+            advanced = Some(entry.remove_entry().0);
+        }
+
+        advanced.map(|offset| Delivery {
+            key: None,
+            offset,
+            partition: position.partition,
+            payload: None,
+            topic: position.topic.clone(),
+        })
+    }
+
+    /// The number of deliveries that have not completed yet.
+    pub fn in_flight(&self) -> usize {
+        self.partitions
+            .values()
+            .map(|offsets| offsets.values().filter(|completed| !**completed).count())
+            .sum()
+    }
+}
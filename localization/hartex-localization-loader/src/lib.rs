@@ -88,6 +88,11 @@ impl LocalizationBundleHolder {
             .get(lang)
             .unwrap_or_else(|| self.get_bundle("en-GB"))
     }
+
+    /// Returns whether a localization bundle is loaded for a language ID.
+    pub fn supports(&self, lang: &str) -> bool {
+        self.bundles.contains_key(lang)
+    }
 }
 
 /// Load a bundle from a path.
@@ -0,0 +1,36 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+ban-success={$userMention} has been banned. Reason: {$reason}
+kick-success={$userMention} has been kicked. Reason: {$reason}
+purge-success={$count} messages have been deleted.
+timeout-success={$userMention} has been timed out until {$until}. Reason: {$reason}
+unban-success={$userMention} has been unbanned. Reason: {$reason}
+warn-success={$userMention} has been warned, and now has {$count} warnings. Reason: {$reason}
+warn-direct-message=You have been warned in {$guildName}. Reason: {$reason}
+moderation-auditlog-reason={$reason} (moderator: {$moderatorName})
+moderation-no-reason=No reason provided.
+moderation-error-target-moderator=You cannot perform moderation actions on yourself.
+moderation-error-target-bot=I cannot perform moderation actions on myself.
+moderation-error-moderator-hierarchy=You cannot perform moderation actions on {$userMention}, as their highest role is not lower than yours.
+moderation-error-bot-hierarchy=I cannot perform moderation actions on {$userMention}, as their highest role is not lower than mine.
+moderation-error-not-member={$userMention} is not a member of this server.
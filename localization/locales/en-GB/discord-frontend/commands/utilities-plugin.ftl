@@ -23,6 +23,19 @@
 botinfo-embed-title=Runtime Information
 botinfo-embed-botstarted-field-name=Bot Started
 botinfo-embed-latency-field-name=Latency (Milliseconds)
+channelinfo-embed-description=Information of {$channelMention}
+channelinfo-embed-generalinfo-field-name=General Information
+channelinfo-embed-generalinfo-id-subfield-name=ID:
+channelinfo-embed-generalinfo-created-subfield-name=Created:
+channelinfo-embed-generalinfo-type-subfield-name=Type:
+channelinfo-embed-generalinfo-category-subfield-name=Category:
+channelinfo-embed-generalinfo-parent-subfield-name=Parent Channel:
+channelinfo-embed-attributes-field-name=Attributes
+channelinfo-embed-attributes-nsfw-subfield-name=Age-Restricted:
+channelinfo-embed-attributes-position-subfield-name=Position:
+channelinfo-embed-attributes-slowmode-subfield-name=Slowmode (Seconds):
+channelinfo-embed-attributes-permission-overwrites-subfield-name=Permission Overwrites:
+channelinfo-embed-topic-field-name=Topic
 emojiinfo-embed-generalinfo-field-name=General Information
 emojiinfo-embed-generalinfo-guild-id-subfield-name=Guild ID:
 emojiinfo-embed-generalinfo-id-subfield-name=ID:
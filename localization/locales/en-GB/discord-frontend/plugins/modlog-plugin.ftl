@@ -0,0 +1,39 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+messagedeleted-text=A message sent by {$authorMention} has been deleted in {$channelMention}. (ID: {$messageId})
+messagedeleted-embed-title=Message Deleted
+messagesdeleted-text={$count} messages have been deleted in {$channelMention}.
+messagesdeleted-embed-title=Messages Deleted
+messageupdated-text=A message sent by {$authorMention} has been edited in {$channelMention}. (ID: {$messageId})
+messageupdated-embed-title=Message Edited
+messagelog-embed-after-field-name=After
+messagelog-embed-attachments-field-name=Attachments
+messagelog-embed-author-field-name=Author
+messagelog-embed-before-field-name=Before
+messagelog-embed-channel-field-name=Channel
+messagelog-embed-content-field-name=Content
+messagelog-embed-count-field-name=Count
+messagelog-embed-footer=Message ID: {$messageId}
+messagelog-author-unknown=an unknown user
+messagelog-content-empty=This message has no text content.
+messagelog-content-unavailable=The content of this message is unavailable, as it was not cached.
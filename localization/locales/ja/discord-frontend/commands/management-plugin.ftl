@@ -0,0 +1,36 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+ban-success={$userMention} をバンしました。理由：{$reason}
+kick-success={$userMention} をキックしました。理由：{$reason}
+purge-success={$count} 件のメッセージを削除しました。
+timeout-success={$userMention} を {$until} までタイムアウトしました。理由：{$reason}
+unban-success={$userMention} のバンを解除しました。理由：{$reason}
+warn-success={$userMention} に警告しました。警告数：{$count}。理由：{$reason}
+warn-direct-message={$guildName} で警告されました。理由：{$reason}
+moderation-auditlog-reason={$reason}（モデレーター：{$moderatorName}）
+moderation-no-reason=理由は指定されていません。
+moderation-error-target-moderator=自分自身にモデレーション操作を行うことはできません。
+moderation-error-target-bot=私自身にモデレーション操作を行うことはできません。
+moderation-error-moderator-hierarchy={$userMention} の最上位ロールがあなたより低くないため、モデレーション操作を行うことはできません。
+moderation-error-bot-hierarchy={$userMention} の最上位ロールが私より低くないため、モデレーション操作を行うことはできません。
+moderation-error-not-member={$userMention} はこのサーバーのメンバーではありません。
@@ -23,6 +23,19 @@
 botinfo-embed-title=運行情報
 botinfo-embed-botstarted-field-name=起動済み
 botinfo-embed-latency-field-name=遅延（ms）
+channelinfo-embed-description={$channelMention}の情報
+channelinfo-embed-generalinfo-field-name=一般情報
+channelinfo-embed-generalinfo-id-subfield-name=ID：
+channelinfo-embed-generalinfo-created-subfield-name=作った時：
+channelinfo-embed-generalinfo-type-subfield-name=種類：
+channelinfo-embed-generalinfo-category-subfield-name=カテゴリー：
+channelinfo-embed-generalinfo-parent-subfield-name=親チャンネル：
+channelinfo-embed-attributes-field-name=属性
+channelinfo-embed-attributes-nsfw-subfield-name=年齢制限：
+channelinfo-embed-attributes-position-subfield-name=位置：
+channelinfo-embed-attributes-slowmode-subfield-name=低速モード（秒）：
+channelinfo-embed-attributes-permission-overwrites-subfield-name=権限の上書き：
+channelinfo-embed-topic-field-name=トピック
 emojiinfo-embed-generalinfo-field-name=一般情報
 emojiinfo-embed-generalinfo-guild-id-subfield-name=鯖ID：
 emojiinfo-embed-generalinfo-id-subfield-name=ID：
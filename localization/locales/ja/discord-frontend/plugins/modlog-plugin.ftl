@@ -0,0 +1,39 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+messagedeleted-text={$channelMention} で {$authorMention} が送信したメッセージが削除されました。(ID: {$messageId})
+messagedeleted-embed-title=メッセージ削除
+messagesdeleted-text={$channelMention} で {$count} 件のメッセージが削除されました。
+messagesdeleted-embed-title=メッセージ一括削除
+messageupdated-text={$channelMention} で {$authorMention} が送信したメッセージが編集されました。(ID: {$messageId})
+messageupdated-embed-title=メッセージ編集
+messagelog-embed-after-field-name=編集後
+messagelog-embed-attachments-field-name=添付ファイル
+messagelog-embed-author-field-name=送信者
+messagelog-embed-before-field-name=編集前
+messagelog-embed-channel-field-name=チャンネル
+messagelog-embed-content-field-name=内容
+messagelog-embed-count-field-name=件数
+messagelog-embed-footer=メッセージ ID: {$messageId}
+messagelog-author-unknown=不明なユーザー
+messagelog-content-empty=このメッセージにはテキストがありません。
+messagelog-content-unavailable=このメッセージはキャッシュされていないため、内容を表示できません。
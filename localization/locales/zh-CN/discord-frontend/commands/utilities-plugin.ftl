@@ -23,6 +23,19 @@
 botinfo-embed-title=运行时信息
 botinfo-embed-botstarted-field-name=机器人已启动
 botinfo-embed-latency-field-name=延迟（毫秒）
+channelinfo-embed-description={$channelMention} 的信息
+channelinfo-embed-generalinfo-field-name=一般信息
+channelinfo-embed-generalinfo-id-subfield-name=ID：
+channelinfo-embed-generalinfo-created-subfield-name=创建：
+channelinfo-embed-generalinfo-type-subfield-name=类型：
+channelinfo-embed-generalinfo-category-subfield-name=类别：
+channelinfo-embed-generalinfo-parent-subfield-name=父频道：
+channelinfo-embed-attributes-field-name=属性
+channelinfo-embed-attributes-nsfw-subfield-name=年龄限制：
+channelinfo-embed-attributes-position-subfield-name=位置：
+channelinfo-embed-attributes-slowmode-subfield-name=慢速模式（秒）：
+channelinfo-embed-attributes-permission-overwrites-subfield-name=权限覆盖：
+channelinfo-embed-topic-field-name=主题
 emojiinfo-embed-generalinfo-field-name=常规信息
 emojiinfo-embed-generalinfo-guild-id-subfield-name=服务器ID：
 emojiinfo-embed-generalinfo-id-subfield-name=ID：
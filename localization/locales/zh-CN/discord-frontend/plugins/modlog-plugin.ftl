@@ -0,0 +1,39 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+messagedeleted-text={$authorMention} 在 {$channelMention} 发送的消息已被删除。(ID: {$messageId})
+messagedeleted-embed-title=消息已删除
+messagesdeleted-text={$channelMention} 中的 {$count} 条消息已被删除。
+messagesdeleted-embed-title=消息已批量删除
+messageupdated-text={$authorMention} 在 {$channelMention} 发送的消息已被编辑。(ID: {$messageId})
+messageupdated-embed-title=消息已编辑
+messagelog-embed-after-field-name=编辑后
+messagelog-embed-attachments-field-name=附件
+messagelog-embed-author-field-name=发送者
+messagelog-embed-before-field-name=编辑前
+messagelog-embed-channel-field-name=频道
+messagelog-embed-content-field-name=内容
+messagelog-embed-count-field-name=数量
+messagelog-embed-footer=消息 ID: {$messageId}
+messagelog-author-unknown=未知用户
+messagelog-content-empty=此消息没有文本内容。
+messagelog-content-unavailable=此消息未被缓存，因此无法显示其内容。
@@ -0,0 +1,36 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+ban-success={$userMention} 已被封鎖。原因：{$reason}
+kick-success={$userMention} 已被踢出。原因：{$reason}
+purge-success=已刪除 {$count} 則訊息。
+timeout-success={$userMention} 已被禁言至 {$until}。原因：{$reason}
+unban-success={$userMention} 已被解除封鎖。原因：{$reason}
+warn-success={$userMention} 已被警告，現共有 {$count} 次警告。原因：{$reason}
+warn-direct-message=你在 {$guildName} 被警告。原因：{$reason}
+moderation-auditlog-reason={$reason}（管理員：{$moderatorName}）
+moderation-no-reason=未提供原因。
+moderation-error-target-moderator=你不能對自己執行管理操作。
+moderation-error-target-bot=我不能對自己執行管理操作。
+moderation-error-moderator-hierarchy={$userMention} 的最高身分組不低於你的，因此你不能對其執行管理操作。
+moderation-error-bot-hierarchy={$userMention} 的最高身分組不低於我的，因此我不能對其執行管理操作。
+moderation-error-not-member={$userMention} 不是此伺服器的成員。
@@ -23,6 +23,19 @@
 botinfo-embed-title=運行資訊
 botinfo-embed-botstarted-field-name=機器人已啟動
 botinfo-embed-latency-field-name=延遲（毫秒）
+channelinfo-embed-description={$channelMention} 的資訊
+channelinfo-embed-generalinfo-field-name=一般資訊
+channelinfo-embed-generalinfo-id-subfield-name=ID：
+channelinfo-embed-generalinfo-created-subfield-name=創建於：
+channelinfo-embed-generalinfo-type-subfield-name=類型：
+channelinfo-embed-generalinfo-category-subfield-name=類別：
+channelinfo-embed-generalinfo-parent-subfield-name=父頻道：
+channelinfo-embed-attributes-field-name=屬性
+channelinfo-embed-attributes-nsfw-subfield-name=年齡限制：
+channelinfo-embed-attributes-position-subfield-name=位置：
+channelinfo-embed-attributes-slowmode-subfield-name=慢速模式（秒）：
+channelinfo-embed-attributes-permission-overwrites-subfield-name=權限覆寫：
+channelinfo-embed-topic-field-name=主題
 emojiinfo-embed-generalinfo-field-name=一般資訊
 emojiinfo-embed-generalinfo-guild-id-subfield-name=伺服器 ID：
 emojiinfo-embed-generalinfo-id-subfield-name=ID：
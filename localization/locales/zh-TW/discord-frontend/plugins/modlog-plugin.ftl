@@ -0,0 +1,39 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+messagedeleted-text={$authorMention} 在 {$channelMention} 傳送的訊息已被刪除。(ID: {$messageId})
+messagedeleted-embed-title=訊息已刪除
+messagesdeleted-text={$channelMention} 中的 {$count} 則訊息已被刪除。
+messagesdeleted-embed-title=訊息已批量刪除
+messageupdated-text={$authorMention} 在 {$channelMention} 傳送的訊息已被編輯。(ID: {$messageId})
+messageupdated-embed-title=訊息已編輯
+messagelog-embed-after-field-name=編輯後
+messagelog-embed-attachments-field-name=附件
+messagelog-embed-author-field-name=傳送者
+messagelog-embed-before-field-name=編輯前
+messagelog-embed-channel-field-name=頻道
+messagelog-embed-content-field-name=內容
+messagelog-embed-count-field-name=數量
+messagelog-embed-footer=訊息 ID: {$messageId}
+messagelog-author-unknown=未知使用者
+messagelog-content-empty=此訊息沒有文字內容。
+messagelog-content-unavailable=此訊息未被快取，因此無法顯示其內容。
@@ -24,6 +24,7 @@
 
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
 use hartex_discord_core::discord::model::id::marker::RoleMarker;
 use hartex_discord_core::discord::model::id::marker::UserMarker;
 use hartex_discord_core::discord::model::id::Id;
@@ -50,6 +51,12 @@ pub trait CommandDataOptionsExt {
     /// Returns the value of a boolean option from a collection of options.
     fn boolean_value_of(&self, name: &str) -> bool;
 
+    /// Returns the value of a channel option from a collection of options.
+    fn channel_value_of(&self, name: &str) -> Id<ChannelMarker>;
+
+    /// Returns the value of an integer option from a collection of options.
+    fn integer_value_of(&self, name: &str) -> i64;
+
     /// Returns the value of a role option from a collection of options.
     fn role_value_of(&self, name: &str) -> Id<RoleMarker>;
 
@@ -75,6 +82,33 @@ impl CommandDataOptionsExt for Vec<CommandDataOption> {
         boolean
     }
 
+    fn channel_value_of(&self, name: &str) -> Id<ChannelMarker> {
+        let CommandOptionValue::Channel(channel_id) = self
+            .iter()
+            .find(|option| option.name.as_str() == name)
+            .map(|option| option.value.clone())
+            .unwrap()
+        else {
+            unreachable!();
+        };
+
+        channel_id
+    }
+
+    fn integer_value_of(&self, name: &str) -> i64 {
+        let CommandOptionValue::Integer(integer) = self
+            .iter()
+            .find(|option| option.name.as_str() == name)
+            .map_or(CommandOptionValue::Integer(0), |option| {
+                option.value.clone()
+            })
+        else {
+            unreachable!()
+        };
+
+        integer
+    }
+
     fn role_value_of(&self, name: &str) -> Id<RoleMarker> {
         let CommandOptionValue::Role(role_id) = self
             .iter()
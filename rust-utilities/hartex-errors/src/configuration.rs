@@ -0,0 +1,69 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Configuration Errors
+
+use miette::Diagnostic;
+use miette::SourceSpan;
+use thiserror::Error;
+
+/// Configuration evaluation error
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(
+    code("E0002: configuration::evaluation_error"),
+    help("consider fixing the errors in the configuration")
+)]
+#[error("failed to evaluate configuration: {message}")]
+pub struct EvaluationError {
+    #[source_code]
+    pub src: String,
+    #[label("error occurs here")]
+    pub err_span: Option<SourceSpan>,
+    pub message: String,
+}
+
+/// Configuration validation error
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(
+    code("E0003: configuration::validation_error"),
+    help("consider fixing the invalid values in the configuration")
+)]
+#[error("configuration contains {} invalid value(s)", .errors.len())]
+pub struct ValidationError {
+    #[related]
+    pub errors: Vec<InvalidValueError>,
+}
+
+/// Invalid configuration value error
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code("E0004: configuration::invalid_value"))]
+#[error("invalid value at `{path}`: {message}")]
+pub struct InvalidValueError {
+    #[source_code]
+    pub src: String,
+    #[label("invalid value")]
+    pub err_span: Option<SourceSpan>,
+    #[help]
+    pub help: Option<String>,
+    pub path: String,
+    pub message: String,
+}
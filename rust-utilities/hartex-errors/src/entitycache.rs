@@ -0,0 +1,58 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Entity Cache Errors
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// Unsupported entity cache backend error
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(
+    code("E0005: entitycache::unsupported_backend"),
+    help("consider setting `ENTITYCACHE_BACKEND` to one of `postgres`, `redis` or `memory`")
+)]
+#[error("unsupported entity cache backend `{backend}`")]
+pub struct UnsupportedBackendError {
+    pub backend: String,
+}
+
+/// Missing Redis URL error
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(
+    code("E0006: entitycache::missing_redis_url"),
+    help("consider setting `ENTITYCACHE_REDIS_URL` to the URL of the Redis-compatible server")
+)]
+#[error("the `redis` entity cache backend requires `ENTITYCACHE_REDIS_URL` to be set")]
+pub struct MissingRedisUrlError;
+
+/// Invalid Redis URL error
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(
+    code("E0007: entitycache::invalid_redis_url"),
+    help("consider fixing the URL in `ENTITYCACHE_REDIS_URL`")
+)]
+#[error("invalid entity cache Redis URL `{url}`: {message}")]
+pub struct InvalidRedisUrlError {
+    pub url: String,
+    pub message: String,
+}
@@ -26,4 +26,6 @@
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+pub mod configuration;
 pub mod dotenv;
+pub mod entitycache;
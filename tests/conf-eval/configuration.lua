@@ -25,6 +25,9 @@ with HarTex. If not, see <https://www.gnu.org/licenses/>.
 -- This sample may change as the data structures and manifest evolves.
 
 return {
+    -- The version of the configuration schema this configuration is written for.
+    version = 10,
+
     -- Dashboard access configurations.
     dashboard = {
         -- Admins of the server, they can add people to the configuration editor.